@@ -0,0 +1,56 @@
+//! Generation time for large specs
+//!
+//! ```bash
+//! cargo bench --bench large_spec
+//! ```
+
+mod support;
+
+use agenterra::Generator;
+use agenterra::infrastructure::openapi::parser::OpenApiParser;
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use tokio::runtime::Runtime;
+
+/// Operation counts to measure; each resource contributes five operations
+const OPERATIONS: &[usize] = &[500, 3_000];
+
+fn bench_parse(c: &mut Criterion) {
+    let runtime = Runtime::new().unwrap();
+    let mut group = c.benchmark_group("parse");
+    group.sample_size(10);
+
+    for &operations in OPERATIONS {
+        let spec = support::large_spec(operations / 5);
+        group.bench_with_input(BenchmarkId::from_parameter(operations), &spec, |b, spec| {
+            b.iter(|| {
+                runtime
+                    .block_on(OpenApiParser::new(spec.clone()).parse())
+                    .unwrap()
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_generate(c: &mut Criterion) {
+    let runtime = Runtime::new().unwrap();
+    let mut group = c.benchmark_group("generate_mcp_server_rust");
+    group.sample_size(10);
+
+    for &operations in OPERATIONS {
+        let generator = Generator::builder()
+            .project_name("large_api")
+            .spec_document(support::large_spec(operations / 5))
+            .build()
+            .unwrap();
+        group.bench_with_input(
+            BenchmarkId::from_parameter(operations),
+            &generator,
+            |b, generator| b.iter(|| runtime.block_on(generator.generate()).unwrap()),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse, bench_generate);
+criterion_main!(benches);
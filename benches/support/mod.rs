@@ -0,0 +1,107 @@
+//! Synthetic spec fixture for benchmarks
+
+use serde_json::{Map, Value, json};
+
+/// Build an OpenAPI 3 spec with `resources` CRUD resources, five operations each
+///
+/// Every resource schema references the same shared components, the pattern that
+/// makes `$ref` resolution dominate parsing on large real-world specs.
+pub fn large_spec(resources: usize) -> Value {
+    let mut paths = Map::new();
+    let mut schemas = Map::new();
+
+    schemas.insert(
+        "Metadata".to_string(),
+        json!({
+            "type": "object",
+            "properties": {
+                "created_at": { "type": "string", "format": "date-time" },
+                "updated_at": { "type": "string", "format": "date-time" },
+                "owner": { "$ref": "#/components/schemas/Owner" },
+                "labels": { "type": "array", "items": { "type": "string" } }
+            }
+        }),
+    );
+    schemas.insert(
+        "Owner".to_string(),
+        json!({
+            "type": "object",
+            "properties": {
+                "id": { "type": "integer", "format": "int64" },
+                "name": { "type": "string" },
+                "email": { "type": "string" }
+            }
+        }),
+    );
+
+    for index in 0..resources {
+        let name = format!("Resource{index}");
+        let schema_ref = json!({ "$ref": format!("#/components/schemas/{name}") });
+        let list = json!({ "type": "array", "items": schema_ref });
+        let id_param = json!({
+            "name": "id",
+            "in": "path",
+            "required": true,
+            "schema": { "type": "integer", "format": "int64" }
+        });
+
+        schemas.insert(
+            name.clone(),
+            json!({
+                "type": "object",
+                "required": ["id", "name"],
+                "properties": {
+                    "id": { "type": "integer", "format": "int64" },
+                    "name": { "type": "string", "description": format!("Name of the {name}") },
+                    "status": { "type": "string", "enum": ["active", "archived"] },
+                    "metadata": { "$ref": "#/components/schemas/Metadata" }
+                }
+            }),
+        );
+
+        paths.insert(
+            format!("/resources{index}"),
+            json!({
+                "get": operation(&format!("list{name}"), None, &list, &[]),
+                "post": operation(&format!("create{name}"), Some(&schema_ref), &schema_ref, &[])
+            }),
+        );
+        paths.insert(
+            format!("/resources{index}/{{id}}"),
+            json!({
+                "get": operation(&format!("get{name}"), None, &schema_ref, &[&id_param]),
+                "put": operation(&format!("update{name}"), Some(&schema_ref), &schema_ref, &[&id_param]),
+                "delete": operation(&format!("delete{name}"), None, &json!({ "type": "boolean" }), &[&id_param])
+            }),
+        );
+    }
+
+    json!({
+        "openapi": "3.0.3",
+        "info": { "title": "Large API", "version": "1.0.0" },
+        "servers": [{ "url": "https://api.example.com" }],
+        "paths": paths,
+        "components": { "schemas": schemas }
+    })
+}
+
+fn operation(id: &str, body: Option<&Value>, response: &Value, parameters: &[&Value]) -> Value {
+    let mut operation = json!({
+        "operationId": id,
+        "summary": format!("Operation {id}"),
+        "parameters": parameters,
+        "responses": {
+            "200": {
+                "description": "ok",
+                "content": { "application/json": { "schema": response } }
+            }
+        }
+    });
+    if let Some(body) = body {
+        operation["requestBody"] = json!({
+            "required": true,
+            "content": { "application/json": { "schema": body } }
+        });
+    }
+    operation
+}
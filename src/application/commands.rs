@@ -53,6 +53,9 @@ pub struct GenerateServerResponse {
     pub artifacts_count: usize,
     pub output_path: PathBuf,
     pub metadata: crate::generation::GenerationMetadata,
+    /// Generated files, relative to `output_path`
+    pub artifacts: Vec<PathBuf>,
+    pub diagnostics: Vec<crate::generation::Diagnostic>,
 }
 
 /// Request to generate a client implementation
@@ -93,6 +96,155 @@ pub struct GenerateClientResponse {
     pub artifacts_count: usize,
     pub output_path: PathBuf,
     pub metadata: crate::generation::GenerationMetadata,
+    /// Generated files, relative to `output_path`
+    pub artifacts: Vec<PathBuf>,
+    pub diagnostics: Vec<crate::generation::Diagnostic>,
+}
+
+/// Request to generate an agent implementation
+#[derive(Debug, Clone, Deserialize)]
+pub struct GenerateAgentRequest {
+    pub protocol: Protocol,
+    pub language: Language,
+    pub project_name: String,
+    pub output_dir: PathBuf,
+    pub options: HashMap<String, serde_json::Value>,
+}
+
+impl GenerateAgentRequest {
+    pub fn validate(&self) -> Result<(), crate::application::ValidationError> {
+        if self.project_name.is_empty() {
+            return Err(crate::application::ValidationError::EmptyProjectName);
+        }
+
+        // Validate protocol supports agent role
+        self.protocol
+            .validate_role(&crate::protocols::Role::Agent)
+            .map_err(|_| crate::application::ValidationError::UnsupportedRole {
+                protocol: self.protocol,
+                role: crate::protocols::Role::Agent,
+            })?;
+
+        // Validate project name
+        crate::generation::rules::validate_project_name(&self.project_name)
+            .map_err(|e| crate::application::ValidationError::InvalidProjectName(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+/// Response from agent generation
+#[derive(Debug, Clone, Serialize)]
+pub struct GenerateAgentResponse {
+    pub artifacts_count: usize,
+    pub output_path: PathBuf,
+    pub metadata: crate::generation::GenerationMetadata,
+    /// Generated files, relative to `output_path`
+    pub artifacts: Vec<PathBuf>,
+    pub diagnostics: Vec<crate::generation::Diagnostic>,
+}
+
+/// Request to generate a bridge exposing an MCP server through another protocol
+#[derive(Debug, Clone, Deserialize)]
+pub struct GenerateBridgeRequest {
+    /// Protocol the bridge speaks to its callers
+    pub protocol: Protocol,
+    pub language: Language,
+    pub project_name: String,
+    /// MCP server whose tools are bridged, and which the bridge connects to
+    pub mcp_server: Option<crate::generation::McpServerSource>,
+    /// OpenAPI spec to derive the tools from, as the MCP server generated
+    /// from it would list them, instead of asking `mcp_server`
+    pub schema_path: Option<String>,
+    pub output_dir: PathBuf,
+    pub options: HashMap<String, serde_json::Value>,
+}
+
+impl GenerateBridgeRequest {
+    pub fn validate(&self) -> Result<(), crate::application::ValidationError> {
+        if self.project_name.is_empty() {
+            return Err(crate::application::ValidationError::EmptyProjectName);
+        }
+
+        // Validate protocol supports bridge role
+        self.protocol
+            .validate_role(&crate::protocols::Role::Bridge)
+            .map_err(|_| crate::application::ValidationError::UnsupportedRole {
+                protocol: self.protocol,
+                role: crate::protocols::Role::Bridge,
+            })?;
+
+        if self.mcp_server.is_none() && self.schema_path.is_none() {
+            return Err(crate::application::ValidationError::MissingField(
+                "Bridge requires an MCP server or an OpenAPI schema path".to_string(),
+            ));
+        }
+
+        // Validate project name
+        crate::generation::rules::validate_project_name(&self.project_name)
+            .map_err(|e| crate::application::ValidationError::InvalidProjectName(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+/// Response from bridge generation
+#[derive(Debug, Clone, Serialize)]
+pub struct GenerateBridgeResponse {
+    pub artifacts_count: usize,
+    pub output_path: PathBuf,
+    pub metadata: crate::generation::GenerationMetadata,
+    /// Generated files, relative to `output_path`
+    pub artifacts: Vec<PathBuf>,
+    /// Number of MCP tools exposed as skills
+    pub tools_count: usize,
+    pub diagnostics: Vec<crate::generation::Diagnostic>,
+}
+
+/// Request to generate a project for a role defined by its template, such as
+/// an MCP `reviewer`
+#[derive(Debug, Clone, Deserialize)]
+pub struct GenerateCustomRequest {
+    pub protocol: Protocol,
+    pub role: crate::protocols::Role,
+    pub language: Language,
+    pub project_name: String,
+    /// OpenAPI spec passed to the template as `openapi_spec`, if any
+    pub schema_path: Option<String>,
+    pub output_dir: PathBuf,
+    pub options: HashMap<String, serde_json::Value>,
+}
+
+impl GenerateCustomRequest {
+    pub fn validate(&self) -> Result<(), crate::application::ValidationError> {
+        if self.project_name.is_empty() {
+            return Err(crate::application::ValidationError::EmptyProjectName);
+        }
+
+        self.protocol.validate_role(&self.role).map_err(|_| {
+            crate::application::ValidationError::UnsupportedRole {
+                protocol: self.protocol,
+                role: self.role.clone(),
+            }
+        })?;
+
+        // Validate project name
+        crate::generation::rules::validate_project_name(&self.project_name)
+            .map_err(|e| crate::application::ValidationError::InvalidProjectName(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+/// Response from custom role generation
+#[derive(Debug, Clone, Serialize)]
+pub struct GenerateCustomResponse {
+    pub artifacts_count: usize,
+    pub output_path: PathBuf,
+    pub metadata: crate::generation::GenerationMetadata,
+    /// Generated files, relative to `output_path`
+    pub artifacts: Vec<PathBuf>,
+    pub diagnostics: Vec<crate::generation::Diagnostic>,
 }
 
 #[cfg(test)]
@@ -149,4 +301,94 @@ mod tests {
             crate::application::ValidationError::EmptyProjectName
         ));
     }
+
+    #[test]
+    fn test_generate_agent_request_validation() {
+        let valid = GenerateAgentRequest {
+            protocol: Protocol::A2a,
+            language: Language::Rust,
+            project_name: "test-agent".to_string(),
+            output_dir: PathBuf::from("/output"),
+            options: HashMap::new(),
+        };
+
+        assert!(valid.validate().is_ok());
+
+        // MCP has no agent role
+        let mut invalid = valid.clone();
+        invalid.protocol = Protocol::Mcp;
+        assert!(matches!(
+            invalid.validate().unwrap_err(),
+            crate::application::ValidationError::UnsupportedRole { .. }
+        ));
+    }
+
+    #[test]
+    fn test_generate_bridge_request_validation() {
+        let valid = GenerateBridgeRequest {
+            protocol: Protocol::A2a,
+            language: Language::Rust,
+            project_name: "test-bridge".to_string(),
+            mcp_server: Some(crate::generation::McpServerSource::Sse {
+                url: "http://localhost:8080/sse".to_string(),
+            }),
+            schema_path: None,
+            output_dir: PathBuf::from("/output"),
+            options: HashMap::new(),
+        };
+
+        assert!(valid.validate().is_ok());
+
+        // An OpenAPI spec can stand in for the server
+        let mut from_spec = valid.clone();
+        from_spec.mcp_server = None;
+        from_spec.schema_path = Some("/path/to/openapi.yaml".to_string());
+        assert!(from_spec.validate().is_ok());
+
+        // The tools have to come from somewhere
+        let mut invalid = from_spec.clone();
+        invalid.schema_path = None;
+        assert!(matches!(
+            invalid.validate().unwrap_err(),
+            crate::application::ValidationError::MissingField(_)
+        ));
+
+        // ACP has no bridge role
+        let mut invalid = valid.clone();
+        invalid.protocol = Protocol::Acp;
+        assert!(matches!(
+            invalid.validate().unwrap_err(),
+            crate::application::ValidationError::UnsupportedRole { .. }
+        ));
+    }
+
+    #[test]
+    fn test_generate_custom_request_validation() {
+        let valid = GenerateCustomRequest {
+            protocol: Protocol::Mcp,
+            role: crate::protocols::Role::Custom("reviewer".to_string()),
+            language: Language::Rust,
+            project_name: "test-reviewer".to_string(),
+            schema_path: None,
+            output_dir: PathBuf::from("/output"),
+            options: HashMap::new(),
+        };
+
+        assert!(valid.validate().is_ok());
+
+        // Built-in roles still have to be supported by the protocol
+        let mut invalid = valid.clone();
+        invalid.role = crate::protocols::Role::Broker;
+        assert!(matches!(
+            invalid.validate().unwrap_err(),
+            crate::application::ValidationError::UnsupportedRole { .. }
+        ));
+
+        let mut invalid = valid.clone();
+        invalid.project_name = "".to_string();
+        assert!(matches!(
+            invalid.validate().unwrap_err(),
+            crate::application::ValidationError::EmptyProjectName
+        ));
+    }
 }
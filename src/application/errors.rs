@@ -11,6 +11,13 @@ pub enum ApplicationError {
     #[error("Generation error: {0}")]
     GenerationError(#[from] crate::generation::GenerationError),
 
+    #[error("{stage} hook '{command}' failed: {reason}")]
+    HookFailed {
+        stage: crate::infrastructure::HookStage,
+        command: String,
+        reason: String,
+    },
+
     #[error("Invalid template: {0}")]
     InvalidTemplate(String),
 
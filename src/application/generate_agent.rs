@@ -0,0 +1,387 @@
+//! Use case for generating agent implementations
+
+use crate::application::{
+    ApplicationError, GenerateAgentRequest, GenerateAgentResponse, HookRunner, OutputService,
+};
+use crate::generation::GenerationOrchestrator;
+use crate::infrastructure::HookStage;
+use crate::protocols::{ProtocolConfig, ProtocolError, ProtocolInput, ProtocolRegistry, Role};
+use std::sync::Arc;
+
+/// Use case for generating agent implementations
+pub struct GenerateAgentUseCase {
+    protocol_registry: Arc<ProtocolRegistry>,
+    generation_orchestrator: Arc<GenerationOrchestrator>,
+    output_service: Arc<dyn OutputService>,
+    hook_runner: Option<Arc<dyn HookRunner>>,
+}
+
+impl GenerateAgentUseCase {
+    pub fn new(
+        protocol_registry: Arc<ProtocolRegistry>,
+        generation_orchestrator: Arc<GenerationOrchestrator>,
+        output_service: Arc<dyn OutputService>,
+    ) -> Self {
+        Self {
+            protocol_registry,
+            generation_orchestrator,
+            output_service,
+            hook_runner: None,
+        }
+    }
+
+    /// Run the template's hooks in the output directory around writing the files
+    pub fn with_hook_runner(mut self, hook_runner: Arc<dyn HookRunner>) -> Self {
+        self.hook_runner = Some(hook_runner);
+        self
+    }
+
+    pub async fn execute(
+        &self,
+        request: GenerateAgentRequest,
+    ) -> Result<GenerateAgentResponse, ApplicationError> {
+        // 1. Validate request
+        request.validate()?;
+
+        // 2. Get protocol handler
+        let handler =
+            self.protocol_registry
+                .get(request.protocol)
+                .ok_or(ApplicationError::ProtocolError(
+                    ProtocolError::NotImplemented(request.protocol),
+                ))?;
+
+        // 3. Prepare protocol input (agents are described by their options, not OpenAPI)
+        let input = ProtocolInput {
+            role: Role::Agent,
+            language: request.language,
+            config: ProtocolConfig {
+                project_name: request.project_name.clone(),
+                version: None,
+                options: request.options.clone(),
+            },
+            openapi_spec: None,
+            mcp_server: None,
+            mcp_tools: None,
+        };
+
+        // 4. Build generation context
+        let context = handler.prepare_context(input).await?;
+
+        // 5. Generate code
+        let result = self.generation_orchestrator.generate(context).await?;
+
+        // 6. Ensure output directory and the template's required directories exist
+        self.output_service
+            .ensure_directory(&request.output_dir)
+            .await?;
+        for directory in &result.directories {
+            self.output_service
+                .ensure_directory(&request.output_dir.join(directory))
+                .await?;
+        }
+
+        // 7. Run pre-generation hooks before any file is written
+        let mut diagnostics = result.diagnostics;
+        if let Some(hook_runner) = &self.hook_runner {
+            diagnostics.extend(
+                hook_runner
+                    .run_hooks(
+                        HookStage::PreGenerate,
+                        &result.pre_generate_hooks,
+                        &request.output_dir,
+                    )
+                    .await?,
+            );
+        }
+
+        // 8. Prepend output directory to artifact paths and write
+        let artifacts = result.artifacts.iter().map(|a| a.path.clone()).collect();
+        let mut output_artifacts = result.artifacts;
+        for artifact in &mut output_artifacts {
+            artifact.path = request.output_dir.join(&artifact.path);
+        }
+
+        let artifacts_count = output_artifacts.len();
+
+        self.output_service
+            .write_artifacts(&output_artifacts)
+            .await?;
+
+        // 9. Run post-generation hooks against the written project
+        if let Some(hook_runner) = &self.hook_runner {
+            diagnostics.extend(
+                hook_runner
+                    .run_hooks(
+                        HookStage::PostGenerate,
+                        &result.post_generate_hooks,
+                        &request.output_dir,
+                    )
+                    .await?,
+            );
+        }
+
+        Ok(GenerateAgentResponse {
+            artifacts_count,
+            output_path: request.output_dir,
+            metadata: result.metadata,
+            artifacts,
+            diagnostics,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::{self, Language};
+    use crate::infrastructure;
+    use crate::protocols::{self, Protocol};
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    fn request(protocol: Protocol) -> GenerateAgentRequest {
+        GenerateAgentRequest {
+            protocol,
+            language: Language::Rust,
+            project_name: "test-agent".to_string(),
+            output_dir: PathBuf::from("/output"),
+            options: HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_success() {
+        let protocol_registry = Arc::new(create_mock_registry());
+        let template_discovery = Arc::new(MockTemplateDiscovery::new());
+        let output_service = Arc::new(MockOutputService::new());
+        let generation_orchestrator =
+            Arc::new(create_mock_orchestrator(template_discovery.clone()));
+
+        let use_case = GenerateAgentUseCase::new(
+            protocol_registry,
+            generation_orchestrator,
+            output_service.clone(),
+        );
+
+        let response = use_case.execute(request(Protocol::A2a)).await.unwrap();
+        assert_eq!(response.artifacts_count, 4);
+        assert_eq!(response.output_path, PathBuf::from("/output"));
+
+        let discovered = template_discovery.get_discovered_templates();
+        assert_eq!(
+            discovered,
+            vec![(Protocol::A2a, Role::Agent, Language::Rust)]
+        );
+
+        assert_eq!(
+            output_service.get_ensured_directories(),
+            vec![PathBuf::from("/output")]
+        );
+        for artifact in &output_service.get_written_artifacts() {
+            assert!(artifact.path.starts_with("/output"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_protocol_without_agent_role() {
+        let template_discovery = Arc::new(MockTemplateDiscovery::new());
+        let use_case = GenerateAgentUseCase::new(
+            Arc::new(create_mock_registry()),
+            Arc::new(create_mock_orchestrator(template_discovery)),
+            Arc::new(MockOutputService::new()),
+        );
+
+        let result = use_case.execute(request(Protocol::Mcp)).await;
+        assert!(matches!(result, Err(ApplicationError::ValidationError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_execute_unregistered_protocol() {
+        let template_discovery = Arc::new(MockTemplateDiscovery::new());
+        let use_case = GenerateAgentUseCase::new(
+            Arc::new(create_mock_registry()),
+            Arc::new(create_mock_orchestrator(template_discovery)),
+            Arc::new(MockOutputService::new()),
+        );
+
+        // ANP has an agent role but no handler in this registry
+        let result = use_case.execute(request(Protocol::Anp)).await;
+        assert!(matches!(
+            result,
+            Err(ApplicationError::ProtocolError(
+                ProtocolError::NotImplemented(Protocol::Anp)
+            ))
+        ));
+    }
+
+    // Helper functions
+    fn create_mock_registry() -> ProtocolRegistry {
+        let registry = ProtocolRegistry::new();
+        let _ = registry.register(
+            Protocol::A2a,
+            Arc::new(protocols::handlers::a2a::A2aProtocolHandler::new()),
+        );
+        registry
+    }
+
+    fn create_mock_orchestrator(
+        template_discovery: Arc<MockTemplateDiscovery>,
+    ) -> GenerationOrchestrator {
+        GenerationOrchestrator::new(
+            template_discovery,
+            Arc::new(MockContextBuilder),
+            Arc::new(MockTemplateRenderer),
+            Arc::new(MockPostProcessor),
+        )
+    }
+
+    // Mock implementations
+    struct MockOutputService {
+        written_artifacts: std::sync::Mutex<Vec<generation::Artifact>>,
+        ensured_directories: std::sync::Mutex<Vec<PathBuf>>,
+    }
+
+    impl MockOutputService {
+        fn new() -> Self {
+            Self {
+                written_artifacts: std::sync::Mutex::new(Vec::new()),
+                ensured_directories: std::sync::Mutex::new(Vec::new()),
+            }
+        }
+
+        fn get_written_artifacts(&self) -> Vec<generation::Artifact> {
+            self.written_artifacts.lock().unwrap().clone()
+        }
+
+        fn get_ensured_directories(&self) -> Vec<PathBuf> {
+            self.ensured_directories.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl OutputService for MockOutputService {
+        async fn write_artifacts(
+            &self,
+            artifacts: &[generation::Artifact],
+        ) -> Result<(), ApplicationError> {
+            self.written_artifacts
+                .lock()
+                .unwrap()
+                .extend_from_slice(artifacts);
+            Ok(())
+        }
+
+        async fn ensure_directory(&self, path: &std::path::Path) -> Result<(), ApplicationError> {
+            self.ensured_directories
+                .lock()
+                .unwrap()
+                .push(path.to_path_buf());
+            Ok(())
+        }
+    }
+
+    struct MockTemplateDiscovery {
+        discovered_templates:
+            std::sync::Mutex<Vec<(protocols::Protocol, protocols::Role, generation::Language)>>,
+    }
+
+    impl MockTemplateDiscovery {
+        fn new() -> Self {
+            Self {
+                discovered_templates: std::sync::Mutex::new(Vec::new()),
+            }
+        }
+
+        fn get_discovered_templates(
+            &self,
+        ) -> Vec<(protocols::Protocol, protocols::Role, generation::Language)> {
+            self.discovered_templates.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl generation::TemplateDiscovery for MockTemplateDiscovery {
+        async fn discover(
+            &self,
+            protocol: protocols::Protocol,
+            role: protocols::Role,
+            language: generation::Language,
+        ) -> Result<infrastructure::Template, generation::GenerationError> {
+            self.discovered_templates
+                .lock()
+                .unwrap()
+                .push((protocol, role.clone(), language));
+
+            // Return a template that matches the requested parameters
+            Ok(infrastructure::Template {
+                manifest: infrastructure::TemplateManifest {
+                    name: "test-template".to_string(),
+                    version: "1.0.0".to_string(),
+                    description: Some("Test template".to_string()),
+                    path: "test-template".to_string(),
+                    protocol,
+                    role,
+                    language,
+                    files: vec![],
+                    variables: HashMap::new(),
+                    variable_schema: vec![],
+                    pre_generate_hooks: vec![],
+                    post_generate_hooks: vec![],
+                    partials: vec![],
+                    required_directories: vec![],
+                    extends: None,
+                },
+                files: vec![],
+                source: infrastructure::TemplateSource::Embedded,
+            })
+        }
+    }
+
+    struct MockContextBuilder;
+
+    #[async_trait::async_trait]
+    impl generation::ContextBuilder for MockContextBuilder {
+        async fn build(
+            &self,
+            _context: &generation::GenerationContext,
+            _template: &infrastructure::Template,
+        ) -> Result<generation::RenderContext, generation::GenerationError> {
+            Ok(generation::RenderContext::default())
+        }
+    }
+
+    struct MockTemplateRenderer;
+
+    #[async_trait::async_trait]
+    impl generation::TemplateRenderingStrategy for MockTemplateRenderer {
+        async fn render(
+            &self,
+            _template: &infrastructure::Template,
+            _context: &generation::RenderContext,
+            _generation_context: &generation::GenerationContext,
+        ) -> Result<Vec<generation::Artifact>, generation::GenerationError> {
+            Ok(vec![
+                generation::Artifact {
+                    path: PathBuf::from("src/main.rs"),
+                    content: "fn main() {}".to_string(),
+                    permissions: None,
+                };
+                4
+            ])
+        }
+    }
+
+    struct MockPostProcessor;
+
+    #[async_trait::async_trait]
+    impl generation::PostProcessor for MockPostProcessor {
+        async fn process(
+            &self,
+            artifacts: Vec<generation::Artifact>,
+            _context: &generation::GenerationContext,
+        ) -> Result<Vec<generation::Artifact>, generation::GenerationError> {
+            Ok(artifacts)
+        }
+    }
+}
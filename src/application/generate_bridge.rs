@@ -0,0 +1,521 @@
+//! Use case for generating bridges that expose an MCP server through another protocol
+
+use crate::application::{
+    ApplicationError, GenerateBridgeRequest, GenerateBridgeResponse, HookRunner, OutputService,
+};
+use crate::generation::{GenerationOrchestrator, McpToolLoader, OpenApiLoader, ProtocolContext};
+use crate::infrastructure::HookStage;
+use crate::protocols::{ProtocolConfig, ProtocolError, ProtocolInput, ProtocolRegistry, Role};
+use std::sync::Arc;
+
+/// Use case for generating bridges
+pub struct GenerateBridgeUseCase {
+    protocol_registry: Arc<ProtocolRegistry>,
+    openapi_loader: Arc<dyn OpenApiLoader>,
+    mcp_tool_loader: Arc<dyn McpToolLoader>,
+    generation_orchestrator: Arc<GenerationOrchestrator>,
+    output_service: Arc<dyn OutputService>,
+    hook_runner: Option<Arc<dyn HookRunner>>,
+}
+
+impl GenerateBridgeUseCase {
+    pub fn new(
+        protocol_registry: Arc<ProtocolRegistry>,
+        openapi_loader: Arc<dyn OpenApiLoader>,
+        mcp_tool_loader: Arc<dyn McpToolLoader>,
+        generation_orchestrator: Arc<GenerationOrchestrator>,
+        output_service: Arc<dyn OutputService>,
+    ) -> Self {
+        Self {
+            protocol_registry,
+            openapi_loader,
+            mcp_tool_loader,
+            generation_orchestrator,
+            output_service,
+            hook_runner: None,
+        }
+    }
+
+    /// Run the template's hooks in the output directory around writing the files
+    pub fn with_hook_runner(mut self, hook_runner: Arc<dyn HookRunner>) -> Self {
+        self.hook_runner = Some(hook_runner);
+        self
+    }
+
+    pub async fn execute(
+        &self,
+        request: GenerateBridgeRequest,
+    ) -> Result<GenerateBridgeResponse, ApplicationError> {
+        // 1. Validate request
+        request.validate()?;
+
+        // 2. Get protocol handler
+        let handler =
+            self.protocol_registry
+                .get(request.protocol)
+                .ok_or(ApplicationError::ProtocolError(
+                    ProtocolError::NotImplemented(request.protocol),
+                ))?;
+
+        // 3. Take the tools from the OpenAPI spec when given, so generating
+        //    does not need the server running; otherwise ask the server
+        let (openapi_spec, mcp_tools) = match (&request.schema_path, &request.mcp_server) {
+            (Some(path), _) => {
+                tracing::debug!("GenerateBridgeUseCase: Loading OpenAPI from path: {}", path);
+                (Some(self.openapi_loader.load(path).await?), None)
+            }
+            (None, Some(server)) => {
+                tracing::debug!("GenerateBridgeUseCase: Listing the tools of {}", server);
+                (None, Some(self.mcp_tool_loader.list_tools(server).await?))
+            }
+            (None, None) => unreachable!("validated above"),
+        };
+
+        // 4. Prepare protocol input
+        let input = ProtocolInput {
+            role: Role::Bridge,
+            language: request.language,
+            config: ProtocolConfig {
+                project_name: request.project_name.clone(),
+                version: None,
+                options: request.options.clone(),
+            },
+            openapi_spec,
+            mcp_server: request.mcp_server.clone(),
+            mcp_tools,
+        };
+
+        // 5. Build generation context
+        let context = handler.prepare_context(input).await?;
+        let tools_count = match &context.protocol_context {
+            Some(ProtocolContext::McpBridge { tools, .. }) => tools.len(),
+            _ => 0,
+        };
+
+        // 6. Generate code
+        let result = self.generation_orchestrator.generate(context).await?;
+
+        // 7. Ensure output directory and the template's required directories exist
+        self.output_service
+            .ensure_directory(&request.output_dir)
+            .await?;
+        for directory in &result.directories {
+            self.output_service
+                .ensure_directory(&request.output_dir.join(directory))
+                .await?;
+        }
+
+        // 8. Run pre-generation hooks before any file is written
+        let mut diagnostics = result.diagnostics;
+        if let Some(hook_runner) = &self.hook_runner {
+            diagnostics.extend(
+                hook_runner
+                    .run_hooks(
+                        HookStage::PreGenerate,
+                        &result.pre_generate_hooks,
+                        &request.output_dir,
+                    )
+                    .await?,
+            );
+        }
+
+        // 9. Prepend output directory to artifact paths and write
+        let artifacts = result.artifacts.iter().map(|a| a.path.clone()).collect();
+        let mut output_artifacts = result.artifacts;
+        for artifact in &mut output_artifacts {
+            artifact.path = request.output_dir.join(&artifact.path);
+        }
+
+        let artifacts_count = output_artifacts.len();
+
+        self.output_service
+            .write_artifacts(&output_artifacts)
+            .await?;
+
+        // 10. Run post-generation hooks against the written project
+        if let Some(hook_runner) = &self.hook_runner {
+            diagnostics.extend(
+                hook_runner
+                    .run_hooks(
+                        HookStage::PostGenerate,
+                        &result.post_generate_hooks,
+                        &request.output_dir,
+                    )
+                    .await?,
+            );
+        }
+
+        Ok(GenerateBridgeResponse {
+            artifacts_count,
+            output_path: request.output_dir,
+            metadata: result.metadata,
+            artifacts,
+            tools_count,
+            diagnostics,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::{self, GenerationError, Language, McpServerSource, McpTool, Operation};
+    use crate::infrastructure;
+    use crate::protocols::{self, Protocol};
+    use serde_json::json;
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    fn request(
+        mcp_server: Option<McpServerSource>,
+        schema_path: Option<&str>,
+    ) -> GenerateBridgeRequest {
+        GenerateBridgeRequest {
+            protocol: Protocol::A2a,
+            language: Language::Rust,
+            project_name: "test-bridge".to_string(),
+            mcp_server,
+            schema_path: schema_path.map(str::to_string),
+            output_dir: PathBuf::from("/output"),
+            options: HashMap::new(),
+        }
+    }
+
+    fn stdio_server() -> McpServerSource {
+        McpServerSource::Stdio {
+            command: "weather-mcp".to_string(),
+            args: vec!["--stdio".to_string()],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_lists_tools_of_the_server() {
+        let tool_loader = Arc::new(MockToolLoader::default());
+        let context_builder = Arc::new(MockContextBuilder::default());
+        let output_service = Arc::new(MockOutputService::new());
+        let use_case = GenerateBridgeUseCase::new(
+            Arc::new(create_mock_registry()),
+            Arc::new(UnreachableOpenApiLoader),
+            tool_loader.clone(),
+            Arc::new(create_mock_orchestrator(context_builder.clone())),
+            output_service.clone(),
+        );
+
+        let response = use_case
+            .execute(request(Some(stdio_server()), None))
+            .await
+            .unwrap();
+        assert_eq!(response.tools_count, 1);
+        assert_eq!(response.artifacts_count, 3);
+        assert_eq!(response.output_path, PathBuf::from("/output"));
+        assert_eq!(*tool_loader.listed.lock().unwrap(), vec![stdio_server()]);
+
+        let (role, protocol_context) = context_builder.built.lock().unwrap().clone().unwrap();
+        assert_eq!(role, Role::Bridge);
+        match protocol_context {
+            Some(ProtocolContext::McpBridge { server, tools }) => {
+                assert_eq!(server, Some(stdio_server()));
+                assert_eq!(tools[0].name, "get_forecast");
+            }
+            other => panic!("Expected an MCP bridge context, got {other:?}"),
+        }
+        for artifact in &output_service.get_written_artifacts() {
+            assert!(artifact.path.starts_with("/output"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_prefers_openapi_spec_over_the_server() {
+        let tool_loader = Arc::new(MockToolLoader::default());
+        let context_builder = Arc::new(MockContextBuilder::default());
+        let use_case = GenerateBridgeUseCase::new(
+            Arc::new(create_mock_registry()),
+            Arc::new(MockOpenApiLoader),
+            tool_loader.clone(),
+            Arc::new(create_mock_orchestrator(context_builder.clone())),
+            Arc::new(MockOutputService::new()),
+        );
+
+        let response = use_case
+            .execute(request(Some(stdio_server()), Some("openapi.json")))
+            .await
+            .unwrap();
+        assert_eq!(response.tools_count, 1);
+        assert!(tool_loader.listed.lock().unwrap().is_empty());
+
+        // The server is still where the bridge connects at runtime
+        let (_, protocol_context) = context_builder.built.lock().unwrap().clone().unwrap();
+        match protocol_context {
+            Some(ProtocolContext::McpBridge { server, tools }) => {
+                assert_eq!(server, Some(stdio_server()));
+                assert_eq!(tools[0].name, "list_alerts");
+            }
+            other => panic!("Expected an MCP bridge context, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_reports_unreachable_servers() {
+        let use_case = GenerateBridgeUseCase::new(
+            Arc::new(create_mock_registry()),
+            Arc::new(UnreachableOpenApiLoader),
+            Arc::new(FailingToolLoader),
+            Arc::new(create_mock_orchestrator(Arc::new(
+                MockContextBuilder::default(),
+            ))),
+            Arc::new(MockOutputService::new()),
+        );
+
+        let err = use_case
+            .execute(request(Some(stdio_server()), None))
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("weather-mcp --stdio exited"),
+            "{err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_protocol_without_bridge_role() {
+        let use_case = GenerateBridgeUseCase::new(
+            Arc::new(create_mock_registry()),
+            Arc::new(MockOpenApiLoader),
+            Arc::new(MockToolLoader::default()),
+            Arc::new(create_mock_orchestrator(Arc::new(
+                MockContextBuilder::default(),
+            ))),
+            Arc::new(MockOutputService::new()),
+        );
+
+        let mut request = request(Some(stdio_server()), None);
+        request.protocol = Protocol::Anp;
+        let result = use_case.execute(request).await;
+        assert!(matches!(result, Err(ApplicationError::ValidationError(_))));
+    }
+
+    // Helper functions
+    fn create_mock_registry() -> ProtocolRegistry {
+        let registry = ProtocolRegistry::new();
+        let _ = registry.register(
+            Protocol::A2a,
+            Arc::new(protocols::handlers::a2a::A2aProtocolHandler::new()),
+        );
+        registry
+    }
+
+    fn create_mock_orchestrator(
+        context_builder: Arc<MockContextBuilder>,
+    ) -> GenerationOrchestrator {
+        GenerationOrchestrator::new(
+            Arc::new(MockTemplateDiscovery),
+            context_builder,
+            Arc::new(MockTemplateRenderer),
+            Arc::new(MockPostProcessor),
+        )
+    }
+
+    // Mock implementations
+    #[derive(Default)]
+    struct MockToolLoader {
+        listed: std::sync::Mutex<Vec<McpServerSource>>,
+    }
+
+    #[async_trait::async_trait]
+    impl McpToolLoader for MockToolLoader {
+        async fn list_tools(
+            &self,
+            server: &McpServerSource,
+        ) -> Result<Vec<McpTool>, GenerationError> {
+            self.listed.lock().unwrap().push(server.clone());
+            Ok(vec![McpTool {
+                name: "get_forecast".to_string(),
+                title: None,
+                description: Some("Weather forecast for a city".to_string()),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": { "city": { "type": "string" } },
+                    "required": ["city"]
+                }),
+            }])
+        }
+    }
+
+    struct FailingToolLoader;
+
+    #[async_trait::async_trait]
+    impl McpToolLoader for FailingToolLoader {
+        async fn list_tools(
+            &self,
+            server: &McpServerSource,
+        ) -> Result<Vec<McpTool>, GenerationError> {
+            Err(GenerationError::LoadError(format!(
+                "{server} exited before listing its tools"
+            )))
+        }
+    }
+
+    struct MockOpenApiLoader;
+
+    #[async_trait::async_trait]
+    impl OpenApiLoader for MockOpenApiLoader {
+        async fn load(&self, _source: &str) -> Result<generation::OpenApiContext, GenerationError> {
+            Ok(generation::OpenApiContext {
+                version: "3.0.0".to_string(),
+                info: generation::ApiInfo {
+                    title: "Weather API".to_string(),
+                    version: "1.0.0".to_string(),
+                    description: None,
+                },
+                servers: vec![],
+                operations: vec![
+                    serde_json::from_value::<Operation>(json!({
+                        "operationId": "listAlerts",
+                        "path": "/alerts",
+                        "method": "get",
+                        "summary": "Active weather alerts",
+                        "parameters": [],
+                        "request_body": null,
+                        "responses": []
+                    }))
+                    .unwrap(),
+                ],
+                components: None,
+                tags: vec![],
+                diagnostics: vec![],
+            })
+        }
+    }
+
+    struct UnreachableOpenApiLoader;
+
+    #[async_trait::async_trait]
+    impl OpenApiLoader for UnreachableOpenApiLoader {
+        async fn load(&self, source: &str) -> Result<generation::OpenApiContext, GenerationError> {
+            Err(GenerationError::LoadError(format!(
+                "unreachable spec {source}"
+            )))
+        }
+    }
+
+    struct MockOutputService {
+        written_artifacts: std::sync::Mutex<Vec<generation::Artifact>>,
+    }
+
+    impl MockOutputService {
+        fn new() -> Self {
+            Self {
+                written_artifacts: std::sync::Mutex::new(Vec::new()),
+            }
+        }
+
+        fn get_written_artifacts(&self) -> Vec<generation::Artifact> {
+            self.written_artifacts.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl OutputService for MockOutputService {
+        async fn write_artifacts(
+            &self,
+            artifacts: &[generation::Artifact],
+        ) -> Result<(), ApplicationError> {
+            self.written_artifacts
+                .lock()
+                .unwrap()
+                .extend_from_slice(artifacts);
+            Ok(())
+        }
+
+        async fn ensure_directory(&self, _path: &std::path::Path) -> Result<(), ApplicationError> {
+            Ok(())
+        }
+    }
+
+    struct MockTemplateDiscovery;
+
+    #[async_trait::async_trait]
+    impl generation::TemplateDiscovery for MockTemplateDiscovery {
+        async fn discover(
+            &self,
+            protocol: Protocol,
+            role: Role,
+            language: Language,
+        ) -> Result<infrastructure::Template, GenerationError> {
+            Ok(infrastructure::Template {
+                manifest: infrastructure::TemplateManifest {
+                    name: "test-template".to_string(),
+                    version: "1.0.0".to_string(),
+                    description: Some("Test template".to_string()),
+                    path: "test-template".to_string(),
+                    protocol,
+                    role,
+                    language,
+                    files: vec![],
+                    variables: HashMap::new(),
+                    variable_schema: vec![],
+                    pre_generate_hooks: vec![],
+                    post_generate_hooks: vec![],
+                    partials: vec![],
+                    required_directories: vec![],
+                    extends: None,
+                },
+                files: vec![],
+                source: infrastructure::TemplateSource::Embedded,
+            })
+        }
+    }
+
+    /// Records the role and protocol context it builds from
+    #[derive(Default)]
+    struct MockContextBuilder {
+        built: std::sync::Mutex<Option<(Role, Option<ProtocolContext>)>>,
+    }
+
+    #[async_trait::async_trait]
+    impl generation::ContextBuilder for MockContextBuilder {
+        async fn build(
+            &self,
+            context: &generation::GenerationContext,
+            _template: &infrastructure::Template,
+        ) -> Result<generation::RenderContext, GenerationError> {
+            *self.built.lock().unwrap() =
+                Some((context.role.clone(), context.protocol_context.clone()));
+            Ok(generation::RenderContext::default())
+        }
+    }
+
+    struct MockTemplateRenderer;
+
+    #[async_trait::async_trait]
+    impl generation::TemplateRenderingStrategy for MockTemplateRenderer {
+        async fn render(
+            &self,
+            _template: &infrastructure::Template,
+            _context: &generation::RenderContext,
+            _generation_context: &generation::GenerationContext,
+        ) -> Result<Vec<generation::Artifact>, GenerationError> {
+            Ok(vec![
+                generation::Artifact {
+                    path: PathBuf::from("src/main.rs"),
+                    content: "fn main() {}".to_string(),
+                    permissions: None,
+                };
+                3
+            ])
+        }
+    }
+
+    struct MockPostProcessor;
+
+    #[async_trait::async_trait]
+    impl generation::PostProcessor for MockPostProcessor {
+        async fn process(
+            &self,
+            artifacts: Vec<generation::Artifact>,
+            _context: &generation::GenerationContext,
+        ) -> Result<Vec<generation::Artifact>, GenerationError> {
+            Ok(artifacts)
+        }
+    }
+}
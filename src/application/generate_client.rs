@@ -1,9 +1,10 @@
 //! Use case for generating client implementations
 
 use crate::application::{
-    ApplicationError, GenerateClientRequest, GenerateClientResponse, OutputService,
+    ApplicationError, GenerateClientRequest, GenerateClientResponse, HookRunner, OutputService,
 };
 use crate::generation::GenerationOrchestrator;
+use crate::infrastructure::HookStage;
 use crate::protocols::{ProtocolConfig, ProtocolError, ProtocolInput, ProtocolRegistry, Role};
 use std::sync::Arc;
 
@@ -12,6 +13,7 @@ pub struct GenerateClientUseCase {
     protocol_registry: Arc<ProtocolRegistry>,
     generation_orchestrator: Arc<GenerationOrchestrator>,
     output_service: Arc<dyn OutputService>,
+    hook_runner: Option<Arc<dyn HookRunner>>,
 }
 
 impl GenerateClientUseCase {
@@ -24,9 +26,16 @@ impl GenerateClientUseCase {
             protocol_registry,
             generation_orchestrator,
             output_service,
+            hook_runner: None,
         }
     }
 
+    /// Run the template's hooks in the output directory around writing the files
+    pub fn with_hook_runner(mut self, hook_runner: Arc<dyn HookRunner>) -> Self {
+        self.hook_runner = Some(hook_runner);
+        self
+    }
+
     pub async fn execute(
         &self,
         request: GenerateClientRequest,
@@ -52,6 +61,8 @@ impl GenerateClientUseCase {
                 options: request.options.clone(),
             },
             openapi_spec: None,
+            mcp_server: None,
+            mcp_tools: None,
         };
 
         // 4. Build generation context
@@ -60,12 +71,32 @@ impl GenerateClientUseCase {
         // 5. Generate code
         let result = self.generation_orchestrator.generate(context).await?;
 
-        // 6. Ensure output directory exists
+        // 6. Ensure output directory and the template's required directories exist
         self.output_service
             .ensure_directory(&request.output_dir)
             .await?;
+        for directory in &result.directories {
+            self.output_service
+                .ensure_directory(&request.output_dir.join(directory))
+                .await?;
+        }
 
-        // 7. Prepend output directory to artifact paths and write
+        // 7. Run pre-generation hooks before any file is written
+        let mut diagnostics = result.diagnostics;
+        if let Some(hook_runner) = &self.hook_runner {
+            diagnostics.extend(
+                hook_runner
+                    .run_hooks(
+                        HookStage::PreGenerate,
+                        &result.pre_generate_hooks,
+                        &request.output_dir,
+                    )
+                    .await?,
+            );
+        }
+
+        // 8. Prepend output directory to artifact paths and write
+        let artifacts = result.artifacts.iter().map(|a| a.path.clone()).collect();
         let mut output_artifacts = result.artifacts;
         for artifact in &mut output_artifacts {
             artifact.path = request.output_dir.join(&artifact.path);
@@ -77,10 +108,25 @@ impl GenerateClientUseCase {
             .write_artifacts(&output_artifacts)
             .await?;
 
+        // 9. Run post-generation hooks against the written project
+        if let Some(hook_runner) = &self.hook_runner {
+            diagnostics.extend(
+                hook_runner
+                    .run_hooks(
+                        HookStage::PostGenerate,
+                        &result.post_generate_hooks,
+                        &request.output_dir,
+                    )
+                    .await?,
+            );
+        }
+
         Ok(GenerateClientResponse {
             artifacts_count,
             output_path: request.output_dir,
             metadata: result.metadata,
+            artifacts,
+            diagnostics,
         })
     }
 }
@@ -306,7 +352,12 @@ mod tests {
                     language,
                     files: vec![],
                     variables: HashMap::new(),
+                    variable_schema: vec![],
+                    pre_generate_hooks: vec![],
                     post_generate_hooks: vec![],
+                    partials: vec![],
+                    required_directories: vec![],
+                    extends: None,
                 },
                 files: vec![],
                 source: infrastructure::TemplateSource::Embedded,
@@ -356,7 +407,6 @@ mod tests {
             &self,
             artifacts: Vec<generation::Artifact>,
             _context: &generation::GenerationContext,
-            _post_generation_commands: &[String],
         ) -> Result<Vec<generation::Artifact>, generation::GenerationError> {
             Ok(artifacts)
         }
@@ -0,0 +1,363 @@
+//! Use case for generating projects of roles defined by their templates
+
+use crate::application::{
+    ApplicationError, GenerateCustomRequest, GenerateCustomResponse, HookRunner, OutputService,
+};
+use crate::generation::{GenerationOrchestrator, OpenApiLoader};
+use crate::infrastructure::HookStage;
+use crate::protocols::{ProtocolConfig, ProtocolError, ProtocolInput, ProtocolRegistry};
+use std::sync::Arc;
+
+/// Use case for generating projects of custom roles, and of built-in roles
+/// without a dedicated use case
+pub struct GenerateCustomUseCase {
+    protocol_registry: Arc<ProtocolRegistry>,
+    openapi_loader: Arc<dyn OpenApiLoader>,
+    generation_orchestrator: Arc<GenerationOrchestrator>,
+    output_service: Arc<dyn OutputService>,
+    hook_runner: Option<Arc<dyn HookRunner>>,
+}
+
+impl GenerateCustomUseCase {
+    pub fn new(
+        protocol_registry: Arc<ProtocolRegistry>,
+        openapi_loader: Arc<dyn OpenApiLoader>,
+        generation_orchestrator: Arc<GenerationOrchestrator>,
+        output_service: Arc<dyn OutputService>,
+    ) -> Self {
+        Self {
+            protocol_registry,
+            openapi_loader,
+            generation_orchestrator,
+            output_service,
+            hook_runner: None,
+        }
+    }
+
+    /// Run the template's hooks in the output directory around writing the files
+    pub fn with_hook_runner(mut self, hook_runner: Arc<dyn HookRunner>) -> Self {
+        self.hook_runner = Some(hook_runner);
+        self
+    }
+
+    pub async fn execute(
+        &self,
+        request: GenerateCustomRequest,
+    ) -> Result<GenerateCustomResponse, ApplicationError> {
+        // 1. Validate request
+        request.validate()?;
+
+        // 2. Get protocol handler
+        let handler =
+            self.protocol_registry
+                .get(request.protocol)
+                .ok_or(ApplicationError::ProtocolError(
+                    ProtocolError::NotImplemented(request.protocol),
+                ))?;
+
+        // 3. Load OpenAPI if given; whether the template needs it is up to the template
+        let openapi_spec = match &request.schema_path {
+            Some(path) => {
+                tracing::debug!("GenerateCustomUseCase: Loading OpenAPI from path: {}", path);
+                Some(self.openapi_loader.load(path).await?)
+            }
+            None => None,
+        };
+
+        // 4. Prepare protocol input
+        let input = ProtocolInput {
+            role: request.role.clone(),
+            language: request.language,
+            config: ProtocolConfig {
+                project_name: request.project_name.clone(),
+                version: None,
+                options: request.options.clone(),
+            },
+            openapi_spec,
+            mcp_server: None,
+            mcp_tools: None,
+        };
+
+        // 5. Build generation context
+        let context = handler.prepare_context(input).await?;
+
+        // 6. Generate code
+        let result = self.generation_orchestrator.generate(context).await?;
+
+        // 7. Ensure output directory and the template's required directories exist
+        self.output_service
+            .ensure_directory(&request.output_dir)
+            .await?;
+        for directory in &result.directories {
+            self.output_service
+                .ensure_directory(&request.output_dir.join(directory))
+                .await?;
+        }
+
+        // 8. Run pre-generation hooks before any file is written
+        let mut diagnostics = result.diagnostics;
+        if let Some(hook_runner) = &self.hook_runner {
+            diagnostics.extend(
+                hook_runner
+                    .run_hooks(
+                        HookStage::PreGenerate,
+                        &result.pre_generate_hooks,
+                        &request.output_dir,
+                    )
+                    .await?,
+            );
+        }
+
+        // 9. Prepend output directory to artifact paths and write
+        let artifacts = result.artifacts.iter().map(|a| a.path.clone()).collect();
+        let mut output_artifacts = result.artifacts;
+        for artifact in &mut output_artifacts {
+            artifact.path = request.output_dir.join(&artifact.path);
+        }
+
+        let artifacts_count = output_artifacts.len();
+
+        self.output_service
+            .write_artifacts(&output_artifacts)
+            .await?;
+
+        // 10. Run post-generation hooks against the written project
+        if let Some(hook_runner) = &self.hook_runner {
+            diagnostics.extend(
+                hook_runner
+                    .run_hooks(
+                        HookStage::PostGenerate,
+                        &result.post_generate_hooks,
+                        &request.output_dir,
+                    )
+                    .await?,
+            );
+        }
+
+        Ok(GenerateCustomResponse {
+            artifacts_count,
+            output_path: request.output_dir,
+            metadata: result.metadata,
+            artifacts,
+            diagnostics,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::{self, GenerationError, Language};
+    use crate::infrastructure;
+    use crate::protocols::{self, Protocol, Role};
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    fn request(schema_path: Option<&str>) -> GenerateCustomRequest {
+        GenerateCustomRequest {
+            protocol: Protocol::Mcp,
+            role: Role::Custom("reviewer".to_string()),
+            language: Language::Rust,
+            project_name: "test-reviewer".to_string(),
+            schema_path: schema_path.map(str::to_string),
+            output_dir: PathBuf::from("/output"),
+            options: HashMap::new(),
+        }
+    }
+
+    fn use_case(
+        template_discovery: Arc<MockTemplateDiscovery>,
+        output_service: Arc<MockOutputService>,
+    ) -> GenerateCustomUseCase {
+        let registry = ProtocolRegistry::new();
+        let _ = registry.register(
+            Protocol::Mcp,
+            Arc::new(protocols::handlers::mcp::McpProtocolHandler::new()),
+        );
+        GenerateCustomUseCase::new(
+            Arc::new(registry),
+            Arc::new(UnreachableOpenApiLoader),
+            Arc::new(GenerationOrchestrator::new(
+                template_discovery,
+                Arc::new(MockContextBuilder),
+                Arc::new(MockTemplateRenderer),
+                Arc::new(MockPostProcessor),
+            )),
+            output_service,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_execute_custom_role() {
+        let template_discovery = Arc::new(MockTemplateDiscovery::default());
+        let output_service = Arc::new(MockOutputService::default());
+
+        let response = use_case(template_discovery.clone(), output_service.clone())
+            .execute(request(None))
+            .await
+            .unwrap();
+        assert_eq!(response.artifacts_count, 1);
+        assert_eq!(response.artifacts, vec![PathBuf::from("REVIEW.md")]);
+
+        assert_eq!(
+            *template_discovery.discovered.lock().unwrap(),
+            vec![(
+                Protocol::Mcp,
+                Role::Custom("reviewer".to_string()),
+                Language::Rust
+            )]
+        );
+        let written = output_service.written_artifacts.lock().unwrap();
+        assert_eq!(written[0].path, PathBuf::from("/output/REVIEW.md"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_loads_given_spec() {
+        let result = use_case(
+            Arc::new(MockTemplateDiscovery::default()),
+            Arc::new(MockOutputService::default()),
+        )
+        .execute(request(Some("/path/to/openapi.yaml")))
+        .await;
+        match result {
+            Err(ApplicationError::GenerationError(GenerationError::LoadError(message))) => {
+                assert!(message.contains("/path/to/openapi.yaml"), "{message}")
+            }
+            other => panic!("Expected the spec to be loaded, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_unsupported_builtin_role() {
+        let mut request = request(None);
+        request.role = Role::Agent;
+        let result = use_case(
+            Arc::new(MockTemplateDiscovery::default()),
+            Arc::new(MockOutputService::default()),
+        )
+        .execute(request)
+        .await;
+        assert!(matches!(result, Err(ApplicationError::ValidationError(_))));
+    }
+
+    // Mock implementations
+    struct UnreachableOpenApiLoader;
+
+    #[async_trait::async_trait]
+    impl generation::OpenApiLoader for UnreachableOpenApiLoader {
+        async fn load(&self, source: &str) -> Result<generation::OpenApiContext, GenerationError> {
+            Err(GenerationError::LoadError(format!(
+                "unreachable spec {source}"
+            )))
+        }
+    }
+
+    #[derive(Default)]
+    struct MockOutputService {
+        written_artifacts: std::sync::Mutex<Vec<generation::Artifact>>,
+    }
+
+    #[async_trait::async_trait]
+    impl OutputService for MockOutputService {
+        async fn write_artifacts(
+            &self,
+            artifacts: &[generation::Artifact],
+        ) -> Result<(), ApplicationError> {
+            self.written_artifacts
+                .lock()
+                .unwrap()
+                .extend_from_slice(artifacts);
+            Ok(())
+        }
+
+        async fn ensure_directory(&self, _path: &std::path::Path) -> Result<(), ApplicationError> {
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct MockTemplateDiscovery {
+        discovered: std::sync::Mutex<Vec<(Protocol, Role, Language)>>,
+    }
+
+    #[async_trait::async_trait]
+    impl generation::TemplateDiscovery for MockTemplateDiscovery {
+        async fn discover(
+            &self,
+            protocol: Protocol,
+            role: Role,
+            language: Language,
+        ) -> Result<infrastructure::Template, GenerationError> {
+            self.discovered
+                .lock()
+                .unwrap()
+                .push((protocol, role.clone(), language));
+
+            Ok(infrastructure::Template {
+                manifest: infrastructure::TemplateManifest {
+                    name: "test-template".to_string(),
+                    version: "1.0.0".to_string(),
+                    description: Some("Test template".to_string()),
+                    path: "test-template".to_string(),
+                    protocol,
+                    role,
+                    language,
+                    files: vec![],
+                    variables: HashMap::new(),
+                    variable_schema: vec![],
+                    pre_generate_hooks: vec![],
+                    post_generate_hooks: vec![],
+                    partials: vec![],
+                    required_directories: vec![],
+                    extends: None,
+                },
+                files: vec![],
+                source: infrastructure::TemplateSource::Embedded,
+            })
+        }
+    }
+
+    struct MockContextBuilder;
+
+    #[async_trait::async_trait]
+    impl generation::ContextBuilder for MockContextBuilder {
+        async fn build(
+            &self,
+            _context: &generation::GenerationContext,
+            _template: &infrastructure::Template,
+        ) -> Result<generation::RenderContext, GenerationError> {
+            Ok(generation::RenderContext::default())
+        }
+    }
+
+    struct MockTemplateRenderer;
+
+    #[async_trait::async_trait]
+    impl generation::TemplateRenderingStrategy for MockTemplateRenderer {
+        async fn render(
+            &self,
+            _template: &infrastructure::Template,
+            _context: &generation::RenderContext,
+            _generation_context: &generation::GenerationContext,
+        ) -> Result<Vec<generation::Artifact>, GenerationError> {
+            Ok(vec![generation::Artifact {
+                path: PathBuf::from("REVIEW.md"),
+                content: "# Review".to_string(),
+                permissions: None,
+            }])
+        }
+    }
+
+    struct MockPostProcessor;
+
+    #[async_trait::async_trait]
+    impl generation::PostProcessor for MockPostProcessor {
+        async fn process(
+            &self,
+            artifacts: Vec<generation::Artifact>,
+            _context: &generation::GenerationContext,
+        ) -> Result<Vec<generation::Artifact>, GenerationError> {
+            Ok(artifacts)
+        }
+    }
+}
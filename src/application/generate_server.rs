@@ -1,9 +1,10 @@
 //! Use case for generating server implementations
 
 use crate::application::{
-    ApplicationError, GenerateServerRequest, GenerateServerResponse, OutputService,
+    ApplicationError, GenerateServerRequest, GenerateServerResponse, HookRunner, OutputService,
 };
 use crate::generation::{GenerationOrchestrator, OpenApiLoader};
+use crate::infrastructure::HookStage;
 use crate::protocols::{ProtocolConfig, ProtocolInput, ProtocolRegistry, Role};
 use std::sync::Arc;
 
@@ -13,6 +14,7 @@ pub struct GenerateServerUseCase {
     openapi_loader: Arc<dyn OpenApiLoader>,
     generation_orchestrator: Arc<GenerationOrchestrator>,
     output_service: Arc<dyn OutputService>,
+    hook_runner: Option<Arc<dyn HookRunner>>,
 }
 
 impl GenerateServerUseCase {
@@ -27,9 +29,16 @@ impl GenerateServerUseCase {
             openapi_loader,
             generation_orchestrator,
             output_service,
+            hook_runner: None,
         }
     }
 
+    /// Run the template's hooks in the output directory around writing the files
+    pub fn with_hook_runner(mut self, hook_runner: Arc<dyn HookRunner>) -> Self {
+        self.hook_runner = Some(hook_runner);
+        self
+    }
+
     pub async fn execute(
         &self,
         request: GenerateServerRequest,
@@ -45,24 +54,21 @@ impl GenerateServerUseCase {
                     crate::protocols::ProtocolError::NotImplemented(request.protocol),
                 ))?;
 
-        // 3. Load OpenAPI if needed
+        // 3. Load OpenAPI if needed, or if given to a protocol where it is optional
         let capabilities = handler.protocol().capabilities();
-        let openapi_spec = if capabilities.requires_openapi {
-            match &request.schema_path {
-                Some(path) => {
-                    tracing::debug!("GenerateServerUseCase: Loading OpenAPI from path: {}", path);
-                    Some(self.openapi_loader.load(path).await?)
-                }
-                None => {
-                    return Err(ApplicationError::ValidationError(
-                        crate::application::ValidationError::MissingField(
-                            "MCP server requires OpenAPI schema path".to_string(),
-                        ),
-                    ));
-                }
+        let openapi_spec = match &request.schema_path {
+            Some(path) => {
+                tracing::debug!("GenerateServerUseCase: Loading OpenAPI from path: {}", path);
+                Some(self.openapi_loader.load(path).await?)
+            }
+            None if capabilities.requires_openapi => {
+                return Err(ApplicationError::ValidationError(
+                    crate::application::ValidationError::MissingField(
+                        "MCP server requires OpenAPI schema path".to_string(),
+                    ),
+                ));
             }
-        } else {
-            None
+            None => None,
         };
 
         // 4. Prepare protocol input
@@ -75,6 +81,8 @@ impl GenerateServerUseCase {
                 options: request.options.clone(),
             },
             openapi_spec,
+            mcp_server: None,
+            mcp_tools: None,
         };
 
         // 5. Build generation context
@@ -83,12 +91,32 @@ impl GenerateServerUseCase {
         // 6. Generate code
         let result = self.generation_orchestrator.generate(context).await?;
 
-        // 7. Ensure output directory exists
+        // 7. Ensure output directory and the template's required directories exist
         self.output_service
             .ensure_directory(&request.output_dir)
             .await?;
+        for directory in &result.directories {
+            self.output_service
+                .ensure_directory(&request.output_dir.join(directory))
+                .await?;
+        }
+
+        // 8. Run pre-generation hooks before any file is written
+        let mut diagnostics = result.diagnostics;
+        if let Some(hook_runner) = &self.hook_runner {
+            diagnostics.extend(
+                hook_runner
+                    .run_hooks(
+                        HookStage::PreGenerate,
+                        &result.pre_generate_hooks,
+                        &request.output_dir,
+                    )
+                    .await?,
+            );
+        }
 
-        // 8. Prepend output directory to artifact paths and write
+        // 9. Prepend output directory to artifact paths and write
+        let artifacts = result.artifacts.iter().map(|a| a.path.clone()).collect();
         let mut output_artifacts = result.artifacts;
         for artifact in &mut output_artifacts {
             artifact.path = request.output_dir.join(&artifact.path);
@@ -100,10 +128,25 @@ impl GenerateServerUseCase {
             .write_artifacts(&output_artifacts)
             .await?;
 
+        // 10. Run post-generation hooks against the written project
+        if let Some(hook_runner) = &self.hook_runner {
+            diagnostics.extend(
+                hook_runner
+                    .run_hooks(
+                        HookStage::PostGenerate,
+                        &result.post_generate_hooks,
+                        &request.output_dir,
+                    )
+                    .await?,
+            );
+        }
+
         Ok(GenerateServerResponse {
             artifacts_count,
             output_path: request.output_dir,
             metadata: result.metadata,
+            artifacts,
+            diagnostics,
         })
     }
 }
@@ -112,7 +155,7 @@ impl GenerateServerUseCase {
 mod tests {
     use super::*;
     use crate::generation::{self, GenerationError, Language};
-    use crate::infrastructure::{Template, TemplateManifest, TemplateSource};
+    use crate::infrastructure::{Template, TemplateHook, TemplateManifest, TemplateSource};
     use crate::protocols::{self, Protocol, Role};
     use std::collections::HashMap;
     use std::path::PathBuf;
@@ -154,6 +197,92 @@ mod tests {
         assert!(dirs.contains(&PathBuf::from("/output")));
     }
 
+    #[tokio::test]
+    async fn test_execute_runs_hooks_around_writing() {
+        let output_service = Arc::new(MockOutputService::new());
+        let hook_runner = Arc::new(MockHookRunner::new(output_service.clone(), None));
+
+        let use_case = GenerateServerUseCase::new(
+            Arc::new(create_mock_registry()),
+            Arc::new(MockOpenApiLoader),
+            Arc::new(create_mock_orchestrator()),
+            output_service.clone(),
+        )
+        .with_hook_runner(hook_runner.clone());
+
+        use_case.execute(request()).await.unwrap();
+
+        // Pre-generation hooks see no files, post-generation hooks see them all
+        let runs = hook_runner.runs.lock().unwrap().clone();
+        assert_eq!(
+            runs,
+            vec![
+                (HookStage::PreGenerate, "echo pre".to_string(), 0),
+                (HookStage::PostGenerate, "echo post".to_string(), 5),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_stops_when_pre_generate_hook_fails() {
+        let output_service = Arc::new(MockOutputService::new());
+        let hook_runner = Arc::new(MockHookRunner::new(
+            output_service.clone(),
+            Some(HookStage::PreGenerate),
+        ));
+
+        let use_case = GenerateServerUseCase::new(
+            Arc::new(create_mock_registry()),
+            Arc::new(MockOpenApiLoader),
+            Arc::new(create_mock_orchestrator()),
+            output_service.clone(),
+        )
+        .with_hook_runner(hook_runner);
+
+        let err = use_case.execute(request()).await.unwrap_err();
+        assert!(matches!(err, ApplicationError::HookFailed { .. }));
+        assert!(output_service.get_written_artifacts().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_execute_loads_optional_openapi_only_when_given() {
+        let registry = create_mock_registry();
+        registry
+            .register(
+                Protocol::Acp,
+                Arc::new(protocols::handlers::acp::AcpProtocolHandler::new()),
+            )
+            .unwrap();
+        let use_case = GenerateServerUseCase::new(
+            Arc::new(registry),
+            Arc::new(UnreachableOpenApiLoader),
+            Arc::new(create_mock_orchestrator()),
+            Arc::new(MockOutputService::new()),
+        );
+
+        let mut request = GenerateServerRequest {
+            protocol: Protocol::Acp,
+            schema_path: None,
+            ..request()
+        };
+        assert!(use_case.execute(request.clone()).await.is_ok());
+
+        request.schema_path = Some("/path/to/openapi.yaml".to_string());
+        let err = use_case.execute(request).await.unwrap_err();
+        assert!(err.to_string().contains("unreachable spec"), "{err}");
+    }
+
+    fn request() -> GenerateServerRequest {
+        GenerateServerRequest {
+            protocol: Protocol::Mcp,
+            language: Language::Rust,
+            project_name: "test-server".to_string(),
+            schema_path: Some("/path/to/openapi.yaml".to_string()),
+            output_dir: PathBuf::from("/output"),
+            options: HashMap::new(),
+        }
+    }
+
     // Helper functions to create mocks
     fn create_mock_registry() -> ProtocolRegistry {
         let registry = ProtocolRegistry::new();
@@ -189,10 +318,23 @@ mod tests {
                 servers: vec![],
                 operations: vec![],
                 components: None,
+                tags: vec![],
+                diagnostics: vec![],
             })
         }
     }
 
+    struct UnreachableOpenApiLoader;
+
+    #[async_trait::async_trait]
+    impl generation::OpenApiLoader for UnreachableOpenApiLoader {
+        async fn load(&self, source: &str) -> Result<generation::OpenApiContext, GenerationError> {
+            Err(GenerationError::LoadError(format!(
+                "unreachable spec {source}"
+            )))
+        }
+    }
+
     struct MockOutputService {
         written_artifacts: std::sync::Mutex<Vec<generation::Artifact>>,
         ensured_directories: std::sync::Mutex<Vec<std::path::PathBuf>>,
@@ -266,6 +408,50 @@ mod tests {
         }
     }
 
+    /// Records each hook it runs with the number of artifacts written so far
+    struct MockHookRunner {
+        output_service: Arc<MockOutputService>,
+        fail_stage: Option<HookStage>,
+        runs: std::sync::Mutex<Vec<(HookStage, String, usize)>>,
+    }
+
+    impl MockHookRunner {
+        fn new(output_service: Arc<MockOutputService>, fail_stage: Option<HookStage>) -> Self {
+            Self {
+                output_service,
+                fail_stage,
+                runs: std::sync::Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl HookRunner for MockHookRunner {
+        async fn run_hooks(
+            &self,
+            stage: HookStage,
+            hooks: &[TemplateHook],
+            working_dir: &std::path::Path,
+        ) -> Result<Vec<generation::Diagnostic>, ApplicationError> {
+            assert_eq!(working_dir, std::path::Path::new("/output"));
+            for hook in hooks {
+                if self.fail_stage == Some(stage) {
+                    return Err(ApplicationError::HookFailed {
+                        stage,
+                        command: hook.command.clone(),
+                        reason: "exited with code 1".to_string(),
+                    });
+                }
+                let written = self.output_service.get_written_artifacts().len();
+                self.runs
+                    .lock()
+                    .unwrap()
+                    .push((stage, hook.command.clone(), written));
+            }
+            Ok(vec![])
+        }
+    }
+
     struct MockTemplateDiscovery;
 
     #[async_trait::async_trait]
@@ -287,7 +473,12 @@ mod tests {
                     language,
                     files: vec![],
                     variables: HashMap::new(),
-                    post_generate_hooks: vec![],
+                    variable_schema: vec![],
+                    pre_generate_hooks: vec![TemplateHook::new("echo pre")],
+                    post_generate_hooks: vec![TemplateHook::new("echo post")],
+                    partials: vec![],
+                    required_directories: vec![],
+                    extends: None,
                 },
                 files: vec![],
                 source: TemplateSource::Embedded,
@@ -337,7 +528,6 @@ mod tests {
             &self,
             artifacts: Vec<generation::Artifact>,
             _context: &generation::GenerationContext,
-            _post_generation_commands: &[String],
         ) -> Result<Vec<generation::Artifact>, GenerationError> {
             Ok(artifacts)
         }
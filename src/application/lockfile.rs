@@ -0,0 +1,119 @@
+//! Generation lockfile
+//!
+//! A project scaffolded from an archive or git template source gets an
+//! `agenterra.lock` recording the source and what it resolved to. Scaffolding
+//! into the same project again from the same source reuses the pinned commit
+//! or digest, so a moved branch or a republished archive cannot silently
+//! change the generated code. Delete the lockfile to pick up a new version.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::ApplicationError;
+use crate::infrastructure::{TemplateError, TemplatePin};
+
+/// File name of the lockfile, at the project root
+pub const LOCKFILE_NAME: &str = "agenterra.lock";
+
+/// Contents of `agenterra.lock`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GenerationLock {
+    /// Version of agenterra that wrote the lockfile
+    pub agenterra_version: String,
+    pub template: LockedTemplate,
+}
+
+/// Template source a project was generated from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockedTemplate {
+    /// The `--template-source` value
+    pub source: String,
+    /// Commit a git source resolved to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+    /// `sha256:` digest of an archive source
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub digest: Option<String>,
+}
+
+impl GenerationLock {
+    pub fn new(source: impl Into<String>, pin: &TemplatePin) -> Self {
+        let (commit, digest) = match pin {
+            TemplatePin::Commit(commit) => (Some(commit.clone()), None),
+            TemplatePin::Digest(digest) => (None, Some(digest.clone())),
+        };
+        Self {
+            agenterra_version: env!("CARGO_PKG_VERSION").to_string(),
+            template: LockedTemplate {
+                source: source.into(),
+                commit,
+                digest,
+            },
+        }
+    }
+
+    /// Read the lockfile of `project_dir`, if it has one
+    pub fn read(project_dir: &Path) -> Result<Option<Self>, ApplicationError> {
+        let path = project_dir.join(LOCKFILE_NAME);
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(TemplateError::IoError(e).into()),
+        };
+        toml::from_str(&content).map(Some).map_err(|e| {
+            ApplicationError::InvalidTemplate(format!("invalid {}: {e}", path.display()))
+        })
+    }
+
+    /// Write the lockfile into `project_dir`
+    pub fn write(&self, project_dir: &Path) -> Result<(), ApplicationError> {
+        let content = toml::to_string(self)
+            .map_err(|e| ApplicationError::OutputError(format!("cannot write lockfile: {e}")))?;
+        std::fs::write(
+            project_dir.join(LOCKFILE_NAME),
+            format!("# Written by agenterra; delete to re-resolve the template source\n{content}"),
+        )
+        .map_err(|e| ApplicationError::OutputError(format!("cannot write lockfile: {e}")))
+    }
+
+    /// Pin recorded for `source`, when the lockfile was written for it
+    pub fn pin_for(&self, source: &str) -> Option<TemplatePin> {
+        if self.template.source != source {
+            return None;
+        }
+        self.template
+            .commit
+            .clone()
+            .map(TemplatePin::Commit)
+            .or_else(|| self.template.digest.clone().map(TemplatePin::Digest))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_round_trip_and_pin_for() {
+        let dir = TempDir::new().unwrap();
+        assert_eq!(GenerationLock::read(dir.path()).unwrap(), None);
+
+        let source = "git+file:///srv/templates.git#main";
+        let lock = GenerationLock::new(source, &TemplatePin::Commit("abc123".into()));
+        lock.write(dir.path()).unwrap();
+
+        let content = std::fs::read_to_string(dir.path().join(LOCKFILE_NAME)).unwrap();
+        assert!(content.contains("commit = \"abc123\""));
+        assert!(!content.contains("digest"));
+
+        let read = GenerationLock::read(dir.path()).unwrap().unwrap();
+        assert_eq!(read, lock);
+        assert_eq!(
+            read.pin_for(source),
+            Some(TemplatePin::Commit("abc123".into()))
+        );
+        assert_eq!(read.pin_for("git+file:///srv/templates.git#v2"), None);
+    }
+}
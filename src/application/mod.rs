@@ -2,14 +2,27 @@
 
 pub mod commands;
 pub mod errors;
+pub mod generate_agent;
+pub mod generate_bridge;
 pub mod generate_client;
+pub mod generate_custom;
 pub mod generate_server;
+pub mod lockfile;
+pub mod report;
 pub mod template_management;
+pub mod template_scaffolding;
+pub mod template_validation;
 pub mod traits;
 
 pub use commands::*;
 pub use errors::*;
+pub use lockfile::{GenerationLock, LOCKFILE_NAME, LockedTemplate};
+pub use report::*;
 pub use template_management::*;
+pub use template_scaffolding::{
+    ContextVariable, NewTemplate, NewTemplateUseCase, VariableReference,
+};
+pub use template_validation::{TemplateIssue, TemplateValidationReport, ValidateTemplateUseCase};
 pub use traits::*;
 
 #[cfg(test)]
@@ -55,7 +68,7 @@ mod tests {
 
         // Invalid protocol for client
         let mut invalid_request = request.clone();
-        invalid_request.protocol = Protocol::Anp; // Assuming ANP doesn't support client
+        invalid_request.protocol = Protocol::Anp; // ANP agents are peers, there is no client role
         assert!(invalid_request.validate().is_err());
     }
 
@@ -0,0 +1,161 @@
+//! Generation report returned at the end of a scaffold run
+
+use crate::generation::{Diagnostic, Language, Severity};
+use crate::protocols::{Protocol, Role};
+use serde::Serialize;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+/// Machine-readable outcome of a scaffold run
+#[derive(Debug, Clone, Serialize)]
+pub struct GenerationReport {
+    pub project_name: String,
+    pub protocol: String,
+    pub role: String,
+    pub language: String,
+    pub output_path: PathBuf,
+    /// Generated files, relative to `output_path`
+    pub artifacts: Vec<PathBuf>,
+    pub diagnostics: Vec<Diagnostic>,
+    pub summary: ReportSummary,
+}
+
+/// Counts for a generation report
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct ReportSummary {
+    pub artifacts: usize,
+    pub info: usize,
+    pub warnings: usize,
+    pub errors: usize,
+}
+
+impl GenerationReport {
+    pub fn new(
+        project_name: impl Into<String>,
+        protocol: Protocol,
+        role: Role,
+        language: Language,
+        output_path: PathBuf,
+        artifacts: Vec<PathBuf>,
+        diagnostics: Vec<Diagnostic>,
+    ) -> Self {
+        let mut summary = ReportSummary {
+            artifacts: artifacts.len(),
+            ..Default::default()
+        };
+        for diagnostic in &diagnostics {
+            match diagnostic.severity {
+                Severity::Info => summary.info += 1,
+                Severity::Warning => summary.warnings += 1,
+                Severity::Error => summary.errors += 1,
+            }
+        }
+
+        Self {
+            project_name: project_name.into(),
+            protocol: protocol.to_string(),
+            role: role.to_string(),
+            language: language.to_string(),
+            output_path,
+            artifacts,
+            diagnostics,
+            summary,
+        }
+    }
+
+    /// Human-readable summary printed at the end of a scaffold
+    ///
+    /// Warnings and errors are listed individually; info entries are only counted.
+    pub fn to_text(&self) -> String {
+        let mut text = format!(
+            "Successfully generated {} {} {} '{}' in {} ({} files)\n",
+            self.protocol,
+            self.role,
+            self.language,
+            self.project_name,
+            self.output_path.display(),
+            self.summary.artifacts
+        );
+
+        for diagnostic in self
+            .diagnostics
+            .iter()
+            .filter(|d| d.severity > Severity::Info)
+        {
+            let _ = writeln!(text, "  {diagnostic}");
+        }
+
+        let _ = write!(
+            text,
+            "{} error(s), {} warning(s), {} info",
+            self.summary.errors, self.summary.warnings, self.summary.info
+        );
+        if self.summary.info > 0 {
+            text.push_str(" (use --report json for details)");
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::diagnostics::codes;
+
+    fn report() -> GenerationReport {
+        GenerationReport::new(
+            "petstore",
+            Protocol::Mcp,
+            Role::Server,
+            Language::Rust,
+            PathBuf::from("/out/petstore"),
+            vec![PathBuf::from("Cargo.toml"), PathBuf::from("src/main.rs")],
+            vec![
+                Diagnostic::new(
+                    Severity::Info,
+                    codes::OPERATION_ID_GENERATED,
+                    "/paths/~1pet/get",
+                    "generated get_pet",
+                ),
+                Diagnostic::new(
+                    Severity::Warning,
+                    codes::RESPONSE_NOT_JSON,
+                    "/paths/~1pet/get/responses/200/content",
+                    "only application/xml",
+                ),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_summary_counts() {
+        assert_eq!(
+            report().summary,
+            ReportSummary {
+                artifacts: 2,
+                info: 1,
+                warnings: 1,
+                errors: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_text_lists_warnings_only() {
+        let text = report().to_text();
+        assert!(text.starts_with(
+            "Successfully generated mcp server rust 'petstore' in /out/petstore (2 files)"
+        ));
+        assert!(text.contains("warning[response-not-json]"));
+        assert!(!text.contains("operation-id-generated"));
+        assert!(text.ends_with("0 error(s), 1 warning(s), 1 info (use --report json for details)"));
+    }
+
+    #[test]
+    fn test_json_shape() {
+        let json = serde_json::to_value(report()).unwrap();
+        assert_eq!(json["protocol"], "mcp");
+        assert_eq!(json["diagnostics"][1]["severity"], "warning");
+        assert_eq!(json["summary"]["warnings"], 1);
+    }
+}
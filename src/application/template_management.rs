@@ -2,7 +2,9 @@
 
 use crate::application::ApplicationError;
 use crate::generation::GenerationError;
-use crate::infrastructure::{TemplateDiscovery, TemplateExporter, TemplateRepository};
+use crate::infrastructure::{
+    TemplateDiscovery, TemplateExporter, TemplateManifest, TemplateRepository,
+};
 use crate::protocols::Role;
 use std::path::Path;
 
@@ -21,6 +23,7 @@ impl<R: TemplateRepository> ListTemplatesUseCase<R> {
 
         // Group templates by role
         let mut agent_manifests = Vec::new();
+        let mut bridge_manifests = Vec::new();
         let mut broker_manifests = Vec::new();
         let mut client_manifests = Vec::new();
         let mut server_manifests = Vec::new();
@@ -29,6 +32,7 @@ impl<R: TemplateRepository> ListTemplatesUseCase<R> {
         for manifest in manifests {
             match &manifest.role {
                 Role::Agent => agent_manifests.push(manifest),
+                Role::Bridge => bridge_manifests.push(manifest),
                 Role::Broker => broker_manifests.push(manifest),
                 Role::Client => client_manifests.push(manifest),
                 Role::Server => server_manifests.push(manifest),
@@ -41,58 +45,65 @@ impl<R: TemplateRepository> ListTemplatesUseCase<R> {
         if !agent_manifests.is_empty() {
             output.push_str("\nAgent Templates:\n");
             for manifest in agent_manifests {
-                output.push_str(&format!("  {}\n", manifest.path));
-                if let Some(desc) = &manifest.description {
-                    output.push_str(&format!("    {desc}\n"));
-                }
+                output.push_str(&describe_variant(&manifest));
+            }
+        }
+
+        if !bridge_manifests.is_empty() {
+            output.push_str("\nBridge Templates:\n");
+            for manifest in bridge_manifests {
+                output.push_str(&describe_variant(&manifest));
             }
         }
 
         if !broker_manifests.is_empty() {
             output.push_str("\nBroker Templates:\n");
             for manifest in broker_manifests {
-                output.push_str(&format!("  {}\n", manifest.path));
-                if let Some(desc) = &manifest.description {
-                    output.push_str(&format!("    {desc}\n"));
-                }
+                output.push_str(&describe_variant(&manifest));
             }
         }
 
         if !server_manifests.is_empty() {
             output.push_str("\nServer Templates:\n");
             for manifest in server_manifests {
-                output.push_str(&format!("  {}\n", manifest.path));
-                if let Some(desc) = &manifest.description {
-                    output.push_str(&format!("    {desc}\n"));
-                }
+                output.push_str(&describe_variant(&manifest));
             }
         }
 
         if !client_manifests.is_empty() {
             output.push_str("\nClient Templates:\n");
             for manifest in client_manifests {
-                output.push_str(&format!("  {}\n", manifest.path));
-                if let Some(desc) = &manifest.description {
-                    output.push_str(&format!("    {desc}\n"));
-                }
+                output.push_str(&describe_variant(&manifest));
             }
         }
 
         if !custom_manifests.is_empty() {
             output.push_str("\nCustom Templates:\n");
             for manifest in custom_manifests {
-                output.push_str(&format!("  {}\n", manifest.path));
-                if let Some(desc) = &manifest.description {
-                    output.push_str(&format!("    {desc}\n"));
-                }
+                output.push_str(&describe_variant(&manifest));
             }
         }
 
-        output.push_str("\nUse 'agenterra templates info <template>' for more details");
+        output.push_str("\nSelect a variant with 'agenterra scaffold ... --template <variant>'\n");
+        output.push_str("Use 'agenterra templates info <template>' for more details");
         output
     }
 }
 
+/// Listing entry for one template: its path, manifest name and variant
+fn describe_variant(manifest: &TemplateManifest) -> String {
+    let mut entry = format!(
+        "  {} ({}, --template {})\n",
+        manifest.path,
+        manifest.name,
+        manifest.variant()
+    );
+    if let Some(desc) = &manifest.description {
+        entry.push_str(&format!("    {desc}\n"));
+    }
+    entry
+}
+
 /// Use case for exporting templates
 pub struct ExportTemplatesUseCase<E: TemplateExporter, R: TemplateRepository> {
     exporter: E,
@@ -153,13 +164,19 @@ impl<R: TemplateRepository, D: TemplateDiscovery> TemplateInfoUseCase<R, D> {
         }
     }
 
-    pub async fn execute(&self, template_path: &str) -> Result<String, ApplicationError> {
-        // Check if template exists first
-        if !self.repository.has_template(template_path) {
-            return Err(ApplicationError::TemplateNotFound(
-                template_path.to_string(),
-            ));
-        }
+    pub async fn execute(&self, template: &str) -> Result<String, ApplicationError> {
+        // Accept a template path or a manifest name
+        let template_path = if self.repository.has_template(template) {
+            template.to_string()
+        } else {
+            self.repository
+                .list_manifests()
+                .into_iter()
+                .find(|manifest| manifest.name == template)
+                .map(|manifest| manifest.path)
+                .ok_or_else(|| ApplicationError::TemplateNotFound(template.to_string()))?
+        };
+        let template_path = template_path.as_str();
 
         // Get template manifest
         let manifest = match self.repository.get_manifest(template_path) {
@@ -174,13 +191,15 @@ impl<R: TemplateRepository, D: TemplateDiscovery> TemplateInfoUseCase<R, D> {
         output.push_str(&format!("Protocol: {}\n", manifest.protocol));
         output.push_str(&format!("Role: {:?}\n", manifest.role));
         output.push_str(&format!("Language: {}\n", manifest.language));
+        output.push_str(&format!("Variant: {}\n", manifest.variant()));
 
         // Try to load the full template to get more manifest details
-        if let Ok(full_template) = self
+        let full_template = self
             .discovery
-            .discover(manifest.protocol, manifest.role.clone(), manifest.language)
+            .discover_named(manifest.protocol, manifest.role.clone(), manifest.variant())
             .await
-        {
+            .ok();
+        if let Some(full_template) = &full_template {
             output.push_str("\nManifest Information:\n");
             output.push_str(&format!("  Name: {}\n", full_template.manifest.name));
             output.push_str(&format!("  Version: {}\n", full_template.manifest.version));
@@ -197,7 +216,12 @@ impl<R: TemplateRepository, D: TemplateDiscovery> TemplateInfoUseCase<R, D> {
             }
         }
 
-        // Display template files from manifest (lightweight - no content loading)
+        // Files and variables inherited through `extends` are only in the full template
+        let manifest = full_template
+            .as_ref()
+            .map(|template| &template.manifest)
+            .unwrap_or(&manifest);
+
         output.push_str(&format!("\nFiles: {} total\n", manifest.files.len()));
 
         output.push_str("\nTemplate files:\n");
@@ -208,6 +232,25 @@ impl<R: TemplateRepository, D: TemplateDiscovery> TemplateInfoUseCase<R, D> {
             output.push_str(&format!("  - {} -> {}\n", file.source, file.target));
         }
 
+        if !manifest.variable_schema.is_empty() {
+            output.push_str("\nVariables:\n");
+            for var in &manifest.variable_schema {
+                let mut line = format!("  - {} ({}", var.name, var.var_type);
+                if var.required {
+                    line.push_str(", required");
+                }
+                if let Some(default) = &var.default {
+                    line.push_str(&format!(", default: {default}"));
+                }
+                line.push(')');
+                if let Some(description) = &var.description {
+                    line.push_str(&format!(": {description}"));
+                }
+                output.push_str(&line);
+                output.push('\n');
+            }
+        }
+
         Ok(output)
     }
 }
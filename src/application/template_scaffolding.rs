@@ -0,0 +1,767 @@
+//! Starter template use case
+//!
+//! Creates the directory a template author starts from when adding a new
+//! protocol, role or language: a `manifest.yml`, stub files that reference
+//! every variable the context builder provides, and a `template_tests/`
+//! directory with a fixture spec and a script that checks the template.
+//! The variables are found by rendering a probe template through the real
+//! pipeline, so the reference always matches what generation provides.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use serde::Serialize;
+use serde_json::{Map as JsonMap, Value as JsonValue};
+
+use super::template_validation::{FIXTURE_SPECS, FixtureSpec, LoadedTemplate};
+use crate::application::{ApplicationError, ValidationError};
+use crate::generation::{
+    ContextBuilder, GenerationOrchestrator, Language, TemplateDiscoveryAdapter, rules,
+};
+use crate::infrastructure::generation::DefaultTemplateRenderer;
+use crate::infrastructure::generation::context_builders::registry::ContextBuilderRegistry;
+use crate::infrastructure::generation::post_processor::PermissionsPostProcessor;
+use crate::infrastructure::openapi::parser::OpenApiParser;
+use crate::infrastructure::{
+    Template, TemplateError, TemplateFile, TemplateFileType, TemplateManifest, TemplateSource,
+};
+use crate::protocols::{Protocol, ProtocolConfig, ProtocolInput, ProtocolRegistry, Role};
+
+/// Probe file that dumps the whole render context
+const PROBE_SOURCE: &str = "context.json.tera";
+
+/// Longest example value shown in the variable reference
+const EXAMPLE_WIDTH: usize = 48;
+
+/// A variable provided by a context builder
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ContextVariable {
+    pub name: String,
+    /// JSON type of the value: string, integer, number, boolean, array, object or null
+    pub kind: &'static str,
+    /// Value seen when rendering against the fixture spec
+    pub example: JsonValue,
+    /// Missing for some specs or endpoints, so templates should give it a default
+    pub optional: bool,
+}
+
+impl ContextVariable {
+    fn new(name: &str, example: &JsonValue, optional: bool) -> Self {
+        let kind = match example {
+            JsonValue::Null => "null",
+            JsonValue::Bool(_) => "boolean",
+            JsonValue::Number(n) if n.is_i64() || n.is_u64() => "integer",
+            JsonValue::Number(_) => "number",
+            JsonValue::String(_) => "string",
+            JsonValue::Array(_) => "array",
+            JsonValue::Object(_) => "object",
+        };
+        Self {
+            name: name.to_string(),
+            kind,
+            example: example.clone(),
+            optional,
+        }
+    }
+
+    /// Tera expression that renders this variable whatever its value
+    ///
+    /// Collections render as their size and strings are kept on one line, so
+    /// the expression is safe inside a comment.
+    fn expression(&self) -> String {
+        let filters = match self.kind {
+            "array" | "object" => " | length",
+            "null" => " | json_encode()",
+            "string" => r#" | replace(from="\n", to=" ")"#,
+            _ => "",
+        };
+        if self.optional {
+            format!("{{{{ {} | default(value=\"\"){filters} }}}}", self.name)
+        } else {
+            format!("{{{{ {}{filters} }}}}", self.name)
+        }
+    }
+
+    /// Short one-line rendering of the example value
+    fn example_text(&self) -> String {
+        let text = match &self.example {
+            JsonValue::Array(items) => format!("[{} item(s)]", items.len()),
+            JsonValue::Object(map) => format!("{{{} key(s)}}", map.len()),
+            value => value.to_string(),
+        };
+        let text = text.replace('\n', " ");
+        if text.chars().count() > EXAMPLE_WIDTH {
+            let truncated: String = text.chars().take(EXAMPLE_WIDTH - 3).collect();
+            format!("{truncated}...")
+        } else {
+            text
+        }
+    }
+}
+
+/// Variables available to templates of one protocol, role and language
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VariableReference {
+    pub protocol: Protocol,
+    pub role: Role,
+    pub language: Language,
+    /// Language whose context builder provided the variables; differs from
+    /// `language` while that language has no builder of its own
+    pub builder: Language,
+    /// Variables every template file sees
+    pub global: Vec<ContextVariable>,
+    /// Variables added for each item of a `for_each: endpoint` file
+    pub endpoint: Vec<ContextVariable>,
+}
+
+impl VariableReference {
+    /// Render the reference as aligned text
+    pub fn to_text(&self) -> String {
+        let mut out = format!(
+            "Variables from the {} context builder ({} {}):\n",
+            self.builder.display_name(),
+            self.protocol,
+            self.role
+        );
+        if self.builder != self.language {
+            let _ = writeln!(
+                out,
+                "{} has no context builder yet; add one under \
+                 src/infrastructure/generation/context_builders",
+                self.language.display_name()
+            );
+        }
+        if rules::validate_language_support(self.protocol, &self.role, self.language).is_err() {
+            let _ = writeln!(
+                out,
+                "{} is not yet enabled for {} {} generation; enable it in generation::rules",
+                self.language.display_name(),
+                self.protocol,
+                self.role
+            );
+        }
+        write_variables(&mut out, "Global", &self.global);
+        if !self.endpoint.is_empty() {
+            write_variables(
+                &mut out,
+                "Per endpoint (for_each: endpoint)",
+                &self.endpoint,
+            );
+        }
+        out
+    }
+}
+
+fn write_variables(out: &mut String, title: &str, variables: &[ContextVariable]) {
+    let width = variables
+        .iter()
+        .map(|var| var.name.len())
+        .max()
+        .unwrap_or(0);
+    let _ = writeln!(out, "\n{title}:");
+    for var in variables {
+        let kind = if var.optional {
+            format!("{}?", var.kind)
+        } else {
+            var.kind.to_string()
+        };
+        let _ = writeln!(
+            out,
+            "  {:<width$}  {:<8}  {}",
+            var.name,
+            kind,
+            var.example_text()
+        );
+    }
+}
+
+/// Starter template written by [`NewTemplateUseCase`]
+#[derive(Debug, Clone)]
+pub struct NewTemplate {
+    /// Directory the template was written to
+    pub directory: PathBuf,
+    /// Files written, relative to `directory`
+    pub files: Vec<String>,
+    pub variables: VariableReference,
+}
+
+/// Use case for creating a starter template
+#[derive(Debug, Default)]
+pub struct NewTemplateUseCase;
+
+impl NewTemplateUseCase {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Create a starter for `template`, a path such as `mcp/server/python`,
+    /// under `output_dir`
+    ///
+    /// The directory must not exist or be empty.
+    pub async fn execute(
+        &self,
+        template: &str,
+        output_dir: &Path,
+    ) -> Result<NewTemplate, ApplicationError> {
+        let (protocol, role, language) = parse_template_path(template)?;
+        let directory = output_dir.join(format!("{protocol}/{role}/{language}"));
+        if directory.exists()
+            && std::fs::read_dir(&directory)
+                .map_err(TemplateError::IoError)?
+                .next()
+                .is_some()
+        {
+            return Err(ApplicationError::OutputError(format!(
+                "{} already exists and is not empty",
+                directory.display()
+            )));
+        }
+
+        let variables = self.variable_reference(protocol, role, language).await?;
+        let files = starter_files(&variables);
+        for (path, content) in &files {
+            let path = directory.join(path);
+            if let Some(parent) = path.parent() {
+                tokio::fs::create_dir_all(parent)
+                    .await
+                    .map_err(TemplateError::IoError)?;
+            }
+            tokio::fs::write(&path, content)
+                .await
+                .map_err(TemplateError::IoError)?;
+            #[cfg(unix)]
+            if path.extension().is_some_and(|ext| ext == "sh") {
+                use std::os::unix::fs::PermissionsExt;
+                std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))
+                    .map_err(TemplateError::IoError)?;
+            }
+        }
+
+        Ok(NewTemplate {
+            directory,
+            files: files.into_iter().map(|(path, _)| path).collect(),
+            variables,
+        })
+    }
+
+    /// Variables the context builder for `language` provides
+    ///
+    /// Renders a probe template that dumps its context, against the bundled
+    /// petstore spec when the role needs one. A language generation does not
+    /// support yet is probed through a supported one, and a language without a
+    /// context builder falls back to the builder of that supported language.
+    pub async fn variable_reference(
+        &self,
+        protocol: Protocol,
+        role: Role,
+        language: Language,
+    ) -> Result<VariableReference, ApplicationError> {
+        let probe_language = if rules::validate_language_support(protocol, &role, language).is_ok()
+        {
+            language
+        } else {
+            Language::all()
+                .into_iter()
+                .find(|candidate| {
+                    rules::validate_language_support(protocol, &role, *candidate).is_ok()
+                })
+                .ok_or_else(|| {
+                    rules::validate_language_support(protocol, &role, language).unwrap_err()
+                })?
+        };
+
+        let builders = ContextBuilderRegistry::new();
+        let builder_language = if builders.get(language).is_ok() {
+            language
+        } else {
+            probe_language
+        };
+
+        let fixtures: Vec<Option<&FixtureSpec>> = if needs_spec(protocol, &role) {
+            FIXTURE_SPECS.iter().map(Some).collect()
+        } else {
+            vec![None]
+        };
+        let mut contexts = Vec::new();
+        for fixture in fixtures {
+            contexts.push(
+                probe_context(
+                    protocol,
+                    &role,
+                    probe_language,
+                    builder_language,
+                    builders.get(builder_language)?,
+                    fixture,
+                )
+                .await?,
+            );
+        }
+
+        let globals: Vec<_> = contexts.iter().collect();
+        let endpoints: Vec<_> = contexts
+            .iter()
+            .filter_map(|context| context.get("endpoints")?.as_array())
+            .flatten()
+            .filter_map(JsonValue::as_object)
+            .collect();
+        let mut endpoint = collect_variables(&endpoints);
+        if !endpoint.is_empty() {
+            endpoint.push(ContextVariable::new("index", &JsonValue::from(0), false));
+        }
+
+        Ok(VariableReference {
+            protocol,
+            role,
+            language,
+            builder: builder_language,
+            global: collect_variables(&globals),
+            endpoint,
+        })
+    }
+}
+
+/// Render a template that dumps its whole context, and parse the dump
+///
+/// The protocol handler prepares the context as for `probe_language`, then the
+/// builder for `builder_language` fills in the variables.
+async fn probe_context(
+    protocol: Protocol,
+    role: &Role,
+    probe_language: Language,
+    builder_language: Language,
+    builder: Arc<dyn ContextBuilder>,
+    fixture: Option<&FixtureSpec>,
+) -> Result<JsonMap<String, JsonValue>, ApplicationError> {
+    let registry = ProtocolRegistry::with_defaults()?;
+    let handler = registry
+        .get(protocol)
+        .ok_or(crate::protocols::ProtocolError::NotImplemented(protocol))?;
+
+    let mut options = HashMap::new();
+    let mut openapi_spec = None;
+    if let Some(fixture) = fixture {
+        let spec = serde_json::from_str(fixture.document).expect("bundled fixture specs are JSON");
+        openapi_spec = Some(OpenApiParser::new(spec).parse().await?);
+        if let Some(base_url) = fixture.base_url {
+            options.insert("base_api_url".to_string(), JsonValue::from(base_url));
+        }
+    }
+    let mut context = handler
+        .prepare_context(ProtocolInput {
+            role: role.clone(),
+            language: probe_language,
+            config: ProtocolConfig {
+                project_name: "starter".to_string(),
+                version: None,
+                options,
+            },
+            openapi_spec,
+            mcp_server: None,
+            mcp_tools: None,
+        })
+        .await?;
+    context.language = builder_language;
+
+    let manifest = TemplateManifest::from_yaml(
+        &format!(
+            "name: {protocol}-{role}-{builder_language}\nversion: \"0.1.0\"\nprotocol: {protocol}\nrole: {role}\nlanguage: {builder_language}\nfiles:\n  - source: {PROBE_SOURCE}\n    destination: context.json\n"
+        ),
+        "probe",
+    )?;
+    let probe = Template {
+        manifest,
+        files: vec![TemplateFile {
+            path: PathBuf::from(PROBE_SOURCE),
+            content: "{{ __tera_context }}".to_string(),
+            file_type: TemplateFileType::Template { for_each: None },
+        }],
+        source: TemplateSource::Embedded,
+    };
+    // The default renderer sees the same context as the protocol renderers,
+    // without their extra artifacts
+    let orchestrator = GenerationOrchestrator::new(
+        Arc::new(TemplateDiscoveryAdapter::new(Arc::new(LoadedTemplate(
+            probe,
+        )))),
+        builder,
+        Arc::new(DefaultTemplateRenderer::new()),
+        Arc::new(PermissionsPostProcessor::new()),
+    );
+    let result = orchestrator.generate(context).await?;
+
+    result
+        .artifacts
+        .iter()
+        .find(|artifact| artifact.path == Path::new("context.json"))
+        .and_then(|artifact| serde_json::from_str(&artifact.content).ok())
+        .ok_or_else(|| {
+            ApplicationError::InvalidTemplate(
+                "probe template did not render its context".to_string(),
+            )
+        })
+}
+
+/// Split `protocol/role/language` and check the protocol supports the role
+fn parse_template_path(template: &str) -> Result<(Protocol, Role, Language), ApplicationError> {
+    let parts: Vec<_> = template.trim_matches('/').split('/').collect();
+    let [protocol, role, language] = parts.as_slice() else {
+        return Err(ApplicationError::InvalidTemplate(format!(
+            "expected protocol/role/language (e.g. mcp/server/python), got '{template}'"
+        )));
+    };
+    let protocol: Protocol = protocol.parse()?;
+    let role: Role = role.parse()?;
+    let language: Language = language.parse()?;
+    if protocol.validate_role(&role).is_err() {
+        return Err(ValidationError::UnsupportedRole { protocol, role }.into());
+    }
+    Ok((protocol, role, language))
+}
+
+fn needs_spec(protocol: Protocol, role: &Role) -> bool {
+    *role == Role::Server && protocol.capabilities().requires_openapi
+}
+
+/// Union of the keys of `objects`, in first-seen order; keys some objects
+/// lack are optional
+fn collect_variables(objects: &[&JsonMap<String, JsonValue>]) -> Vec<ContextVariable> {
+    let mut variables: Vec<ContextVariable> = Vec::new();
+    for object in objects {
+        for (name, value) in *object {
+            if !is_identifier(name) || variables.iter().any(|var| var.name == *name) {
+                continue;
+            }
+            let optional = objects.iter().any(|other| !other.contains_key(name));
+            variables.push(ContextVariable::new(name, value, optional));
+        }
+    }
+    variables
+}
+
+/// Whether `name` can be used as a Tera variable
+fn is_identifier(name: &str) -> bool {
+    !name.starts_with("__")
+        && name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Files of the starter template, as relative path and content
+fn starter_files(variables: &VariableReference) -> Vec<(String, String)> {
+    let VariableReference {
+        protocol,
+        role,
+        language,
+        ..
+    } = variables;
+    let ext = language.file_extension();
+    let comment = language.line_comment();
+    let (main_path, main_body) = entry_point(*language);
+    let spec = needs_spec(*protocol, role).then_some(&FIXTURE_SPECS[0]);
+    let has_endpoints = !variables.endpoint.is_empty();
+
+    let mut manifest = format!(
+        "# Starter template created by `agenterra templates new`\n\
+         name: {protocol}-{role}-{language}\n\
+         version: \"0.1.0\"\n\
+         description: \"{} {} {role} template\"\n\
+         protocol: {protocol}\n\
+         role: {role}\n\
+         language: {language}\n\
+         \n\
+         files:\n  \
+         - source: README.md.tera\n    \
+         destination: README.md\n  \
+         - source: {main_path}.tera\n    \
+         destination: {main_path}\n",
+        language.display_name(),
+        protocol.to_string().to_uppercase(),
+    );
+    if has_endpoints {
+        let _ = write!(
+            manifest,
+            "  - source: src/handlers/endpoint.{ext}.tera\n    \
+             destination: \"src/handlers/{{endpoint}}.{ext}\"\n    \
+             for_each: endpoint\n"
+        );
+    }
+
+    let mut readme = String::from(
+        "# {{ project_name }}\n\n\
+         Every variable the context builder provides, with the value it had\n\
+         for this project.\n\n\
+         | Variable | Type | Value |\n\
+         |----------|------|-------|\n",
+    );
+    for var in &variables.global {
+        let _ = writeln!(
+            readme,
+            "| `{}` | {} | {} |",
+            var.name,
+            var.kind,
+            var.expression()
+        );
+    }
+
+    let main = format!(
+        "{comment} {{{{ project_name }}}}: entry point generated by the {protocol}-{role}-{language} template\n\
+         {comment} See README.md for every variable this template can use\n\n\
+         {main_body}"
+    );
+
+    let mut files = vec![
+        ("manifest.yml".to_string(), manifest),
+        ("README.md.tera".to_string(), readme),
+        (format!("{main_path}.tera"), main),
+    ];
+
+    if has_endpoints {
+        let mut handler = format!(
+            "{comment} Rendered once per endpoint, with these variables on top of the global ones\n{comment}\n"
+        );
+        for var in &variables.endpoint {
+            let _ = writeln!(handler, "{comment} {}: {}", var.name, var.expression());
+        }
+        files.push((format!("src/handlers/endpoint.{ext}.tera"), handler));
+    }
+
+    if let Some(spec) = spec {
+        files.push((
+            format!("template_tests/{}", spec.name),
+            spec.document.to_string(),
+        ));
+    }
+    files.push((
+        "template_tests/run.sh".to_string(),
+        test_script(variables, &main_path, spec.map(|spec| spec.name)),
+    ));
+    files
+}
+
+/// Entry point path and body for `language`
+fn entry_point(language: Language) -> (String, &'static str) {
+    let ext = language.file_extension();
+    match language {
+        Language::Rust => (
+            format!("src/main.{ext}"),
+            "fn main() {\n    println!(\"{{ project_name }}\");\n}\n",
+        ),
+        Language::Python => (
+            format!("src/main.{ext}"),
+            "def main() -> None:\n    print(\"{{ project_name }}\")\n\n\nif __name__ == \"__main__\":\n    main()\n",
+        ),
+        Language::TypeScript => (
+            format!("src/index.{ext}"),
+            "function main(): void {\n  console.log(\"{{ project_name }}\");\n}\n\nmain();\n",
+        ),
+        Language::Go => (
+            format!("main.{ext}"),
+            "package main\n\nimport \"fmt\"\n\nfunc main() {\n\tfmt.Println(\"{{ project_name }}\")\n}\n",
+        ),
+        Language::Java => (
+            format!("src/main/java/Main.{ext}"),
+            "public class Main {\n    public static void main(String[] args) {\n        System.out.println(\"{{ project_name }}\");\n    }\n}\n",
+        ),
+        Language::CSharp => (
+            format!("Program.{ext}"),
+            "Console.WriteLine(\"{{ project_name }}\");\n",
+        ),
+    }
+}
+
+/// Script that validates the template and scaffolds a project from it
+fn test_script(variables: &VariableReference, main_path: &str, spec: Option<&str>) -> String {
+    let VariableReference {
+        protocol,
+        role,
+        language,
+        ..
+    } = variables;
+    let mut scaffold = format!(
+        "\"$agenterra\" scaffold {protocol} {role} \\\n    \
+         --template {language} \\\n    \
+         --template-dir \"$template_dir\" \\\n    \
+         --output-dir \"$output_dir\" \\\n    \
+         --project-name starter_check"
+    );
+    if let Some(spec) = spec {
+        let _ = write!(
+            scaffold,
+            " \\\n    --schema-path \"$template_dir/template_tests/{spec}\""
+        );
+    }
+    let mut expected = vec!["README.md".to_string(), main_path.to_string()];
+    if !variables.endpoint.is_empty() {
+        expected.push("src/handlers".to_string());
+    }
+
+    format!(
+        "#!/bin/sh\n\
+         # Checks this template: validates it, then scaffolds a project from it\n\
+         # and checks the expected files were generated.\n\
+         #\n\
+         # Usage: template_tests/run.sh [path/to/agenterra]\n\
+         set -eu\n\
+         \n\
+         template_dir=\"$(cd \"$(dirname \"$0\")/..\" && pwd)\"\n\
+         agenterra=\"${{1:-agenterra}}\"\n\
+         output_dir=\"$(mktemp -d)\"\n\
+         trap 'rm -rf \"$output_dir\"' EXIT\n\
+         \n\
+         \"$agenterra\" templates validate \"$template_dir\"\n\
+         {scaffold}\n\
+         \n\
+         for path in {}; do\n    \
+         if [ ! -e \"$output_dir/starter_check/$path\" ]; then\n        \
+         echo \"missing $path\" >&2\n        \
+         exit 1\n    \
+         fi\n\
+         done\n\
+         echo \"template_tests passed\"\n",
+        expected.join(" ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ValidateTemplateUseCase;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_template_path() {
+        let (protocol, role, language) = parse_template_path("mcp/server/python").unwrap();
+        assert_eq!(
+            (protocol, role, language),
+            (Protocol::Mcp, Role::Server, Language::Python)
+        );
+        assert!(parse_template_path("mcp/server").is_err());
+        assert!(parse_template_path("mcp/agent/rust").is_err());
+        assert!(parse_template_path("mcp/server/cobol").is_err());
+
+        // Any protocol takes custom roles
+        let (_, role, _) = parse_template_path("mcp/reviewer/rust").unwrap();
+        assert_eq!(role, Role::Custom("reviewer".to_string()));
+    }
+
+    #[test]
+    fn test_expression() {
+        let var = |name, value, optional| ContextVariable::new(name, &value, optional);
+        assert_eq!(
+            var("endpoints", serde_json::json!([1, 2]), false).expression(),
+            "{{ endpoints | length }}"
+        );
+        assert_eq!(
+            var("summary", serde_json::json!("Find pets"), true).expression(),
+            r#"{{ summary | default(value="") | replace(from="\n", to=" ") }}"#
+        );
+        assert_eq!(
+            var("port", serde_json::json!(8080), false).expression(),
+            "{{ port }}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_starter_templates_are_valid() {
+        let dir = TempDir::new().unwrap();
+        for template in ["mcp/server/rust", "mcp/client/rust"] {
+            let created = NewTemplateUseCase::new()
+                .execute(template, dir.path())
+                .await
+                .unwrap();
+            assert!(!created.variables.global.is_empty());
+            assert_eq!(
+                created.variables.endpoint.is_empty(),
+                template.contains("client"),
+                "{template}"
+            );
+            for file in &created.files {
+                assert!(created.directory.join(file).is_file(), "{file}");
+            }
+
+            let report = ValidateTemplateUseCase::new()
+                .execute(created.directory.to_str().unwrap())
+                .await
+                .unwrap();
+            assert!(report.issues.is_empty(), "{}", report.to_text());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_new_language_uses_its_context_builder() {
+        let use_case = NewTemplateUseCase::new();
+        let python = use_case
+            .variable_reference(Protocol::Mcp, Role::Server, Language::Python)
+            .await
+            .unwrap();
+        assert_eq!(python.builder, Language::Python);
+        assert!(!python.endpoint.is_empty());
+        assert!(!python.to_text().contains("not yet enabled"));
+
+        let python_client = use_case
+            .variable_reference(Protocol::Mcp, Role::Client, Language::Python)
+            .await
+            .unwrap();
+        assert!(!python_client.to_text().contains("not yet enabled"));
+
+        let go_client = use_case
+            .variable_reference(Protocol::Mcp, Role::Client, Language::Go)
+            .await
+            .unwrap();
+        assert!(
+            go_client
+                .to_text()
+                .contains("Go is not yet enabled for mcp client generation")
+        );
+
+        let go = use_case
+            .variable_reference(Protocol::Mcp, Role::Server, Language::Go)
+            .await
+            .unwrap();
+        assert_eq!(go.builder, Language::Go);
+        assert!(!go.to_text().contains("no context builder yet"));
+
+        let java = use_case
+            .variable_reference(Protocol::Mcp, Role::Client, Language::Java)
+            .await
+            .unwrap();
+        assert_eq!(java.builder, Language::Rust);
+        assert!(java.to_text().contains("Java has no context builder yet"));
+    }
+
+    #[tokio::test]
+    async fn test_existing_directory_is_not_overwritten() {
+        let dir = TempDir::new().unwrap();
+        let target = dir.path().join("mcp/client/python");
+        std::fs::create_dir_all(&target).unwrap();
+        std::fs::write(target.join("manifest.yml"), "name: mine").unwrap();
+
+        let err = NewTemplateUseCase::new()
+            .execute("mcp/client/python", dir.path())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ApplicationError::OutputError(_)));
+        assert_eq!(
+            std::fs::read_to_string(target.join("manifest.yml")).unwrap(),
+            "name: mine"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_variable_reference_text() {
+        let reference = NewTemplateUseCase::new()
+            .variable_reference(Protocol::Mcp, Role::Server, Language::Rust)
+            .await
+            .unwrap();
+        let text = reference.to_text();
+        assert!(text.starts_with("Variables from the Rust context builder (mcp server):"));
+        assert!(text.contains("Per endpoint (for_each: endpoint):"));
+        assert!(
+            reference
+                .global
+                .iter()
+                .any(|var| var.name == "project_name" && var.kind == "string")
+        );
+    }
+}
@@ -0,0 +1,844 @@
+//! Template validation use case
+//!
+//! Checks a template the way generation would use it, without writing
+//! anything: the manifest parses, every `source` exists, every `.tera` file
+//! compiles, `for_each` and `when` are valid, hooks point at scripts that
+//! exist, and the templates render against the bundled fixture specs without
+//! undefined variables. Problems are reported with the file and line they
+//! concern.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::path::Path;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+use crate::application::ApplicationError;
+use crate::generation::{GenerationError, Language, Severity, TemplateDiscoveryAdapter};
+use crate::generator::Generator;
+use crate::infrastructure::generation::for_each::ForEach;
+use crate::infrastructure::generation::template_functions;
+use crate::infrastructure::{
+    EmbeddedTemplateRepository, FileSystemTemplateLoader, Template, TemplateDiscovery,
+    TemplateError, TemplateLoader, TemplateManifest, TemplateRepository, VariableType,
+    filesystem_loader,
+};
+use crate::protocols::{Protocol, Role};
+
+/// Stable template issue codes
+pub mod codes {
+    /// `manifest.yml` is missing, is not valid YAML or has invalid fields
+    pub const MANIFEST_INVALID: &str = "manifest-invalid";
+    /// A `files:` entry names a source that does not exist
+    pub const SOURCE_MISSING: &str = "source-missing";
+    /// A `.tera` file or `when:` expression does not compile
+    pub const TEMPLATE_SYNTAX: &str = "template-syntax";
+    /// A `for_each` value names an unsupported collection
+    pub const FOR_EACH_INVALID: &str = "for-each-invalid";
+    /// A template uses a variable the context does not provide
+    pub const UNDEFINED_VARIABLE: &str = "undefined-variable";
+    /// Rendering against a fixture failed for another reason
+    pub const RENDER_FAILED: &str = "render-failed";
+    /// A hook runs a script that is not part of the template
+    pub const HOOK_MISSING: &str = "hook-missing";
+    /// A script under `hooks/` is not declared as a hook
+    pub const HOOK_UNDECLARED: &str = "hook-undeclared";
+    /// A file is neither listed under `files:`, a partial nor a hook script
+    pub const FILE_UNLISTED: &str = "file-unlisted";
+}
+
+/// Spec a server template is rendered against
+pub(super) struct FixtureSpec {
+    /// Name of the test fixture the document comes from
+    pub(super) name: &'static str,
+    pub(super) document: &'static str,
+    /// Passed as `--base-url` would be, for specs that declare no servers
+    pub(super) base_url: Option<&'static str>,
+}
+
+pub(super) const FIXTURE_SPECS: &[FixtureSpec] = &[
+    FixtureSpec {
+        name: "petstore.openapi.v3.json",
+        document: include_str!("../../tests/fixtures/openapi/petstore.openapi.v3.json"),
+        base_url: None,
+    },
+    FixtureSpec {
+        name: "petstore.swagger.v2.json",
+        document: include_str!("../../tests/fixtures/openapi/petstore.swagger.v2.json"),
+        base_url: Some("https://petstore.swagger.io/v2"),
+    },
+];
+
+/// Project name used when rendering against fixtures
+const FIXTURE_PROJECT_NAME: &str = "template_check";
+
+/// A single problem found in a template
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TemplateIssue {
+    pub severity: Severity,
+    pub code: String,
+    /// File relative to the template directory
+    pub file: String,
+    /// 1-based line, when the problem can be located
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl TemplateIssue {
+    fn new(
+        severity: Severity,
+        code: &str,
+        file: impl Into<String>,
+        line: Option<usize>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            severity,
+            code: code.to_string(),
+            file: file.into(),
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for TemplateIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}[{}] {}", self.severity, self.code, self.file)?;
+        if let Some(line) = self.line {
+            write!(f, ":{line}")?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// Outcome of validating one template
+#[derive(Debug, Clone, Serialize)]
+pub struct TemplateValidationReport {
+    pub template: String,
+    pub issues: Vec<TemplateIssue>,
+}
+
+impl TemplateValidationReport {
+    fn new(template: impl Into<String>) -> Self {
+        Self {
+            template: template.into(),
+            issues: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, issue: TemplateIssue) {
+        if !self.issues.contains(&issue) {
+            self.issues.push(issue);
+        }
+    }
+
+    pub fn errors(&self) -> usize {
+        self.count(Severity::Error)
+    }
+
+    pub fn warnings(&self) -> usize {
+        self.count(Severity::Warning)
+    }
+
+    fn count(&self, severity: Severity) -> usize {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == severity)
+            .count()
+    }
+
+    /// Whether the template can be used for generation
+    pub fn is_valid(&self) -> bool {
+        self.errors() == 0
+    }
+
+    /// Human-readable report printed by `templates validate`
+    pub fn to_text(&self) -> String {
+        let mut text = format!("Validated template {}\n", self.template);
+        for issue in &self.issues {
+            let _ = writeln!(text, "  {issue}");
+        }
+        let _ = write!(
+            text,
+            "{} error(s), {} warning(s)",
+            self.errors(),
+            self.warnings()
+        );
+        text
+    }
+}
+
+/// Use case for validating a template directory or embedded template
+pub struct ValidateTemplateUseCase {
+    repository: EmbeddedTemplateRepository,
+    loader: FileSystemTemplateLoader,
+}
+
+impl ValidateTemplateUseCase {
+    pub fn new() -> Self {
+        Self {
+            repository: EmbeddedTemplateRepository::new(),
+            loader: FileSystemTemplateLoader::new(),
+        }
+    }
+
+    /// Validate `template`, a directory holding a `manifest.yml` or an
+    /// embedded template path such as `mcp/server/rust`
+    pub async fn execute(
+        &self,
+        template: &str,
+    ) -> Result<TemplateValidationReport, ApplicationError> {
+        let dir = Path::new(template);
+        let files = if dir.is_dir() {
+            let mut files = HashMap::new();
+            for path in filesystem_loader::list_files(dir).await? {
+                let content = tokio::fs::read(dir.join(&path))
+                    .await
+                    .map_err(TemplateError::IoError)?;
+                files.insert(path, String::from_utf8_lossy(&content).to_string());
+            }
+            files
+        } else if self.repository.has_template(template) {
+            self.repository
+                .get_template_files(template)
+                .into_iter()
+                .map(|file| {
+                    let content = String::from_utf8_lossy(&file.contents).to_string();
+                    (file.relative_path, content)
+                })
+                .collect()
+        } else {
+            return Err(ApplicationError::TemplateNotFound(template.to_string()));
+        };
+
+        let mut report = TemplateValidationReport::new(template);
+        let Some((manifest_name, manifest)) = check_manifest(template, &files, &mut report) else {
+            return Ok(report);
+        };
+        let manifest_text = &files[&manifest_name];
+
+        check_entries(
+            &manifest,
+            &manifest_name,
+            manifest_text,
+            &files,
+            &mut report,
+        );
+        check_hooks(
+            &manifest,
+            &manifest_name,
+            manifest_text,
+            &files,
+            &mut report,
+        );
+        check_syntax(&files, &mut report);
+        if !report.is_valid() {
+            return Ok(report);
+        }
+
+        let loaded = if dir.is_dir() {
+            self.loader.load_template(dir).await
+        } else {
+            self.repository.load(template)
+        };
+        let loaded = match loaded {
+            Ok(loaded) => loaded,
+            Err(e) => {
+                report.push(TemplateIssue::new(
+                    Severity::Error,
+                    codes::MANIFEST_INVALID,
+                    &manifest_name,
+                    line_of(manifest_text, "extends"),
+                    e.to_string(),
+                ));
+                return Ok(report);
+            }
+        };
+
+        check_unlisted(&loaded.manifest, &manifest_name, &files, &mut report);
+        for entry in &loaded.manifest.files {
+            if !loaded
+                .files
+                .iter()
+                .any(|file| file.path.to_string_lossy() == entry.source)
+            {
+                report.push(TemplateIssue::new(
+                    Severity::Error,
+                    codes::SOURCE_MISSING,
+                    &manifest_name,
+                    line_of(manifest_text, &entry.source),
+                    format!(
+                        "source '{}' exists in neither the template nor its parent",
+                        entry.source
+                    ),
+                ));
+            }
+        }
+        if let Err(e) = template_functions::load_template(&loaded) {
+            report.push(TemplateIssue::new(
+                Severity::Error,
+                codes::TEMPLATE_SYNTAX,
+                &manifest_name,
+                None,
+                e.to_string(),
+            ));
+        }
+        if report.is_valid() {
+            render_fixtures(loaded, &manifest_name, &files, &mut report).await;
+        }
+
+        Ok(report)
+    }
+}
+
+impl Default for ValidateTemplateUseCase {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parse the manifest, returning its file name and contents
+fn check_manifest(
+    template: &str,
+    files: &HashMap<String, String>,
+    report: &mut TemplateValidationReport,
+) -> Option<(String, TemplateManifest)> {
+    let Some(name) = ["manifest.yml", "manifest.yaml"]
+        .into_iter()
+        .find(|name| files.contains_key(*name))
+    else {
+        report.push(TemplateIssue::new(
+            Severity::Error,
+            codes::MANIFEST_INVALID,
+            "manifest.yml",
+            None,
+            "no manifest.yml or manifest.yaml found",
+        ));
+        return None;
+    };
+    let text = &files[name];
+
+    if let Err(e) = serde_yaml::from_str::<serde_yaml::Value>(text) {
+        report.push(TemplateIssue::new(
+            Severity::Error,
+            codes::MANIFEST_INVALID,
+            name,
+            e.location().map(|location| location.line()),
+            e.to_string(),
+        ));
+        return None;
+    }
+
+    match TemplateManifest::from_yaml(text, template) {
+        Ok(manifest) => Some((name.to_string(), manifest)),
+        Err(e) => {
+            report.push(TemplateIssue::new(
+                Severity::Error,
+                codes::MANIFEST_INVALID,
+                name,
+                None,
+                e.to_string(),
+            ));
+            None
+        }
+    }
+}
+
+/// Check that sources exist and that `for_each` and `when` are valid
+fn check_entries(
+    manifest: &TemplateManifest,
+    manifest_name: &str,
+    manifest_text: &str,
+    files: &HashMap<String, String>,
+    report: &mut TemplateValidationReport,
+) {
+    use crate::infrastructure::TemplateFileType;
+
+    for entry in &manifest.files {
+        let line = line_of(manifest_text, &entry.source);
+
+        // Sources of an extending template may come from the parent, which
+        // is checked once inheritance is resolved
+        if manifest.extends.is_none() && !files.contains_key(&entry.source) {
+            report.push(TemplateIssue::new(
+                Severity::Error,
+                codes::SOURCE_MISSING,
+                manifest_name,
+                line,
+                format!("source '{}' does not exist", entry.source),
+            ));
+        }
+
+        if let TemplateFileType::Template {
+            for_each: Some(for_each),
+        } = &entry.file_type
+            && let Err(e) = for_each.parse::<ForEach>()
+        {
+            report.push(TemplateIssue::new(
+                Severity::Error,
+                codes::FOR_EACH_INVALID,
+                manifest_name,
+                line_of(manifest_text, &format!("for_each: {for_each}")).or(line),
+                e.to_string(),
+            ));
+        }
+
+        if let Some(when) = &entry.when {
+            let expression = format!("{{% if {when} %}}true{{% endif %}}");
+            if let Err(e) = tera::Template::new(&entry.source, None, &expression) {
+                report.push(TemplateIssue::new(
+                    Severity::Error,
+                    codes::TEMPLATE_SYNTAX,
+                    manifest_name,
+                    line_of(manifest_text, when).or(line),
+                    format!(
+                        "invalid when '{when}' of '{}': {}",
+                        entry.source,
+                        template_functions::describe_error(&e)
+                    ),
+                ));
+            }
+        }
+    }
+}
+
+/// Check that hooks run scripts that exist and that scripts are declared
+fn check_hooks(
+    manifest: &TemplateManifest,
+    manifest_name: &str,
+    manifest_text: &str,
+    files: &HashMap<String, String>,
+    report: &mut TemplateValidationReport,
+) {
+    let scripts: Vec<_> = manifest
+        .pre_generate_hooks
+        .iter()
+        .chain(&manifest.post_generate_hooks)
+        .filter_map(|hook| hook_script(&hook.command).map(|script| (&hook.command, script)))
+        .collect();
+
+    for (hook, script) in &scripts {
+        if !files.contains_key(script) {
+            report.push(TemplateIssue::new(
+                Severity::Error,
+                codes::HOOK_MISSING,
+                manifest_name,
+                line_of(manifest_text, hook),
+                format!("hook '{hook}' runs '{script}', which is not part of the template"),
+            ));
+        }
+    }
+
+    for path in files.keys().filter(|path| path.starts_with("hooks/")) {
+        if !scripts.iter().any(|(_, script)| script == path) {
+            report.push(TemplateIssue::new(
+                Severity::Warning,
+                codes::HOOK_UNDECLARED,
+                path,
+                None,
+                "script is not declared under hooks.pre_generate or hooks.post_generate",
+            ));
+        }
+    }
+}
+
+/// Compile every `.tera` file on its own, so each error points at its file
+fn check_syntax(files: &HashMap<String, String>, report: &mut TemplateValidationReport) {
+    let mut sources: Vec<_> = files
+        .keys()
+        .filter(|path| path.ends_with(".tera"))
+        .collect();
+    sources.sort();
+
+    for source in sources {
+        if let Err(e) = tera::Template::new(source, None, &files[source]) {
+            let message = template_functions::describe_error(&e);
+            report.push(TemplateIssue::new(
+                Severity::Error,
+                codes::TEMPLATE_SYNTAX,
+                source,
+                parse_error_line(&message),
+                message,
+            ));
+        }
+    }
+}
+
+/// Warn about files generation never reads
+fn check_unlisted(
+    manifest: &TemplateManifest,
+    manifest_name: &str,
+    files: &HashMap<String, String>,
+    report: &mut TemplateValidationReport,
+) {
+    let mut paths: Vec<_> = files.keys().collect();
+    paths.sort();
+
+    for path in paths {
+        // Hidden files such as `.gitkeep` only keep directories around
+        let hidden = path
+            .rsplit('/')
+            .next()
+            .is_some_and(|name| name.starts_with('.'));
+        let listed = hidden
+            || path == manifest_name
+            || path.starts_with("hooks/")
+            || path.starts_with("template_tests/")
+            || manifest.files.iter().any(|entry| entry.source == *path)
+            || manifest.is_partial(path);
+        if !listed {
+            report.push(TemplateIssue::new(
+                Severity::Warning,
+                codes::FILE_UNLISTED,
+                path,
+                None,
+                "file is not listed under files: or partials:",
+            ));
+        }
+    }
+}
+
+/// Template whose files are already loaded
+pub(super) struct LoadedTemplate(pub(super) Template);
+
+#[async_trait]
+impl TemplateDiscovery for LoadedTemplate {
+    async fn discover(
+        &self,
+        _protocol: Protocol,
+        _role: Role,
+        _language: Language,
+    ) -> Result<Template, TemplateError> {
+        Ok(self.0.clone())
+    }
+}
+
+/// Render the template against each fixture spec
+///
+/// A file that fails to render is reported and left out of the next attempt,
+/// so one run reports every broken file rather than the first.
+async fn render_fixtures(
+    template: Template,
+    manifest_name: &str,
+    files: &HashMap<String, String>,
+    report: &mut TemplateValidationReport,
+) {
+    let manifest = &template.manifest;
+    // Bridges take their tools from a spec when no MCP server is at hand
+    let needs_spec = match manifest.role {
+        Role::Server => manifest.protocol.capabilities().requires_openapi,
+        Role::Bridge => true,
+        _ => false,
+    };
+    let fixtures: Vec<Option<&FixtureSpec>> = if needs_spec {
+        FIXTURE_SPECS.iter().map(Some).collect()
+    } else {
+        vec![None]
+    };
+
+    // Required variables without defaults get a value of their declared type
+    let placeholders: HashMap<String, JsonValue> = manifest
+        .variable_schema
+        .iter()
+        .filter(|var| var.required && var.default.is_none())
+        .map(|var| (var.name.clone(), placeholder(var.var_type)))
+        .collect();
+
+    for fixture in fixtures {
+        let mut remaining = template.clone();
+        loop {
+            let mut builder = Generator::builder()
+                .protocol(manifest.protocol)
+                .role(manifest.role.clone())
+                .language(manifest.language)
+                .project_name(FIXTURE_PROJECT_NAME)
+                .variables(placeholders.clone());
+            if let Some(fixture) = fixture {
+                let spec =
+                    serde_json::from_str(fixture.document).expect("bundled fixture specs are JSON");
+                builder = builder.spec_document(spec);
+                if let Some(base_url) = fixture.base_url {
+                    builder = builder.variable("base_api_url", JsonValue::from(base_url));
+                }
+            }
+            let fixture_note = fixture
+                .map(|fixture| format!(" (rendering against {})", fixture.name))
+                .unwrap_or_default();
+
+            let result = match builder.build() {
+                Ok(generator) => {
+                    let discovery = Arc::new(TemplateDiscoveryAdapter::new(Arc::new(
+                        LoadedTemplate(remaining.clone()),
+                    )));
+                    generator.generate_with(discovery).await
+                }
+                Err(e) => Err(e),
+            };
+
+            let message = match result {
+                Ok(_) => break,
+                Err(ApplicationError::GenerationError(GenerationError::RenderError(message))) => {
+                    message
+                }
+                Err(e) => {
+                    report.push(TemplateIssue::new(
+                        Severity::Error,
+                        codes::RENDER_FAILED,
+                        manifest_name,
+                        None,
+                        format!("{e}{fixture_note}"),
+                    ));
+                    break;
+                }
+            };
+
+            let failed = quoted_after(&message, "Failed to render template '");
+            report.push(render_issue(
+                &message,
+                failed,
+                manifest_name,
+                files,
+                &fixture_note,
+            ));
+
+            // Drop the failing file and try again
+            let Some(failed) = failed else { break };
+            let before = remaining.manifest.files.len();
+            remaining
+                .manifest
+                .files
+                .retain(|entry| entry.source != failed);
+            if remaining.manifest.files.len() == before {
+                break;
+            }
+        }
+    }
+}
+
+/// Turn a render error into an issue at the file and line it concerns
+fn render_issue(
+    message: &str,
+    failed: Option<&str>,
+    manifest_name: &str,
+    files: &HashMap<String, String>,
+    fixture_note: &str,
+) -> TemplateIssue {
+    if let Some(variable) = message
+        .split_once("Variable `")
+        .and_then(|(_, rest)| rest.split_once('`'))
+        .map(|(variable, _)| variable)
+    {
+        // The innermost template, which may be a partial the failed file includes
+        let file = quoted_after(message, "while rendering '")
+            .or(failed)
+            .unwrap_or(manifest_name);
+        let line = files
+            .get(file)
+            .and_then(|content| variable_line(content, variable));
+        return TemplateIssue::new(
+            Severity::Error,
+            codes::UNDEFINED_VARIABLE,
+            file,
+            line,
+            format!("variable `{variable}` is not defined"),
+        );
+    }
+
+    TemplateIssue::new(
+        Severity::Error,
+        codes::RENDER_FAILED,
+        failed.unwrap_or(manifest_name),
+        None,
+        format!("{message}{fixture_note}"),
+    )
+}
+
+/// Example value for a required variable
+fn placeholder(var_type: VariableType) -> JsonValue {
+    match var_type {
+        VariableType::Any | VariableType::String => JsonValue::from("example"),
+        VariableType::Integer => JsonValue::from(1),
+        VariableType::Number => JsonValue::from(1.0),
+        VariableType::Boolean => JsonValue::from(true),
+        VariableType::Array => JsonValue::Array(Vec::new()),
+        VariableType::Object => JsonValue::Object(Default::default()),
+    }
+}
+
+/// Script a hook runs from the template, when it runs one
+///
+/// Hooks such as `cargo fmt` run installed tools; a first word containing a
+/// `/`, such as `hooks/post-generate.sh` or `./hooks/fix.sh`, is a script
+/// shipped with the template.
+fn hook_script(hook: &str) -> Option<String> {
+    let command = hook.split_whitespace().next()?;
+    if !command.contains('/') || Path::new(command).is_absolute() {
+        return None;
+    }
+    Some(command.trim_start_matches("./").to_string())
+}
+
+/// 1-based line of the first occurrence of `needle`
+fn line_of(text: &str, needle: &str) -> Option<usize> {
+    text.lines()
+        .position(|line| line.contains(needle))
+        .map(|index| index + 1)
+}
+
+/// Line of a variable, preferring lines where it sits inside a Tera tag
+fn variable_line(content: &str, variable: &str) -> Option<usize> {
+    content
+        .lines()
+        .position(|line| line.contains(variable) && (line.contains("{{") || line.contains("{%")))
+        .map(|index| index + 1)
+        .or_else(|| line_of(content, variable))
+}
+
+/// Line of a Tera parse error, from its ` --> line:column` marker
+fn parse_error_line(message: &str) -> Option<usize> {
+    let (_, position) = message.split_once("--> ")?;
+    position.split(':').next()?.trim().parse().ok()
+}
+
+/// Text between `prefix` and the next `'`
+fn quoted_after<'a>(message: &'a str, prefix: &str) -> Option<&'a str> {
+    let (_, rest) = message.split_once(prefix)?;
+    rest.split_once('\'').map(|(quoted, _)| quoted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const MANIFEST: &str = r#"name: broken
+version: "1.0.0"
+protocol: mcp
+role: server
+language: rust
+files:
+  - source: main.rs.tera
+    destination: src/main.rs
+  - source: missing.rs.tera
+    destination: src/missing.rs
+  - source: handler.rs.tera
+    destination: "src/{endpoint}.rs"
+    for_each: endpoints
+  - source: syntax.rs.tera
+    destination: src/syntax.rs
+hooks:
+  post_generate:
+    - cargo fmt
+    - hooks/format.sh
+"#;
+
+    async fn validate(files: &[(&str, &str)]) -> TemplateValidationReport {
+        let dir = TempDir::new().unwrap();
+        for (path, content) in files {
+            let path = dir.path().join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+        ValidateTemplateUseCase::new()
+            .execute(dir.path().to_str().unwrap())
+            .await
+            .unwrap()
+    }
+
+    fn issue<'a>(report: &'a TemplateValidationReport, code: &str) -> &'a TemplateIssue {
+        report
+            .issues
+            .iter()
+            .find(|issue| issue.code == code)
+            .unwrap_or_else(|| panic!("no {code} in {:#?}", report.issues))
+    }
+
+    #[tokio::test]
+    async fn test_static_checks() {
+        let report = validate(&[
+            ("manifest.yml", MANIFEST),
+            ("main.rs.tera", "fn main() {}\n"),
+            ("handler.rs.tera", "// {{ endpoint }}\n"),
+            ("syntax.rs.tera", "fn a() {}\n{% if %}\n"),
+            ("notes.txt", "scratch"),
+            ("hooks/unused.sh", "#!/bin/sh"),
+        ])
+        .await;
+
+        let missing = issue(&report, codes::SOURCE_MISSING);
+        assert_eq!(missing.file, "manifest.yml");
+        assert_eq!(missing.line, Some(9));
+
+        assert_eq!(issue(&report, codes::FOR_EACH_INVALID).line, Some(13));
+
+        let syntax = issue(&report, codes::TEMPLATE_SYNTAX);
+        assert_eq!(syntax.file, "syntax.rs.tera");
+        assert_eq!(syntax.line, Some(2));
+
+        let hook = issue(&report, codes::HOOK_MISSING);
+        assert_eq!(hook.line, Some(19));
+        assert_eq!(
+            issue(&report, codes::HOOK_UNDECLARED).file,
+            "hooks/unused.sh"
+        );
+
+        assert!(!report.is_valid());
+        assert!(
+            report
+                .to_text()
+                .contains("error[source-missing] manifest.yml:9:")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_undefined_variables_are_located() {
+        let report = validate(&[
+            (
+                "manifest.yml",
+                "name: vars\nversion: \"1.0.0\"\nprotocol: mcp\nrole: server\nlanguage: rust\nfiles:\n  - source: main.rs.tera\n    destination: src/main.rs\n  - source: handler.rs.tera\n    destination: \"src/{endpoint}.rs\"\n    for_each: endpoint\n  - source: lib.rs.tera\n    destination: src/lib.rs\n",
+            ),
+            ("main.rs.tera", "// {{ project_name }}\n// {{ missing_name }}\n"),
+            ("handler.rs.tera", "{% include \"_partials/doc.tera\" %}\n"),
+            ("_partials/doc.tera", "/// {{ endpoint }}\n/// {{ endpoint_summary }}\n"),
+            ("lib.rs.tera", "// fine\n"),
+            ("notes.txt", "scratch"),
+        ])
+        .await;
+
+        let undefined: Vec<_> = report
+            .issues
+            .iter()
+            .filter(|issue| issue.code == codes::UNDEFINED_VARIABLE)
+            .map(|issue| (issue.file.as_str(), issue.line))
+            .collect();
+        assert_eq!(
+            undefined,
+            vec![("main.rs.tera", Some(2)), ("_partials/doc.tera", Some(2))]
+        );
+        assert_eq!(issue(&report, codes::FILE_UNLISTED).file, "notes.txt");
+    }
+
+    #[tokio::test]
+    async fn test_embedded_templates_are_valid() {
+        let use_case = ValidateTemplateUseCase::new();
+        for manifest in EmbeddedTemplateRepository::new().list_manifests() {
+            let report = use_case.execute(&manifest.path).await.unwrap();
+            assert!(report.issues.is_empty(), "{}", report.to_text());
+        }
+    }
+
+    #[test]
+    fn test_hook_script() {
+        assert_eq!(hook_script("cargo fmt"), None);
+        assert_eq!(
+            hook_script("hooks/fix.sh --all"),
+            Some("hooks/fix.sh".into())
+        );
+        assert_eq!(hook_script("./hooks/fix.sh"), Some("hooks/fix.sh".into()));
+        assert_eq!(hook_script("/usr/bin/true"), None);
+    }
+}
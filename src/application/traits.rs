@@ -3,6 +3,8 @@
 use async_trait::async_trait;
 use std::path::Path;
 
+use crate::infrastructure::{HookStage, TemplateHook};
+
 /// Service for writing generated artifacts to the output destination
 #[async_trait]
 pub trait OutputService: Send + Sync {
@@ -18,3 +20,20 @@ pub trait OutputService: Send + Sync {
         path: &Path,
     ) -> Result<(), crate::application::ApplicationError>;
 }
+
+/// Service for running template hooks in the generated project
+#[async_trait]
+pub trait HookRunner: Send + Sync {
+    /// Run the hooks of one stage in `working_dir`, in order
+    ///
+    /// Hooks that fail with `continue_on_error` set are returned as warnings;
+    /// any other failure stops the run with [`ApplicationError::HookFailed`].
+    ///
+    /// [`ApplicationError::HookFailed`]: crate::application::ApplicationError::HookFailed
+    async fn run_hooks(
+        &self,
+        stage: HookStage,
+        hooks: &[TemplateHook],
+        working_dir: &Path,
+    ) -> Result<Vec<crate::generation::Diagnostic>, crate::application::ApplicationError>;
+}
@@ -58,6 +58,12 @@ impl<T: InfrastructureTemplateDiscovery> TemplateDiscovery for TemplateDiscovery
                 TemplateError::YamlError(e) => {
                     GenerationError::LoadError(format!("YAML error: {e}"))
                 }
+                TemplateError::InvalidVariables(msg) => {
+                    GenerationError::ValidationError(format!("Invalid template variables: {msg}"))
+                }
+                TemplateError::FetchError(msg) => {
+                    GenerationError::LoadError(format!("Failed to fetch template: {msg}"))
+                }
             })
     }
 }
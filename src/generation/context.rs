@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
 
-use crate::generation::{Language, ProtocolContext};
+use crate::generation::{Diagnostics, Language, ProtocolContext};
 use crate::protocols::{Protocol, Role};
 
 /// The main generation context that flows through the generation workflow
@@ -16,6 +16,8 @@ pub struct GenerationContext {
     pub variables: HashMap<String, JsonValue>,
     pub metadata: GenerationMetadata,
     pub protocol_context: Option<ProtocolContext>,
+    /// Collector shared by every stage of the generation run
+    pub diagnostics: Diagnostics,
 }
 
 /// Metadata about the generation
@@ -39,6 +41,7 @@ impl GenerationContext {
             variables: HashMap::new(),
             metadata: GenerationMetadata::default(),
             protocol_context: None,
+            diagnostics: Diagnostics::new(),
         }
     }
 
@@ -0,0 +1,204 @@
+//! Diagnostics collected while generating code
+//!
+//! The parser, context builders and renderers report anything they degrade or
+//! skip here instead of dropping it silently. Each entry points at the part of
+//! the spec it concerns with a JSON pointer (RFC 6901).
+
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+
+/// Stable diagnostic codes
+pub mod codes {
+    /// A parameter could not be parsed and was left out of the operation
+    pub const PARAMETER_DROPPED: &str = "parameter-dropped";
+    /// An operation has no `operationId`, so one was derived from its method and path
+    pub const OPERATION_ID_GENERATED: &str = "operation-id-generated";
+    /// The spec declares no servers to derive the backend base URL from
+    pub const NO_SERVERS: &str = "no-servers";
+    /// A response has content, but none of it is `application/json`
+    pub const RESPONSE_NOT_JSON: &str = "response-not-json";
+    /// A request body has content, but none of it is `application/json`
+    pub const REQUEST_BODY_NOT_JSON: &str = "request-body-not-json";
+    /// A `oneOf`/`anyOf`/`allOf` schema was mapped to an untyped value
+    pub const SCHEMA_COMPOSITION_UNTYPED: &str = "schema-composition-untyped";
+    /// A schema without a `type` was mapped to a fallback type
+    pub const SCHEMA_TYPE_MISSING: &str = "schema-type-missing";
+    /// Two generated files resolved to the same path, so the later one replaced the earlier
+    pub const ARTIFACT_PATH_COLLISION: &str = "artifact-path-collision";
+    /// A template hook marked `continue_on_error` failed
+    pub const HOOK_FAILED: &str = "hook-failed";
+}
+
+/// How serious a diagnostic is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Info => write!(f, "info"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// A single diagnostic entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: String,
+    /// JSON pointer into the spec, empty when the entry concerns the whole document
+    pub pointer: String,
+    pub message: String,
+}
+
+impl Diagnostic {
+    pub fn new(
+        severity: Severity,
+        code: impl Into<String>,
+        pointer: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            severity,
+            code: code.into(),
+            pointer: pointer.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}[{}]", self.severity, self.code)?;
+        if !self.pointer.is_empty() {
+            write!(f, " {}", self.pointer)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// Thread-safe diagnostics collector
+///
+/// Clones share the same buffer, so a collector handed to a cloned
+/// `GenerationContext` still reports into the original run.
+#[derive(Debug, Clone, Default)]
+pub struct Diagnostics {
+    entries: Arc<Mutex<Vec<Diagnostic>>>,
+}
+
+impl Diagnostics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a diagnostic
+    pub fn push(&self, diagnostic: Diagnostic) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .push(diagnostic);
+    }
+
+    /// Record several diagnostics at once
+    pub fn extend(&self, diagnostics: impl IntoIterator<Item = Diagnostic>) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .extend(diagnostics);
+    }
+
+    pub fn info(&self, code: &str, pointer: impl Into<String>, message: impl Into<String>) {
+        self.push(Diagnostic::new(Severity::Info, code, pointer, message));
+    }
+
+    pub fn warning(&self, code: &str, pointer: impl Into<String>, message: impl Into<String>) {
+        self.push(Diagnostic::new(Severity::Warning, code, pointer, message));
+    }
+
+    /// Copy of everything recorded so far, in reporting order
+    pub fn snapshot(&self) -> Vec<Diagnostic> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+}
+
+/// Build a JSON pointer from unescaped path segments
+///
+/// # Examples
+/// ```
+/// use agenterra::generation::diagnostics::json_pointer;
+///
+/// assert_eq!(json_pointer(["paths", "/pet/{petId}", "get"]), "/paths/~1pet~1{petId}/get");
+/// ```
+pub fn json_pointer<I, S>(segments: I) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    segments
+        .into_iter()
+        .fold(String::new(), |mut pointer, segment| {
+            pointer.push('/');
+            pointer.push_str(&segment.as_ref().replace('~', "~0").replace('/', "~1"));
+            pointer
+        })
+}
+
+/// JSON pointer to an operation object
+pub fn operation_pointer(path: &str, method: &str) -> String {
+    json_pointer(["paths", path, method])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_pointer_escaping() {
+        assert_eq!(json_pointer(Vec::<&str>::new()), "");
+        assert_eq!(json_pointer(["servers"]), "/servers");
+        assert_eq!(
+            operation_pointer("/pet/{petId}", "get"),
+            "/paths/~1pet~1{petId}/get"
+        );
+        assert_eq!(json_pointer(["a~b", "c/d"]), "/a~0b/c~1d");
+    }
+
+    #[test]
+    fn test_collector_clones_share_entries() {
+        let diagnostics = Diagnostics::new();
+        let clone = diagnostics.clone();
+
+        clone.warning(codes::PARAMETER_DROPPED, "/paths/~1pet/get", "dropped");
+        diagnostics.info(codes::NO_SERVERS, "/servers", "no servers");
+
+        let entries = diagnostics.snapshot();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].severity, Severity::Warning);
+        assert_eq!(entries[1].code, codes::NO_SERVERS);
+        assert_eq!(clone.snapshot(), entries);
+    }
+
+    #[test]
+    fn test_diagnostic_display() {
+        let diagnostic = Diagnostic::new(
+            Severity::Warning,
+            codes::RESPONSE_NOT_JSON,
+            "/paths/~1pet/get/responses/200",
+            "only application/xml content",
+        );
+        assert_eq!(
+            diagnostic.to_string(),
+            "warning[response-not-json] /paths/~1pet/get/responses/200: only application/xml content"
+        );
+    }
+}
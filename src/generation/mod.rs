@@ -6,6 +6,7 @@
 
 pub mod adapters;
 pub mod context;
+pub mod diagnostics;
 pub mod errors;
 pub mod orchestrator;
 pub mod rules;
@@ -16,6 +17,7 @@ pub mod utils;
 
 pub use adapters::*;
 pub use context::*;
+pub use diagnostics::{Diagnostic, Diagnostics, Severity};
 pub use errors::*;
 pub use orchestrator::*;
 pub use traits::*;
@@ -154,7 +156,11 @@ mod tests {
 
         let result = GenerationResult {
             artifacts: artifacts.clone(),
+            directories: vec![],
+            pre_generate_hooks: vec![],
+            post_generate_hooks: vec![],
             metadata: metadata.clone(),
+            diagnostics: vec![],
         };
 
         assert_eq!(result.artifacts.len(), 1);
@@ -211,7 +217,6 @@ mod tests {
                 &self,
                 _artifacts: Vec<Artifact>,
                 _context: &GenerationContext,
-                _post_generation_commands: &[String],
             ) -> Result<Vec<Artifact>, GenerationError> {
                 panic!("Should not be called when validation fails");
             }
@@ -276,7 +281,12 @@ mod tests {
                     language,
                     files: vec![],
                     variables: HashMap::new(),
+                    variable_schema: vec![],
+                    pre_generate_hooks: vec![],
                     post_generate_hooks: vec![],
+                    partials: vec![],
+                    required_directories: vec![],
+                    extends: None,
                 };
                 Ok(crate::infrastructure::Template {
                     manifest,
@@ -329,7 +339,6 @@ mod tests {
                 &self,
                 artifacts: Vec<Artifact>,
                 _context: &GenerationContext,
-                _post_generation_commands: &[String],
             ) -> Result<Vec<Artifact>, GenerationError> {
                 self.call_order.lock().unwrap().push("process");
                 Ok(artifacts)
@@ -381,11 +390,13 @@ mod tests {
             }],
             operations: vec![],
             components: None,
+            tags: vec![],
+            diagnostics: vec![],
         };
 
         // Set protocol context for MCP Server
         context.protocol_context = Some(ProtocolContext::McpServer {
-            openapi_spec: openapi_spec.clone(),
+            openapi_spec: Box::new(openapi_spec.clone()),
             endpoints: vec![],
         });
 
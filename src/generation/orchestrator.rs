@@ -1,9 +1,11 @@
 //! Generation orchestration - coordinates the generation workflow
 
 use crate::generation::{
-    ContextBuilder, GenerationContext, GenerationError, GenerationResult, PostProcessor,
-    TemplateDiscovery, TemplateRenderingStrategy,
+    Artifact, ContextBuilder, Diagnostics, GenerationContext, GenerationError, GenerationResult,
+    PostProcessor, TemplateDiscovery, TemplateRenderingStrategy, diagnostics::codes,
 };
+use std::collections::HashSet;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 /// Orchestrates the code generation workflow
@@ -33,7 +35,7 @@ impl GenerationOrchestrator {
     /// Execute the generation workflow
     pub async fn generate(
         &self,
-        context: GenerationContext,
+        mut context: GenerationContext,
     ) -> Result<GenerationResult, GenerationError> {
         // 1. Validate context
         context.validate()?;
@@ -58,25 +60,90 @@ impl GenerationOrchestrator {
             "Using template for generation"
         );
 
-        // 3. Build render context from generation context
+        // 3. Check variables against the template's schema and apply defaults
+        template
+            .manifest
+            .resolve_variables(&mut context.variables)
+            .map_err(|e| GenerationError::ValidationError(e.to_string()))?;
+
+        // 4. Build render context from generation context
         let render_context = self.context_builder.build(&context, &template).await?;
 
-        // 4. Render templates to artifacts using strategy pattern
+        // 5. Render templates to artifacts using strategy pattern
         let artifacts = self
             .template_renderer
             .render(&template, &render_context, &context)
             .await?;
 
-        // 5. Post-process artifacts and execute post-generation commands
-        let processed_artifacts = self
-            .post_processor
-            .process(artifacts, &context, &template.manifest.post_generate_hooks)
-            .await?;
+        report_path_collisions(&artifacts, &context.diagnostics);
 
-        // 6. Return result
+        // 6. Post-process artifacts
+        let processed_artifacts = self.post_processor.process(artifacts, &context).await?;
+
+        // 7. Return result
         Ok(GenerationResult {
             artifacts: processed_artifacts,
+            directories: template
+                .manifest
+                .required_directories
+                .iter()
+                .map(PathBuf::from)
+                .collect(),
+            pre_generate_hooks: template.manifest.pre_generate_hooks,
+            post_generate_hooks: template.manifest.post_generate_hooks,
             metadata: context.metadata,
+            diagnostics: context.diagnostics.snapshot(),
         })
     }
 }
+
+/// Warn about rendered files that overwrite each other
+///
+/// Per-operation targets such as `src/handlers/{endpoint}.rs` collide when two
+/// operation ids normalize to the same file name; only the last one survives.
+fn report_path_collisions(artifacts: &[Artifact], diagnostics: &Diagnostics) {
+    let mut seen = HashSet::new();
+    for artifact in artifacts {
+        if !seen.insert(&artifact.path) {
+            diagnostics.warning(
+                codes::ARTIFACT_PATH_COLLISION,
+                "",
+                format!(
+                    "More than one file renders to '{}'; only the last one is kept",
+                    artifact.path.display()
+                ),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn artifact(path: &str) -> Artifact {
+        Artifact {
+            path: PathBuf::from(path),
+            content: String::new(),
+            permissions: None,
+        }
+    }
+
+    #[test]
+    fn test_reports_path_collisions() {
+        let diagnostics = Diagnostics::new();
+        report_path_collisions(
+            &[
+                artifact("src/handlers/get_pet.rs"),
+                artifact("src/main.rs"),
+                artifact("src/handlers/get_pet.rs"),
+            ],
+            &diagnostics,
+        );
+
+        let entries = diagnostics.snapshot();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].code, codes::ARTIFACT_PATH_COLLISION);
+        assert!(entries[0].message.contains("src/handlers/get_pet.rs"));
+    }
+}
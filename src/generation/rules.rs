@@ -12,19 +12,32 @@ pub fn validate_language_support(
     // Define supported languages per protocol/role combination
     match (protocol, role) {
         (Protocol::Mcp, Role::Server) => match language {
-            Language::Rust => Ok(()),
+            Language::Rust
+            | Language::Python
+            | Language::TypeScript
+            | Language::Go
+            | Language::CSharp => Ok(()),
             _ => Err(GenerationError::UnsupportedLanguageForProtocol { language, protocol }),
         },
         (Protocol::Mcp, Role::Client) => match language {
+            Language::Rust | Language::Python | Language::TypeScript => Ok(()),
+            _ => Err(GenerationError::UnsupportedLanguageForProtocol { language, protocol }),
+        },
+        (Protocol::Acp, Role::Server | Role::Client) => match language {
             Language::Rust => Ok(()),
             _ => Err(GenerationError::UnsupportedLanguageForProtocol { language, protocol }),
         },
-        _ => {
-            // For now, only MCP is implemented
-            Err(GenerationError::ValidationError(format!(
-                "Protocol {protocol} is not yet implemented"
-            )))
+        (Protocol::A2a | Protocol::Anp, Role::Agent) | (Protocol::A2a, Role::Bridge) => {
+            match language {
+                Language::Rust => Ok(()),
+                _ => Err(GenerationError::UnsupportedLanguageForProtocol { language, protocol }),
+            }
         }
+        // Custom roles exist only as templates, which pick their own languages
+        (_, Role::Custom(_)) => Ok(()),
+        _ => Err(GenerationError::ValidationError(format!(
+            "Protocol {protocol} is not yet implemented for the {role} role"
+        ))),
     }
 }
 
@@ -61,16 +74,48 @@ mod tests {
 
     #[test]
     fn test_validate_language_support() {
-        // MCP Server supports Rust, Python, TypeScript
+        // MCP Server supports Rust, Python, TypeScript, Go, C#
         assert!(validate_language_support(Protocol::Mcp, &Role::Server, Language::Rust).is_ok());
-        assert!(validate_language_support(Protocol::Mcp, &Role::Server, Language::Python).is_err());
+        assert!(validate_language_support(Protocol::Mcp, &Role::Server, Language::Python).is_ok());
         assert!(
-            validate_language_support(Protocol::Mcp, &Role::Server, Language::TypeScript).is_err()
+            validate_language_support(Protocol::Mcp, &Role::Server, Language::TypeScript).is_ok()
         );
-        assert!(validate_language_support(Protocol::Mcp, &Role::Server, Language::Go).is_err());
+        assert!(validate_language_support(Protocol::Mcp, &Role::Server, Language::Go).is_ok());
+        assert!(validate_language_support(Protocol::Mcp, &Role::Server, Language::CSharp).is_ok());
+        assert!(validate_language_support(Protocol::Mcp, &Role::Server, Language::Java).is_err());
 
-        // MCP Client supports more languages
+        // MCP Client supports Rust, Python, TypeScript
+        assert!(validate_language_support(Protocol::Mcp, &Role::Client, Language::Rust).is_ok());
+        assert!(validate_language_support(Protocol::Mcp, &Role::Client, Language::Python).is_ok());
+        assert!(
+            validate_language_support(Protocol::Mcp, &Role::Client, Language::TypeScript).is_ok()
+        );
         assert!(validate_language_support(Protocol::Mcp, &Role::Client, Language::Go).is_err());
+
+        // A2A Agent supports Rust
+        assert!(validate_language_support(Protocol::A2a, &Role::Agent, Language::Rust).is_ok());
+        assert!(validate_language_support(Protocol::A2a, &Role::Agent, Language::Python).is_err());
+
+        // The MCP to A2A bridge is generated in Rust
+        assert!(validate_language_support(Protocol::A2a, &Role::Bridge, Language::Rust).is_ok());
+        assert!(validate_language_support(Protocol::A2a, &Role::Bridge, Language::Go).is_err());
+        assert!(validate_language_support(Protocol::Anp, &Role::Bridge, Language::Rust).is_err());
+
+        // ACP Server and Client support Rust; brokers are not generated yet
+        assert!(validate_language_support(Protocol::Acp, &Role::Server, Language::Rust).is_ok());
+        assert!(validate_language_support(Protocol::Acp, &Role::Client, Language::Rust).is_ok());
+        assert!(validate_language_support(Protocol::Acp, &Role::Server, Language::Go).is_err());
+        assert!(validate_language_support(Protocol::Acp, &Role::Broker, Language::Rust).is_err());
+
+        // ANP Agent supports Rust; ANP has no broker role
+        assert!(validate_language_support(Protocol::Anp, &Role::Agent, Language::Rust).is_ok());
+        assert!(validate_language_support(Protocol::Anp, &Role::Agent, Language::Go).is_err());
+        assert!(validate_language_support(Protocol::Anp, &Role::Broker, Language::Rust).is_err());
+
+        // Custom roles take any language their templates are written in
+        let reviewer = Role::Custom("reviewer".to_string());
+        assert!(validate_language_support(Protocol::Mcp, &reviewer, Language::Go).is_ok());
+        assert!(validate_language_support(Protocol::Anp, &reviewer, Language::Java).is_ok());
     }
 
     #[test]
@@ -18,7 +18,7 @@ use regex::Regex;
 /// ```
 /// use agenterra::generation::sanitizers::sanitize_markdown;
 ///
-/// let input = "This is a "smart quote" example—with em-dash";
+/// let input = "This is a “smart quote” example—with em-dash";
 /// let output = sanitize_markdown(input);
 /// assert!(output.contains("\\\"smart quote\\\""));
 /// assert!(output.contains("-with em-dash"));
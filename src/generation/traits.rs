@@ -27,15 +27,17 @@ pub trait TemplateRenderingStrategy: Send + Sync {
     ) -> Result<Vec<Artifact>, GenerationError>;
 }
 
-/// Post-processes generated artifacts and executes post-generation commands
+/// Post-processes generated artifacts before they are written
+///
+/// Template hooks are not run here: they need the files on disk, so the
+/// application layer runs them around writing the artifacts.
 #[async_trait]
 pub trait PostProcessor: Send + Sync {
-    /// Process artifacts after generation and execute post-generation commands
+    /// Process artifacts after generation
     async fn process(
         &self,
         artifacts: Vec<Artifact>,
         context: &GenerationContext,
-        post_generation_commands: &[String],
     ) -> Result<Vec<Artifact>, GenerationError>;
 }
 
@@ -48,3 +50,13 @@ pub trait OpenApiLoader: Send + Sync {
         source: &str,
     ) -> Result<crate::generation::OpenApiContext, GenerationError>;
 }
+
+/// Lists the tools of MCP servers
+#[async_trait]
+pub trait McpToolLoader: Send + Sync {
+    /// Connect to the server, list its tools and disconnect
+    async fn list_tools(
+        &self,
+        server: &crate::generation::McpServerSource,
+    ) -> Result<Vec<crate::generation::McpTool>, GenerationError>;
+}
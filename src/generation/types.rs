@@ -41,6 +41,14 @@ impl Language {
         }
     }
 
+    /// Get the line comment marker for this language
+    pub fn line_comment(&self) -> &'static str {
+        match self {
+            Language::Python => "#",
+            _ => "//",
+        }
+    }
+
     /// Get all supported languages
     pub fn all() -> Vec<Language> {
         vec![
@@ -97,13 +105,21 @@ pub struct Artifact {
 #[derive(Debug, Clone)]
 pub struct GenerationResult {
     pub artifacts: Vec<Artifact>,
+    /// Directories the template requires, relative to the project root; they
+    /// must exist even when no artifact is written into them
+    pub directories: Vec<PathBuf>,
+    /// Template hooks to run in the output directory before the artifacts are written
+    pub pre_generate_hooks: Vec<crate::infrastructure::TemplateHook>,
+    /// Template hooks to run in the output directory after the artifacts are written
+    pub post_generate_hooks: Vec<crate::infrastructure::TemplateHook>,
     pub metadata: crate::generation::GenerationMetadata,
+    pub diagnostics: Vec<crate::generation::Diagnostic>,
 }
 
 // Re-export OpenAPI types from infrastructure module
 pub use crate::infrastructure::openapi::{
     ApiInfo, Components, OpenApiContext, Operation, Parameter, ParameterLocation, RequestBody,
-    Response, Schema, Server,
+    Response, Schema, Server, Tag,
 };
 
 /// Protocol-specific context data
@@ -112,10 +128,61 @@ pub enum ProtocolContext {
     /// MCP Server context with OpenAPI specification
     McpServer {
         /// The full OpenAPI specification
-        openapi_spec: OpenApiContext,
+        openapi_spec: Box<OpenApiContext>,
         /// Operations extracted from OpenAPI that become MCP endpoints/tools
         endpoints: Vec<Operation>,
     },
+    /// Bridge exposing the tools of an MCP server to another protocol
+    McpBridge {
+        /// Server the generated bridge connects to unless told otherwise;
+        /// `None` when the tools were derived from an OpenAPI spec
+        server: Option<McpServerSource>,
+        /// The server's tools, in the order it listed them
+        tools: Vec<McpTool>,
+    },
+}
+
+/// How to reach an MCP server
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "transport", rename_all = "lowercase")]
+pub enum McpServerSource {
+    /// A server started as a child process, speaking over stdin and stdout
+    Stdio { command: String, args: Vec<String> },
+    /// A server accepting SSE connections at `url`
+    Sse { url: String },
+}
+
+impl fmt::Display for McpServerSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            McpServerSource::Stdio { command, args } => {
+                write!(f, "{command}")?;
+                for arg in args {
+                    write!(f, " {arg}")?;
+                }
+                Ok(())
+            }
+            McpServerSource::Sse { url } => write!(f, "{url}"),
+        }
+    }
+}
+
+/// A tool an MCP server lists in its `tools/list` response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct McpTool {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// JSON Schema of the tool's arguments
+    #[serde(default = "empty_object_schema")]
+    pub input_schema: serde_json::Value,
+}
+
+fn empty_object_schema() -> serde_json::Value {
+    serde_json::json!({ "type": "object" })
 }
 
 #[cfg(test)]
@@ -3,6 +3,8 @@
 //! These utilities belong in the generation domain as they are used
 //! for transforming identifiers during code generation.
 
+use crate::generation::Language;
+
 /// Converts a string to snake_case format for Rust identifiers.
 ///
 /// This function handles various input formats including camelCase, PascalCase,
@@ -16,7 +18,7 @@
 ///
 /// # Examples
 /// ```
-/// use generation::utils::to_snake_case;
+/// use agenterra::generation::utils::to_snake_case;
 ///
 /// assert_eq!(to_snake_case("findPetsByStatus"), "find_pets_by_status");
 /// assert_eq!(to_snake_case("FindPetsByStatus"), "find_pets_by_status");
@@ -79,7 +81,7 @@ pub fn to_snake_case(s: &str) -> String {
 ///
 /// # Examples
 /// ```
-/// use generation::utils::to_proper_case;
+/// use agenterra::generation::utils::to_proper_case;
 ///
 /// assert_eq!(to_proper_case("find_pets_by_status"), "FindPetsByStatus");
 /// assert_eq!(to_proper_case("http_response"), "HttpResponse");
@@ -116,7 +118,7 @@ pub fn to_proper_case(s: &str) -> String {
 ///
 /// # Examples
 /// ```
-/// use generation::utils::to_camel_case;
+/// use agenterra::generation::utils::to_camel_case;
 ///
 /// assert_eq!(to_camel_case("find_pets_by_status"), "findPetsByStatus");
 /// assert_eq!(to_camel_case("http_response"), "httpResponse");
@@ -148,24 +150,214 @@ pub fn to_camel_case(s: &str) -> String {
 ///
 /// # Examples
 /// ```
-/// use generation::utils::sanitize_rust_field_name;
+/// use agenterra::generation::utils::sanitize_rust_field_name;
 ///
 /// assert_eq!(sanitize_rust_field_name("type"), "type_");
 /// assert_eq!(sanitize_rust_field_name("self"), "self_");
 /// assert_eq!(sanitize_rust_field_name("firstName"), "first_name");
 /// ```
 pub fn sanitize_rust_field_name(s: &str) -> String {
-    let snake_case = to_snake_case(s);
-
-    // List of Rust reserved keywords
-    match snake_case.as_str() {
-        "as" | "break" | "const" | "continue" | "crate" | "else" | "enum" | "extern" | "false"
-        | "fn" | "for" | "if" | "impl" | "in" | "let" | "loop" | "match" | "mod" | "move"
-        | "mut" | "pub" | "ref" | "return" | "self" | "Self" | "static" | "struct" | "super"
-        | "trait" | "true" | "type" | "unsafe" | "use" | "where" | "while" | "async" | "await"
-        | "dyn" | "abstract" | "become" | "box" | "do" | "final" | "macro" | "override"
-        | "priv" | "typeof" | "unsized" | "virtual" | "yield" | "try" => format!("{snake_case}_"),
-        _ => snake_case,
+    escape_identifier(&to_snake_case(s), Language::Rust)
+}
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn", "for",
+    "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return",
+    "self", "Self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where",
+    "while", "async", "await", "dyn", "abstract", "become", "box", "do", "final", "macro",
+    "override", "priv", "typeof", "unsized", "virtual", "yield", "try",
+];
+
+const PYTHON_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+const TYPESCRIPT_KEYWORDS: &[&str] = &[
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "import",
+    "in",
+    "instanceof",
+    "new",
+    "null",
+    "return",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "implements",
+    "interface",
+    "let",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "static",
+    "yield",
+];
+
+const GO_KEYWORDS: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "fallthrough",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "type",
+    "var",
+];
+
+const CSHARP_KEYWORDS: &[&str] = &[
+    "abstract",
+    "as",
+    "base",
+    "bool",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "checked",
+    "class",
+    "const",
+    "continue",
+    "decimal",
+    "default",
+    "delegate",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "event",
+    "explicit",
+    "extern",
+    "false",
+    "finally",
+    "fixed",
+    "float",
+    "for",
+    "foreach",
+    "goto",
+    "if",
+    "implicit",
+    "in",
+    "int",
+    "interface",
+    "internal",
+    "is",
+    "lock",
+    "long",
+    "namespace",
+    "new",
+    "null",
+    "object",
+    "operator",
+    "out",
+    "override",
+    "params",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "ref",
+    "return",
+    "sbyte",
+    "sealed",
+    "short",
+    "sizeof",
+    "stackalloc",
+    "static",
+    "string",
+    "struct",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "uint",
+    "ulong",
+    "unchecked",
+    "unsafe",
+    "ushort",
+    "using",
+    "virtual",
+    "void",
+    "volatile",
+    "while",
+];
+
+/// Escapes an identifier that collides with a reserved word of `language`
+///
+/// Reserved words get a trailing underscore; the casing of `ident` is kept.
+/// Languages without a keyword list return the identifier unchanged.
+///
+/// # Examples
+/// ```
+/// use agenterra::generation::{Language, utils::escape_identifier};
+///
+/// assert_eq!(escape_identifier("type", Language::Rust), "type_");
+/// assert_eq!(escape_identifier("from", Language::Python), "from_");
+/// assert_eq!(escape_identifier("default", Language::TypeScript), "default_");
+/// assert_eq!(escape_identifier("petId", Language::TypeScript), "petId");
+/// ```
+pub fn escape_identifier(ident: &str, language: Language) -> String {
+    let keywords = match language {
+        Language::Rust => RUST_KEYWORDS,
+        Language::Python => PYTHON_KEYWORDS,
+        Language::TypeScript => TYPESCRIPT_KEYWORDS,
+        Language::Go => GO_KEYWORDS,
+        Language::CSharp => CSHARP_KEYWORDS,
+        _ => &[],
+    };
+    if keywords.contains(&ident) {
+        format!("{ident}_")
+    } else {
+        ident.to_string()
     }
 }
 
@@ -218,4 +410,19 @@ mod tests {
         // Test that already snake_case keywords still get underscore
         assert_eq!(sanitize_rust_field_name("for"), "for_");
     }
+
+    #[test]
+    fn test_escape_identifier() {
+        assert_eq!(escape_identifier("Self", Language::Rust), "Self_");
+        assert_eq!(escape_identifier("class", Language::Python), "class_");
+        assert_eq!(escape_identifier("Class", Language::Python), "Class");
+        assert_eq!(
+            escape_identifier("interface", Language::TypeScript),
+            "interface_"
+        );
+        assert_eq!(escape_identifier("func", Language::Go), "func_");
+        assert_eq!(escape_identifier("Func", Language::Go), "Func");
+        assert_eq!(escape_identifier("event", Language::CSharp), "event_");
+        assert_eq!(escape_identifier("func", Language::Java), "func");
+    }
 }
@@ -0,0 +1,481 @@
+//! High-level entry point for embedding agenterra
+//!
+//! [`Generator`] wires the protocol registry, spec loading, template discovery,
+//! context building and rendering together, and returns the artifacts in
+//! memory. Writing them to disk is left to the caller.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use serde_json::Value as JsonValue;
+
+use crate::application::{ApplicationError, ValidationError};
+use crate::generation::{
+    GenerationOrchestrator, GenerationResult, Language, OpenApiContext, OpenApiLoader,
+    TemplateDiscovery, TemplateDiscoveryAdapter,
+};
+use crate::infrastructure::generation::context_builders::registry::CompositeContextBuilder;
+use crate::infrastructure::generation::post_processor::PermissionsPostProcessor;
+use crate::infrastructure::openapi::{CompositeOpenApiLoader, parser::OpenApiParser};
+use crate::infrastructure::{
+    EmbeddedTemplateRepository, FileSystemTemplateLoader, TemplateError, TemplateFetcher,
+    TemplateLoaderDiscoveryAdapter, TemplateSource, TemplateVariantDiscoveryAdapter,
+};
+use crate::protocols::{Protocol, ProtocolConfig, ProtocolInput, ProtocolRegistry, Role};
+
+/// Where the API spec for a generation run comes from
+#[derive(Debug, Clone)]
+pub enum SpecSource {
+    /// File path, or `http(s)` URL when the `http-loader` feature is enabled
+    Location(String),
+    /// Unparsed OpenAPI 3 or Swagger 2 document
+    Document(JsonValue),
+    /// Spec that has already been parsed
+    Parsed(Box<OpenApiContext>),
+}
+
+/// Configured code generator
+///
+/// Build one with [`Generator::builder`], then call [`Generator::generate`].
+///
+/// # Examples
+/// ```
+/// use agenterra::{Generator, Language, Protocol, Role};
+/// use serde_json::json;
+///
+/// # tokio_test::block_on(async {
+/// let result = Generator::builder()
+///     .protocol(Protocol::Mcp)
+///     .role(Role::Server)
+///     .language(Language::Rust)
+///     .project_name("petstore_server")
+///     .spec_document(json!({
+///         "openapi": "3.0.0",
+///         "info": { "title": "Petstore", "version": "1.0.0" },
+///         "servers": [{ "url": "https://petstore.example.com" }],
+///         "paths": {
+///             "/pets": {
+///                 "get": {
+///                     "operationId": "listPets",
+///                     "responses": { "200": { "description": "ok" } }
+///                 }
+///             }
+///         }
+///     }))
+///     .build()?
+///     .generate()
+///     .await?;
+///
+/// assert!(result.artifacts.iter().any(|a| a.path.ends_with("list_pets.rs")));
+/// # Ok::<(), agenterra::application::ApplicationError>(())
+/// # })?;
+/// # Ok::<(), agenterra::application::ApplicationError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Generator {
+    protocol: Protocol,
+    role: Role,
+    language: Language,
+    project_name: String,
+    version: Option<String>,
+    spec: Option<SpecSource>,
+    template_source: TemplateSource,
+    template: Option<String>,
+    variables: HashMap<String, JsonValue>,
+}
+
+impl Generator {
+    pub fn builder() -> GeneratorBuilder {
+        GeneratorBuilder::default()
+    }
+
+    /// Run the generation pipeline and return the rendered artifacts
+    ///
+    /// Artifact paths are relative to the project root. Directories listed in
+    /// [`GenerationResult::directories`] should be created alongside them.
+    /// Template hooks are not run; they are returned in
+    /// [`GenerationResult::pre_generate_hooks`] and
+    /// [`GenerationResult::post_generate_hooks`] for the caller to run around
+    /// writing the files.
+    pub async fn generate(&self) -> Result<GenerationResult, ApplicationError> {
+        self.generate_with(self.template_discovery().await?).await
+    }
+
+    /// Run the pipeline against templates from `discovery` instead of the configured source
+    pub(crate) async fn generate_with(
+        &self,
+        discovery: Arc<dyn TemplateDiscovery>,
+    ) -> Result<GenerationResult, ApplicationError> {
+        let registry = ProtocolRegistry::with_defaults()?;
+        let handler =
+            registry
+                .get(self.protocol)
+                .ok_or(crate::protocols::ProtocolError::NotImplemented(
+                    self.protocol,
+                ))?;
+
+        let openapi_spec = match &self.spec {
+            Some(SpecSource::Location(location)) => {
+                Some(CompositeOpenApiLoader::new().load(location).await?)
+            }
+            Some(SpecSource::Document(document)) => {
+                Some(OpenApiParser::new(document.clone()).parse().await?)
+            }
+            Some(SpecSource::Parsed(spec)) => Some(spec.as_ref().clone()),
+            None => None,
+        };
+
+        let input = ProtocolInput {
+            role: self.role.clone(),
+            language: self.language,
+            config: ProtocolConfig {
+                project_name: self.project_name.clone(),
+                version: self.version.clone(),
+                options: self.variables.clone(),
+            },
+            openapi_spec,
+            mcp_server: None,
+            mcp_tools: None,
+        };
+        let context = handler.prepare_context(input).await?;
+
+        let orchestrator = GenerationOrchestrator::new(
+            discovery,
+            Arc::new(CompositeContextBuilder::default()),
+            crate::infrastructure::generation::select_renderer(self.protocol, self.role.clone()),
+            Arc::new(PermissionsPostProcessor::new()),
+        );
+
+        Ok(orchestrator.generate(context).await?)
+    }
+
+    /// Discovery for the configured source, fetching archives and git
+    /// repositories into the template cache first
+    async fn template_discovery(&self) -> Result<Arc<dyn TemplateDiscovery>, ApplicationError> {
+        let dir = match &self.template_source {
+            TemplateSource::Embedded => {
+                let embedded = Arc::new(EmbeddedTemplateRepository::new());
+                return Ok(match &self.template {
+                    Some(name) => Arc::new(TemplateDiscoveryAdapter::new(Arc::new(
+                        TemplateVariantDiscoveryAdapter::new(embedded, name.clone()),
+                    ))),
+                    None => Arc::new(TemplateDiscoveryAdapter::new(embedded)),
+                });
+            }
+            TemplateSource::FileSystem(dir) => dir.clone(),
+            source @ (TemplateSource::Archive(_) | TemplateSource::Git { .. }) => {
+                let variant = match &self.template {
+                    Some(name) => name.clone(),
+                    None => self.language.to_string(),
+                };
+                TemplateFetcher::new()
+                    .fetch(source, None)
+                    .await?
+                    .template_dir(self.protocol, &self.role, &variant)?
+            }
+        };
+
+        Ok(Arc::new(TemplateDiscoveryAdapter::new(Arc::new(
+            TemplateLoaderDiscoveryAdapter::new(Arc::new(FileSystemTemplateLoader::new()), dir),
+        ))))
+    }
+}
+
+/// Builder for [`Generator`]
+///
+/// Defaults to an MCP server in Rust from the embedded templates.
+#[derive(Debug, Clone)]
+pub struct GeneratorBuilder {
+    protocol: Protocol,
+    role: Role,
+    language: Language,
+    project_name: Option<String>,
+    version: Option<String>,
+    spec: Option<SpecSource>,
+    template_source: TemplateSource,
+    template: Option<String>,
+    variables: HashMap<String, JsonValue>,
+}
+
+impl Default for GeneratorBuilder {
+    fn default() -> Self {
+        Self {
+            protocol: Protocol::Mcp,
+            role: Role::Server,
+            language: Language::Rust,
+            project_name: None,
+            version: None,
+            spec: None,
+            template_source: TemplateSource::Embedded,
+            template: None,
+            variables: HashMap::new(),
+        }
+    }
+}
+
+impl GeneratorBuilder {
+    pub fn protocol(mut self, protocol: Protocol) -> Self {
+        self.protocol = protocol;
+        self
+    }
+
+    pub fn role(mut self, role: Role) -> Self {
+        self.role = role;
+        self
+    }
+
+    pub fn language(mut self, language: Language) -> Self {
+        self.language = language;
+        self
+    }
+
+    /// Project name; required
+    pub fn project_name(mut self, project_name: impl Into<String>) -> Self {
+        self.project_name = Some(project_name.into());
+        self
+    }
+
+    /// Version of the generated project, `0.1.0` when unset
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    pub fn spec(mut self, spec: SpecSource) -> Self {
+        self.spec = Some(spec);
+        self
+    }
+
+    /// Load the spec from a file path or URL
+    pub fn spec_location(self, location: impl Into<String>) -> Self {
+        self.spec(SpecSource::Location(location.into()))
+    }
+
+    /// Parse the spec from an in-memory JSON document
+    pub fn spec_document(self, document: JsonValue) -> Self {
+        self.spec(SpecSource::Document(document))
+    }
+
+    /// Templates to render, the embedded set when unset
+    pub fn template_source(mut self, source: TemplateSource) -> Self {
+        self.template_source = source;
+        self
+    }
+
+    /// Render templates from a directory holding a `manifest.yml`
+    pub fn template_dir(self, dir: impl Into<PathBuf>) -> Self {
+        self.template_source(TemplateSource::FileSystem(dir.into()))
+    }
+
+    /// Template variant to render, such as `rust-minimal`, rather than the one
+    /// named after the language
+    ///
+    /// An embedded variant also sets the language, from its manifest.
+    pub fn template(mut self, name: impl Into<String>) -> Self {
+        self.template = Some(name.into());
+        self
+    }
+
+    /// Set a template variable, checked against the manifest's variable schema
+    pub fn variable(mut self, name: impl Into<String>, value: JsonValue) -> Self {
+        self.variables.insert(name.into(), value);
+        self
+    }
+
+    pub fn variables(mut self, variables: impl IntoIterator<Item = (String, JsonValue)>) -> Self {
+        self.variables.extend(variables);
+        self
+    }
+
+    pub fn build(self) -> Result<Generator, ApplicationError> {
+        let project_name = self
+            .project_name
+            .filter(|name| !name.is_empty())
+            .ok_or(ValidationError::EmptyProjectName)?;
+        crate::generation::rules::validate_project_name(&project_name)
+            .map_err(|e| ValidationError::InvalidProjectName(e.to_string()))?;
+
+        self.protocol
+            .validate_role(&self.role)
+            .map_err(|_| ValidationError::UnsupportedRole {
+                protocol: self.protocol,
+                role: self.role.clone(),
+            })?;
+
+        if self.role == Role::Server
+            && self.protocol.capabilities().requires_openapi
+            && self.spec.is_none()
+        {
+            return Err(ValidationError::MissingField(format!(
+                "{} server requires an OpenAPI spec",
+                self.protocol
+            ))
+            .into());
+        }
+
+        let mut language = self.language;
+        if let (Some(name), TemplateSource::Embedded) = (&self.template, &self.template_source) {
+            let manifest = EmbeddedTemplateRepository::new()
+                .find_variant(self.protocol, &self.role, name)
+                .ok_or_else(|| {
+                    TemplateError::not_found(format!("{}/{}/{name}", self.protocol, self.role))
+                })?;
+            language = manifest.language;
+        }
+
+        Ok(Generator {
+            protocol: self.protocol,
+            role: self.role,
+            language,
+            project_name,
+            version: self.version,
+            spec: self.spec,
+            template_source: self.template_source,
+            template: self.template,
+            variables: self.variables,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn petstore() -> JsonValue {
+        json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Petstore", "version": "1.0.0" },
+            "servers": [{ "url": "https://petstore.example.com" }],
+            "paths": {
+                "/pets/{petId}": {
+                    "get": {
+                        "operationId": "getPet",
+                        "parameters": [
+                            { "name": "petId", "in": "path", "required": true, "schema": { "type": "integer" } }
+                        ],
+                        "responses": { "200": { "description": "ok" } }
+                    }
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_build_requires_project_name() {
+        let result = Generator::builder().spec_document(petstore()).build();
+        assert!(matches!(
+            result,
+            Err(ApplicationError::ValidationError(
+                ValidationError::EmptyProjectName
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_build_requires_spec_for_mcp_server() {
+        let result = Generator::builder().project_name("petstore").build();
+        assert!(matches!(
+            result,
+            Err(ApplicationError::ValidationError(
+                ValidationError::MissingField(_)
+            ))
+        ));
+
+        // Clients don't need one
+        assert!(
+            Generator::builder()
+                .role(Role::Client)
+                .project_name("petstore_client")
+                .build()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_build_rejects_unsupported_role() {
+        let result = Generator::builder()
+            .protocol(Protocol::A2a)
+            .role(Role::Server)
+            .project_name("agent")
+            .build();
+        assert!(matches!(
+            result,
+            Err(ApplicationError::ValidationError(
+                ValidationError::UnsupportedRole { .. }
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_generate_from_document() {
+        let result = Generator::builder()
+            .project_name("petstore_server")
+            .spec_document(petstore())
+            .variable("server_port", json!(9090))
+            .build()
+            .unwrap()
+            .generate()
+            .await
+            .unwrap();
+
+        assert_eq!(result.metadata.project_name, "petstore_server");
+        let handler = result
+            .artifacts
+            .iter()
+            .find(|a| a.path == std::path::Path::new("src/handlers/get_pet.rs"))
+            .expect("handler for getPet");
+        assert!(handler.content.contains("pet_id"));
+
+        let config = result
+            .artifacts
+            .iter()
+            .find(|a| a.path == std::path::Path::new("src/config.rs"))
+            .unwrap();
+        assert!(config.content.contains("9090"));
+    }
+
+    #[tokio::test]
+    async fn test_generate_named_variant() {
+        let result = Generator::builder()
+            .project_name("petstore_server")
+            .spec_document(petstore())
+            .template("rust-minimal")
+            .build()
+            .unwrap()
+            .generate()
+            .await
+            .unwrap();
+
+        let paths: Vec<_> = result.artifacts.iter().map(|a| a.path.clone()).collect();
+        assert!(paths.contains(&PathBuf::from("src/handlers/get_pet.rs")));
+        assert!(!paths.contains(&PathBuf::from("src/transport.rs")));
+
+        let result = Generator::builder()
+            .project_name("petstore_server")
+            .spec_document(petstore())
+            .template("rust-axum-http")
+            .build();
+        assert!(matches!(
+            result,
+            Err(ApplicationError::TemplateError(
+                TemplateError::TemplateNotFound(_)
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_generate_rejects_invalid_variables() {
+        let result = Generator::builder()
+            .project_name("petstore_server")
+            .spec_document(petstore())
+            .variable("server_port", json!("not a port"))
+            .build()
+            .unwrap()
+            .generate()
+            .await;
+
+        assert!(matches!(result, Err(ApplicationError::GenerationError(_))));
+    }
+}
@@ -0,0 +1,395 @@
+//! Helpers shared by the language-specific context builders
+
+use serde_json::{Map as JsonMap, Value as JsonValue, json};
+use std::collections::HashSet;
+
+use crate::generation::{
+    Diagnostics, McpServerSource, McpTool, Operation, RenderContext,
+    diagnostics::{codes, json_pointer, operation_pointer},
+};
+
+const JSON_MEDIA_TYPE: &str = "application/json";
+const COMPOSITION_KEYWORDS: &[&str] = &["oneOf", "anyOf", "allOf"];
+
+/// Nesting limit when flattening `allOf` chains into model fields
+const MAX_MODEL_DEPTH: usize = 8;
+
+/// Add the `mcp_server` and `mcp_tools` of a bridge to the render context
+///
+/// `mcp_server` is null when the tools were derived from an OpenAPI spec.
+pub fn add_mcp_bridge_variables(
+    render_context: &mut RenderContext,
+    server: Option<&McpServerSource>,
+    tools: &[McpTool],
+) {
+    render_context.add_variable("mcp_server", json!(server));
+    render_context.add_variable("mcp_tools", json!(tools));
+}
+
+/// Report the parts of an operation that context builders cannot map to typed code
+///
+/// Every builder only reads `application/json` bodies and maps object properties
+/// one level deep, so the same degradations apply regardless of target language.
+pub fn report_operation_diagnostics(op: &Operation, diagnostics: &Diagnostics) {
+    let pointer = operation_pointer(&op.path, &op.method);
+
+    for param in &op.parameters {
+        if param.schema.schema_type.is_none() {
+            let composed = param.schema.one_of.is_some()
+                || param.schema.any_of.is_some()
+                || param.schema.all_of.is_some();
+            let parameters_pointer = format!("{pointer}/parameters");
+            if composed {
+                diagnostics.warning(
+                    codes::SCHEMA_COMPOSITION_UNTYPED,
+                    parameters_pointer,
+                    format!(
+                        "Parameter '{}' uses oneOf/anyOf/allOf, which is not supported; a fallback type is generated",
+                        param.name
+                    ),
+                );
+            } else {
+                diagnostics.info(
+                    codes::SCHEMA_TYPE_MISSING,
+                    parameters_pointer,
+                    format!(
+                        "Parameter '{}' has no schema type; a fallback type is generated",
+                        param.name
+                    ),
+                );
+            }
+        }
+    }
+
+    if let Some(request_body) = &op.request_body
+        && let Some(content) = request_body.content.as_object()
+        && !content.is_empty()
+    {
+        let content_pointer = format!("{pointer}/requestBody/content");
+        match content.get(JSON_MEDIA_TYPE) {
+            Some(media) => {
+                if let Some(schema) = media.get("schema") {
+                    check_schema_properties(
+                        schema,
+                        &format!("{content_pointer}{}", json_pointer([JSON_MEDIA_TYPE, "schema"])),
+                        diagnostics,
+                    );
+                }
+            }
+            None => diagnostics.warning(
+                codes::REQUEST_BODY_NOT_JSON,
+                content_pointer,
+                format!(
+                    "Request body has no {JSON_MEDIA_TYPE} content (found {}); no typed properties are generated",
+                    media_types(content)
+                ),
+            ),
+        }
+    }
+
+    for response in op
+        .responses
+        .iter()
+        .filter(|r| r.status_code.starts_with('2'))
+    {
+        let Some(content) = response.content.as_ref().and_then(JsonValue::as_object) else {
+            continue;
+        };
+        if content.is_empty() {
+            continue;
+        }
+
+        let content_pointer = format!(
+            "{pointer}{}",
+            json_pointer(["responses", response.status_code.as_str(), "content"])
+        );
+        match content.get(JSON_MEDIA_TYPE) {
+            Some(media) => {
+                if let Some(schema) = media.get("schema") {
+                    check_schema_properties(
+                        schema,
+                        &format!("{content_pointer}{}", json_pointer([JSON_MEDIA_TYPE, "schema"])),
+                        diagnostics,
+                    );
+                }
+            }
+            None => diagnostics.warning(
+                codes::RESPONSE_NOT_JSON,
+                content_pointer,
+                format!(
+                    "Response {} has no {JSON_MEDIA_TYPE} content (found {}); it is treated as untyped",
+                    response.status_code,
+                    media_types(content)
+                ),
+            ),
+        }
+    }
+}
+
+/// Check the properties of an object schema, or of an array's item schema
+fn check_schema_properties(schema: &JsonValue, pointer: &str, diagnostics: &Diagnostics) {
+    if schema.get("type").and_then(JsonValue::as_str) == Some("array") {
+        if let Some(items) = schema.get("items") {
+            check_schema_properties(items, &format!("{pointer}/items"), diagnostics);
+        }
+        return;
+    }
+
+    let Some(properties) = schema.get("properties").and_then(JsonValue::as_object) else {
+        return;
+    };
+
+    for (name, property) in properties {
+        if property.get("type").is_some() {
+            continue;
+        }
+        let property_pointer = format!("{pointer}{}", json_pointer(["properties", name]));
+
+        if let Some(keyword) = COMPOSITION_KEYWORDS
+            .iter()
+            .find(|keyword| property.get(**keyword).is_some())
+        {
+            diagnostics.warning(
+                codes::SCHEMA_COMPOSITION_UNTYPED,
+                property_pointer,
+                format!("Property '{name}' uses {keyword}, which is not supported; it is generated as an untyped value"),
+            );
+        } else if property.get("properties").is_none() && property.get("enum").is_none() {
+            diagnostics.info(
+                codes::SCHEMA_TYPE_MISSING,
+                property_pointer,
+                format!("Property '{name}' has no type; a fallback type is generated"),
+            );
+        }
+    }
+}
+
+fn media_types(content: &serde_json::Map<String, JsonValue>) -> String {
+    content.keys().cloned().collect::<Vec<_>>().join(", ")
+}
+
+/// The `application/json` schema of an operation's request body
+pub fn request_schema(op: &Operation) -> Option<&JsonValue> {
+    op.request_body
+        .as_ref()?
+        .content
+        .get(JSON_MEDIA_TYPE)?
+        .get("schema")
+}
+
+/// The `application/json` schema of an operation's first successful response
+pub fn response_schema(op: &Operation) -> Option<&JsonValue> {
+    op.responses
+        .iter()
+        .filter(|response| response.status_code.starts_with('2'))
+        .find_map(|response| {
+            response
+                .content
+                .as_ref()?
+                .get(JSON_MEDIA_TYPE)?
+                .get("schema")
+        })
+}
+
+/// The object properties of a request body, or of its array items
+pub fn properties_of(schema: &JsonValue) -> JsonValue {
+    schema
+        .get("properties")
+        .or_else(|| {
+            schema
+                .get("items")
+                .and_then(|items| items.get("properties"))
+        })
+        .cloned()
+        .unwrap_or_else(|| json!({}))
+}
+
+/// Whether a component schema maps to a model type rather than an alias
+pub fn is_model_schema(schema: &JsonValue) -> bool {
+    schema
+        .get("properties")
+        .and_then(JsonValue::as_object)
+        .is_some_and(|properties| !properties.is_empty())
+        || schema.get("allOf").is_some_and(JsonValue::is_array)
+}
+
+/// Properties and required names of an object schema, with `allOf` parts merged in
+pub fn object_properties(
+    schema: &JsonValue,
+    schemas: &JsonMap<String, JsonValue>,
+) -> (JsonMap<String, JsonValue>, HashSet<String>) {
+    fn collect(
+        schema: &JsonValue,
+        schemas: &JsonMap<String, JsonValue>,
+        depth: usize,
+        properties: &mut JsonMap<String, JsonValue>,
+        required: &mut HashSet<String>,
+    ) {
+        if depth > MAX_MODEL_DEPTH {
+            return;
+        }
+        if let Some(reference) = schema.get("$ref").and_then(JsonValue::as_str) {
+            let name = reference.rsplit('/').next().unwrap_or(reference);
+            if let Some(target) = schemas.get(name) {
+                collect(target, schemas, depth + 1, properties, required);
+            }
+            return;
+        }
+        for part in schema
+            .get("allOf")
+            .and_then(JsonValue::as_array)
+            .into_iter()
+            .flatten()
+        {
+            collect(part, schemas, depth + 1, properties, required);
+        }
+        if let Some(own) = schema.get("properties").and_then(JsonValue::as_object) {
+            properties.extend(own.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+        required.extend(
+            schema
+                .get("required")
+                .and_then(JsonValue::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(JsonValue::as_str)
+                .map(str::to_string),
+        );
+    }
+
+    let mut properties = JsonMap::new();
+    let mut required = HashSet::new();
+    collect(schema, schemas, 0, &mut properties, &mut required);
+    (properties, required)
+}
+
+/// The component a resolved schema was expanded from
+///
+/// Request bodies arrive with their `$ref`s resolved, so a schema is matched
+/// back to the only object component with the same properties and required
+/// names.
+pub fn matching_component<'a>(
+    schema: &JsonValue,
+    schemas: &'a JsonMap<String, JsonValue>,
+) -> Option<&'a str> {
+    let (properties, required) = object_properties(schema, schemas);
+    if properties.is_empty() {
+        return None;
+    }
+
+    let mut matches = schemas.iter().filter(|(_, component)| {
+        if !is_model_schema(component) {
+            return false;
+        }
+        let (component_properties, component_required) = object_properties(component, schemas);
+        component_required == required
+            && component_properties.len() == properties.len()
+            && component_properties
+                .keys()
+                .all(|key| properties.contains_key(key))
+    });
+    match (matches.next(), matches.next()) {
+        (Some((name, _)), None) => Some(name.as_str()),
+        _ => None,
+    }
+}
+
+/// `ident`, suffixed with a counter if it is already taken
+pub fn unique_identifier(ident: String, used: &mut HashSet<String>) -> String {
+    let mut candidate = ident.clone();
+    let mut counter = 2;
+    while !used.insert(candidate.clone()) {
+        candidate = format!("{ident}_{counter}");
+        counter += 1;
+    }
+    candidate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::{RequestBody, Response, Severity};
+    use serde_json::json;
+
+    fn operation() -> Operation {
+        serde_json::from_value(json!({
+            "operationId": "updatePet",
+            "path": "/pet/{petId}",
+            "method": "put",
+            "parameters": [],
+            "request_body": null,
+            "responses": []
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_reports_non_json_bodies() {
+        let mut op = operation();
+        op.request_body = Some(RequestBody {
+            required: true,
+            content: json!({ "application/x-www-form-urlencoded": { "schema": {} } }),
+            description: None,
+        });
+        op.responses = vec![Response {
+            status_code: "200".to_string(),
+            description: "ok".to_string(),
+            content: Some(json!({ "application/xml": { "schema": {} } })),
+        }];
+
+        let diagnostics = Diagnostics::new();
+        report_operation_diagnostics(&op, &diagnostics);
+        let entries = diagnostics.snapshot();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].code, codes::REQUEST_BODY_NOT_JSON);
+        assert_eq!(
+            entries[0].pointer,
+            "/paths/~1pet~1{petId}/put/requestBody/content"
+        );
+        assert_eq!(entries[1].code, codes::RESPONSE_NOT_JSON);
+        assert_eq!(
+            entries[1].pointer,
+            "/paths/~1pet~1{petId}/put/responses/200/content"
+        );
+        assert!(entries[1].message.contains("application/xml"));
+    }
+
+    #[test]
+    fn test_reports_untyped_properties() {
+        let mut op = operation();
+        op.responses = vec![Response {
+            status_code: "200".to_string(),
+            description: "ok".to_string(),
+            content: Some(json!({
+                "application/json": {
+                    "schema": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "id": { "type": "integer" },
+                                "owner": { "oneOf": [{ "type": "string" }, { "type": "integer" }] },
+                                "extra": {}
+                            }
+                        }
+                    }
+                }
+            })),
+        }];
+
+        let diagnostics = Diagnostics::new();
+        report_operation_diagnostics(&op, &diagnostics);
+        let entries = diagnostics.snapshot();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].code, codes::SCHEMA_COMPOSITION_UNTYPED);
+        assert_eq!(entries[0].severity, Severity::Warning);
+        assert_eq!(
+            entries[0].pointer,
+            "/paths/~1pet~1{petId}/put/responses/200/content/application~1json/schema/items/properties/owner"
+        );
+        assert_eq!(entries[1].code, codes::SCHEMA_TYPE_MISSING);
+        assert_eq!(entries[1].severity, Severity::Info);
+    }
+}
@@ -0,0 +1,575 @@
+//! C#-specific context builder for code generation
+
+use async_trait::async_trait;
+use serde_json::{Map as JsonMap, Value as JsonValue, json};
+use std::collections::HashSet;
+
+use super::common;
+use crate::generation::{
+    ContextBuilder, GenerationContext, GenerationError, Language, Operation, RenderContext,
+    sanitizers::sanitize_markdown,
+    utils::{escape_identifier, to_camel_case, to_proper_case, to_snake_case},
+};
+use crate::infrastructure::Template;
+
+/// C#-specific context builder
+pub struct CSharpContextBuilder;
+
+impl CSharpContextBuilder {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for CSharpContextBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ContextBuilder for CSharpContextBuilder {
+    async fn build(
+        &self,
+        context: &GenerationContext,
+        template: &Template,
+    ) -> Result<RenderContext, GenerationError> {
+        if context.language != Language::CSharp {
+            return Err(GenerationError::InvalidConfiguration(format!(
+                "CSharpContextBuilder can only build contexts for C#, got {:?}",
+                context.language
+            )));
+        }
+
+        let mut render_context = RenderContext::new();
+
+        // Base context
+        render_context.add_variable("project_name", json!(context.metadata.project_name));
+        render_context.add_variable("version", json!(context.metadata.version));
+        render_context.add_variable("description", json!(context.metadata.description));
+        render_context.add_variable("protocol", json!(context.protocol.to_string()));
+        render_context.add_variable("role", json!(context.role.to_string()));
+        render_context.add_variable("language", json!("csharp"));
+
+        // C#-specific naming
+        let root_namespace = csharp_type_name(&context.metadata.project_name);
+        render_context.add_variable("root_namespace", json!(root_namespace));
+        render_context.add_variable("assembly_name", json!(context.metadata.project_name));
+
+        // Handle protocol-specific context
+        let mut endpoints = Vec::new();
+        if let Some(protocol_context) = &context.protocol_context {
+            match protocol_context {
+                crate::generation::ProtocolContext::McpServer {
+                    openapi_spec,
+                    endpoints: operations,
+                } => {
+                    // Add OpenAPI spec information
+                    render_context.add_variable("api_version", json!(openapi_spec.version));
+                    render_context.add_variable("api_title", json!(openapi_spec.info.title));
+                    render_context
+                        .add_variable("api_info_version", json!(openapi_spec.info.version));
+                    if let Some(desc) = &openapi_spec.info.description {
+                        render_context.add_variable("api_description", json!(desc));
+                    }
+
+                    // Add servers information
+                    if !openapi_spec.servers.is_empty() {
+                        render_context.add_variable("api_servers", json!(openapi_spec.servers));
+                        render_context
+                            .add_variable("api_base_url", json!(openapi_spec.servers[0].url));
+                    }
+
+                    // Add components for potential $ref resolution
+                    let schemas = openapi_spec
+                        .components
+                        .as_ref()
+                        .and_then(|components| components.schemas.as_object())
+                        .cloned()
+                        .unwrap_or_default();
+                    if let Some(components) = &openapi_spec.components {
+                        render_context.add_variable("api_components", json!(components.schemas));
+                        if !components.security_schemes.is_null() {
+                            render_context.add_variable(
+                                "api_security_schemes",
+                                components.security_schemes.clone(),
+                            );
+                        }
+                    }
+
+                    // Records for the object component schemas
+                    render_context.add_variable("models", json!(build_csharp_models(&schemas)));
+
+                    // Process operations into C# endpoint contexts
+                    let mut method_names = HashSet::new();
+                    for operation in operations {
+                        common::report_operation_diagnostics(operation, &context.diagnostics);
+                        let endpoint_context =
+                            build_csharp_endpoint_context(operation, &schemas, &mut method_names)?;
+                        endpoints.push(endpoint_context);
+                    }
+                }
+                crate::generation::ProtocolContext::McpBridge { server, tools } => {
+                    super::common::add_mcp_bridge_variables(
+                        &mut render_context,
+                        server.as_ref(),
+                        tools,
+                    );
+                }
+            }
+        }
+        render_context.add_variable("endpoints", json!(endpoints));
+
+        // Add custom variables
+        for (key, value) in &context.variables {
+            render_context.add_variable(key, value.clone());
+        }
+
+        // Add template variables
+        for (key, value) in &template.manifest.variables {
+            if !render_context.has_variable(key) {
+                render_context.add_variable(key, value.clone());
+            }
+        }
+
+        // Add template manifest metadata
+        render_context.add_variable("template_name", json!(template.manifest.name));
+        render_context.add_variable("template_version", json!(template.manifest.version));
+        if let Some(description) = &template.manifest.description {
+            render_context.add_variable("template_description", json!(description));
+        }
+
+        Ok(render_context)
+    }
+}
+
+/// Types the generated project declares or uses unqualified, which models must not shadow
+const RESERVED_TYPE_NAMES: &[&str] = &[
+    "ApiClient",
+    "ApiClientServiceCollectionExtensions",
+    "ApiException",
+    "ApiRequest",
+    "ApiSettings",
+    "ApiTools",
+    "CancellationToken",
+    "Description",
+    "Dictionary",
+    "Exception",
+    "HttpClient",
+    "JsonElement",
+    "Object",
+    "Program",
+    "String",
+    "Task",
+    "Uri",
+];
+
+/// Tool method parameters the generated code declares itself
+const RESERVED_PARAMETER_NAMES: &[&str] = &["body", "cancellationToken", "client"];
+
+/// A PascalCase C# type name for a component schema name
+fn csharp_type_name(name: &str) -> String {
+    let type_name = to_proper_case(name);
+    if type_name.is_empty() || type_name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("Model{type_name}")
+    } else if RESERVED_TYPE_NAMES.contains(&type_name.as_str()) {
+        format!("{type_name}Model")
+    } else {
+        type_name
+    }
+}
+
+/// A PascalCase C# member name for an OpenAPI name
+fn csharp_member_name(name: &str) -> String {
+    let member = to_proper_case(name);
+    if member.is_empty() || member.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("Value{member}")
+    } else {
+        member
+    }
+}
+
+/// A camelCase C# parameter name for an OpenAPI name
+fn csharp_parameter_name(name: &str) -> String {
+    let ident = to_camel_case(name);
+    let ident = if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("value{}", to_proper_case(&ident))
+    } else {
+        ident
+    };
+    escape_identifier(&ident, Language::CSharp)
+}
+
+/// Escape text for an XML doc comment
+fn xml_doc(text: &str) -> String {
+    sanitize_markdown(text)
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn build_csharp_endpoint_context(
+    op: &Operation,
+    schemas: &JsonMap<String, JsonValue>,
+    method_names: &mut HashSet<String>,
+) -> Result<JsonValue, GenerationError> {
+    let request_schema = common::request_schema(op);
+    let parameters = build_csharp_parameters(op, schemas);
+
+    // The request body is passed to the tool as a single `body` argument
+    let body = op
+        .request_body
+        .as_ref()
+        .zip(request_schema)
+        .map(|(body, schema)| {
+            json!({
+                "name": "body",
+                "cs_type": nullable_if(!body.required, body_csharp_type(schema, schemas)),
+                "required": body.required,
+                "description": body.description.as_ref().map(|d| sanitize_markdown(d)),
+            })
+        });
+
+    // C# wants optional parameters last: required parameters, the body, then the rest
+    let required = |argument: &&JsonValue| argument["required"] == true;
+    let arguments: Vec<_> = parameters
+        .iter()
+        .filter(required)
+        .chain(body.iter().filter(required))
+        .chain(body.iter().filter(|argument| !required(argument)))
+        .chain(parameters.iter().filter(|argument| !required(argument)))
+        .cloned()
+        .collect();
+
+    let method_name = common::unique_identifier(csharp_member_name(&op.id), method_names);
+    Ok(json!({
+        "endpoint": to_snake_case(&op.id),
+        "method_name": method_name,
+        "path": op.path,
+        "http_method": op.method.to_uppercase(),
+        "summary": op.summary.as_ref().map(|s| sanitize_markdown(s)).unwrap_or_default(),
+        "description": op.description.as_ref().map(|s| sanitize_markdown(s)).unwrap_or_default(),
+        "doc": op.summary.as_ref().or(op.description.as_ref()).map(|s| xml_doc(s)),
+        "parameters": parameters,
+        "body": body,
+        "arguments": arguments,
+        "properties_schema": request_schema.map(common::properties_of).unwrap_or_else(|| json!({})),
+        "response_schema": common::response_schema(op).cloned().unwrap_or_else(|| json!({})),
+        "tags": op.tags.clone().unwrap_or_default(),
+    }))
+}
+
+fn build_csharp_parameters(op: &Operation, schemas: &JsonMap<String, JsonValue>) -> Vec<JsonValue> {
+    let mut used: HashSet<String> = RESERVED_PARAMETER_NAMES
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+
+    op.parameters
+        .iter()
+        .map(|p| {
+            let schema = serde_json::to_value(&p.schema).unwrap_or_default();
+            json!({
+                "name": common::unique_identifier(csharp_parameter_name(&p.name), &mut used),
+                "wire_name": p.name,
+                "cs_type": nullable_if(!p.required, csharp_type(&schema, schemas, 0)),
+                "in": format!("{:?}", p.location).to_lowercase(),
+                "required": p.required,
+                "description": p.description.as_ref().map(|d| sanitize_markdown(d)),
+            })
+        })
+        .collect()
+}
+
+/// Records for the object component schemas
+///
+/// Other component schemas have no C# counterpart to alias them with, so
+/// references to them use the referenced type directly.
+fn build_csharp_models(schemas: &JsonMap<String, JsonValue>) -> Vec<JsonValue> {
+    schemas
+        .iter()
+        .filter(|(_, schema)| common::is_model_schema(schema))
+        .map(|(name, schema)| {
+            let type_name = csharp_type_name(name);
+            let (properties, required) = common::object_properties(schema, schemas);
+            // Members can't share the name of their record
+            let mut used = HashSet::from([type_name.clone()]);
+            let properties: Vec<_> = properties
+                .iter()
+                .map(|(wire_name, property)| {
+                    let is_required = required.contains(wire_name);
+                    let nullable = !is_required || is_nullable(property);
+                    let member = csharp_member_name(wire_name);
+                    let member = if used.contains(&member) {
+                        common::unique_identifier(format!("{member}Value"), &mut used)
+                    } else {
+                        common::unique_identifier(member, &mut used)
+                    };
+                    json!({
+                        "name": member,
+                        "wire_name": wire_name,
+                        "cs_type": nullable_if(nullable, csharp_type(property, schemas, 0)),
+                        "required": is_required,
+                        "description": property
+                            .get("description")
+                            .and_then(JsonValue::as_str)
+                            .map(sanitize_markdown),
+                    })
+                })
+                .collect();
+            json!({
+                "name": type_name,
+                "schema_name": name,
+                "doc": schema.get("description").and_then(JsonValue::as_str).map(xml_doc),
+                "properties": properties,
+            })
+        })
+        .collect()
+}
+
+/// C# type for a request body, the matching record when there is one
+fn body_csharp_type(schema: &JsonValue, schemas: &JsonMap<String, JsonValue>) -> String {
+    if let Some(name) = common::matching_component(schema, schemas) {
+        return csharp_type_name(name);
+    }
+    if schema.get("type").and_then(JsonValue::as_str) == Some("array")
+        && let Some(name) = schema
+            .get("items")
+            .and_then(|items| common::matching_component(items, schemas))
+    {
+        return format!("IReadOnlyList<{}>", csharp_type_name(name));
+    }
+    csharp_type(schema, schemas, 0)
+}
+
+fn nullable_if(nullable: bool, cs_type: String) -> String {
+    if nullable && !cs_type.ends_with('?') {
+        format!("{cs_type}?")
+    } else {
+        cs_type
+    }
+}
+
+fn is_nullable(schema: &JsonValue) -> bool {
+    schema.get("nullable") == Some(&JsonValue::Bool(true))
+        || schema
+            .get("type")
+            .and_then(JsonValue::as_array)
+            .is_some_and(|types| types.iter().any(|t| t == "null"))
+}
+
+/// Map a JSON schema to a C# type without component schemas at hand
+///
+/// Shared with the `type_for` template function. Every `$ref` names a record.
+pub(crate) fn map_json_to_csharp_type(schema: &JsonValue) -> String {
+    csharp_type(schema, &JsonMap::new(), 0)
+}
+
+/// Map a JSON schema to a C# type
+///
+/// `$ref`s to object schemas name their record; references to other schemas
+/// resolve to the referenced schema's type. Values without a fixed shape are
+/// `JsonElement`s.
+fn csharp_type(schema: &JsonValue, schemas: &JsonMap<String, JsonValue>, depth: usize) -> String {
+    if let Some(reference) = schema.get("$ref").and_then(JsonValue::as_str) {
+        let name = reference.rsplit('/').next().unwrap_or(reference);
+        return match schemas.get(name) {
+            Some(target) if !common::is_model_schema(target) && depth < 8 => {
+                csharp_type(target, schemas, depth + 1)
+            }
+            _ => csharp_type_name(name),
+        };
+    }
+
+    let all_of = schema.get("allOf").and_then(JsonValue::as_array);
+    if let Some([only]) = all_of.map(Vec::as_slice) {
+        return csharp_type(only, schemas, depth);
+    }
+    if schema.get("oneOf").is_some()
+        || schema.get("anyOf").is_some()
+        || common::is_model_schema(schema)
+    {
+        return "JsonElement".to_string();
+    }
+
+    // OpenAPI 3.1 spells nullable types as `type: [T, "null"]`
+    let schema_type = match schema.get("type") {
+        Some(JsonValue::Array(types)) => types
+            .iter()
+            .filter_map(JsonValue::as_str)
+            .find(|t| *t != "null"),
+        Some(t) => t.as_str(),
+        None => None,
+    };
+    let format = schema.get("format").and_then(JsonValue::as_str);
+    match schema_type {
+        Some("string") => "string".to_string(),
+        Some("integer") if format == Some("int32") => "int".to_string(),
+        Some("integer") => "long".to_string(),
+        Some("number") if format == Some("float") => "float".to_string(),
+        Some("number") => "double".to_string(),
+        Some("boolean") => "bool".to_string(),
+        Some("array") => match schema.get("items").filter(|items| !items.is_null()) {
+            Some(items) => format!("IReadOnlyList<{}>", csharp_type(items, schemas, depth)),
+            None => "IReadOnlyList<JsonElement>".to_string(),
+        },
+        Some("object") => match schema.get("additionalProperties") {
+            Some(values) if values.is_object() => format!(
+                "IReadOnlyDictionary<string, {}>",
+                csharp_type(values, schemas, depth)
+            ),
+            _ => "IReadOnlyDictionary<string, JsonElement>".to_string(),
+        },
+        _ => "JsonElement".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::RequestBody;
+    use crate::infrastructure::{TemplateManifest, TemplateSource};
+    use crate::protocols::{Protocol, Role};
+    use std::collections::HashMap;
+
+    fn schemas() -> JsonMap<String, JsonValue> {
+        json!({
+            "Task": {
+                "type": "object",
+                "description": "A <task>",
+                "required": ["id", "task"],
+                "properties": {
+                    "id": { "type": "integer", "format": "int32" },
+                    "task": { "type": "string", "nullable": true },
+                    "status": { "$ref": "#/components/schemas/Status" },
+                    "labels": { "type": "object", "additionalProperties": { "type": "string" } },
+                    "2fa": { "type": "boolean" }
+                }
+            },
+            "Status": { "type": "string", "enum": ["open", "done"] }
+        })
+        .as_object()
+        .cloned()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_csharp_models() {
+        let models = build_csharp_models(&schemas());
+
+        // Only object schemas become records
+        assert_eq!(models.len(), 1);
+        let task = &models[0];
+        assert_eq!(task["name"], "TaskModel");
+        assert_eq!(task["schema_name"], "Task");
+        assert_eq!(task["doc"], "A &lt;task&gt;");
+
+        let properties: Vec<_> = task["properties"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| (p["name"].as_str().unwrap(), p["cs_type"].as_str().unwrap()))
+            .collect();
+        assert_eq!(
+            properties,
+            [
+                ("Id", "int"),
+                ("Task", "string?"),
+                // References to non-object schemas use their type directly
+                ("Status", "string?"),
+                ("Labels", "IReadOnlyDictionary<string, string>?"),
+                ("Value2fa", "bool?"),
+            ]
+        );
+        assert_eq!(task["properties"][1]["required"], true);
+    }
+
+    #[test]
+    fn test_csharp_endpoint_arguments() {
+        let schemas = schemas();
+        let mut op: Operation = serde_json::from_value(json!({
+            "operationId": "updateTask",
+            "path": "/tasks/{taskId}",
+            "method": "put",
+            "parameters": [
+                { "name": "dryRun", "location": "query", "required": false, "schema": { "type": "boolean" } },
+                { "name": "taskId", "location": "path", "required": true, "schema": { "type": "integer" } },
+                { "name": "class", "location": "header", "required": false, "schema": { "type": "string" } },
+                { "name": "client", "location": "query", "required": false, "schema": { "type": "array", "items": { "type": "string" } } }
+            ],
+            "request_body": null,
+            "responses": []
+        }))
+        .unwrap();
+        op.request_body = Some(RequestBody {
+            required: true,
+            content: json!({ "application/json": { "schema": { "type": "array", "items": schemas["Task"] } } }),
+            description: None,
+        });
+
+        let mut method_names = HashSet::new();
+        let endpoint = build_csharp_endpoint_context(&op, &schemas, &mut method_names).unwrap();
+
+        assert_eq!(endpoint["endpoint"], "update_task");
+        assert_eq!(endpoint["method_name"], "UpdateTask");
+        assert_eq!(endpoint["body"]["cs_type"], "IReadOnlyList<TaskModel>");
+
+        let arguments: Vec<_> = endpoint["arguments"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|a| (a["name"].as_str().unwrap(), a["cs_type"].as_str().unwrap()))
+            .collect();
+        assert_eq!(
+            arguments,
+            [
+                ("taskId", "long"),
+                ("body", "IReadOnlyList<TaskModel>"),
+                ("dryRun", "bool?"),
+                ("class_", "string?"),
+                // `client` is taken by the injected API client
+                ("client_2", "IReadOnlyList<string>?"),
+            ]
+        );
+        assert_eq!(endpoint["parameters"][3]["wire_name"], "client");
+    }
+
+    #[tokio::test]
+    async fn test_csharp_context_builder() {
+        let builder = CSharpContextBuilder::new();
+
+        let mut context = GenerationContext::new(Protocol::Mcp, Role::Server, Language::CSharp);
+        context.metadata.project_name = "petstore-server".to_string();
+
+        let manifest = TemplateManifest {
+            name: "test-template".to_string(),
+            version: "1.0.0".to_string(),
+            description: None,
+            path: "mcp/server/csharp".to_string(),
+            protocol: Protocol::Mcp,
+            role: Role::Server,
+            language: Language::CSharp,
+            files: vec![],
+            variables: HashMap::new(),
+            variable_schema: vec![],
+            pre_generate_hooks: vec![],
+            post_generate_hooks: vec![],
+            partials: vec![],
+            required_directories: vec![],
+            extends: None,
+        };
+
+        let template = Template {
+            manifest,
+            files: vec![],
+            source: TemplateSource::Embedded,
+        };
+
+        let render_context = builder.build(&context, &template).await.unwrap();
+        assert_eq!(render_context.variables["root_namespace"], "PetstoreServer");
+        assert_eq!(render_context.variables["assembly_name"], "petstore-server");
+
+        let context = GenerationContext::new(Protocol::Mcp, Role::Server, Language::Go);
+        assert!(builder.build(&context, &template).await.is_err());
+    }
+}
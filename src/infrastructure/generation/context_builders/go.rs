@@ -0,0 +1,805 @@
+//! Go-specific context builder for code generation
+
+use async_trait::async_trait;
+use serde_json::{Map as JsonMap, Value as JsonValue, json};
+use std::collections::HashSet;
+
+use super::common;
+use crate::generation::{
+    ContextBuilder, GenerationContext, GenerationError, Language, Operation, RenderContext,
+    sanitizers::sanitize_markdown,
+    utils::{escape_identifier, to_snake_case},
+};
+use crate::infrastructure::Template;
+
+/// Go-specific context builder
+pub struct GoContextBuilder;
+
+impl GoContextBuilder {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for GoContextBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ContextBuilder for GoContextBuilder {
+    async fn build(
+        &self,
+        context: &GenerationContext,
+        template: &Template,
+    ) -> Result<RenderContext, GenerationError> {
+        if context.language != Language::Go {
+            return Err(GenerationError::InvalidConfiguration(format!(
+                "GoContextBuilder can only build contexts for Go, got {:?}",
+                context.language
+            )));
+        }
+
+        let mut render_context = RenderContext::new();
+
+        // Base context
+        render_context.add_variable("project_name", json!(context.metadata.project_name));
+        render_context.add_variable("version", json!(context.metadata.version));
+        render_context.add_variable("description", json!(context.metadata.description));
+        render_context.add_variable("protocol", json!(context.protocol.to_string()));
+        render_context.add_variable("role", json!(context.role.to_string()));
+        render_context.add_variable("language", json!("go"));
+
+        // Go-specific naming
+        let package_name = go_package_name(&context.metadata.project_name);
+        render_context.add_variable("package_name", json!(package_name));
+        render_context.add_variable("module_path", json!(package_name));
+        render_context.add_variable("type_name", json!(go_exported_name(&package_name)));
+        render_context.add_variable("binary_name", json!(package_name));
+
+        // Handle protocol-specific context
+        let mut endpoints = Vec::new();
+        if let Some(protocol_context) = &context.protocol_context {
+            match protocol_context {
+                crate::generation::ProtocolContext::McpServer {
+                    openapi_spec,
+                    endpoints: operations,
+                } => {
+                    // Add OpenAPI spec information
+                    render_context.add_variable("api_version", json!(openapi_spec.version));
+                    render_context.add_variable("api_title", json!(openapi_spec.info.title));
+                    render_context
+                        .add_variable("api_info_version", json!(openapi_spec.info.version));
+                    if let Some(desc) = &openapi_spec.info.description {
+                        render_context.add_variable("api_description", json!(desc));
+                    }
+
+                    // Add servers information
+                    if !openapi_spec.servers.is_empty() {
+                        render_context.add_variable("api_servers", json!(openapi_spec.servers));
+                        render_context
+                            .add_variable("api_base_url", json!(openapi_spec.servers[0].url));
+                    }
+
+                    // Add components for potential $ref resolution
+                    let schemas = openapi_spec
+                        .components
+                        .as_ref()
+                        .and_then(|components| components.schemas.as_object())
+                        .cloned()
+                        .unwrap_or_default();
+                    if let Some(components) = &openapi_spec.components {
+                        render_context.add_variable("api_components", json!(components.schemas));
+                        if !components.security_schemes.is_null() {
+                            render_context.add_variable(
+                                "api_security_schemes",
+                                components.security_schemes.clone(),
+                            );
+                        }
+                    }
+
+                    // Structs and named types for the component schemas
+                    let mut type_names: HashSet<String> =
+                        RESERVED_TYPE_NAMES.iter().map(|s| s.to_string()).collect();
+                    render_context
+                        .add_variable("models", json!(build_go_models(&schemas, &mut type_names)));
+
+                    // Process operations into Go endpoint contexts
+                    for operation in operations {
+                        common::report_operation_diagnostics(operation, &context.diagnostics);
+                        let endpoint_context =
+                            build_go_endpoint_context(operation, &schemas, &mut type_names)?;
+                        endpoints.push(endpoint_context);
+                    }
+                }
+                crate::generation::ProtocolContext::McpBridge { server, tools } => {
+                    super::common::add_mcp_bridge_variables(
+                        &mut render_context,
+                        server.as_ref(),
+                        tools,
+                    );
+                }
+            }
+        }
+        render_context.add_variable("endpoints", json!(endpoints));
+
+        // Add custom variables
+        for (key, value) in &context.variables {
+            render_context.add_variable(key, value.clone());
+        }
+
+        // Add template variables
+        for (key, value) in &template.manifest.variables {
+            if !render_context.has_variable(key) {
+                render_context.add_variable(key, value.clone());
+            }
+        }
+
+        // Add template manifest metadata
+        render_context.add_variable("template_name", json!(template.manifest.name));
+        render_context.add_variable("template_version", json!(template.manifest.version));
+        if let Some(description) = &template.manifest.description {
+            render_context.add_variable("template_description", json!(description));
+        }
+
+        Ok(render_context)
+    }
+}
+
+/// Initialisms Go spells in a single case, as in `PetID` or `HTTPServer`
+const COMMON_INITIALISMS: &[&str] = &[
+    "ACL", "API", "ASCII", "CPU", "CSS", "DNS", "EOF", "GUID", "HTML", "HTTP", "HTTPS", "ID", "IP",
+    "JSON", "JWT", "OS", "QPS", "RAM", "RPC", "SKU", "SLA", "SMTP", "SQL", "SSH", "TCP", "TLS",
+    "TTL", "UDP", "UI", "UID", "URI", "URL", "UTF8", "UUID", "VM", "XML", "XMPP", "XSRF", "XSS",
+];
+
+/// Names the generated package declares, which models and tool inputs must not take
+const RESERVED_TYPE_NAMES: &[&str] = &[
+    "APIError",
+    "Client",
+    "Config",
+    "ConfigFromEnv",
+    "DefaultBaseURL",
+    "New",
+    "NewClient",
+    "Request",
+];
+
+/// One word of a Go identifier, keeping initialisms and their plurals upper case
+fn go_word(word: &str) -> String {
+    let upper = word.to_ascii_uppercase();
+    if COMMON_INITIALISMS.contains(&upper.as_str()) {
+        return upper;
+    }
+    if let Some(stem) = upper.strip_suffix('S')
+        && stem.len() > 1
+        && COMMON_INITIALISMS.contains(&stem)
+    {
+        return format!("{stem}s");
+    }
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// An exported Go identifier for an OpenAPI name: `pet_id` becomes `PetID`
+fn go_exported_name(name: &str) -> String {
+    let ident: String = to_snake_case(name).split('_').map(go_word).collect();
+    if ident.is_empty() {
+        "Field".to_string()
+    } else if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("X{ident}")
+    } else {
+        ident
+    }
+}
+
+/// An unexported Go identifier for an OpenAPI name: `Pet_ID` becomes `petID`
+fn go_unexported_name(name: &str) -> String {
+    let snake = to_snake_case(name);
+    let mut words = snake.split('_').filter(|word| !word.is_empty());
+    let ident = match words.next() {
+        Some(first) => first.to_string() + &words.map(go_word).collect::<String>(),
+        None => "value".to_string(),
+    };
+    if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("x{ident}")
+    } else {
+        escape_identifier(&ident, Language::Go)
+    }
+}
+
+/// A Go package name: short, lower case and without underscores
+fn go_package_name(name: &str) -> String {
+    let package: String = to_snake_case(name)
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .collect();
+    if package.is_empty() {
+        "app".to_string()
+    } else if package.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("pkg{package}")
+    } else if escape_identifier(&package, Language::Go) != package {
+        format!("{package}pkg")
+    } else {
+        package
+    }
+}
+
+/// An exported name that is not in `used` yet, numbered when taken
+fn unique_go_name(name: String, used: &mut HashSet<String>) -> String {
+    let mut candidate = name.clone();
+    let mut counter = 2;
+    while !used.insert(candidate.clone()) {
+        candidate = format!("{name}{counter}");
+        counter += 1;
+    }
+    candidate
+}
+
+/// A Go type name for a component schema name
+fn go_type_name(name: &str) -> String {
+    let type_name = go_exported_name(name);
+    if RESERVED_TYPE_NAMES.contains(&type_name.as_str()) {
+        format!("{type_name}Model")
+    } else {
+        type_name
+    }
+}
+
+/// A struct tag with the JSON name and, for the schema, the description
+///
+/// The `jsonschema` tag is what the MCP SDK reads tool argument descriptions
+/// from; quotes and backquotes would end the tag early, so they are dropped.
+fn go_struct_tag(wire_name: &str, omitempty: bool, description: Option<&str>) -> String {
+    let mut tag = format!(
+        "json:\"{wire_name}{}\"",
+        if omitempty { ",omitempty" } else { "" }
+    );
+    if let Some(description) = description {
+        let description: String = sanitize_markdown(description)
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .chars()
+            .filter(|c| !matches!(c, '"' | '`' | '\\'))
+            .collect();
+        if !description.is_empty() {
+            tag.push_str(&format!(" jsonschema:\"{description}\""));
+        }
+    }
+    tag
+}
+
+fn build_go_endpoint_context(
+    op: &Operation,
+    schemas: &JsonMap<String, JsonValue>,
+    type_names: &mut HashSet<String>,
+) -> Result<JsonValue, GenerationError> {
+    let request_schema = common::request_schema(op);
+
+    // The request body is passed to the tool as a single `body` argument
+    let body = op
+        .request_body
+        .as_ref()
+        .zip(request_schema)
+        .map(|(body, schema)| {
+            let go_type = body_go_type(schema, schemas);
+            let optional = !body.required;
+            json!({
+                "go_type": pointer_if(optional && !is_nilable(&go_type, schema, schemas, 0), go_type),
+                "tag": go_struct_tag("body", optional, body.description.as_deref()),
+                "required": body.required,
+                "description": body.description.as_ref().map(|d| sanitize_markdown(d)),
+            })
+        });
+
+    Ok(json!({
+        "endpoint": to_snake_case(&op.id),
+        "method_name": go_unexported_name(&op.id),
+        "input_type": unique_go_name(format!("{}Input", go_exported_name(&op.id)), type_names),
+        "path": op.path,
+        "http_method": op.method.to_uppercase(),
+        "summary": op.summary.as_ref().map(|s| sanitize_markdown(s)).unwrap_or_default(),
+        "description": op.description.as_ref().map(|s| sanitize_markdown(s)).unwrap_or_default(),
+        "parameters": build_go_parameters(op, schemas),
+        "body": body,
+        "properties_schema": request_schema.map(common::properties_of).unwrap_or_else(|| json!({})),
+        "response_schema": common::response_schema(op).cloned().unwrap_or_else(|| json!({})),
+        "tags": op.tags.clone().unwrap_or_default(),
+    }))
+}
+
+fn build_go_parameters(op: &Operation, schemas: &JsonMap<String, JsonValue>) -> Vec<JsonValue> {
+    // `Body` is taken by the request body field
+    let mut used = HashSet::from(["Body".to_string()]);
+
+    op.parameters
+        .iter()
+        .map(|p| {
+            let schema = serde_json::to_value(&p.schema).unwrap_or_default();
+            let go_type = go_type(&schema, None);
+            let optional = !p.required;
+            let go_type = pointer_if(
+                optional && !is_nilable(&go_type, &schema, schemas, 0),
+                go_type,
+            );
+            json!({
+                "name": to_snake_case(&p.name),
+                "field_name": unique_go_name(go_exported_name(&p.name), &mut used),
+                "wire_name": p.name,
+                "go_type": go_type,
+                "tag": go_struct_tag(&p.name, optional, p.description.as_deref()),
+                "in": format!("{:?}", p.location).to_lowercase(),
+                "required": p.required,
+                "description": p.description.as_ref().map(|d| sanitize_markdown(d)),
+            })
+        })
+        .collect()
+}
+
+/// Object schemas nested in a model, generated as structs of their own
+struct InlineStructs<'a> {
+    used: &'a mut HashSet<String>,
+    pending: Vec<(String, JsonValue)>,
+}
+
+impl InlineStructs<'_> {
+    /// Queue `schema` as a struct named after `name` and return that name
+    fn add(&mut self, name: String, schema: &JsonValue) -> String {
+        let name = unique_go_name(name, self.used);
+        self.pending.push((name.clone(), schema.clone()));
+        name
+    }
+}
+
+/// Go types for the component schemas
+///
+/// Object schemas become structs, string enums become named string types
+/// with a constant per value, and everything else a type alias. Objects
+/// nested inside a struct get a struct of their own, declared right after it.
+fn build_go_models(
+    schemas: &JsonMap<String, JsonValue>,
+    used: &mut HashSet<String>,
+) -> Vec<JsonValue> {
+    let names: Vec<_> = schemas
+        .iter()
+        .map(|(name, schema)| (name, schema, go_type_name(name)))
+        .collect();
+    used.extend(names.iter().map(|(_, _, type_name)| type_name.clone()));
+
+    let mut models = Vec::new();
+    for (name, schema, type_name) in names {
+        push_go_model(type_name, name, schema, schemas, used, &mut models);
+    }
+    models
+}
+
+fn push_go_model(
+    type_name: String,
+    schema_name: &str,
+    schema: &JsonValue,
+    schemas: &JsonMap<String, JsonValue>,
+    used: &mut HashSet<String>,
+    models: &mut Vec<JsonValue>,
+) {
+    let mut inline = InlineStructs {
+        used,
+        pending: Vec::new(),
+    };
+    let model = go_model(&type_name, schema_name, schema, schemas, &mut inline);
+    let pending = inline.pending;
+    models.push(model);
+    for (nested_name, nested) in pending {
+        push_go_model(nested_name, schema_name, &nested, schemas, used, models);
+    }
+}
+
+fn go_model(
+    type_name: &str,
+    schema_name: &str,
+    schema: &JsonValue,
+    schemas: &JsonMap<String, JsonValue>,
+    inline: &mut InlineStructs,
+) -> JsonValue {
+    let description = schema
+        .get("description")
+        .and_then(JsonValue::as_str)
+        .map(sanitize_markdown);
+
+    if common::is_model_schema(schema) {
+        let (properties, required) = common::object_properties(schema, schemas);
+        let mut used = HashSet::new();
+        let fields: Vec<_> = properties
+            .iter()
+            .map(|(wire_name, property)| {
+                let field_name = unique_go_name(go_exported_name(wire_name), &mut used);
+                let go_type = go_type(
+                    property,
+                    Some((&format!("{type_name}{field_name}"), &mut *inline)),
+                );
+                let optional = !required.contains(wire_name);
+                // A struct can only hold itself through a pointer
+                let pointer = !is_nilable(&go_type, property, schemas, 0)
+                    && (optional || is_nullable(property) || go_type == type_name);
+                let description = property.get("description").and_then(JsonValue::as_str);
+                json!({
+                    "name": field_name,
+                    "wire_name": wire_name,
+                    "go_type": pointer_if(pointer, go_type),
+                    "tag": go_struct_tag(wire_name, optional, description),
+                    "required": !optional,
+                    "description": description.map(sanitize_markdown),
+                })
+            })
+            .collect();
+        return json!({
+            "kind": "struct",
+            "name": type_name,
+            "schema_name": schema_name,
+            "description": description,
+            "fields": fields,
+        });
+    }
+
+    if let Some(values) = string_enum(schema) {
+        let mut used = HashSet::new();
+        let constants: Vec<_> = values
+            .iter()
+            .map(|value| {
+                let suffix = if value.is_empty() {
+                    "Empty".to_string()
+                } else {
+                    go_exported_name(value)
+                };
+                json!({
+                    "name": unique_go_name(format!("{type_name}{suffix}"), &mut used),
+                    "value": json!(value).to_string(),
+                })
+            })
+            .collect();
+        return json!({
+            "kind": "enum",
+            "name": type_name,
+            "schema_name": schema_name,
+            "description": description,
+            "go_type": "string",
+            "constants": constants,
+        });
+    }
+
+    json!({
+        "kind": "alias",
+        "name": type_name,
+        "schema_name": schema_name,
+        "description": description,
+        "go_type": go_type(schema, Some((type_name, inline))),
+    })
+}
+
+/// The values of a string enum schema
+fn string_enum(schema: &JsonValue) -> Option<Vec<&str>> {
+    let values = schema.get("enum")?.as_array()?;
+    let values: Option<Vec<_>> = values
+        .iter()
+        .filter(|value| !value.is_null())
+        .map(JsonValue::as_str)
+        .collect();
+    values.filter(|values| !values.is_empty())
+}
+
+/// Go type for a request body, the matching model's type when there is one
+fn body_go_type(schema: &JsonValue, schemas: &JsonMap<String, JsonValue>) -> String {
+    if let Some(name) = common::matching_component(schema, schemas) {
+        return go_type_name(name);
+    }
+    if schema.get("type").and_then(JsonValue::as_str) == Some("array")
+        && let Some(name) = schema
+            .get("items")
+            .and_then(|items| common::matching_component(items, schemas))
+    {
+        return format!("[]{}", go_type_name(name));
+    }
+    go_type(schema, None)
+}
+
+fn pointer_if(pointer: bool, go_type: String) -> String {
+    if pointer {
+        format!("*{go_type}")
+    } else {
+        go_type
+    }
+}
+
+/// The schema's type, skipping the `"null"` of OpenAPI 3.1 `type: [T, "null"]`
+fn schema_type(schema: &JsonValue) -> Option<&str> {
+    match schema.get("type") {
+        Some(JsonValue::Array(types)) => types
+            .iter()
+            .filter_map(JsonValue::as_str)
+            .find(|t| *t != "null"),
+        Some(t) => t.as_str(),
+        None => None,
+    }
+}
+
+fn is_nullable(schema: &JsonValue) -> bool {
+    schema.get("nullable") == Some(&JsonValue::Bool(true))
+        || schema
+            .get("type")
+            .and_then(JsonValue::as_array)
+            .is_some_and(|types| types.iter().any(|t| t == "null"))
+}
+
+/// Whether values of `type_name` can already be nil: slices, maps, `any` and aliases of those
+fn is_nilable(
+    type_name: &str,
+    schema: &JsonValue,
+    schemas: &JsonMap<String, JsonValue>,
+    depth: usize,
+) -> bool {
+    if type_name.starts_with("[]") || type_name.starts_with("map[") || type_name == "any" {
+        return true;
+    }
+    let component = schema
+        .get("$ref")
+        .and_then(JsonValue::as_str)
+        .and_then(|reference| schemas.get(reference.rsplit('/').next().unwrap_or(reference)));
+    match component {
+        Some(component)
+            if depth < 8
+                && !common::is_model_schema(component)
+                && string_enum(component).is_none() =>
+        {
+            let aliased = go_type(component, None);
+            is_nilable(&aliased, component, schemas, depth + 1)
+        }
+        _ => false,
+    }
+}
+
+/// Map a JSON schema to a Go type, with objects as `map[string]any`
+///
+/// Shared with the `type_for` template function.
+pub(crate) fn map_json_to_go_type(schema: &JsonValue) -> String {
+    go_type(schema, None)
+}
+
+/// Map a JSON schema to a Go type
+///
+/// `$ref`s name the referenced component's type. With `inline`, objects with
+/// properties of their own become structs named after the given name;
+/// without it they are `map[string]any`.
+fn go_type(schema: &JsonValue, mut inline: Option<(&str, &mut InlineStructs)>) -> String {
+    if let Some(reference) = schema.get("$ref").and_then(JsonValue::as_str) {
+        return go_type_name(reference.rsplit('/').next().unwrap_or(reference));
+    }
+
+    let all_of = schema.get("allOf").and_then(JsonValue::as_array);
+    if let Some([only]) = all_of.map(Vec::as_slice) {
+        return go_type(only, inline);
+    }
+    if schema.get("oneOf").is_some() || schema.get("anyOf").is_some() {
+        return "any".to_string();
+    }
+    if common::is_model_schema(schema) {
+        return match inline {
+            Some((name, structs)) => structs.add(name.to_string(), schema),
+            None => "map[string]any".to_string(),
+        };
+    }
+
+    match schema_type(schema) {
+        Some("string") => "string".to_string(),
+        Some("integer") => match schema.get("format").and_then(JsonValue::as_str) {
+            Some("int32") => "int32".to_string(),
+            _ => "int64".to_string(),
+        },
+        Some("number") => match schema.get("format").and_then(JsonValue::as_str) {
+            Some("float") => "float32".to_string(),
+            _ => "float64".to_string(),
+        },
+        Some("boolean") => "bool".to_string(),
+        Some("array") => match schema.get("items").filter(|items| !items.is_null()) {
+            Some(items) => {
+                let inline = inline
+                    .as_mut()
+                    .map(|(name, structs)| (format!("{name}Item"), &mut **structs));
+                let item = match inline {
+                    Some((name, structs)) => go_type(items, Some((&name, structs))),
+                    None => go_type(items, None),
+                };
+                format!("[]{item}")
+            }
+            None => "[]any".to_string(),
+        },
+        Some("object") => match schema.get("additionalProperties") {
+            Some(values) if values.is_object() => {
+                format!("map[string]{}", go_type(values, None))
+            }
+            _ => "map[string]any".to_string(),
+        },
+        _ => "any".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::RequestBody;
+    use crate::infrastructure::{TemplateManifest, TemplateSource};
+    use crate::protocols::{Protocol, Role};
+    use std::collections::HashMap;
+
+    fn schemas() -> JsonMap<String, JsonValue> {
+        json!({
+            "Node": {
+                "type": "object",
+                "required": ["id", "parent"],
+                "properties": {
+                    "id": { "type": "integer", "format": "int32" },
+                    "parent": { "$ref": "#/components/schemas/Node" },
+                    "children": { "type": "array", "items": { "$ref": "#/components/schemas/Node" } },
+                    "status": { "$ref": "#/components/schemas/Status" },
+                    "photoUrls": { "$ref": "#/components/schemas/Urls" },
+                    "owner": {
+                        "type": "object",
+                        "description": "Who owns \"the\" node",
+                        "properties": { "name": { "type": "string" } }
+                    }
+                }
+            },
+            "Status": { "type": "string", "enum": ["available", "sold-out"] },
+            "Urls": { "type": "array", "items": { "type": "string" } }
+        })
+        .as_object()
+        .cloned()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_go_identifiers() {
+        assert_eq!(go_exported_name("petId"), "PetID");
+        assert_eq!(go_exported_name("photo_urls"), "PhotoURLs");
+        assert_eq!(go_exported_name("api-key"), "APIKey");
+        assert_eq!(go_exported_name("2fa"), "X2fa");
+        assert_eq!(go_unexported_name("Get_Pet_By_ID"), "getPetByID");
+        assert_eq!(go_unexported_name("type"), "type_");
+        assert_eq!(go_package_name("petstore-server"), "petstoreserver");
+        assert_eq!(go_package_name("func"), "funcpkg");
+        assert_eq!(go_type_name("Client"), "ClientModel");
+    }
+
+    #[test]
+    fn test_go_models() {
+        let models = build_go_models(&schemas(), &mut HashSet::new());
+
+        let node = &models[0];
+        assert_eq!(node["kind"], "struct");
+        let fields: Vec<_> = node["fields"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|field| {
+                (
+                    field["name"].as_str().unwrap(),
+                    field["go_type"].as_str().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                ("ID", "int32"),
+                // Recursive fields hold a pointer even when required
+                ("Parent", "*Node"),
+                ("Children", "[]Node"),
+                ("Status", "*Status"),
+                // Aliases of slices can already be nil
+                ("PhotoURLs", "URLs"),
+                ("Owner", "*NodeOwner"),
+            ]
+        );
+        assert_eq!(node["fields"][0]["tag"], r#"json:"id""#);
+        assert_eq!(node["fields"][4]["tag"], r#"json:"photoUrls,omitempty""#);
+        assert_eq!(
+            node["fields"][5]["tag"],
+            r#"json:"owner,omitempty" jsonschema:"Who owns the node""#
+        );
+
+        // The nested object follows its parent
+        assert_eq!(models[1]["name"], "NodeOwner");
+        assert_eq!(models[1]["fields"][0]["go_type"], "*string");
+
+        assert_eq!(models[2]["kind"], "enum");
+        assert_eq!(models[2]["constants"][1]["name"], "StatusSoldOut");
+        assert_eq!(models[2]["constants"][1]["value"], r#""sold-out""#);
+        assert_eq!(models[3]["kind"], "alias");
+        assert_eq!(models[3]["name"], "URLs");
+        assert_eq!(models[3]["go_type"], "[]string");
+    }
+
+    #[test]
+    fn test_go_endpoint_tool_inputs() {
+        let schemas = schemas();
+        let mut op: Operation = serde_json::from_value(json!({
+            "operationId": "updateStatus",
+            "path": "/nodes/{nodeId}",
+            "method": "put",
+            "parameters": [
+                { "name": "nodeId", "location": "path", "required": true, "schema": { "type": "integer" }, "description": "Node id" },
+                { "name": "body", "location": "query", "required": false, "schema": { "type": "boolean" } },
+                { "name": "tags", "location": "query", "required": false, "schema": { "type": "array", "items": { "type": "string" } } }
+            ],
+            "request_body": null,
+            "responses": []
+        }))
+        .unwrap();
+        op.request_body = Some(RequestBody {
+            required: false,
+            content: json!({ "application/json": { "schema": { "type": "object", "properties": { "name": { "type": "string" } } } } }),
+            description: None,
+        });
+
+        let mut type_names = HashSet::from(["UpdateStatusInput".to_string()]);
+        let endpoint = build_go_endpoint_context(&op, &schemas, &mut type_names).unwrap();
+
+        assert_eq!(endpoint["endpoint"], "update_status");
+        assert_eq!(endpoint["input_type"], "UpdateStatusInput2");
+        assert_eq!(endpoint["http_method"], "PUT");
+        assert_eq!(endpoint["body"]["go_type"], "map[string]any");
+        assert_eq!(endpoint["body"]["tag"], r#"json:"body,omitempty""#);
+        assert_eq!(endpoint["parameters"][0]["field_name"], "NodeID");
+        assert_eq!(endpoint["parameters"][0]["go_type"], "int64");
+        assert_eq!(
+            endpoint["parameters"][0]["tag"],
+            r#"json:"nodeId" jsonschema:"Node id""#
+        );
+        // `Body` is taken by the request body field
+        assert_eq!(endpoint["parameters"][1]["field_name"], "Body2");
+        assert_eq!(endpoint["parameters"][1]["go_type"], "*bool");
+        assert_eq!(endpoint["parameters"][2]["go_type"], "[]string");
+    }
+
+    #[tokio::test]
+    async fn test_go_context_builder() {
+        let builder = GoContextBuilder::new();
+
+        let mut context = GenerationContext::new(Protocol::Mcp, Role::Server, Language::Go);
+        context.metadata.project_name = "test-server".to_string();
+
+        let manifest = TemplateManifest {
+            name: "test-template".to_string(),
+            version: "1.0.0".to_string(),
+            description: None,
+            path: "mcp/server/go".to_string(),
+            protocol: Protocol::Mcp,
+            role: Role::Server,
+            language: Language::Go,
+            files: vec![],
+            variables: HashMap::new(),
+            variable_schema: vec![],
+            pre_generate_hooks: vec![],
+            post_generate_hooks: vec![],
+            partials: vec![],
+            required_directories: vec![],
+            extends: None,
+        };
+
+        let template = Template {
+            manifest,
+            files: vec![],
+            source: TemplateSource::Embedded,
+        };
+
+        let render_context = builder.build(&context, &template).await.unwrap();
+        assert_eq!(render_context.variables["package_name"], "testserver");
+        assert_eq!(render_context.variables["module_path"], "testserver");
+
+        let context = GenerationContext::new(Protocol::Mcp, Role::Server, Language::Rust);
+        assert!(builder.build(&context, &template).await.is_err());
+    }
+}
@@ -1,10 +1,15 @@
 //! Language-specific context builders
 
+pub mod common;
+pub mod csharp;
+pub mod go;
 pub mod python;
 pub mod registry;
 pub mod rust;
 pub mod typescript;
 
+pub use csharp::CSharpContextBuilder;
+pub use go::GoContextBuilder;
 pub use python::PythonContextBuilder;
 pub use rust::RustContextBuilder;
 pub use typescript::TypeScriptContextBuilder;
@@ -2,12 +2,14 @@
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
-use serde_json::{Value as JsonValue, json};
+use serde_json::{Map as JsonMap, Value as JsonValue, json};
+use std::collections::{BTreeSet, HashSet};
 
+use super::common;
 use crate::generation::{
     ContextBuilder, GenerationContext, GenerationError, Language, Operation, RenderContext,
     sanitizers::sanitize_markdown,
-    utils::{to_proper_case, to_snake_case},
+    utils::{escape_identifier, to_proper_case, to_snake_case},
 };
 use crate::infrastructure::Template;
 
@@ -30,6 +32,12 @@ impl PythonContextBuilder {
     }
 }
 
+impl Default for PythonContextBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[async_trait]
 impl ContextBuilder for PythonContextBuilder {
     async fn build(
@@ -89,15 +97,41 @@ impl ContextBuilder for PythonContextBuilder {
                     }
 
                     // Add components for potential $ref resolution
+                    let schemas = openapi_spec
+                        .components
+                        .as_ref()
+                        .and_then(|components| components.schemas.as_object())
+                        .cloned()
+                        .unwrap_or_default();
                     if let Some(components) = &openapi_spec.components {
                         render_context.add_variable("api_components", json!(components.schemas));
+                        if !components.security_schemes.is_null() {
+                            render_context.add_variable(
+                                "api_security_schemes",
+                                components.security_schemes.clone(),
+                            );
+                        }
                     }
 
+                    // Pydantic models for the component schemas
+                    render_context.add_variable("models", build_python_models(&schemas));
+
                     // Process operations into Python endpoint contexts
+                    let mut tool_models = BTreeSet::new();
                     for operation in operations {
-                        let endpoint_context = build_python_endpoint_context(operation)?;
+                        common::report_operation_diagnostics(operation, &context.diagnostics);
+                        let endpoint_context =
+                            build_python_endpoint_context(operation, &schemas, &mut tool_models)?;
                         endpoints.push(endpoint_context);
                     }
+                    render_context.add_variable("tool_models", json!(tool_models));
+                }
+                crate::generation::ProtocolContext::McpBridge { server, tools } => {
+                    super::common::add_mcp_bridge_variables(
+                        &mut render_context,
+                        server.as_ref(),
+                        tools,
+                    );
                 }
             }
         }
@@ -126,31 +160,120 @@ impl ContextBuilder for PythonContextBuilder {
     }
 }
 
-fn build_python_endpoint_context(op: &Operation) -> Result<JsonValue, GenerationError> {
-    let method_name = to_snake_case(&op.id);
+/// Field names that would shadow `BaseModel` attributes or the builtins used in annotations
+const RESERVED_FIELD_NAMES: &[&str] = &[
+    "bool",
+    "bytes",
+    "construct",
+    "copy",
+    "dict",
+    "float",
+    "int",
+    "json",
+    "model_config",
+    "model_fields",
+    "schema",
+    "str",
+    "validate",
+];
+
+/// Names the generated modules import or define, which models must not shadow
+const RESERVED_CLASS_NAMES: &[&str] = &[
+    "Any",
+    "ApiClient",
+    "BaseModel",
+    "ConfigDict",
+    "Context",
+    "Dict",
+    "False",
+    "FastMCP",
+    "Field",
+    "List",
+    "Literal",
+    "None",
+    "Optional",
+    "True",
+    "Union",
+];
+
+/// A snake_case Python identifier for an OpenAPI name
+fn python_identifier(name: &str) -> String {
+    let ident = to_snake_case(name);
+    let ident = if ident.is_empty() {
+        "field".to_string()
+    } else if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("field_{ident}")
+    } else {
+        ident
+    };
+    escape_identifier(&ident, Language::Python)
+}
+
+/// A PascalCase Python class name for a component schema name
+fn python_class_name(name: &str) -> String {
+    let class = to_proper_case(name);
+    if class.is_empty() || class.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("Model{class}")
+    } else if RESERVED_CLASS_NAMES.contains(&class.as_str()) {
+        format!("{class}Model")
+    } else {
+        class
+    }
+}
+
+fn build_python_endpoint_context(
+    op: &Operation,
+    schemas: &JsonMap<String, JsonValue>,
+    tool_models: &mut BTreeSet<String>,
+) -> Result<JsonValue, GenerationError> {
+    let method_name = python_identifier(&op.id);
+    let request_schema = common::request_schema(op);
+
+    // The request body is passed to the tool as a single `body` argument
+    let body = op
+        .request_body
+        .as_ref()
+        .zip(request_schema)
+        .map(|(body, schema)| {
+            json!({
+                "type_hint": body_type_hint(schema, schemas, tool_models),
+                "required": body.required,
+                "description": body.description.as_ref().map(|d| sanitize_markdown(d)),
+            })
+        });
 
     Ok(json!({
+        "endpoint": method_name,
         "method_name": method_name,
         "class_name": to_proper_case(&format!("{}_handler", op.id)),
         "path": op.path,
         "http_method": op.method.to_lowercase(),
         "summary": op.summary.as_ref().map(|s| sanitize_markdown(s)).unwrap_or_default(),
         "description": op.description.as_ref().map(|s| sanitize_markdown(s)).unwrap_or_default(),
-        "parameters": build_python_parameters(op),
+        "parameters": build_python_parameters(op, tool_models),
+        "body": body,
+        "properties_schema": request_schema.map(common::properties_of).unwrap_or_else(|| json!({})),
+        "response_schema": common::response_schema(op).cloned().unwrap_or_else(|| json!({})),
         "response_type": map_response_to_python_type(op),
         "tags": op.tags.clone().unwrap_or_default(),
     }))
 }
 
-fn build_python_parameters(op: &Operation) -> Vec<JsonValue> {
+fn build_python_parameters(op: &Operation, tool_models: &mut BTreeSet<String>) -> Vec<JsonValue> {
+    // `ctx` and `body` are taken by the generated tool signature
+    let mut used: HashSet<String> = ["ctx", "body"].iter().map(|s| s.to_string()).collect();
+
     op.parameters
         .iter()
         .map(|p| {
-            let python_type = map_schema_to_python_type(&p.schema);
+            let python_name = common::unique_identifier(python_identifier(&p.name), &mut used);
+            let schema = serde_json::to_value(&p.schema).unwrap_or_default();
+            let python_type = python_type_hint(&schema, false, tool_models);
             json!({
-                "name": to_snake_case(&p.name),
-                "python_name": to_snake_case(&p.name),
-                "type": python_type.clone(),
+                "name": python_name,
+                "python_name": python_name,
+                "wire_name": p.name,
+                "type": python_type,
                 "type_hint": python_type,
                 "in": format!("{:?}", p.location).to_lowercase(),
                 "required": p.required,
@@ -161,25 +284,181 @@ fn build_python_parameters(op: &Operation) -> Vec<JsonValue> {
         .collect()
 }
 
-fn map_schema_to_python_type(schema: &crate::generation::Schema) -> String {
-    if let Some(typ) = &schema.schema_type {
-        match typ.as_str() {
-            "string" => "str".to_string(),
-            "integer" => "int".to_string(),
-            "boolean" => "bool".to_string(),
-            "number" => "float".to_string(),
-            "array" => {
-                if let Some(items) = &schema.items {
-                    format!("List[{}]", map_schema_to_python_type(items))
-                } else {
-                    "List[Any]".to_string()
+/// Pydantic classes for object component schemas and type aliases for the rest
+fn build_python_models(schemas: &JsonMap<String, JsonValue>) -> JsonValue {
+    let mut classes = Vec::new();
+    let mut aliases = Vec::new();
+
+    for (name, schema) in schemas {
+        let class_name = python_class_name(name);
+        if !common::is_model_schema(schema) {
+            // Aliases are evaluated on import, so their references stay quoted
+            aliases.push(json!({
+                "name": class_name,
+                "type_hint": python_type_hint(schema, true, &mut BTreeSet::new()),
+            }));
+            continue;
+        }
+
+        let (properties, required) = common::object_properties(schema, schemas);
+        let mut used = HashSet::new();
+        let fields: Vec<_> = properties
+            .iter()
+            .map(|(wire_name, property)| {
+                let mut field_name = python_identifier(wire_name);
+                if RESERVED_FIELD_NAMES.contains(&field_name.as_str()) {
+                    field_name.push('_');
                 }
+                let field_name = common::unique_identifier(field_name, &mut used);
+                let is_required = required.contains(wire_name);
+                let type_hint = python_type_hint(property, false, &mut BTreeSet::new());
+                json!({
+                    "alias": (&field_name != wire_name).then_some(wire_name),
+                    "name": field_name,
+                    "type_hint": if is_required { type_hint } else { optional(type_hint) },
+                    "required": is_required,
+                    "description": property
+                        .get("description")
+                        .and_then(JsonValue::as_str)
+                        .map(sanitize_markdown),
+                })
+            })
+            .collect();
+
+        classes.push(json!({
+            "name": class_name,
+            "description": schema
+                .get("description")
+                .and_then(JsonValue::as_str)
+                .map(sanitize_markdown),
+            "fields": fields,
+        }));
+    }
+
+    json!({ "classes": classes, "aliases": aliases })
+}
+
+/// Type hint for a request body, the matching model when there is one
+fn body_type_hint(
+    schema: &JsonValue,
+    schemas: &JsonMap<String, JsonValue>,
+    tool_models: &mut BTreeSet<String>,
+) -> String {
+    let matched =
+        |schema: &JsonValue| common::matching_component(schema, schemas).map(python_class_name);
+
+    if let Some(class) = matched(schema) {
+        tool_models.insert(class.clone());
+        return class;
+    }
+    if schema.get("type").and_then(JsonValue::as_str) == Some("array")
+        && let Some(class) = schema.get("items").and_then(matched)
+    {
+        tool_models.insert(class.clone());
+        return format!("List[{class}]");
+    }
+    python_type_hint(schema, false, tool_models)
+}
+
+/// Map a JSON schema to a Python type hint, recording referenced models in `refs`
+fn python_type_hint(schema: &JsonValue, quote_refs: bool, refs: &mut BTreeSet<String>) -> String {
+    if let Some(reference) = schema.get("$ref").and_then(JsonValue::as_str) {
+        let class = python_class_name(reference.rsplit('/').next().unwrap_or(reference));
+        refs.insert(class.clone());
+        return if quote_refs {
+            format!("\"{class}\"")
+        } else {
+            class
+        };
+    }
+
+    // OpenAPI 3.1 spells nullable types as `type: [T, "null"]`
+    let (schema_type, nullable) = match schema.get("type") {
+        Some(JsonValue::Array(types)) => (
+            types
+                .iter()
+                .filter_map(JsonValue::as_str)
+                .find(|t| *t != "null"),
+            types.iter().any(|t| t == "null"),
+        ),
+        Some(t) => (t.as_str(), false),
+        None => (None, false),
+    };
+    let nullable = nullable || schema.get("nullable") == Some(&JsonValue::Bool(true));
+
+    let variants = schema
+        .get("oneOf")
+        .or_else(|| schema.get("anyOf"))
+        .and_then(JsonValue::as_array);
+    let hint = if let Some(values) = schema.get("enum").and_then(JsonValue::as_array)
+        && let Some(literals) = python_literals(values)
+    {
+        format!("Literal[{literals}]")
+    } else if let Some(variants) = variants {
+        let mut hints: Vec<String> = Vec::new();
+        for variant in variants {
+            let hint = python_type_hint(variant, quote_refs, refs);
+            if !hints.contains(&hint) {
+                hints.push(hint);
+            }
+        }
+        match hints.as_slice() {
+            [] => "Any".to_string(),
+            [only] => only.clone(),
+            _ => format!("Union[{}]", hints.join(", ")),
+        }
+    } else if let Some([only]) = schema
+        .get("allOf")
+        .and_then(JsonValue::as_array)
+        .map(Vec::as_slice)
+    {
+        python_type_hint(only, quote_refs, refs)
+    } else {
+        match schema_type {
+            Some("string") => "str".to_string(),
+            Some("integer") => "int".to_string(),
+            Some("boolean") => "bool".to_string(),
+            Some("number") => "float".to_string(),
+            Some("array") => match schema.get("items").filter(|items| !items.is_null()) {
+                Some(items) => format!("List[{}]", python_type_hint(items, quote_refs, refs)),
+                None => "List[Any]".to_string(),
+            },
+            Some("object") => match schema.get("additionalProperties") {
+                Some(values) if values.is_object() => {
+                    format!("Dict[str, {}]", python_type_hint(values, quote_refs, refs))
+                }
+                _ => "Dict[str, Any]".to_string(),
+            },
+            _ if schema.get("properties").is_some_and(JsonValue::is_object) => {
+                "Dict[str, Any]".to_string()
             }
-            "object" => "Dict[str, Any]".to_string(),
             _ => "Any".to_string(),
         }
+    };
+
+    if nullable { optional(hint) } else { hint }
+}
+
+/// Python literals for the scalar values of an enum, if they all are
+fn python_literals(values: &[JsonValue]) -> Option<String> {
+    let literals = values
+        .iter()
+        .filter(|value| !value.is_null())
+        .map(|value| match value {
+            JsonValue::String(_) | JsonValue::Number(_) => Some(value.to_string()),
+            JsonValue::Bool(true) => Some("True".to_string()),
+            JsonValue::Bool(false) => Some("False".to_string()),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+    (!literals.is_empty()).then(|| literals.join(", "))
+}
+
+fn optional(hint: String) -> String {
+    if hint == "Any" || hint.starts_with("Optional[") {
+        hint
     } else {
-        "Any".to_string()
+        format!("Optional[{hint}]")
     }
 }
 
@@ -198,35 +477,207 @@ fn map_response_to_python_type(op: &Operation) -> String {
     "Dict[str, Any]".to_string()
 }
 
-fn map_json_to_python_type(schema: &JsonValue) -> String {
-    if let Some(typ) = schema.get("type").and_then(|v| v.as_str()) {
-        match typ {
-            "string" => "str".to_string(),
-            "integer" => "int".to_string(),
-            "boolean" => "bool".to_string(),
-            "number" => "float".to_string(),
-            "array" => {
-                if let Some(items) = schema.get("items") {
-                    format!("List[{}]", map_json_to_python_type(items))
-                } else {
-                    "List[Any]".to_string()
-                }
-            }
-            "object" => "Dict[str, Any]".to_string(),
-            _ => "Any".to_string(),
-        }
-    } else {
-        "Any".to_string()
+/// Map a JSON schema to a Python type
+///
+/// Shared with the `python_type` template filter. `$ref`s map to the class
+/// name of the referenced component.
+pub(crate) fn map_json_to_python_type(schema: &JsonValue) -> String {
+    if let Some(reference) = schema.get("$ref").and_then(JsonValue::as_str) {
+        return python_class_name(reference.rsplit('/').next().unwrap_or(reference));
+    }
+
+    match schema.get("type").and_then(JsonValue::as_str) {
+        Some("string") => "str".to_string(),
+        Some("integer") => "int".to_string(),
+        Some("boolean") => "bool".to_string(),
+        Some("number") => "float".to_string(),
+        Some("array") => match schema.get("items").filter(|items| !items.is_null()) {
+            Some(items) => format!("List[{}]", map_json_to_python_type(items)),
+            None => "List[Any]".to_string(),
+        },
+        Some("object") => "Dict[str, Any]".to_string(),
+        _ => "Any".to_string(),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::generation::RequestBody;
     use crate::infrastructure::{Template, TemplateManifest, TemplateSource};
     use crate::protocols::{Protocol, Role};
     use std::collections::HashMap;
 
+    fn schemas() -> JsonMap<String, JsonValue> {
+        json!({
+            "Tag": {
+                "type": "object",
+                "properties": { "id": { "type": "integer" }, "name": { "type": "string" } }
+            },
+            "Status": { "type": "string", "enum": ["available", "sold"] },
+            "Pet": {
+                "type": "object",
+                "description": "A pet",
+                "required": ["name"],
+                "properties": {
+                    "name": { "type": "string" },
+                    "photoUrls": { "type": "array", "items": { "type": "string" } },
+                    "tags": { "type": "array", "items": { "$ref": "#/components/schemas/Tag" } },
+                    "status": { "$ref": "#/components/schemas/Status" },
+                    "class": { "type": "string", "nullable": true },
+                    "json": { "type": "object", "additionalProperties": { "type": "integer" } }
+                }
+            },
+            "Dog": {
+                "allOf": [
+                    { "$ref": "#/components/schemas/Pet" },
+                    { "type": "object", "properties": { "bark": { "type": "boolean" } } }
+                ]
+            }
+        })
+        .as_object()
+        .cloned()
+        .unwrap()
+    }
+
+    fn operation(request_schema: JsonValue) -> Operation {
+        let mut op: Operation = serde_json::from_value(json!({
+            "operationId": "addPet",
+            "path": "/pet/{petId}",
+            "method": "POST",
+            "parameters": [
+                { "name": "petId", "location": "path", "required": true, "schema": { "type": "integer" } },
+                { "name": "body", "location": "query", "required": false, "schema": { "type": "string" } }
+            ],
+            "request_body": null,
+            "responses": []
+        }))
+        .unwrap();
+        op.request_body = Some(RequestBody {
+            required: true,
+            content: json!({ "application/json": { "schema": request_schema } }),
+            description: None,
+        });
+        op
+    }
+
+    #[test]
+    fn test_python_models_from_component_schemas() {
+        let models = build_python_models(&schemas());
+
+        assert_eq!(
+            models["aliases"],
+            json!([{ "name": "Status", "type_hint": "Literal[\"available\", \"sold\"]" }])
+        );
+
+        let pet = &models["classes"][1];
+        assert_eq!(pet["name"], "Pet");
+        assert_eq!(pet["description"], "A pet");
+        let fields: Vec<_> = pet["fields"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|f| {
+                (
+                    f["name"].as_str().unwrap(),
+                    f["type_hint"].as_str().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                ("name", "str"),
+                ("photo_urls", "Optional[List[str]]"),
+                ("tags", "Optional[List[Tag]]"),
+                ("status", "Optional[Status]"),
+                ("class_", "Optional[str]"),
+                ("json_", "Optional[Dict[str, int]]"),
+            ]
+        );
+        assert_eq!(pet["fields"][0]["alias"], JsonValue::Null);
+        assert_eq!(pet["fields"][1]["alias"], "photoUrls");
+        assert_eq!(pet["fields"][0]["required"], true);
+
+        // allOf parts are flattened into the model
+        let dog = &models["classes"][2];
+        assert_eq!(dog["name"], "Dog");
+        assert_eq!(dog["fields"].as_array().unwrap().len(), 7);
+        assert_eq!(dog["fields"][6]["name"], "bark");
+    }
+
+    #[test]
+    fn test_python_endpoint_types_body_with_model() {
+        let schemas = schemas();
+        // Bodies arrive with their references resolved
+        let mut resolved_pet = schemas["Pet"].clone();
+        resolved_pet["properties"]["tags"]["items"] = schemas["Tag"].clone();
+
+        let mut tool_models = BTreeSet::new();
+        let endpoint = build_python_endpoint_context(
+            &operation(resolved_pet.clone()),
+            &schemas,
+            &mut tool_models,
+        )
+        .unwrap();
+
+        assert_eq!(endpoint["endpoint"], "add_pet");
+        assert_eq!(endpoint["body"]["type_hint"], "Pet");
+        assert_eq!(endpoint["body"]["required"], true);
+        assert_eq!(endpoint["properties_schema"]["name"]["type"], "string");
+        assert_eq!(endpoint["parameters"][0]["python_name"], "pet_id");
+        assert_eq!(endpoint["parameters"][0]["wire_name"], "petId");
+        assert_eq!(endpoint["parameters"][0]["type_hint"], "int");
+        // `body` is taken by the request body argument
+        assert_eq!(endpoint["parameters"][1]["python_name"], "body_2");
+
+        let list = json!({ "type": "array", "items": resolved_pet });
+        let endpoint =
+            build_python_endpoint_context(&operation(list), &schemas, &mut tool_models).unwrap();
+        assert_eq!(endpoint["body"]["type_hint"], "List[Pet]");
+
+        let inline = json!({ "type": "object", "properties": { "note": { "type": "string" } } });
+        let endpoint =
+            build_python_endpoint_context(&operation(inline), &schemas, &mut tool_models).unwrap();
+        assert_eq!(endpoint["body"]["type_hint"], "Dict[str, Any]");
+
+        assert_eq!(tool_models.into_iter().collect::<Vec<_>>(), ["Pet"]);
+    }
+
+    #[test]
+    fn test_python_type_hints() {
+        let mut refs = BTreeSet::new();
+        let hint =
+            |schema: JsonValue, refs: &mut BTreeSet<String>| python_type_hint(&schema, false, refs);
+
+        assert_eq!(
+            hint(json!({ "type": ["integer", "null"] }), &mut refs),
+            "Optional[int]"
+        );
+        assert_eq!(
+            hint(
+                json!({ "oneOf": [{ "type": "string" }, { "$ref": "#/components/schemas/Tag" }] }),
+                &mut refs
+            ),
+            "Union[str, Tag]"
+        );
+        assert_eq!(hint(json!({ "enum": [1, 2] }), &mut refs), "Literal[1, 2]");
+        assert_eq!(hint(json!({}), &mut refs), "Any");
+        assert_eq!(
+            python_type_hint(
+                &json!({ "type": "array", "items": { "$ref": "#/components/schemas/Tag" } }),
+                true,
+                &mut refs
+            ),
+            "List[\"Tag\"]"
+        );
+        assert_eq!(refs.into_iter().collect::<Vec<_>>(), ["Tag"]);
+
+        assert_eq!(python_identifier("2fa-code"), "field_2fa_code");
+        assert_eq!(python_identifier("from"), "from_");
+        assert_eq!(python_class_name("any"), "AnyModel");
+    }
+
     #[tokio::test]
     async fn test_python_context_builder() {
         let builder = PythonContextBuilder::new();
@@ -244,7 +695,12 @@ mod tests {
             language: Language::Python,
             files: vec![],
             variables: HashMap::new(),
+            variable_schema: vec![],
+            pre_generate_hooks: vec![],
             post_generate_hooks: vec![],
+            partials: vec![],
+            required_directories: vec![],
+            extends: None,
         };
 
         let template = Template {
@@ -276,7 +732,12 @@ mod tests {
             language: Language::Python,
             files: vec![],
             variables: HashMap::new(),
+            variable_schema: vec![],
+            pre_generate_hooks: vec![],
             post_generate_hooks: vec![],
+            partials: vec![],
+            required_directories: vec![],
+            extends: None,
         };
 
         let template = Template {
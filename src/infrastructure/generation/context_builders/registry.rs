@@ -28,6 +28,14 @@ impl ContextBuilderRegistry {
             Language::TypeScript,
             Arc::new(super::TypeScriptContextBuilder::new()) as Arc<dyn ContextBuilder>,
         );
+        builders.insert(
+            Language::Go,
+            Arc::new(super::GoContextBuilder::new()) as Arc<dyn ContextBuilder>,
+        );
+        builders.insert(
+            Language::CSharp,
+            Arc::new(super::CSharpContextBuilder::new()) as Arc<dyn ContextBuilder>,
+        );
 
         Self { builders }
     }
@@ -104,6 +112,8 @@ mod tests {
         assert!(registry.get(Language::Rust).is_ok());
         assert!(registry.get(Language::Python).is_ok());
         assert!(registry.get(Language::TypeScript).is_ok());
+        assert!(registry.get(Language::Go).is_ok());
+        assert!(registry.get(Language::CSharp).is_ok());
     }
 
     #[tokio::test]
@@ -124,7 +134,12 @@ mod tests {
             language: Language::Rust,
             files: vec![],
             variables: HashMap::new(),
+            variable_schema: vec![],
+            pre_generate_hooks: vec![],
             post_generate_hooks: vec![],
+            partials: vec![],
+            required_directories: vec![],
+            extends: None,
         };
 
         let template = Template {
@@ -61,6 +61,12 @@ impl RustContextBuilder {
     }
 }
 
+impl Default for RustContextBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[async_trait]
 impl ContextBuilder for RustContextBuilder {
     async fn build(
@@ -124,6 +130,12 @@ impl ContextBuilder for RustContextBuilder {
                     // Add components for potential $ref resolution
                     if let Some(components) = &openapi_spec.components {
                         render_context.add_variable("api_components", json!(components.schemas));
+                        if !components.security_schemes.is_null() {
+                            render_context.add_variable(
+                                "api_security_schemes",
+                                components.security_schemes.clone(),
+                            );
+                        }
                     }
 
                     // Process operations into Rust endpoint contexts
@@ -132,10 +144,21 @@ impl ContextBuilder for RustContextBuilder {
                         operations.len()
                     );
                     for operation in operations {
+                        super::common::report_operation_diagnostics(
+                            operation,
+                            &context.diagnostics,
+                        );
                         let endpoint_context = build_rust_endpoint_context(operation)?;
                         endpoints.push(serde_json::to_value(endpoint_context)?);
                     }
                 }
+                crate::generation::ProtocolContext::McpBridge { server, tools } => {
+                    super::common::add_mcp_bridge_variables(
+                        &mut render_context,
+                        server.as_ref(),
+                        tools,
+                    );
+                }
             }
         }
         tracing::debug!(
@@ -180,6 +203,15 @@ impl ContextBuilder for RustContextBuilder {
 fn build_rust_endpoint_context(op: &Operation) -> Result<RustEndpointContext, GenerationError> {
     let endpoint_id = to_snake_case(&op.id);
 
+    // Parse the typed schemas once; every helper below reads from them
+    let response_schema = get_typed_response_schema(op);
+    let request_schema = get_typed_request_schema(op);
+    let properties = request_schema
+        .as_ref()
+        .map(extract_typed_schema_properties)
+        .unwrap_or_default();
+    let response_properties = extract_response_properties(op);
+
     Ok(RustEndpointContext {
         fn_name: endpoint_id.clone(),
         parameters_type: to_proper_case(&format!("{}_params", op.id)),
@@ -189,9 +221,12 @@ fn build_rust_endpoint_context(op: &Operation) -> Result<RustEndpointContext, Ge
         path: op.path.clone(),
         properties_type: to_proper_case(&format!("{}_properties", op.id)),
         response_type: to_proper_case(&format!("{}_response", op.id)),
-        envelope_properties: extract_envelope_properties(op),
-        properties: extract_request_body_properties(op),
-        properties_for_handler: extract_handler_properties(op),
+        envelope_properties: response_schema
+            .as_ref()
+            .map(extract_typed_envelope_properties)
+            .unwrap_or_else(|| json!({})),
+        properties_for_handler: properties.iter().map(|prop| prop.name.clone()).collect(),
+        properties,
         parameters: extract_parameters(op),
         summary: op
             .summary
@@ -204,39 +239,26 @@ fn build_rust_endpoint_context(op: &Operation) -> Result<RustEndpointContext, Ge
             .map(|s| sanitize_markdown(s))
             .unwrap_or_default(),
         tags: op.tags.clone().unwrap_or_default(),
-        properties_schema: extract_properties_schema(op),
+        properties_schema: request_schema
+            .as_ref()
+            .and_then(extract_typed_properties_map)
+            .unwrap_or_default(),
         response_schema: extract_response_schema(op),
         spec_file_name: None, // Would need to be passed from context
-        valid_fields: extract_valid_fields(op),
+        valid_fields: response_properties
+            .iter()
+            .map(|prop| prop.name.clone())
+            .collect(),
         // Simple response type analysis
-        response_is_array: is_array_response(op),
-        response_is_object: is_object_response(op),
-        response_is_primitive: is_primitive_response(op),
-        response_item_type: get_array_item_type(op),
-        response_primitive_type: get_primitive_type(op),
-        response_properties: extract_response_properties(op),
+        response_is_array: is_array_response(response_schema.as_ref()),
+        response_is_object: is_object_response(response_schema.as_ref()),
+        response_is_primitive: is_primitive_response(response_schema.as_ref()),
+        response_item_type: get_array_item_type(response_schema.as_ref()),
+        response_primitive_type: get_primitive_type(response_schema.as_ref()),
+        response_properties,
     })
 }
 
-fn extract_envelope_properties(op: &Operation) -> JsonValue {
-    for response in &op.responses {
-        if response.status_code.starts_with('2') {
-            if let Some(content) = response.content.as_ref() {
-                if let Some(json_content) = content.get("application/json") {
-                    if let Some(schema_json) = json_content.get("schema") {
-                        if let Ok(schema) =
-                            serde_json::from_value::<crate::generation::Schema>(schema_json.clone())
-                        {
-                            return extract_typed_envelope_properties(&schema);
-                        }
-                    }
-                }
-            }
-        }
-    }
-    json!({})
-}
-
 fn extract_response_properties(op: &Operation) -> Vec<RustPropertyInfo> {
     let mut properties = Vec::new();
 
@@ -259,13 +281,6 @@ fn extract_response_properties(op: &Operation) -> Vec<RustPropertyInfo> {
     properties
 }
 
-fn extract_handler_properties(op: &Operation) -> Vec<String> {
-    extract_request_body_properties(op)
-        .into_iter()
-        .map(|prop| prop.name)
-        .collect()
-}
-
 fn extract_parameters(op: &Operation) -> Vec<JsonValue> {
     op.parameters
         .iter()
@@ -286,7 +301,7 @@ fn extract_parameters(op: &Operation) -> Vec<JsonValue> {
 
 fn extract_typed_envelope_properties(schema: &crate::generation::Schema) -> JsonValue {
     if let Some(properties) = &schema.properties {
-        // Convert HashMap<String, Schema> back to JsonValue for compatibility
+        // Convert IndexMap<String, Schema> back to JsonValue for compatibility
         let mut json_props = serde_json::Map::new();
         for (key, value) in properties {
             if let Ok(json_val) = serde_json::to_value(value) {
@@ -338,46 +353,30 @@ fn extract_typed_schema_properties(schema: &crate::generation::Schema) -> Vec<Ru
 }
 
 fn map_schema_to_rust_type(schema: &crate::generation::Schema) -> String {
-    if let Some(typ) = &schema.schema_type {
-        match typ.as_str() {
-            "string" => "String".to_string(),
-            "integer" => "i32".to_string(),
-            "boolean" => "bool".to_string(),
-            "number" => "f64".to_string(),
-            "array" => {
-                if let Some(items) = &schema.items {
-                    format!("Vec<{}>", map_schema_to_rust_type(items))
-                } else {
-                    "Vec<serde_json::Value>".to_string()
-                }
-            }
-            "object" => "serde_json::Value".to_string(),
-            _ => "String".to_string(),
-        }
-    } else {
-        "String".to_string()
-    }
+    map_json_to_rust_type(&serde_json::to_value(schema).unwrap_or_default())
 }
 
-// Removed map_json_schema_to_rust_type - now using map_schema_to_rust_type for typed schemas
-
-fn extract_properties_schema(op: &Operation) -> JsonMap<String, JsonValue> {
-    if let Some(request_body) = &op.request_body {
-        if let Some(content) = request_body.content.as_object() {
-            if let Some(json_content) = content.get("application/json") {
-                if let Some(schema_json) = json_content.get("schema") {
-                    if let Ok(schema) =
-                        serde_json::from_value::<crate::generation::Schema>(schema_json.clone())
-                    {
-                        if let Some(properties) = extract_typed_properties_map(&schema) {
-                            return properties;
-                        }
-                    }
-                }
-            }
-        }
+/// Map a JSON schema to a Rust type
+///
+/// Shared with the `rust_type` template filter. `$ref`s map to the PascalCase
+/// name of the referenced component.
+pub(crate) fn map_json_to_rust_type(schema: &JsonValue) -> String {
+    if let Some(reference) = schema.get("$ref").and_then(JsonValue::as_str) {
+        return to_proper_case(reference.rsplit('/').next().unwrap_or(reference));
+    }
+
+    match schema.get("type").and_then(JsonValue::as_str) {
+        Some("string") => "String".to_string(),
+        Some("integer") => "i32".to_string(),
+        Some("boolean") => "bool".to_string(),
+        Some("number") => "f64".to_string(),
+        Some("array") => match schema.get("items").filter(|items| !items.is_null()) {
+            Some(items) => format!("Vec<{}>", map_json_to_rust_type(items)),
+            None => "Vec<serde_json::Value>".to_string(),
+        },
+        Some("object") => "serde_json::Value".to_string(),
+        _ => "String".to_string(),
     }
-    JsonMap::new()
 }
 
 fn extract_response_schema(op: &Operation) -> JsonValue {
@@ -395,18 +394,11 @@ fn extract_response_schema(op: &Operation) -> JsonValue {
     json!({})
 }
 
-fn extract_valid_fields(op: &Operation) -> Vec<String> {
-    extract_response_properties(op)
-        .into_iter()
-        .map(|prop| prop.name)
-        .collect()
-}
-
 fn extract_typed_properties_map(
     schema: &crate::generation::Schema,
 ) -> Option<JsonMap<String, JsonValue>> {
     if let Some(properties) = &schema.properties {
-        // Convert HashMap<String, Schema> back to JsonMap<String, JsonValue> for compatibility
+        // Convert IndexMap<String, Schema> back to JsonMap<String, JsonValue> for compatibility
         let mut json_map = JsonMap::new();
         for (key, value) in properties {
             if let Ok(json_val) = serde_json::to_value(value) {
@@ -425,70 +417,49 @@ fn extract_typed_properties_map(
     None
 }
 
-fn is_array_response(op: &Operation) -> bool {
-    if let Some(schema) = get_typed_response_schema(op) {
-        schema.schema_type.as_deref() == Some("array")
-    } else {
-        false
-    }
+fn is_array_response(schema: Option<&crate::generation::Schema>) -> bool {
+    schema.is_some_and(|schema| schema.schema_type.as_deref() == Some("array"))
 }
 
-fn is_object_response(op: &Operation) -> bool {
-    if let Some(schema) = get_typed_response_schema(op) {
+fn is_object_response(schema: Option<&crate::generation::Schema>) -> bool {
+    schema.is_some_and(|schema| {
         schema.schema_type.as_deref() == Some("object") || schema.properties.is_some()
-    } else {
-        false
-    }
+    })
 }
 
-fn is_primitive_response(op: &Operation) -> bool {
-    if let Some(schema) = get_typed_response_schema(op) {
+fn is_primitive_response(schema: Option<&crate::generation::Schema>) -> bool {
+    schema.is_some_and(|schema| {
         matches!(
             schema.schema_type.as_deref(),
             Some("string") | Some("integer") | Some("number") | Some("boolean")
         )
-    } else {
-        false
-    }
+    })
 }
 
-fn get_array_item_type(op: &Operation) -> String {
-    if is_array_response(op) {
-        if let Some(schema) = get_typed_response_schema(op) {
-            if let Some(items) = &schema.items {
-                return map_schema_to_rust_type(items);
-            }
-        }
+fn get_array_item_type(schema: Option<&crate::generation::Schema>) -> String {
+    if is_array_response(schema)
+        && let Some(items) = schema.and_then(|schema| schema.items.as_ref())
+    {
+        return map_schema_to_rust_type(items);
     }
     "serde_json::Value".to_string()
 }
 
-fn get_primitive_type(op: &Operation) -> String {
-    if is_primitive_response(op) {
-        if let Some(schema) = get_typed_response_schema(op) {
-            return map_schema_to_rust_type(&schema);
-        }
+fn get_primitive_type(schema: Option<&crate::generation::Schema>) -> String {
+    match schema {
+        Some(schema) if is_primitive_response(Some(schema)) => map_schema_to_rust_type(schema),
+        _ => "serde_json::Value".to_string(),
     }
-    "serde_json::Value".to_string()
 }
-fn extract_request_body_properties(op: &Operation) -> Vec<RustPropertyInfo> {
-    let mut properties = Vec::new();
 
-    if let Some(request_body) = &op.request_body {
-        if let Some(content) = request_body.content.as_object() {
-            if let Some(json_content) = content.get("application/json") {
-                if let Some(schema_json) = json_content.get("schema") {
-                    if let Ok(schema) =
-                        serde_json::from_value::<crate::generation::Schema>(schema_json.clone())
-                    {
-                        properties.extend(extract_typed_schema_properties(&schema));
-                    }
-                }
-            }
-        }
-    }
-
-    properties
+fn get_typed_request_schema(op: &Operation) -> Option<crate::generation::Schema> {
+    let schema_json = op
+        .request_body
+        .as_ref()?
+        .content
+        .get("application/json")?
+        .get("schema")?;
+    serde_json::from_value(schema_json.clone()).ok()
 }
 
 fn get_typed_response_schema(op: &Operation) -> Option<crate::generation::Schema> {
@@ -538,7 +509,12 @@ mod tests {
             language: Language::Rust,
             files: vec![],
             variables: HashMap::new(),
+            variable_schema: vec![],
+            pre_generate_hooks: vec![],
             post_generate_hooks: vec![],
+            partials: vec![],
+            required_directories: vec![],
+            extends: None,
         };
 
         let template = Template {
@@ -573,7 +549,12 @@ mod tests {
             language: Language::Python,
             files: vec![],
             variables: HashMap::new(),
+            variable_schema: vec![],
+            pre_generate_hooks: vec![],
             post_generate_hooks: vec![],
+            partials: vec![],
+            required_directories: vec![],
+            extends: None,
         };
 
         let template = Template {
@@ -603,7 +584,12 @@ mod tests {
             language: Language::Rust,
             files: vec![],
             variables: HashMap::new(),
+            variable_schema: vec![],
+            pre_generate_hooks: vec![],
             post_generate_hooks: vec![],
+            partials: vec![],
+            required_directories: vec![],
+            extends: None,
         };
 
         let template = Template {
@@ -1,8 +1,10 @@
 //! TypeScript-specific context builder for code generation
 
 use async_trait::async_trait;
-use serde_json::{Value as JsonValue, json};
+use serde_json::{Map as JsonMap, Value as JsonValue, json};
+use std::collections::{BTreeSet, HashSet};
 
+use super::common;
 use crate::generation::{
     ContextBuilder, GenerationContext, GenerationError, Language, RenderContext,
     sanitizers::sanitize_markdown,
@@ -19,6 +21,12 @@ impl TypeScriptContextBuilder {
     }
 }
 
+impl Default for TypeScriptContextBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[async_trait]
 impl ContextBuilder for TypeScriptContextBuilder {
     async fn build(
@@ -78,15 +86,44 @@ impl ContextBuilder for TypeScriptContextBuilder {
                     }
 
                     // Add components for potential $ref resolution
+                    let schemas = openapi_spec
+                        .components
+                        .as_ref()
+                        .and_then(|components| components.schemas.as_object())
+                        .cloned()
+                        .unwrap_or_default();
                     if let Some(components) = &openapi_spec.components {
                         render_context.add_variable("api_components", json!(components.schemas));
+                        if !components.security_schemes.is_null() {
+                            render_context.add_variable(
+                                "api_security_schemes",
+                                components.security_schemes.clone(),
+                            );
+                        }
                     }
 
+                    // Zod schemas for the component schemas
+                    render_context.add_variable("models", json!(build_zod_models(&schemas)));
+
                     // Process operations into TypeScript endpoint contexts
+                    let mut tool_schemas = BTreeSet::new();
                     for operation in operations {
-                        let endpoint_context = build_typescript_endpoint_context(operation)?;
+                        common::report_operation_diagnostics(operation, &context.diagnostics);
+                        let endpoint_context = build_typescript_endpoint_context(
+                            operation,
+                            &schemas,
+                            &mut tool_schemas,
+                        )?;
                         endpoints.push(endpoint_context);
                     }
+                    render_context.add_variable("tool_schemas", json!(tool_schemas));
+                }
+                crate::generation::ProtocolContext::McpBridge { server, tools } => {
+                    super::common::add_mcp_bridge_variables(
+                        &mut render_context,
+                        server.as_ref(),
+                        tools,
+                    );
                 }
             }
         }
@@ -117,10 +154,27 @@ impl ContextBuilder for TypeScriptContextBuilder {
 
 fn build_typescript_endpoint_context(
     op: &crate::generation::Operation,
+    schemas: &JsonMap<String, JsonValue>,
+    tool_schemas: &mut BTreeSet<String>,
 ) -> Result<JsonValue, GenerationError> {
     let method_name = to_camel_case(&op.id);
+    let request_schema = common::request_schema(op);
+
+    // The request body is passed to the tool as a single `body` argument
+    let body = op
+        .request_body
+        .as_ref()
+        .zip(request_schema)
+        .map(|(body, schema)| {
+            json!({
+                "zod": body_zod_schema(schema, schemas, tool_schemas),
+                "required": body.required,
+                "description": body.description.as_ref().map(|d| sanitize_markdown(d)),
+            })
+        });
 
     Ok(json!({
+        "endpoint": to_snake_case(&op.id),
         "method_name": method_name,
         "interface_name": to_proper_case(&format!("{}_params", op.id)),
         "response_interface": to_proper_case(&format!("{}_response", op.id)),
@@ -128,20 +182,43 @@ fn build_typescript_endpoint_context(
         "http_method": op.method.to_lowercase(),
         "summary": op.summary.as_ref().map(|s| sanitize_markdown(s)).unwrap_or_default(),
         "description": op.description.as_ref().map(|s| sanitize_markdown(s)).unwrap_or_default(),
-        "parameters": build_typescript_parameters(op),
+        "parameters": build_typescript_parameters(op, schemas, tool_schemas),
+        "body": body,
+        "properties_schema": request_schema.map(common::properties_of).unwrap_or_else(|| json!({})),
+        "response_schema": common::response_schema(op).cloned().unwrap_or_else(|| json!({})),
         "response_type": map_response_to_typescript_type(op),
         "tags": op.tags.clone().unwrap_or_default(),
     }))
 }
 
-fn build_typescript_parameters(op: &crate::generation::Operation) -> Vec<JsonValue> {
+fn build_typescript_parameters(
+    op: &crate::generation::Operation,
+    schemas: &JsonMap<String, JsonValue>,
+    tool_schemas: &mut BTreeSet<String>,
+) -> Vec<JsonValue> {
+    // Tool arguments keep the wire names; `body` is taken by the request body
+    let mut used: HashSet<String> = HashSet::from(["body".to_string()]);
+
     op.parameters
         .iter()
         .map(|p| {
+            let schema = serde_json::to_value(&p.schema).unwrap_or_default();
+            let mut zod = zod_schema(&schema, schemas, None, tool_schemas);
+            if let Some(description) = p.description.as_deref().filter(|d| !d.is_empty()) {
+                zod.push_str(&format!(
+                    ".describe({})",
+                    json!(sanitize_markdown(description))
+                ));
+            }
+            if !p.required {
+                zod.push_str(".optional()");
+            }
             json!({
                 "name": to_camel_case(&p.name),
                 "original_name": p.name.clone(),
-                "type": "any", // Simplified for now
+                "arg_name": common::unique_identifier(p.name.clone(), &mut used),
+                "type": map_json_to_typescript_type(&schema),
+                "zod": zod,
                 "in": format!("{:?}", p.location).to_lowercase(),
                 "required": p.required,
                 "description": p.description.as_ref().map(|d| sanitize_markdown(d)),
@@ -151,6 +228,249 @@ fn build_typescript_parameters(op: &crate::generation::Operation) -> Vec<JsonVal
         .collect()
 }
 
+/// A TypeScript type name for a component schema name
+fn typescript_type_name(name: &str) -> String {
+    let type_name = to_proper_case(name);
+    if type_name.is_empty() || type_name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("Model{type_name}")
+    } else {
+        type_name
+    }
+}
+
+/// Zod schemas for the component schemas, each declared after the ones it uses
+///
+/// References back to a schema that is not declared yet, as in recursive
+/// schemas, go through `z.lazy`.
+fn build_zod_models(schemas: &JsonMap<String, JsonValue>) -> Vec<JsonValue> {
+    fn references(schema: &JsonValue, found: &mut Vec<String>) {
+        match schema {
+            JsonValue::Object(map) => {
+                if let Some(reference) = map.get("$ref").and_then(JsonValue::as_str) {
+                    found.push(
+                        reference
+                            .rsplit('/')
+                            .next()
+                            .unwrap_or(reference)
+                            .to_string(),
+                    );
+                }
+                map.values().for_each(|value| references(value, found));
+            }
+            JsonValue::Array(values) => values.iter().for_each(|value| references(value, found)),
+            _ => {}
+        }
+    }
+
+    fn visit<'a>(
+        name: &str,
+        schemas: &'a JsonMap<String, JsonValue>,
+        visited: &mut HashSet<&'a str>,
+        order: &mut Vec<&'a str>,
+    ) {
+        let Some((name, schema)) = schemas.get_key_value(name) else {
+            return;
+        };
+        if !visited.insert(name) {
+            return;
+        }
+        let mut found = Vec::new();
+        references(schema, &mut found);
+        for reference in &found {
+            visit(reference, schemas, visited, order);
+        }
+        order.push(name);
+    }
+
+    let mut visited = HashSet::new();
+    let mut order = Vec::new();
+    for name in schemas.keys() {
+        visit(name, schemas, &mut visited, &mut order);
+    }
+
+    let mut declared = HashSet::new();
+    order
+        .into_iter()
+        .map(|name| {
+            let schema = &schemas[name];
+            let type_name = typescript_type_name(name);
+            let zod = zod_schema(schema, schemas, Some(&declared), &mut BTreeSet::new());
+            declared.insert(type_name.clone());
+            json!({
+                "name": type_name,
+                "schema_name": format!("{type_name}Schema"),
+                "zod": zod,
+                "description": schema
+                    .get("description")
+                    .and_then(JsonValue::as_str)
+                    .map(sanitize_markdown),
+            })
+        })
+        .collect()
+}
+
+/// Zod schema for a request body, the matching model's schema when there is one
+fn body_zod_schema(
+    schema: &JsonValue,
+    schemas: &JsonMap<String, JsonValue>,
+    tool_schemas: &mut BTreeSet<String>,
+) -> String {
+    let matched = |schema: &JsonValue| {
+        common::matching_component(schema, schemas)
+            .map(|name| format!("{}Schema", typescript_type_name(name)))
+    };
+
+    if let Some(schema_name) = matched(schema) {
+        tool_schemas.insert(schema_name.clone());
+        return schema_name;
+    }
+    if schema.get("type").and_then(JsonValue::as_str) == Some("array")
+        && let Some(schema_name) = schema.get("items").and_then(matched)
+    {
+        tool_schemas.insert(schema_name.clone());
+        return format!("z.array({schema_name})");
+    }
+    zod_schema(schema, schemas, None, tool_schemas)
+}
+
+/// Map a JSON schema to a zod schema expression
+///
+/// `$ref`s name the referenced component's schema, recorded in `refs`. With
+/// `declared`, references to schemas outside it are wrapped in `z.lazy`.
+fn zod_schema(
+    schema: &JsonValue,
+    schemas: &JsonMap<String, JsonValue>,
+    declared: Option<&HashSet<String>>,
+    refs: &mut BTreeSet<String>,
+) -> String {
+    if let Some(reference) = schema.get("$ref").and_then(JsonValue::as_str) {
+        let type_name = typescript_type_name(reference.rsplit('/').next().unwrap_or(reference));
+        let schema_name = format!("{type_name}Schema");
+        refs.insert(schema_name.clone());
+        return match declared {
+            Some(declared) if !declared.contains(&type_name) => {
+                format!("z.lazy((): z.ZodTypeAny => {schema_name})")
+            }
+            _ => schema_name,
+        };
+    }
+
+    // OpenAPI 3.1 spells nullable types as `type: [T, "null"]`
+    let (schema_type, nullable) = match schema.get("type") {
+        Some(JsonValue::Array(types)) => (
+            types
+                .iter()
+                .filter_map(JsonValue::as_str)
+                .find(|t| *t != "null"),
+            types.iter().any(|t| t == "null"),
+        ),
+        Some(t) => (t.as_str(), false),
+        None => (None, false),
+    };
+    let nullable = nullable || schema.get("nullable") == Some(&JsonValue::Bool(true));
+
+    let variants = schema
+        .get("oneOf")
+        .or_else(|| schema.get("anyOf"))
+        .and_then(JsonValue::as_array);
+    let all_of = schema.get("allOf").and_then(JsonValue::as_array);
+    let properties = schema
+        .get("properties")
+        .and_then(JsonValue::as_object)
+        .filter(|properties| !properties.is_empty());
+
+    let zod = if let Some(values) = schema.get("enum").and_then(JsonValue::as_array)
+        && let Some(zod) = zod_enum(values)
+    {
+        zod
+    } else if let Some(variants) = variants {
+        let mut members: Vec<String> = Vec::new();
+        for variant in variants {
+            let member = zod_schema(variant, schemas, declared, refs);
+            if !members.contains(&member) {
+                members.push(member);
+            }
+        }
+        match members.as_slice() {
+            [] => "z.unknown()".to_string(),
+            [only] => only.clone(),
+            _ => format!("z.union([{}])", members.join(", ")),
+        }
+    } else if let Some([only]) = all_of.map(Vec::as_slice) {
+        zod_schema(only, schemas, declared, refs)
+    } else if all_of.is_some() || properties.is_some() {
+        let (properties, required) = common::object_properties(schema, schemas);
+        let fields: Vec<_> = properties
+            .iter()
+            .map(|(name, property)| {
+                let mut field = zod_schema(property, schemas, declared, refs);
+                if let Some(description) = property.get("description").and_then(JsonValue::as_str) {
+                    field.push_str(&format!(
+                        ".describe({})",
+                        json!(sanitize_markdown(description))
+                    ));
+                }
+                if !required.contains(name) {
+                    field.push_str(".optional()");
+                }
+                format!("{}: {field}", json!(name))
+            })
+            .collect();
+        format!("z.object({{ {} }})", fields.join(", "))
+    } else {
+        match schema_type {
+            Some("string") => "z.string()".to_string(),
+            Some("integer") => "z.number().int()".to_string(),
+            Some("number") => "z.number()".to_string(),
+            Some("boolean") => "z.boolean()".to_string(),
+            Some("null") => "z.null()".to_string(),
+            Some("array") => match schema.get("items").filter(|items| !items.is_null()) {
+                Some(items) => format!("z.array({})", zod_schema(items, schemas, declared, refs)),
+                None => "z.array(z.unknown())".to_string(),
+            },
+            Some("object") => match schema.get("additionalProperties") {
+                Some(values) if values.is_object() => {
+                    format!("z.record({})", zod_schema(values, schemas, declared, refs))
+                }
+                _ => "z.record(z.unknown())".to_string(),
+            },
+            _ => "z.unknown()".to_string(),
+        }
+    };
+
+    if nullable {
+        format!("{zod}.nullable()")
+    } else {
+        zod
+    }
+}
+
+/// `z.enum` for string enums, literals for other scalar enums
+fn zod_enum(values: &[JsonValue]) -> Option<String> {
+    let values: Vec<_> = values.iter().filter(|value| !value.is_null()).collect();
+    if values.is_empty() {
+        return None;
+    }
+    if values.iter().all(|value| value.is_string()) {
+        let members: Vec<_> = values.iter().map(|value| value.to_string()).collect();
+        return Some(format!("z.enum([{}])", members.join(", ")));
+    }
+    if !values
+        .iter()
+        .all(|value| value.is_string() || value.is_number() || value.is_boolean())
+    {
+        return None;
+    }
+    let literals: Vec<_> = values
+        .iter()
+        .map(|value| format!("z.literal({value})"))
+        .collect();
+    match literals.as_slice() {
+        [only] => Some(only.clone()),
+        _ => Some(format!("z.union([{}])", literals.join(", "))),
+    }
+}
+
 fn map_response_to_typescript_type(op: &crate::generation::Operation) -> String {
     for response in &op.responses {
         if response.status_code.starts_with('2') {
@@ -166,34 +486,146 @@ fn map_response_to_typescript_type(op: &crate::generation::Operation) -> String
     "Record<string, any>".to_string()
 }
 
-fn map_json_to_typescript_type(schema: &JsonValue) -> String {
-    if let Some(typ) = schema.get("type").and_then(|v| v.as_str()) {
-        match typ {
-            "string" => "string".to_string(),
-            "integer" | "number" => "number".to_string(),
-            "boolean" => "boolean".to_string(),
-            "array" => {
-                if let Some(items) = schema.get("items") {
-                    format!("{}[]", map_json_to_typescript_type(items))
-                } else {
-                    "any[]".to_string()
-                }
-            }
-            "object" => "Record<string, any>".to_string(),
-            _ => "any".to_string(),
-        }
-    } else {
-        "any".to_string()
+/// Map a JSON schema to a TypeScript type
+///
+/// Shared with the `ts_type` template filter. `$ref`s map to the type name of
+/// the referenced component.
+pub(crate) fn map_json_to_typescript_type(schema: &JsonValue) -> String {
+    if let Some(reference) = schema.get("$ref").and_then(JsonValue::as_str) {
+        return typescript_type_name(reference.rsplit('/').next().unwrap_or(reference));
+    }
+
+    match schema.get("type").and_then(JsonValue::as_str) {
+        Some("string") => "string".to_string(),
+        Some("integer") | Some("number") => "number".to_string(),
+        Some("boolean") => "boolean".to_string(),
+        Some("array") => match schema.get("items").filter(|items| !items.is_null()) {
+            Some(items) => format!("{}[]", map_json_to_typescript_type(items)),
+            None => "any[]".to_string(),
+        },
+        Some("object") => "Record<string, any>".to_string(),
+        _ => "any".to_string(),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::generation::{Operation, RequestBody};
     use crate::infrastructure::{Template, TemplateManifest, TemplateSource};
     use crate::protocols::{Protocol, Role};
     use std::collections::HashMap;
 
+    fn schemas() -> JsonMap<String, JsonValue> {
+        json!({
+            "Node": {
+                "type": "object",
+                "required": ["id"],
+                "properties": {
+                    "id": { "type": "integer" },
+                    "children": { "type": "array", "items": { "$ref": "#/components/schemas/Node" } },
+                    "tag": { "$ref": "#/components/schemas/Tag" }
+                }
+            },
+            "Tag": {
+                "type": "object",
+                "description": "A tag",
+                "properties": {
+                    "name": { "type": "string", "description": "Tag name" },
+                    "kind": { "type": "string", "enum": ["a", "b"], "nullable": true }
+                }
+            }
+        })
+        .as_object()
+        .cloned()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_zod_models_in_dependency_order() {
+        let models = build_zod_models(&schemas());
+
+        assert_eq!(models[0]["schema_name"], "TagSchema");
+        assert_eq!(models[0]["description"], "A tag");
+        assert_eq!(
+            models[0]["zod"],
+            r#"z.object({ "name": z.string().describe("Tag name").optional(), "kind": z.enum(["a", "b"]).nullable().optional() })"#
+        );
+        // Tag is declared first; the recursive reference is lazy
+        assert_eq!(models[1]["name"], "Node");
+        assert_eq!(
+            models[1]["zod"],
+            r#"z.object({ "id": z.number().int(), "children": z.array(z.lazy((): z.ZodTypeAny => NodeSchema)).optional(), "tag": TagSchema.optional() })"#
+        );
+    }
+
+    #[test]
+    fn test_typescript_endpoint_tool_inputs() {
+        let schemas = schemas();
+        let mut op: Operation = serde_json::from_value(json!({
+            "operationId": "addTag",
+            "path": "/tags/{tagId}",
+            "method": "POST",
+            "parameters": [
+                { "name": "tagId", "location": "path", "required": true, "schema": { "type": "integer" }, "description": "Tag id" },
+                { "name": "body", "location": "query", "required": false, "schema": { "type": "array", "items": { "type": "string" } } }
+            ],
+            "request_body": null,
+            "responses": []
+        }))
+        .unwrap();
+        op.request_body = Some(RequestBody {
+            required: false,
+            content: json!({ "application/json": { "schema": { "type": "array", "items": schemas["Tag"] } } }),
+            description: None,
+        });
+
+        let mut tool_schemas = BTreeSet::new();
+        let endpoint = build_typescript_endpoint_context(&op, &schemas, &mut tool_schemas).unwrap();
+
+        assert_eq!(endpoint["endpoint"], "add_tag");
+        assert_eq!(endpoint["method_name"], "addTag");
+        assert_eq!(endpoint["body"]["zod"], "z.array(TagSchema)");
+        assert_eq!(endpoint["body"]["required"], false);
+        assert_eq!(endpoint["parameters"][0]["arg_name"], "tagId");
+        assert_eq!(
+            endpoint["parameters"][0]["zod"],
+            r#"z.number().int().describe("Tag id")"#
+        );
+        assert_eq!(endpoint["parameters"][0]["type"], "number");
+        // `body` is taken by the request body argument
+        assert_eq!(endpoint["parameters"][1]["arg_name"], "body_2");
+        assert_eq!(
+            endpoint["parameters"][1]["zod"],
+            "z.array(z.string()).optional()"
+        );
+        assert_eq!(tool_schemas.into_iter().collect::<Vec<_>>(), ["TagSchema"]);
+    }
+
+    #[test]
+    fn test_zod_scalars_and_unions() {
+        let schemas = JsonMap::new();
+        let zod = |schema: JsonValue| zod_schema(&schema, &schemas, None, &mut BTreeSet::new());
+
+        assert_eq!(
+            zod(json!({ "type": ["number", "null"] })),
+            "z.number().nullable()"
+        );
+        assert_eq!(
+            zod(json!({ "enum": [1, 2] })),
+            "z.union([z.literal(1), z.literal(2)])"
+        );
+        assert_eq!(
+            zod(json!({ "oneOf": [{ "type": "string" }, { "type": "boolean" }] })),
+            "z.union([z.string(), z.boolean()])"
+        );
+        assert_eq!(
+            zod(json!({ "type": "object", "additionalProperties": { "type": "integer" } })),
+            "z.record(z.number().int())"
+        );
+        assert_eq!(zod(json!({})), "z.unknown()");
+    }
+
     #[tokio::test]
     async fn test_typescript_context_builder() {
         let builder = TypeScriptContextBuilder::new();
@@ -211,7 +643,12 @@ mod tests {
             language: Language::TypeScript,
             files: vec![],
             variables: HashMap::new(),
+            variable_schema: vec![],
+            pre_generate_hooks: vec![],
             post_generate_hooks: vec![],
+            partials: vec![],
+            required_directories: vec![],
+            extends: None,
         };
 
         let template = Template {
@@ -241,7 +678,12 @@ mod tests {
             language: Language::TypeScript,
             files: vec![],
             variables: HashMap::new(),
+            variable_schema: vec![],
+            pre_generate_hooks: vec![],
             post_generate_hooks: vec![],
+            partials: vec![],
+            required_directories: vec![],
+            extends: None,
         };
 
         let template = Template {
@@ -2,8 +2,10 @@
 
 use async_trait::async_trait;
 use std::path::PathBuf;
-use tera::{Context, Tera};
+use tera::Context;
 
+use super::for_each::{self, ForEach};
+use super::template_functions;
 use crate::generation::{
     Artifact, GenerationContext, GenerationError, RenderContext, TemplateRenderingStrategy,
 };
@@ -30,53 +32,64 @@ impl TemplateRenderingStrategy for DefaultTemplateRenderer {
         &self,
         template: &Template,
         context: &RenderContext,
-        _generation_context: &GenerationContext,
+        generation_context: &GenerationContext,
     ) -> Result<Vec<Artifact>, GenerationError> {
         let mut artifacts = Vec::new();
 
-        // Create a new Tera instance for this template
-        let mut tera = Tera::default();
+        // Template files and partials, indexed by their source names
+        let tera = template_functions::load_template(template)?;
 
-        // Add all template files to Tera
-        for file in &template.files {
-            let template_name = file.path.to_string_lossy().to_string();
-            tera.add_raw_template(&template_name, &file.content)
-                .map_err(|e| {
-                    GenerationError::RenderError(format!("Failed to add template: {e}"))
-                })?;
+        let mut tera_context = Context::new();
+        for (key, value) in context.variables.iter() {
+            tera_context.insert(key, value);
         }
 
         // Process each template file
         for file in &template.files {
             let template_name = file.path.to_string_lossy().to_string();
+            let manifest_file = template
+                .manifest
+                .files
+                .iter()
+                .find(|f| f.source == template_name);
+
+            if let Some(manifest_file) = manifest_file
+                && !template_functions::is_enabled(&tera, manifest_file, &tera_context)?
+            {
+                tracing::debug!("Skipping '{template_name}': when is false");
+                continue;
+            }
 
             match &file.file_type {
-                TemplateFileType::Template { for_each } => {
-                    if for_each.is_some() {
-                        // Default renderer doesn't support for_each
-                        return Err(GenerationError::InvalidConfiguration(
-                            "Default renderer does not support for_each templates. Use a protocol-specific renderer.".to_string()
-                        ));
-                    }
-
+                TemplateFileType::Template {
+                    for_each: Some(collection_key),
+                } => {
+                    let for_each: ForEach = collection_key.parse()?;
+                    let items = for_each::items(for_each, context, generation_context)?;
+                    let destination = manifest_file
+                        .map(|f| f.target.clone())
+                        .unwrap_or_else(|| template_name.clone());
+
+                    artifacts.extend(for_each::render_items(
+                        &tera,
+                        &template_name,
+                        &destination,
+                        &tera_context,
+                        for_each,
+                        &items,
+                    )?);
+                }
+                TemplateFileType::Template { for_each: None } => {
                     // Single file template
-                    let mut tera_context = Context::new();
-                    for (key, value) in context.variables.iter() {
-                        tera_context.insert(key, value);
-                    }
-
                     let rendered = tera.render(&template_name, &tera_context).map_err(|e| {
                         GenerationError::RenderError(format!(
-                            "Failed to render template '{template_name}': {e}"
+                            "Failed to render template '{template_name}': {}",
+                            template_functions::describe_error(&e)
                         ))
                     })?;
 
                     // Get destination from manifest
-                    let destination = template
-                        .manifest
-                        .files
-                        .iter()
-                        .find(|f| f.source == template_name)
+                    let destination = manifest_file
                         .map(|f| PathBuf::from(&f.target))
                         .unwrap_or_else(|| file.path.clone());
 
@@ -86,13 +99,9 @@ impl TemplateRenderingStrategy for DefaultTemplateRenderer {
                         permissions: None,
                     });
                 }
-                TemplateFileType::Static => {
-                    // Copy static files as-is
-                    let destination = template
-                        .manifest
-                        .files
-                        .iter()
-                        .find(|f| f.source == template_name)
+                TemplateFileType::Static | TemplateFileType::Configuration => {
+                    // Copy static and configuration files as-is
+                    let destination = manifest_file
                         .map(|f| PathBuf::from(&f.target))
                         .unwrap_or_else(|| file.path.clone());
 
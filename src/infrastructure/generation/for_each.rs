@@ -0,0 +1,570 @@
+//! Collections a manifest file can iterate over with `for_each`
+//!
+//! A `for_each` file renders once per item of its collection. Each rendering
+//! sees the shared template context plus the item, and the item's name fills
+//! the collection's placeholder in the destination path:
+//!
+//! | `for_each` | Item variables | Placeholder |
+//! |------------|----------------|-------------|
+//! | `endpoint`, `operation` | the endpoint's fields, at the top level | `{endpoint}`, `{operation_id}` |
+//! | `tag` | `tag` | `{tag}` |
+//! | `schema` | `schema` | `{schema}` |
+//! | `security_scheme` | `security_scheme` | `{security_scheme}` |
+//! | `server` | `server` | `{server}` |
+//!
+//! Every collection also sets `index` and fills `{index}`. Swagger 2.0 specs
+//! supply schemas and security schemes from `definitions` and
+//! `securityDefinitions`.
+
+use rayon::prelude::*;
+use serde_json::{Map as JsonMap, Value as JsonValue, json};
+use std::path::PathBuf;
+use std::str::FromStr;
+use tera::{Context as TeraContext, Tera};
+
+use crate::generation::{
+    Artifact, GenerationContext, GenerationError, OpenApiContext, ProtocolContext, RenderContext,
+    utils::{to_proper_case, to_snake_case},
+};
+use crate::infrastructure::generation::template_functions;
+
+/// Tag used for operations that declare none
+pub const DEFAULT_TAG: &str = "default";
+
+/// Collection named by a manifest file's `for_each`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ForEach {
+    Endpoint,
+    Tag,
+    Schema,
+    SecurityScheme,
+    Server,
+}
+
+impl ForEach {
+    /// Accepted `for_each` values
+    pub const VALUES: &'static [&'static str] = &[
+        "endpoint",
+        "operation",
+        "tag",
+        "schema",
+        "security_scheme",
+        "server",
+    ];
+
+    /// Placeholders replaced by the item name in destination paths
+    fn placeholders(&self) -> &'static [&'static str] {
+        match self {
+            ForEach::Endpoint => &["{endpoint}", "{operation_id}"],
+            ForEach::Tag => &["{tag}"],
+            ForEach::Schema => &["{schema}"],
+            ForEach::SecurityScheme => &["{security_scheme}"],
+            ForEach::Server => &["{server}"],
+        }
+    }
+}
+
+impl FromStr for ForEach {
+    type Err = GenerationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "endpoint" | "operation" => Ok(ForEach::Endpoint),
+            "tag" => Ok(ForEach::Tag),
+            "schema" => Ok(ForEach::Schema),
+            "security_scheme" => Ok(ForEach::SecurityScheme),
+            "server" => Ok(ForEach::Server),
+            _ => Err(GenerationError::InvalidConfiguration(format!(
+                "Unsupported for_each value: {s} (expected one of: {})",
+                Self::VALUES.join(", ")
+            ))),
+        }
+    }
+}
+
+/// One rendering of a `for_each` file
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForEachItem {
+    /// Fills the collection's placeholder in the destination path
+    pub name: String,
+    /// Variables added on top of the shared context
+    pub variables: JsonMap<String, JsonValue>,
+}
+
+impl ForEachItem {
+    fn new(name: String, key: &str, value: JsonValue) -> Self {
+        let mut variables = JsonMap::new();
+        variables.insert(key.to_string(), value);
+        Self { name, variables }
+    }
+}
+
+/// Items of a collection, in spec order
+pub fn items(
+    for_each: ForEach,
+    context: &RenderContext,
+    generation_context: &GenerationContext,
+) -> Result<Vec<ForEachItem>, GenerationError> {
+    if for_each == ForEach::Endpoint {
+        return endpoint_items(endpoints(context)?);
+    }
+
+    let spec = match &generation_context.protocol_context {
+        Some(ProtocolContext::McpServer { openapi_spec, .. }) => openapi_spec,
+        Some(ProtocolContext::McpBridge { .. }) | None => {
+            return Err(GenerationError::InvalidConfiguration(format!(
+                "for_each: {} requires an OpenAPI spec",
+                for_each.placeholders()[0].trim_matches(['{', '}'])
+            )));
+        }
+    };
+
+    Ok(match for_each {
+        ForEach::Endpoint => unreachable!("handled above"),
+        ForEach::Tag => tag_items(spec, endpoints(context)?),
+        ForEach::Schema => schema_items(spec),
+        ForEach::SecurityScheme => security_scheme_items(spec),
+        ForEach::Server => server_items(spec),
+    })
+}
+
+/// Render a `for_each` file once per item, in parallel, keeping item order
+pub fn render_items(
+    tera: &Tera,
+    template_name: &str,
+    destination: &str,
+    base_context: &TeraContext,
+    for_each: ForEach,
+    items: &[ForEachItem],
+) -> Result<Vec<Artifact>, GenerationError> {
+    items
+        .par_iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let mut context = base_context.clone();
+            context.insert("index", &index);
+            for (key, value) in &item.variables {
+                context.insert(key, value);
+            }
+
+            let content = tera.render(template_name, &context).map_err(|e| {
+                tracing::error!(
+                    "Template render error for '{template_name}' item '{}': Full Tera error: {e:?}",
+                    item.name
+                );
+                GenerationError::RenderError(format!(
+                    "Failed to render template '{template_name}' for '{}': {}",
+                    item.name,
+                    template_functions::describe_error(&e)
+                ))
+            })?;
+
+            Ok(Artifact {
+                path: PathBuf::from(destination_path(destination, for_each, item, index)),
+                content,
+                permissions: None,
+            })
+        })
+        .collect()
+}
+
+/// Fill the collection placeholders and `{index}` in a destination path
+pub fn destination_path(
+    destination: &str,
+    for_each: ForEach,
+    item: &ForEachItem,
+    index: usize,
+) -> String {
+    let path = for_each
+        .placeholders()
+        .iter()
+        .fold(destination.to_string(), |path, placeholder| {
+            path.replace(placeholder, &item.name)
+        });
+    path.replace("{index}", &index.to_string())
+}
+
+fn endpoints(context: &RenderContext) -> Result<&[JsonValue], GenerationError> {
+    context
+        .variables
+        .get("endpoints")
+        .or_else(|| context.variables.get("endpoint"))
+        .and_then(|v| v.as_array())
+        .map(Vec::as_slice)
+        .ok_or_else(|| {
+            GenerationError::RenderError("No endpoints found in context for for_each".to_string())
+        })
+}
+
+fn endpoint_items(endpoints: &[JsonValue]) -> Result<Vec<ForEachItem>, GenerationError> {
+    endpoints
+        .iter()
+        .map(|endpoint| {
+            let name = endpoint
+                .get("endpoint")
+                .or_else(|| endpoint.get("endpoint_fs"))
+                .or_else(|| endpoint.get("fn_name"))
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| {
+                    GenerationError::RenderError(
+                        "Endpoint object missing 'endpoint' field".to_string(),
+                    )
+                })?;
+            Ok(ForEachItem {
+                name: name.to_string(),
+                variables: endpoint.as_object().cloned().unwrap_or_default(),
+            })
+        })
+        .collect()
+}
+
+/// Declared tags first, then undeclared ones in order of first use; untagged
+/// endpoints go under [`DEFAULT_TAG`]
+fn tag_items(spec: &OpenApiContext, endpoints: &[JsonValue]) -> Vec<ForEachItem> {
+    let endpoint_tags = |endpoint: &JsonValue| -> Vec<String> {
+        let tags: Vec<String> = endpoint
+            .get("tags")
+            .and_then(|v| v.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|t| t.as_str().map(str::to_string))
+            .collect();
+        if tags.is_empty() {
+            vec![DEFAULT_TAG.to_string()]
+        } else {
+            tags
+        }
+    };
+
+    let mut names: Vec<(String, Option<String>)> = spec
+        .tags
+        .iter()
+        .map(|tag| (tag.name.clone(), tag.description.clone()))
+        .collect();
+    for tag in endpoints.iter().flat_map(endpoint_tags) {
+        if !names.iter().any(|(name, _)| *name == tag) {
+            names.push((tag, None));
+        }
+    }
+
+    names
+        .into_iter()
+        .map(|(name, description)| {
+            let tagged: Vec<&JsonValue> = endpoints
+                .iter()
+                .filter(|endpoint| endpoint_tags(endpoint).contains(&name))
+                .collect();
+            let module_name = to_snake_case(&name);
+            ForEachItem::new(
+                module_name.clone(),
+                "tag",
+                json!({
+                    "name": name,
+                    "description": description,
+                    "module_name": module_name,
+                    "type_name": to_proper_case(&name),
+                    "endpoints": tagged,
+                }),
+            )
+        })
+        .collect()
+}
+
+fn schema_items(spec: &OpenApiContext) -> Vec<ForEachItem> {
+    let Some(schemas) = spec.components.as_ref().and_then(|c| c.schemas.as_object()) else {
+        return Vec::new();
+    };
+
+    schemas
+        .iter()
+        .map(|(name, definition)| {
+            let required: Vec<&str> = definition
+                .get("required")
+                .and_then(|v| v.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|v| v.as_str())
+                .collect();
+            let properties: Vec<JsonValue> = definition
+                .get("properties")
+                .and_then(|v| v.as_object())
+                .into_iter()
+                .flatten()
+                .map(|(property, schema)| {
+                    json!({
+                        "name": property,
+                        "field_name": to_snake_case(property),
+                        "required": required.contains(&property.as_str()),
+                        "schema": schema,
+                    })
+                })
+                .collect();
+
+            let module_name = to_snake_case(name);
+            ForEachItem::new(
+                module_name.clone(),
+                "schema",
+                json!({
+                    "name": name,
+                    "module_name": module_name,
+                    "type_name": to_proper_case(name),
+                    "description": definition.get("description"),
+                    "properties": properties,
+                    "definition": definition,
+                }),
+            )
+        })
+        .collect()
+}
+
+fn security_scheme_items(spec: &OpenApiContext) -> Vec<ForEachItem> {
+    let Some(schemes) = spec
+        .components
+        .as_ref()
+        .and_then(|c| c.security_schemes.as_object())
+    else {
+        return Vec::new();
+    };
+
+    schemes
+        .iter()
+        .map(|(name, definition)| {
+            let module_name = to_snake_case(name);
+            ForEachItem::new(
+                module_name.clone(),
+                "security_scheme",
+                json!({
+                    "name": name,
+                    "module_name": module_name,
+                    "type_name": to_proper_case(name),
+                    "kind": definition.get("type"),
+                    "definition": definition,
+                }),
+            )
+        })
+        .collect()
+}
+
+fn server_items(spec: &OpenApiContext) -> Vec<ForEachItem> {
+    spec.servers
+        .iter()
+        .enumerate()
+        .map(|(index, server)| {
+            let name = server
+                .description
+                .as_deref()
+                .map(to_snake_case)
+                .filter(|name| !name.is_empty())
+                .unwrap_or_else(|| format!("server_{index}"));
+            ForEachItem::new(
+                name.clone(),
+                "server",
+                json!({
+                    "name": name,
+                    "url": server.url,
+                    "description": server.description,
+                }),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::{ApiInfo, Components, Language, Server, Tag};
+    use crate::protocols::{Protocol, Role};
+
+    fn spec() -> OpenApiContext {
+        OpenApiContext {
+            version: "3.0.0".to_string(),
+            info: ApiInfo {
+                title: "Petstore".to_string(),
+                version: "1.0.0".to_string(),
+                description: None,
+            },
+            servers: vec![
+                Server {
+                    url: "https://petstore.example.com".to_string(),
+                    description: Some("Production".to_string()),
+                },
+                Server {
+                    url: "http://localhost:8080".to_string(),
+                    description: None,
+                },
+            ],
+            operations: vec![],
+            components: Some(Components {
+                schemas: json!({
+                    "PetRecord": {
+                        "type": "object",
+                        "required": ["id"],
+                        "properties": {
+                            "id": { "type": "integer" },
+                            "ownerName": { "type": "string" }
+                        }
+                    }
+                }),
+                security_schemes: json!({
+                    "apiKey": { "type": "apiKey", "in": "header", "name": "X-API-Key" }
+                }),
+            }),
+            tags: vec![
+                Tag {
+                    name: "store".to_string(),
+                    description: Some("Orders".to_string()),
+                },
+                Tag {
+                    name: "unused".to_string(),
+                    description: None,
+                },
+            ],
+            diagnostics: vec![],
+        }
+    }
+
+    fn contexts() -> (RenderContext, GenerationContext) {
+        let mut render_context = RenderContext::new();
+        render_context.add_variable(
+            "endpoints",
+            json!([
+                { "endpoint": "get_pet", "tags": ["pet"] },
+                { "endpoint": "place_order", "tags": ["store", "pet"] },
+                { "endpoint": "health", "tags": [] }
+            ]),
+        );
+
+        let mut generation_context =
+            GenerationContext::new(Protocol::Mcp, Role::Server, Language::Rust);
+        generation_context.protocol_context = Some(ProtocolContext::McpServer {
+            openapi_spec: Box::new(spec()),
+            endpoints: vec![],
+        });
+        (render_context, generation_context)
+    }
+
+    fn names(items: &[ForEachItem]) -> Vec<&str> {
+        items.iter().map(|item| item.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_parse_for_each() {
+        assert_eq!("operation".parse::<ForEach>().unwrap(), ForEach::Endpoint);
+        assert_eq!(
+            "security_scheme".parse::<ForEach>().unwrap(),
+            ForEach::SecurityScheme
+        );
+        let err = "model".parse::<ForEach>().unwrap_err().to_string();
+        assert!(err.contains("expected one of: endpoint, operation, tag"));
+    }
+
+    #[test]
+    fn test_tag_items() {
+        let (render_context, generation_context) = contexts();
+        let items = items(ForEach::Tag, &render_context, &generation_context).unwrap();
+
+        assert_eq!(names(&items), vec!["store", "unused", "pet", "default"]);
+        let store = &items[0].variables["tag"];
+        assert_eq!(store["description"], "Orders");
+        assert_eq!(store["type_name"], "Store");
+        assert_eq!(store["endpoints"][0]["endpoint"], "place_order");
+        let pet = &items[2].variables["tag"];
+        assert_eq!(pet["endpoints"].as_array().unwrap().len(), 2);
+        assert_eq!(
+            items[3].variables["tag"]["endpoints"][0]["endpoint"],
+            "health"
+        );
+    }
+
+    #[test]
+    fn test_schema_security_scheme_and_server_items() {
+        let (render_context, generation_context) = contexts();
+
+        let schemas = items(ForEach::Schema, &render_context, &generation_context).unwrap();
+        assert_eq!(names(&schemas), vec!["pet_record"]);
+        let schema = &schemas[0].variables["schema"];
+        assert_eq!(schema["type_name"], "PetRecord");
+        assert_eq!(schema["properties"][0]["required"], true);
+        assert_eq!(schema["properties"][1]["field_name"], "owner_name");
+        assert_eq!(schema["properties"][1]["required"], false);
+
+        let schemes = items(
+            ForEach::SecurityScheme,
+            &render_context,
+            &generation_context,
+        )
+        .unwrap();
+        assert_eq!(names(&schemes), vec!["api_key"]);
+        assert_eq!(schemes[0].variables["security_scheme"]["kind"], "apiKey");
+
+        let servers = items(ForEach::Server, &render_context, &generation_context).unwrap();
+        assert_eq!(names(&servers), vec!["production", "server_1"]);
+        assert_eq!(
+            servers[1].variables["server"]["url"],
+            "http://localhost:8080"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_swagger_v2_schema_and_security_scheme_items() {
+        use crate::infrastructure::openapi::parser::OpenApiParser;
+
+        let document: JsonValue = serde_json::from_str(include_str!(
+            "../../../tests/fixtures/openapi/petstore.swagger.v2.json"
+        ))
+        .unwrap();
+        let (render_context, mut generation_context) = contexts();
+        generation_context.protocol_context = Some(ProtocolContext::McpServer {
+            openapi_spec: Box::new(OpenApiParser::new(document).parse().await.unwrap()),
+            endpoints: vec![],
+        });
+
+        let schemas = items(ForEach::Schema, &render_context, &generation_context).unwrap();
+        assert_eq!(
+            names(&schemas),
+            vec!["api_response", "category", "pet", "tag", "order", "user"]
+        );
+        let schemes = items(
+            ForEach::SecurityScheme,
+            &render_context,
+            &generation_context,
+        )
+        .unwrap();
+        assert_eq!(names(&schemes), vec!["api_key", "petstore_auth"]);
+        assert_eq!(schemes[1].variables["security_scheme"]["kind"], "oauth2");
+    }
+
+    #[test]
+    fn test_spec_collections_require_spec() {
+        let (render_context, _) = contexts();
+        let generation_context =
+            GenerationContext::new(Protocol::Mcp, Role::Client, Language::Rust);
+
+        let err = items(ForEach::Schema, &render_context, &generation_context).unwrap_err();
+        assert!(err.to_string().contains("for_each: schema requires"));
+    }
+
+    #[test]
+    fn test_render_items() {
+        let mut tera = Tera::default();
+        tera.add_raw_template("model.rs.tera", "{{ index }}:{{ schema.type_name }}")
+            .unwrap();
+        let (render_context, generation_context) = contexts();
+        let items = items(ForEach::Schema, &render_context, &generation_context).unwrap();
+
+        let artifacts = render_items(
+            &tera,
+            "model.rs.tera",
+            "src/models/{index}_{schema}.rs",
+            &TeraContext::new(),
+            ForEach::Schema,
+            &items,
+        )
+        .unwrap();
+        assert_eq!(
+            artifacts[0].path,
+            PathBuf::from("src/models/0_pet_record.rs")
+        );
+        assert_eq!(artifacts[0].content, "0:PetRecord");
+    }
+}
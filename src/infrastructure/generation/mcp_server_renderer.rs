@@ -3,8 +3,10 @@
 use async_trait::async_trait;
 use serde_json::json;
 use std::path::PathBuf;
-use tera::{Context as TeraContext, Tera};
+use tera::Context as TeraContext;
 
+use super::for_each::{self, ForEach};
+use super::template_functions;
 use crate::generation::{
     Artifact, GenerationContext, GenerationError, RenderContext, TemplateRenderingStrategy,
     utils::to_snake_case,
@@ -16,6 +18,12 @@ use crate::protocols::{Protocol, Role};
 /// Handles OpenAPI operation iteration and endpoint path substitution
 pub struct McpServerTemplateRenderer;
 
+impl Default for McpServerTemplateRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl McpServerTemplateRenderer {
     pub fn new() -> Self {
         Self
@@ -130,8 +138,12 @@ impl McpServerTemplateRenderer {
                             if let Some(desc) = p.get("description").and_then(|v| v.as_str()) {
                                 param.insert("description".to_string(), json!(desc));
                             }
-                            if let Some(rust_type) = p.get("rust_type").and_then(|v| v.as_str()) {
-                                param.insert("type".to_string(), json!(rust_type));
+                            if let Some(param_type) = p
+                                .get("rust_type")
+                                .or_else(|| p.get("type"))
+                                .and_then(|v| v.as_str())
+                            {
+                                param.insert("type".to_string(), json!(param_type));
                             }
                             if let Some(required) = p.get("required").and_then(|v| v.as_bool()) {
                                 param.insert("required".to_string(), json!(required));
@@ -228,113 +240,6 @@ impl McpServerTemplateRenderer {
 
         Ok(artifacts)
     }
-
-    /// Process a template file for each operation
-    async fn process_operation_file(
-        &self,
-        tera: &Tera,
-        template_name: &str,
-        file_destination: &str,
-        context: &RenderContext,
-        _generation_context: &GenerationContext,
-    ) -> Result<Vec<Artifact>, GenerationError> {
-        let mut artifacts = Vec::new();
-
-        // Get endpoints from context
-        let endpoints = context
-            .variables
-            .get("endpoints")
-            .or_else(|| context.variables.get("endpoint"))
-            .and_then(|v| v.as_array())
-            .ok_or_else(|| {
-                GenerationError::RenderError(
-                    "No endpoints found in context for operation template".to_string(),
-                )
-            })?;
-
-        // Debug: log template name
-        tracing::debug!("Processing operation template: {}", template_name);
-
-        // Generate one file per endpoint
-        for endpoint in endpoints {
-            let mut tera_context = TeraContext::new();
-
-            // Add base context variables
-            for (key, value) in &context.variables {
-                tera_context.insert(key, value);
-            }
-
-            // Extract endpoint name for path substitution
-            let endpoint_name = endpoint
-                .get("endpoint")
-                .or_else(|| endpoint.get("endpoint_fs"))
-                .or_else(|| endpoint.get("fn_name"))
-                .and_then(|v| v.as_str())
-                .ok_or_else(|| {
-                    GenerationError::RenderError(
-                        "Endpoint object missing 'endpoint' field".to_string(),
-                    )
-                })?;
-
-            // Add endpoint fields to context at top level for template access
-            if let Some(obj) = endpoint.as_object() {
-                for (key, value) in obj {
-                    tera_context.insert(key, value);
-                }
-                // Debug logging
-                tracing::debug!(
-                    "Endpoint context for '{}': properties count = {}, parameters count = {}",
-                    endpoint_name,
-                    obj.get("properties")
-                        .and_then(|v| v.as_array())
-                        .map(|a| a.len())
-                        .unwrap_or(0),
-                    obj.get("parameters")
-                        .and_then(|v| v.as_array())
-                        .map(|a| a.len())
-                        .unwrap_or(0)
-                );
-
-                // Additional debug: check specific fields that template expects
-                tracing::debug!(
-                    "Endpoint '{}' has response_type: {}, response_is_array: {}",
-                    endpoint_name,
-                    obj.get("response_type")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("missing"),
-                    obj.get("response_is_array")
-                        .and_then(|v| v.as_bool())
-                        .unwrap_or(false)
-                );
-            }
-
-            // Replace {endpoint} placeholder in destination path
-            let output_path = file_destination
-                .replace("{endpoint}", endpoint_name)
-                .replace("{operation_id}", endpoint_name);
-
-            // Render the template
-            let rendered = tera.render(template_name, &tera_context)
-                .map_err(|e| {
-                    // Extract the actual error message and source
-                    let error_msg = format!("{e:?}");
-                    tracing::error!(
-                        "Template render error for '{template_name}' endpoint '{endpoint_name}': Full Tera error: {error_msg}", 
-                    );
-                    GenerationError::RenderError(format!(
-                        "Failed to render template '{template_name}' for endpoint '{endpoint_name}': {e}"
-                    ))
-                })?;
-
-            artifacts.push(Artifact {
-                path: PathBuf::from(output_path),
-                content: rendered,
-                permissions: None,
-            });
-        }
-
-        Ok(artifacts)
-    }
 }
 
 #[async_trait]
@@ -353,24 +258,18 @@ impl TemplateRenderingStrategy for McpServerTemplateRenderer {
         }
 
         let mut artifacts = Vec::new();
-        let mut tera = Tera::default();
-
-        // Add template files to Tera, indexed by their manifest source names
-        for manifest_file in &template.manifest.files {
-            if let Some(template_file) = template
-                .files
-                .iter()
-                .find(|f| f.path.to_string_lossy() == manifest_file.source)
-            {
-                if matches!(manifest_file.file_type, TemplateFileType::Template { .. }) {
-                    tera.add_raw_template(&manifest_file.source, &template_file.content)
-                        .map_err(|e| {
-                            GenerationError::RenderError(format!(
-                                "Failed to add template '{}': {}",
-                                manifest_file.source, e
-                            ))
-                        })?;
-                }
+        // Template files and partials, indexed by their source names
+        let tera = template_functions::load_template(template)?;
+
+        // Contexts shared by every file: the full variable set for templates rendered
+        // once, and the same without the endpoint list for for_each templates, so large
+        // specs don't copy every endpoint into every item's context
+        let mut global_context = TeraContext::new();
+        let mut base_context = TeraContext::new();
+        for (key, value) in &context.variables {
+            global_context.insert(key, value);
+            if key != "endpoints" && key != "endpoint" {
+                base_context.insert(key, value);
             }
         }
 
@@ -387,41 +286,43 @@ impl TemplateRenderingStrategy for McpServerTemplateRenderer {
                     ))
                 })?;
 
+            if !template_functions::is_enabled(&tera, manifest_file, &global_context)? {
+                tracing::debug!("Skipping '{}': when is false", manifest_file.source);
+                continue;
+            }
+
             match &manifest_file.file_type {
                 TemplateFileType::Template { for_each } => {
                     if let Some(collection_key) = for_each {
-                        if collection_key == "endpoint" || collection_key == "operation" {
-                            // Generate one file per endpoint
-                            artifacts.extend(
-                                self.process_operation_file(
-                                    &tera,
-                                    &manifest_file.source,
-                                    &manifest_file.target,
-                                    context,
-                                    generation_context,
-                                )
-                                .await?,
-                            );
-                        } else {
-                            return Err(GenerationError::InvalidConfiguration(format!(
-                                "Unsupported for_each value: {collection_key}"
-                            )));
-                        }
+                        let for_each: ForEach = collection_key.parse()?;
+                        let items = for_each::items(for_each, context, generation_context)?;
+                        tracing::debug!(
+                            "Rendering '{}' for {} {} item(s)",
+                            manifest_file.source,
+                            items.len(),
+                            collection_key
+                        );
+
+                        // Items render in parallel from the shared base context
+                        artifacts.extend(for_each::render_items(
+                            &tera,
+                            &manifest_file.source,
+                            &manifest_file.target,
+                            &base_context,
+                            for_each,
+                            &items,
+                        )?);
                     } else {
                         // Regular template - render once
-                        let mut tera_context = TeraContext::new();
-                        for (key, value) in &context.variables {
-                            tera_context.insert(key, value);
-                        }
-
-                        let rendered =
-                            tera.render(&manifest_file.source, &tera_context)
-                                .map_err(|e| {
-                                    GenerationError::RenderError(format!(
-                                        "Failed to render template '{}': {}",
-                                        manifest_file.source, e
-                                    ))
-                                })?;
+                        let rendered = tera
+                            .render(&manifest_file.source, &global_context)
+                            .map_err(|e| {
+                                GenerationError::RenderError(format!(
+                                    "Failed to render template '{}': {}",
+                                    manifest_file.source,
+                                    template_functions::describe_error(&e)
+                                ))
+                            })?;
 
                         artifacts.push(Artifact {
                             path: PathBuf::from(&manifest_file.target),
@@ -430,8 +331,8 @@ impl TemplateRenderingStrategy for McpServerTemplateRenderer {
                         });
                     }
                 }
-                TemplateFileType::Static => {
-                    // Copy static files as-is
+                TemplateFileType::Static | TemplateFileType::Configuration => {
+                    // Copy static and configuration files as-is
                     artifacts.push(Artifact {
                         path: PathBuf::from(&manifest_file.target),
                         content: template_file.content.clone(),
@@ -2,9 +2,11 @@
 
 pub mod context_builders;
 pub mod default_renderer;
+pub mod for_each;
 pub mod mcp_server_renderer;
 pub mod post_processor;
 pub mod renderer_factory;
+pub mod template_functions;
 
 pub use default_renderer::DefaultTemplateRenderer;
 pub use mcp_server_renderer::McpServerTemplateRenderer;
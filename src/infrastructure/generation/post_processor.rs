@@ -1,10 +1,8 @@
 //! Default post-processor implementation
 
 use async_trait::async_trait;
-use std::sync::Arc;
 
 use crate::generation::{Artifact, GenerationContext, GenerationError, PostProcessor};
-use crate::infrastructure::shell::CommandExecutor;
 
 /// Post-processor that adds file permissions based on file type
 pub struct PermissionsPostProcessor;
@@ -15,13 +13,18 @@ impl PermissionsPostProcessor {
     }
 }
 
+impl Default for PermissionsPostProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[async_trait]
 impl PostProcessor for PermissionsPostProcessor {
     async fn process(
         &self,
         mut artifacts: Vec<Artifact>,
         context: &GenerationContext,
-        _post_generation_commands: &[String],
     ) -> Result<Vec<Artifact>, GenerationError> {
         for artifact in &mut artifacts {
             // Make scripts executable - including language-specific executable extensions
@@ -51,81 +54,6 @@ impl PostProcessor for PermissionsPostProcessor {
     }
 }
 
-/// Post-processor that executes commands after artifact generation
-pub struct CommandPostProcessor {
-    executor: Arc<dyn CommandExecutor>,
-}
-
-impl CommandPostProcessor {
-    pub fn new(executor: Arc<dyn CommandExecutor>) -> Self {
-        Self { executor }
-    }
-}
-
-#[async_trait]
-impl PostProcessor for CommandPostProcessor {
-    async fn process(
-        &self,
-        artifacts: Vec<Artifact>,
-        context: &GenerationContext,
-        post_generation_commands: &[String],
-    ) -> Result<Vec<Artifact>, GenerationError> {
-        // Execute post-generation commands in the output directory
-        for command in post_generation_commands {
-            tracing::info!(
-                project_name = %context.metadata.project_name,
-                command = %command,
-                "Executing post-generation command"
-            );
-
-            let result = self
-                .executor
-                .execute(command, std::path::Path::new("."))
-                .await;
-
-            match result {
-                Ok(cmd_result) => {
-                    if cmd_result.is_success() {
-                        tracing::debug!(
-                            project_name = %context.metadata.project_name,
-                            command = %command,
-                            "Post-generation command completed successfully"
-                        );
-
-                        // Log command output if present
-                        if !cmd_result.stdout.trim().is_empty() {
-                            tracing::debug!(
-                                project_name = %context.metadata.project_name,
-                                command = %command,
-                                output = %cmd_result.stdout.trim(),
-                                "Post-generation command output"
-                            );
-                        }
-                    } else {
-                        tracing::error!(
-                            project_name = %context.metadata.project_name,
-                            command = %command,
-                            exit_code = cmd_result.exit_code,
-                            stderr = %cmd_result.stderr,
-                            "Post-generation command failed"
-                        );
-                    }
-                }
-                Err(e) => {
-                    tracing::error!(
-                        project_name = %context.metadata.project_name,
-                        command = %command,
-                        error = %e,
-                        "Failed to execute post-generation command"
-                    );
-                }
-            }
-        }
-
-        Ok(artifacts)
-    }
-}
-
 /// Composite post-processor that runs multiple processors in sequence
 pub struct CompositePostProcessor {
     processors: Vec<Box<dyn PostProcessor>>,
@@ -133,30 +61,27 @@ pub struct CompositePostProcessor {
 
 impl CompositePostProcessor {
     pub fn new() -> Self {
-        // Actually wire up the processors!
         Self {
-            processors: vec![
-                Box::new(PermissionsPostProcessor::new()),
-                Box::new(CommandPostProcessor::new(Arc::new(
-                    crate::infrastructure::ShellCommandExecutor::new(),
-                ))),
-            ],
+            processors: vec![Box::new(PermissionsPostProcessor::new())],
         }
     }
 }
 
+impl Default for CompositePostProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[async_trait]
 impl PostProcessor for CompositePostProcessor {
     async fn process(
         &self,
         mut artifacts: Vec<Artifact>,
         context: &GenerationContext,
-        post_generation_commands: &[String],
     ) -> Result<Vec<Artifact>, GenerationError> {
         for processor in &self.processors {
-            artifacts = processor
-                .process(artifacts, context, post_generation_commands)
-                .await?;
+            artifacts = processor.process(artifacts, context).await?;
         }
         Ok(artifacts)
     }
@@ -166,7 +91,6 @@ impl PostProcessor for CompositePostProcessor {
 mod tests {
     use super::*;
     use crate::generation::Language;
-    use crate::infrastructure::shell::MockCommandExecutor;
     use crate::protocols::{Protocol, Role};
     use std::path::PathBuf;
 
@@ -198,7 +122,7 @@ mod tests {
             },
         ];
 
-        let result = processor.process(artifacts, &context, &[]).await.unwrap();
+        let result = processor.process(artifacts, &context).await.unwrap();
 
         assert_eq!(result[0].permissions, Some(0o755)); // script.sh
         assert_eq!(result[1].permissions, Some(0o755)); // run.py
@@ -206,59 +130,6 @@ mod tests {
         assert_eq!(result[3].permissions, None); // README.md
     }
 
-    #[tokio::test]
-    async fn test_command_post_processor_success() {
-        let mock_executor = MockCommandExecutor::new()
-            .with_result("npm install", 0, "packages installed", "")
-            .with_result("chmod +x script.sh", 0, "", "");
-
-        let processor = CommandPostProcessor::new(Arc::new(mock_executor));
-        let context = GenerationContext::new(Protocol::Mcp, Role::Server, Language::TypeScript);
-
-        let artifacts = vec![
-            Artifact {
-                path: PathBuf::from("package.json"),
-                content: "{}".to_string(),
-                permissions: None,
-            },
-            Artifact {
-                path: PathBuf::from("script.sh"),
-                content: "#!/bin/bash".to_string(),
-                permissions: None,
-            },
-        ];
-
-        let result = processor.process(artifacts, &context, &[]).await.unwrap();
-
-        // Commands processing completed successfully
-        assert_eq!(result.len(), 2);
-        assert_eq!(result[0].path, PathBuf::from("package.json"));
-        assert_eq!(result[1].path, PathBuf::from("script.sh"));
-    }
-
-    #[tokio::test]
-    async fn test_command_post_processor_no_commands() {
-        let mock_executor =
-            MockCommandExecutor::new().with_result("npm install", 1, "", "npm not found");
-
-        let processor = CommandPostProcessor::new(Arc::new(mock_executor));
-        let context = GenerationContext::new(Protocol::Mcp, Role::Server, Language::TypeScript);
-
-        let artifacts = vec![Artifact {
-            path: PathBuf::from("package.json"),
-            content: "{}".to_string(),
-            permissions: None,
-        }];
-
-        let result = processor.process(artifacts, &context, &[]).await;
-
-        // Since artifacts no longer have post_commands, processing should succeed
-        assert!(result.is_ok());
-        let processed_artifacts = result.unwrap();
-        assert_eq!(processed_artifacts.len(), 1);
-        assert_eq!(processed_artifacts[0].path, PathBuf::from("package.json"));
-    }
-
     #[tokio::test]
     async fn test_composite_post_processor() {
         // CompositePostProcessor is now created with built-in processors
@@ -272,11 +143,10 @@ mod tests {
             permissions: None,
         }];
 
-        let result = composite.process(artifacts, &context, &[]).await.unwrap();
+        let result = composite.process(artifacts, &context).await.unwrap();
 
         // Should have permissions set by PermissionsPostProcessor
         assert_eq!(result[0].permissions, Some(0o755));
-        // Command processing completed successfully
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].path, PathBuf::from("script.sh"));
     }
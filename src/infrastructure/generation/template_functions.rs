@@ -0,0 +1,556 @@
+//! Filters and functions available to every template
+//!
+//! Both renderers build their Tera instance with [`load_template`], so custom
+//! templates can derive identifiers, types and doc comments from raw spec
+//! values instead of relying on a precomputed context field for each variant,
+//! and can `include` or `import` the template's partials.
+//! The full list is documented in `docs/TEMPLATES.md`.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use serde_json::Value;
+use tera::{Error, Result, Tera, try_get_value};
+
+use crate::generation::{
+    GenerationError, Language,
+    sanitizers::sanitize_markdown,
+    utils::{escape_identifier, to_camel_case, to_proper_case, to_snake_case},
+};
+use crate::infrastructure::generation::context_builders::{csharp, go, python, rust, typescript};
+use crate::infrastructure::{ManifestFile, Template, TemplateFileType};
+
+/// Tera instance with the agenterra filters and functions registered
+pub fn new_tera() -> Tera {
+    let mut tera = Tera::default();
+    register(&mut tera);
+    tera
+}
+
+/// Tera instance holding a template's renderable files and partials
+///
+/// Files are keyed by their source path, so templates refer to partials as
+/// `{% include "_partials/struct.tera" %}`. Everything is added in one batch,
+/// which lets files import or extend each other regardless of load order.
+pub fn load_template(template: &Template) -> std::result::Result<Tera, GenerationError> {
+    let mut tera = new_tera();
+    let mut sources: Vec<(String, String)> = template
+        .files
+        .iter()
+        .filter(|file| {
+            matches!(
+                file.file_type,
+                TemplateFileType::Template { .. } | TemplateFileType::Partial
+            )
+        })
+        .map(|file| {
+            (
+                file.path.to_string_lossy().to_string(),
+                file.content.clone(),
+            )
+        })
+        .collect();
+
+    // `when:` conditions compile alongside the files, so syntax errors surface
+    // before anything renders
+    sources.extend(template.manifest.files.iter().filter_map(|file| {
+        let expression = file.when.as_ref()?;
+        Some((
+            condition_name(&file.source),
+            format!("{{% if {expression} %}}true{{% endif %}}"),
+        ))
+    }));
+
+    tera.add_raw_templates(sources).map_err(|e| {
+        GenerationError::RenderError(format!("Failed to load templates: {}", describe_error(&e)))
+    })?;
+    Ok(tera)
+}
+
+/// Format a Tera error together with its causes
+///
+/// Tera keeps the useful part, such as the line of a parse error or the name
+/// of an undefined variable, in the source chain rather than the top message.
+pub fn describe_error(error: &tera::Error) -> String {
+    let mut message = error.to_string();
+    let mut source = std::error::Error::source(error);
+    while let Some(cause) = source {
+        message.push_str(&format!(": {cause}"));
+        source = cause.source();
+    }
+    message
+}
+
+/// Whether a manifest file's `when:` condition holds for `context`
+///
+/// Files without a condition are always emitted. `tera` must come from
+/// [`load_template`], which compiles the conditions.
+pub fn is_enabled(
+    tera: &Tera,
+    file: &ManifestFile,
+    context: &tera::Context,
+) -> std::result::Result<bool, GenerationError> {
+    let Some(expression) = &file.when else {
+        return Ok(true);
+    };
+    let result = tera
+        .render(&condition_name(&file.source), context)
+        .map_err(|e| {
+            GenerationError::RenderError(format!(
+                "Failed to evaluate when '{expression}' of '{}': {e}",
+                file.source
+            ))
+        })?;
+    Ok(result == "true")
+}
+
+fn condition_name(source: &str) -> String {
+    format!("__when__/{source}")
+}
+
+/// Register the agenterra filters and functions on an existing Tera instance
+pub fn register(tera: &mut Tera) {
+    tera.register_filter("snake_case", string_filter("snake_case", to_snake_case));
+    tera.register_filter("camel_case", string_filter("camel_case", to_camel_case));
+    tera.register_filter("pascal_case", string_filter("pascal_case", to_proper_case));
+    tera.register_filter(
+        "kebab_case",
+        string_filter("kebab_case", |s| to_snake_case(s).replace('_', "-")),
+    );
+    tera.register_filter(
+        "screaming_snake_case",
+        string_filter("screaming_snake_case", |s| to_snake_case(s).to_uppercase()),
+    );
+    tera.register_filter(
+        "sanitize_markdown",
+        string_filter("sanitize_markdown", sanitize_markdown),
+    );
+    tera.register_filter("escape_ident", escape_ident);
+    tera.register_filter("rust_type", language_type_filter(Language::Rust));
+    tera.register_filter("python_type", language_type_filter(Language::Python));
+    tera.register_filter("ts_type", language_type_filter(Language::TypeScript));
+    tera.register_filter("doc_comment", doc_comment);
+    tera.register_filter("json_pointer", json_pointer_filter);
+    tera.register_filter("dedent", string_filter("dedent", dedent));
+    tera.register_filter("reindent", reindent);
+
+    tera.register_function("type_for", type_for);
+    tera.register_function("json_pointer", json_pointer_function);
+}
+
+/// Wrap a `&str -> String` conversion as a Tera filter
+fn string_filter(
+    name: &'static str,
+    convert: impl Fn(&str) -> String + Send + Sync + 'static,
+) -> impl tera::Filter {
+    move |value: &Value, _: &HashMap<String, Value>| -> Result<Value> {
+        let s = try_get_value!(name, "value", String, value);
+        Ok(Value::String(convert(&s)))
+    }
+}
+
+fn language_arg(name: &str, args: &HashMap<String, Value>) -> Result<Language> {
+    let language = match args.get("language") {
+        Some(value) => try_get_value!(name, "language", String, value),
+        None => {
+            return Err(Error::msg(format!(
+                "`{name}` requires a `language` argument"
+            )));
+        }
+    };
+    Language::from_str(&language)
+        .map_err(|_| Error::msg(format!("`{name}`: unknown language '{language}'")))
+}
+
+/// `{{ name | escape_ident(language="python") }}`
+fn escape_ident(value: &Value, args: &HashMap<String, Value>) -> Result<Value> {
+    let ident = try_get_value!("escape_ident", "value", String, value);
+    let language = language_arg("escape_ident", args)?;
+    Ok(Value::String(escape_identifier(&ident, language)))
+}
+
+fn language_type_filter(language: Language) -> impl tera::Filter {
+    move |value: &Value, _: &HashMap<String, Value>| -> Result<Value> {
+        Ok(Value::String(schema_type(value, language)))
+    }
+}
+
+/// `{{ type_for(schema=property.schema, language="typescript") }}`
+fn type_for(args: &HashMap<String, Value>) -> Result<Value> {
+    let schema = args
+        .get("schema")
+        .ok_or_else(|| Error::msg("`type_for` requires a `schema` argument"))?;
+    let language = language_arg("type_for", args)?;
+    Ok(Value::String(schema_type(schema, language)))
+}
+
+/// Map a JSON schema to a type name of `language`
+///
+/// Delegates to the context builder of that language, so templates and
+/// builders agree on every type.
+fn schema_type(schema: &Value, language: Language) -> String {
+    match language {
+        Language::Rust => rust::map_json_to_rust_type(schema),
+        Language::Python => python::map_json_to_python_type(schema),
+        Language::TypeScript => typescript::map_json_to_typescript_type(schema),
+        Language::Go => go::map_json_to_go_type(schema),
+        Language::CSharp => csharp::map_json_to_csharp_type(schema),
+        // Languages without a mapping yet get the raw schema type
+        _ => schema
+            .get("type")
+            .and_then(Value::as_str)
+            .unwrap_or("object")
+            .to_string(),
+    }
+}
+
+/// `{{ endpoint.description | doc_comment(prefix="# ") }}`
+///
+/// Prefixes every line, `/// ` by default, and breaks up `*/` so the text
+/// can't close a surrounding block comment.
+fn doc_comment(value: &Value, args: &HashMap<String, Value>) -> Result<Value> {
+    let text = try_get_value!("doc_comment", "value", String, value);
+    let prefix = match args.get("prefix") {
+        Some(prefix) => try_get_value!("doc_comment", "prefix", String, prefix),
+        None => "/// ".to_string(),
+    };
+
+    let comment = text
+        .trim_end()
+        .lines()
+        .map(|line| {
+            let line = line.trim_end().replace("*/", "*\\/");
+            if line.is_empty() {
+                prefix.trim_end().to_string()
+            } else {
+                format!("{prefix}{line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    Ok(Value::String(comment))
+}
+
+/// `{{ spec | json_pointer(pointer="/components/schemas/Pet") }}`
+fn json_pointer_filter(value: &Value, args: &HashMap<String, Value>) -> Result<Value> {
+    let pointer = match args.get("pointer") {
+        Some(pointer) => try_get_value!("json_pointer", "pointer", String, pointer),
+        None => return Err(Error::msg("`json_pointer` requires a `pointer` argument")),
+    };
+    Ok(lookup_pointer(value, &pointer))
+}
+
+/// `{{ json_pointer(value=spec, pointer="/info/title") }}`
+fn json_pointer_function(args: &HashMap<String, Value>) -> Result<Value> {
+    let value = args
+        .get("value")
+        .ok_or_else(|| Error::msg("`json_pointer` requires a `value` argument"))?;
+    json_pointer_filter(value, args)
+}
+
+/// Resolve an RFC 6901 pointer, also accepting `#/...` fragment form; null when absent
+fn lookup_pointer(value: &Value, pointer: &str) -> Value {
+    let pointer = pointer.strip_prefix('#').unwrap_or(pointer);
+    value.pointer(pointer).cloned().unwrap_or(Value::Null)
+}
+
+/// Remove the indentation shared by all non-blank lines
+///
+/// Only ASCII spaces and tabs count as indentation, so other whitespace such
+/// as a non-breaking space stays part of the text.
+fn dedent(text: &str) -> String {
+    let indentation = |line: &str| line.len() - line.trim_start_matches([' ', '\t']).len();
+    let indent = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(indentation)
+        .min()
+        .unwrap_or(0);
+
+    text.lines()
+        .map(|line| line[indentation(line).min(indent)..].trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// `{{ block | reindent(width=8) }}`
+///
+/// Dedents, then indents every line after the first by `width` spaces
+/// (4 by default), matching the column where the block is inserted.
+/// Pass `first=true` to indent the first line too.
+fn reindent(value: &Value, args: &HashMap<String, Value>) -> Result<Value> {
+    let text = try_get_value!("reindent", "value", String, value);
+    let width = match args.get("width") {
+        Some(width) => try_get_value!("reindent", "width", usize, width),
+        None => 4,
+    };
+    let first = match args.get("first") {
+        Some(first) => try_get_value!("reindent", "first", bool, first),
+        None => false,
+    };
+
+    let padding = " ".repeat(width);
+    let reindented = dedent(&text)
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            if line.is_empty() || (i == 0 && !first) {
+                line.to_string()
+            } else {
+                format!("{padding}{line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    Ok(Value::String(reindented))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tera::Context;
+
+    fn render(template: &str, context: Value) -> String {
+        new_tera()
+            .render_str(template, &Context::from_value(context).unwrap())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_load_template_with_partials() {
+        use crate::generation::Language;
+        use crate::infrastructure::{TemplateFile, TemplateManifest, TemplateSource};
+        use crate::protocols::{Protocol, Role};
+        use std::path::PathBuf;
+
+        let file = |path: &str, content: &str, file_type| TemplateFile {
+            path: PathBuf::from(path),
+            content: content.to_string(),
+            file_type,
+        };
+        let template = Template {
+            manifest: TemplateManifest {
+                name: "partials".to_string(),
+                version: "1.0.0".to_string(),
+                description: None,
+                path: "mcp/server/rust".to_string(),
+                protocol: Protocol::Mcp,
+                role: Role::Server,
+                language: Language::Rust,
+                files: vec![],
+                variables: HashMap::new(),
+                variable_schema: vec![],
+                pre_generate_hooks: vec![],
+                post_generate_hooks: vec![],
+                partials: vec![],
+                required_directories: vec![],
+                extends: None,
+            },
+            // The importing file comes before the macros it needs
+            files: vec![
+                file(
+                    "main.rs.tera",
+                    r#"{% import "_macros.tera" as m %}{{ m::field(name=name) }}{% include "_partials/footer.tera" %}"#,
+                    TemplateFileType::Template { for_each: None },
+                ),
+                file(
+                    "_macros.tera",
+                    "{% macro field(name) %}pub {{ name | snake_case }}: String,{% endmacro %}",
+                    TemplateFileType::Partial,
+                ),
+                file(
+                    "_partials/footer.tera",
+                    "\n// end",
+                    TemplateFileType::Partial,
+                ),
+                file("static.txt", "{{ not tera", TemplateFileType::Static),
+            ],
+            source: TemplateSource::Embedded,
+        };
+
+        let tera = load_template(&template).unwrap();
+        let rendered = tera
+            .render(
+                "main.rs.tera",
+                &Context::from_value(json!({ "name": "petId" })).unwrap(),
+            )
+            .unwrap();
+        assert_eq!(rendered, "pub pet_id: String,\n// end");
+    }
+
+    #[test]
+    fn test_when_conditions() {
+        use crate::generation::Language;
+        use crate::infrastructure::{ManifestFile, TemplateManifest, TemplateSource};
+        use crate::protocols::{Protocol, Role};
+
+        let file = |source: &str, when: Option<&str>| ManifestFile {
+            source: source.to_string(),
+            target: source.to_string(),
+            file_type: TemplateFileType::Template { for_each: None },
+            when: when.map(str::to_string),
+        };
+        let files = vec![
+            file("always.tera", None),
+            file("auth.tera", Some("api_security_schemes")),
+            file("docker.tera", Some("docker and not minimal")),
+        ];
+        let template = Template {
+            manifest: TemplateManifest {
+                name: "conditions".to_string(),
+                version: "1.0.0".to_string(),
+                description: None,
+                path: "mcp/server/rust".to_string(),
+                protocol: Protocol::Mcp,
+                role: Role::Server,
+                language: Language::Rust,
+                files: files.clone(),
+                variables: HashMap::new(),
+                variable_schema: vec![],
+                pre_generate_hooks: vec![],
+                post_generate_hooks: vec![],
+                partials: vec![],
+                required_directories: vec![],
+                extends: None,
+            },
+            files: vec![],
+            source: TemplateSource::Embedded,
+        };
+        let tera = load_template(&template).unwrap();
+
+        let context = Context::from_value(json!({ "docker": true, "minimal": false })).unwrap();
+        let enabled: Vec<bool> = files
+            .iter()
+            .map(|f| is_enabled(&tera, f, &context).unwrap())
+            .collect();
+        // Undefined variables are falsy
+        assert_eq!(enabled, vec![true, false, true]);
+
+        let mut broken = template.clone();
+        broken.manifest.files = vec![file("bad.tera", Some("docker and"))];
+        assert!(load_template(&broken).is_err());
+    }
+
+    #[test]
+    fn test_case_filters() {
+        assert_eq!(
+            render(
+                "{{ n | snake_case }} {{ n | camel_case }} {{ n | pascal_case }} {{ n | kebab_case }} {{ n | screaming_snake_case }}",
+                json!({ "n": "findPetsByStatus" })
+            ),
+            "find_pets_by_status findPetsByStatus FindPetsByStatus find-pets-by-status FIND_PETS_BY_STATUS"
+        );
+    }
+
+    #[test]
+    fn test_escape_ident() {
+        assert_eq!(
+            render(
+                r#"{{ "type" | escape_ident(language="rust") }} {{ "from" | escape_ident(language="py") }}"#,
+                json!({})
+            ),
+            "type_ from_"
+        );
+
+        let err = new_tera()
+            .render_str("{{ 'x' | escape_ident }}", &Context::new())
+            .unwrap_err();
+        assert!(format!("{err:?}").contains("requires a `language` argument"));
+    }
+
+    #[test]
+    fn test_type_filters() {
+        let context = json!({
+            "ids": { "type": "array", "items": { "type": "integer" } },
+            "pets": { "type": "array", "items": { "$ref": "#/components/schemas/pet_record" } },
+        });
+        assert_eq!(
+            render(
+                "{{ ids | rust_type }} {{ ids | python_type }} {{ ids | ts_type }}",
+                context.clone()
+            ),
+            "Vec<i32> List[int] number[]"
+        );
+        assert_eq!(
+            render(
+                r#"{{ pets | rust_type }} {{ pets | python_type }} {{ type_for(schema=pets, language="typescript") }}"#,
+                context.clone()
+            ),
+            "Vec<PetRecord> List[PetRecord] PetRecord[]"
+        );
+        assert_eq!(
+            render(
+                r#"{{ type_for(schema=ids, language="go") }} {{ type_for(schema=pets, language="go") }}"#,
+                context.clone()
+            ),
+            "[]int64 []PetRecord"
+        );
+        assert_eq!(
+            render(
+                r#"{{ type_for(schema=ids, language="csharp") }} {{ type_for(schema=pets, language="csharp") }}"#,
+                context.clone()
+            ),
+            "IReadOnlyList<long> IReadOnlyList<PetRecord>"
+        );
+        // The filters are the context builders' own mappers
+        assert_eq!(
+            render("{{ pets | python_type }}", context.clone()),
+            python::map_json_to_python_type(&context["pets"])
+        );
+    }
+
+    #[test]
+    fn test_doc_comment() {
+        assert_eq!(
+            render(
+                "{{ text | doc_comment }}\n{{ text | doc_comment(prefix=' * ') }}",
+                json!({ "text": "Finds pets.\n\nSee /pets/{id} */\n" })
+            ),
+            "/// Finds pets.\n///\n/// See /pets/{id} *\\/\n * Finds pets.\n *\n * See /pets/{id} *\\/"
+        );
+    }
+
+    #[test]
+    fn test_json_pointer() {
+        let context = json!({
+            "spec": { "components": { "schemas": { "a/b": { "type": "string" } } } }
+        });
+        assert_eq!(
+            render(
+                r##"{{ spec | json_pointer(pointer="#/components/schemas/a~1b/type") }}"##,
+                context.clone()
+            ),
+            "string"
+        );
+        assert_eq!(
+            render(
+                r#"{{ json_pointer(value=spec, pointer="/missing") | json_encode }}"#,
+                context
+            ),
+            "null"
+        );
+    }
+
+    #[test]
+    fn test_indentation() {
+        let context = json!({ "block": "    fn a() {\n        b();\n    }\n" });
+        assert_eq!(
+            render("{{ block | dedent }}", context.clone()),
+            "fn a() {\n    b();\n}"
+        );
+        assert_eq!(
+            render("{{ block | reindent(width=2, first=true) }}", context),
+            "  fn a() {\n      b();\n  }"
+        );
+
+        // Non-breaking spaces are text, not indentation
+        let context = json!({ "block": "\u{a0}Note: keep this\n  y\n\tz\n    w" });
+        assert_eq!(
+            render("{{ block | dedent }}", context),
+            "\u{a0}Note: keep this\n  y\n\tz\n    w"
+        );
+        let context = json!({ "block": "    a\n  \u{a0}b\n\t\tc" });
+        assert_eq!(render("{{ block | dedent }}", context), "  a\n\u{a0}b\nc");
+    }
+}
@@ -0,0 +1,507 @@
+//! MCP client that lists the tools of a server
+//!
+//! Bridges are generated from the tools an MCP server lists. The client
+//! speaks just enough of the protocol for that: it initializes a session,
+//! pages through `tools/list` and disconnects.
+
+#[cfg(feature = "http-loader")]
+mod sse;
+
+use async_trait::async_trait;
+use serde_json::{Value as JsonValue, json};
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::{
+    AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader,
+};
+use tokio::process::Command;
+
+use crate::generation::{GenerationError, McpServerSource, McpTool, McpToolLoader};
+
+/// Protocol version sent in `initialize`; servers answer with the version they speak
+const PROTOCOL_VERSION: &str = "2024-11-05";
+
+/// Pages of `tools/list` read before giving up on a server that keeps returning cursors
+const MAX_PAGES: usize = 100;
+
+/// How much of a failed server's stderr to report
+const MAX_STDERR: usize = 4096;
+
+/// Lists the tools of MCP servers over stdio, or over SSE with the
+/// `http-loader` feature
+pub struct McpToolLister {
+    timeout: Duration,
+}
+
+impl McpToolLister {
+    pub fn new() -> Self {
+        Self {
+            timeout: Duration::from_secs(60),
+        }
+    }
+
+    /// Give up on servers that have not listed their tools after `timeout`,
+    /// including the time they take to start
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+impl Default for McpToolLister {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl McpToolLoader for McpToolLister {
+    async fn list_tools(&self, server: &McpServerSource) -> Result<Vec<McpTool>, GenerationError> {
+        tracing::debug!("Listing the tools of the MCP server {server}");
+        let listing = async {
+            match server {
+                McpServerSource::Stdio { command, args } => list_stdio_tools(command, args).await,
+                McpServerSource::Sse { url } => {
+                    #[cfg(feature = "http-loader")]
+                    {
+                        sse::list_tools(url).await
+                    }
+                    #[cfg(not(feature = "http-loader"))]
+                    Err(GenerationError::LoadError(format!(
+                        "Cannot connect to {url}: MCP servers over SSE require the `http-loader` feature"
+                    )))
+                }
+            }
+        };
+        tokio::time::timeout(self.timeout, listing)
+            .await
+            .map_err(|_| {
+                GenerationError::LoadError(format!(
+                    "The MCP server {server} did not list its tools within {} seconds",
+                    self.timeout.as_secs()
+                ))
+            })?
+    }
+}
+
+/// A JSON-RPC channel to an MCP server
+#[async_trait]
+trait Connection: Send {
+    async fn send(&mut self, message: &JsonValue) -> Result<(), GenerationError>;
+
+    /// The next message from the server
+    async fn receive(&mut self) -> Result<JsonValue, GenerationError>;
+}
+
+/// Initialize a session and page through `tools/list`
+async fn list_tools_over(connection: &mut dyn Connection) -> Result<Vec<McpTool>, GenerationError> {
+    let mut session = Session {
+        connection,
+        next_id: 1,
+    };
+    session
+        .request(
+            "initialize",
+            json!({
+                "protocolVersion": PROTOCOL_VERSION,
+                "capabilities": {},
+                "clientInfo": {
+                    "name": env!("CARGO_PKG_NAME"),
+                    "version": env!("CARGO_PKG_VERSION")
+                }
+            }),
+        )
+        .await?;
+    session
+        .connection
+        .send(&json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }))
+        .await?;
+
+    let mut tools = Vec::new();
+    let mut cursor: Option<String> = None;
+    for _ in 0..MAX_PAGES {
+        let params = match &cursor {
+            Some(cursor) => json!({ "cursor": cursor }),
+            None => json!({}),
+        };
+        let mut page = session.request("tools/list", params).await?;
+        let page_tools: Vec<McpTool> = serde_json::from_value(page["tools"].take())
+            .map_err(|e| GenerationError::LoadError(format!("Invalid tools/list result: {e}")))?;
+        tools.extend(page_tools);
+        match page["nextCursor"].as_str() {
+            Some(next) if !next.is_empty() => cursor = Some(next.to_string()),
+            _ => return Ok(tools),
+        }
+    }
+    Err(GenerationError::LoadError(format!(
+        "The MCP server returned more than {MAX_PAGES} pages of tools"
+    )))
+}
+
+struct Session<'a> {
+    connection: &'a mut dyn Connection,
+    next_id: u64,
+}
+
+impl Session<'_> {
+    /// Send a request and wait for its response, answering the server's own
+    /// requests in the meantime
+    async fn request(
+        &mut self,
+        method: &str,
+        params: JsonValue,
+    ) -> Result<JsonValue, GenerationError> {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.connection
+            .send(&json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }))
+            .await?;
+
+        loop {
+            let mut message = self.connection.receive().await?;
+            if let Some(server_method) = message.get("method").and_then(JsonValue::as_str) {
+                // Notifications need no answer; requests such as `ping` do
+                if let Some(request_id) = message.get("id") {
+                    let reply = match server_method {
+                        "ping" => json!({ "jsonrpc": "2.0", "id": request_id, "result": {} }),
+                        other => json!({
+                            "jsonrpc": "2.0",
+                            "id": request_id,
+                            "error": { "code": -32601, "message": format!("Method not found: {other}") }
+                        }),
+                    };
+                    self.connection.send(&reply).await?;
+                }
+                continue;
+            }
+            if message.get("id").and_then(JsonValue::as_u64) != Some(id) {
+                tracing::debug!("Ignoring unexpected MCP message: {message}");
+                continue;
+            }
+            if let Some(error) = message.get("error") {
+                return Err(GenerationError::LoadError(format!(
+                    "The MCP server answered {method} with error {}: {}",
+                    error["code"],
+                    error["message"].as_str().unwrap_or("no message")
+                )));
+            }
+            return Ok(message["result"].take());
+        }
+    }
+}
+
+/// Newline-delimited JSON-RPC over a byte stream, as spoken on stdio
+struct StreamConnection<R, W> {
+    reader: R,
+    writer: W,
+}
+
+#[async_trait]
+impl<R, W> Connection for StreamConnection<R, W>
+where
+    R: AsyncBufRead + Unpin + Send,
+    W: AsyncWrite + Unpin + Send,
+{
+    async fn send(&mut self, message: &JsonValue) -> Result<(), GenerationError> {
+        let mut line = message.to_string();
+        line.push('\n');
+        self.writer.write_all(line.as_bytes()).await?;
+        self.writer.flush().await?;
+        Ok(())
+    }
+
+    async fn receive(&mut self) -> Result<JsonValue, GenerationError> {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            if self.reader.read_line(&mut line).await? == 0 {
+                return Err(GenerationError::LoadError(
+                    "The MCP server closed the connection before listing its tools".to_string(),
+                ));
+            }
+            // Some servers log to stdout; skip anything that is not a message
+            match serde_json::from_str(line.trim()) {
+                Ok(message @ JsonValue::Object(_)) => return Ok(message),
+                _ if line.trim().is_empty() => {}
+                _ => tracing::debug!("Ignoring MCP server output: {}", line.trim_end()),
+            }
+        }
+    }
+}
+
+/// Start the server, list its tools and stop it
+async fn list_stdio_tools(command: &str, args: &[String]) -> Result<Vec<McpTool>, GenerationError> {
+    let mut child = Command::new(command)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| {
+            GenerationError::LoadError(format!("Failed to start the MCP server '{command}': {e}"))
+        })?;
+
+    let (Some(stdin), Some(stdout), Some(mut stderr)) =
+        (child.stdin.take(), child.stdout.take(), child.stderr.take())
+    else {
+        return Err(GenerationError::LoadError(
+            "The MCP server's stdio is not piped".to_string(),
+        ));
+    };
+    // Keep the tail of stderr to explain failures; draining it also keeps a
+    // chatty server from blocking on a full pipe
+    let stderr_tail = tokio::spawn(async move {
+        let mut tail = Vec::new();
+        let mut buffer = [0u8; 1024];
+        while let Ok(read) = stderr.read(&mut buffer).await {
+            if read == 0 {
+                break;
+            }
+            tail.extend_from_slice(&buffer[..read]);
+            if tail.len() > MAX_STDERR {
+                tail.drain(..tail.len() - MAX_STDERR);
+            }
+        }
+        String::from_utf8_lossy(&tail).trim().to_string()
+    });
+
+    let mut connection = StreamConnection {
+        reader: BufReader::new(stdout),
+        writer: stdin,
+    };
+    let result = list_tools_over(&mut connection).await;
+
+    // Closing stdin asks the server to exit; it is killed if it does not
+    drop(connection);
+    if tokio::time::timeout(Duration::from_secs(2), child.wait())
+        .await
+        .is_err()
+    {
+        let _ = child.kill().await;
+    }
+
+    match result {
+        Err(GenerationError::LoadError(message)) => {
+            // Grandchildren may hold stderr open after the server is gone
+            let stderr = tokio::time::timeout(Duration::from_secs(1), stderr_tail)
+                .await
+                .ok()
+                .and_then(Result::ok)
+                .unwrap_or_default();
+            Err(GenerationError::LoadError(if stderr.is_empty() {
+                message
+            } else {
+                format!("{message}. The server's stderr ends with:\n{stderr}")
+            }))
+        }
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{DuplexStream, ReadHalf, WriteHalf};
+
+    type ClientConnection =
+        StreamConnection<BufReader<ReadHalf<DuplexStream>>, WriteHalf<DuplexStream>>;
+    type ServerLines = tokio::io::Lines<BufReader<ReadHalf<DuplexStream>>>;
+
+    fn connect() -> (ClientConnection, ServerLines, WriteHalf<DuplexStream>) {
+        let (client, server) = tokio::io::duplex(64 * 1024);
+        let (client_read, client_write) = tokio::io::split(client);
+        let (server_read, server_write) = tokio::io::split(server);
+        (
+            StreamConnection {
+                reader: BufReader::new(client_read),
+                writer: client_write,
+            },
+            BufReader::new(server_read).lines(),
+            server_write,
+        )
+    }
+
+    async fn read(lines: &mut ServerLines) -> JsonValue {
+        serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap()
+    }
+
+    async fn write(writer: &mut WriteHalf<DuplexStream>, message: JsonValue) {
+        writer
+            .write_all(format!("{message}\n").as_bytes())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_lists_tools_across_pages() {
+        let (mut connection, mut lines, mut writer) = connect();
+        let server = tokio::spawn(async move {
+            let initialize = read(&mut lines).await;
+            assert_eq!(initialize["method"], "initialize");
+            assert_eq!(initialize["params"]["protocolVersion"], PROTOCOL_VERSION);
+            writer
+                .write_all(b"Starting weather server\n")
+                .await
+                .unwrap();
+            write(
+                &mut writer,
+                json!({ "jsonrpc": "2.0", "method": "notifications/message", "params": {} }),
+            )
+            .await;
+            write(
+                &mut writer,
+                json!({ "jsonrpc": "2.0", "id": initialize["id"], "result": { "capabilities": {} } }),
+            )
+            .await;
+            assert_eq!(
+                read(&mut lines).await["method"],
+                "notifications/initialized"
+            );
+
+            let first = read(&mut lines).await;
+            assert_eq!(first["method"], "tools/list");
+            // The server pings the client before answering
+            write(
+                &mut writer,
+                json!({ "jsonrpc": "2.0", "id": "ping-1", "method": "ping" }),
+            )
+            .await;
+            assert_eq!(
+                read(&mut lines).await,
+                json!({ "jsonrpc": "2.0", "id": "ping-1", "result": {} })
+            );
+            write(
+                &mut writer,
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": first["id"],
+                    "result": {
+                        "tools": [{
+                            "name": "get_forecast",
+                            "description": "Weather forecast",
+                            "inputSchema": { "type": "object", "properties": { "city": { "type": "string" } } }
+                        }],
+                        "nextCursor": "page-2"
+                    }
+                }),
+            )
+            .await;
+
+            let second = read(&mut lines).await;
+            assert_eq!(second["params"]["cursor"], "page-2");
+            write(
+                &mut writer,
+                json!({ "jsonrpc": "2.0", "id": second["id"], "result": { "tools": [{ "name": "list_alerts" }] } }),
+            )
+            .await;
+        });
+
+        let tools = list_tools_over(&mut connection).await.unwrap();
+        server.await.unwrap();
+        assert_eq!(tools.len(), 2);
+        assert_eq!(tools[0].name, "get_forecast");
+        assert_eq!(
+            tools[0].input_schema["properties"]["city"]["type"],
+            "string"
+        );
+        assert_eq!(tools[1].name, "list_alerts");
+        assert_eq!(tools[1].input_schema, json!({ "type": "object" }));
+    }
+
+    #[tokio::test]
+    async fn test_reports_error_responses() {
+        let (mut connection, mut lines, mut writer) = connect();
+        tokio::spawn(async move {
+            let initialize = read(&mut lines).await;
+            write(
+                &mut writer,
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": initialize["id"],
+                    "error": { "code": -32602, "message": "Unsupported protocol version" }
+                }),
+            )
+            .await;
+        });
+
+        let error = list_tools_over(&mut connection).await.unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("answered initialize with error -32602: Unsupported protocol version"),
+            "{error}"
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_lists_tools_of_a_stdio_server() {
+        // A server that answers by request order, ignoring what it is sent
+        let script = r#"
+            read request
+            echo '{"jsonrpc":"2.0","id":1,"result":{"capabilities":{}}}'
+            read notification
+            read request
+            echo '{"jsonrpc":"2.0","id":2,"result":{"tools":[{"name":"echo"}]}}'
+            read eof
+        "#;
+        let server = McpServerSource::Stdio {
+            command: "sh".to_string(),
+            args: vec!["-c".to_string(), script.to_string()],
+        };
+
+        let tools = McpToolLister::new().list_tools(&server).await.unwrap();
+        assert_eq!(tools.len(), 1);
+        assert_eq!(tools[0].name, "echo");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_reports_stderr_of_failed_servers() {
+        let server = McpServerSource::Stdio {
+            command: "sh".to_string(),
+            args: vec!["-c".to_string(), "echo 'missing API key' >&2".to_string()],
+        };
+
+        let error = McpToolLister::new().list_tools(&server).await.unwrap_err();
+        let message = error.to_string();
+        assert!(message.contains("closed the connection"), "{message}");
+        assert!(message.contains("missing API key"), "{message}");
+    }
+
+    #[tokio::test]
+    async fn test_reports_servers_that_fail_to_start() {
+        let server = McpServerSource::Stdio {
+            command: "agenterra-no-such-mcp-server".to_string(),
+            args: Vec::new(),
+        };
+
+        let error = McpToolLister::new().list_tools(&server).await.unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("Failed to start the MCP server 'agenterra-no-such-mcp-server'"),
+            "{error}"
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_times_out_on_silent_servers() {
+        let server = McpServerSource::Stdio {
+            command: "sleep".to_string(),
+            args: vec!["10".to_string()],
+        };
+
+        let error = McpToolLister::new()
+            .with_timeout(Duration::from_millis(200))
+            .list_tools(&server)
+            .await
+            .unwrap_err();
+        assert!(
+            error.to_string().contains("did not list its tools"),
+            "{error}"
+        );
+    }
+}
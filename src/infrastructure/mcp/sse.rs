@@ -0,0 +1,217 @@
+//! MCP over Server-Sent Events
+//!
+//! The client opens an event stream, whose first `endpoint` event names the
+//! URL to POST messages to; the server answers on the stream.
+
+use async_trait::async_trait;
+use futures::StreamExt;
+use futures::stream::BoxStream;
+use reqwest::Client;
+use serde_json::Value as JsonValue;
+use url::Url;
+
+use super::Connection;
+use crate::generation::{GenerationError, McpTool};
+
+/// List the tools of the server with its event stream at `url`
+pub async fn list_tools(url: &str) -> Result<Vec<McpTool>, GenerationError> {
+    let sse_url = Url::parse(url)
+        .map_err(|e| GenerationError::LoadError(format!("Invalid MCP server URL {url}: {e}")))?;
+    let client = Client::new();
+    let response = client
+        .get(sse_url.clone())
+        .header(reqwest::header::ACCEPT, "text/event-stream")
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .map_err(|e| GenerationError::LoadError(format!("Failed to connect to {url}: {e}")))?;
+
+    let mut events = EventStream {
+        bytes: response
+            .bytes_stream()
+            .map(|chunk| chunk.map(|bytes| bytes.to_vec()))
+            .boxed(),
+        buffer: Vec::new(),
+    };
+    let endpoint = loop {
+        let event = events.next(url).await?;
+        if event.name == "endpoint" {
+            break sse_url.join(event.data.trim()).map_err(|e| {
+                GenerationError::LoadError(format!(
+                    "Invalid message endpoint '{}' from {url}: {e}",
+                    event.data
+                ))
+            })?;
+        }
+    };
+    tracing::debug!("MCP server {url} takes messages at {endpoint}");
+
+    let mut connection = SseConnection {
+        client,
+        url: url.to_string(),
+        endpoint,
+        events,
+    };
+    super::list_tools_over(&mut connection).await
+}
+
+struct Event {
+    name: String,
+    data: String,
+}
+
+/// Events parsed from a `text/event-stream` body
+struct EventStream {
+    bytes: BoxStream<'static, reqwest::Result<Vec<u8>>>,
+    /// Bytes of incomplete events, without carriage returns
+    buffer: Vec<u8>,
+}
+
+impl EventStream {
+    async fn next(&mut self, url: &str) -> Result<Event, GenerationError> {
+        loop {
+            if let Some(event) = self.parse_event() {
+                return Ok(event);
+            }
+            match self.bytes.next().await {
+                Some(Ok(chunk)) => self
+                    .buffer
+                    .extend(chunk.into_iter().filter(|&b| b != b'\r')),
+                Some(Err(e)) => {
+                    return Err(GenerationError::LoadError(format!(
+                        "Lost the event stream of {url}: {e}"
+                    )));
+                }
+                None => {
+                    return Err(GenerationError::LoadError(format!(
+                        "{url} closed its event stream before listing its tools"
+                    )));
+                }
+            }
+        }
+    }
+
+    /// Take the first complete event off the buffer, skipping comments
+    fn parse_event(&mut self) -> Option<Event> {
+        while let Some(end) = self.buffer.windows(2).position(|pair| pair == b"\n\n") {
+            let block: Vec<u8> = self.buffer.drain(..end + 2).collect();
+            let block = String::from_utf8_lossy(&block);
+            let mut name = "message".to_string();
+            let mut data = Vec::new();
+            for line in block.lines() {
+                let (field, value) = line.split_once(':').unwrap_or((line, ""));
+                let value = value.strip_prefix(' ').unwrap_or(value);
+                match field {
+                    "event" => name = value.to_string(),
+                    "data" => data.push(value),
+                    _ => {}
+                }
+            }
+            if !data.is_empty() {
+                return Some(Event {
+                    name,
+                    data: data.join("\n"),
+                });
+            }
+        }
+        None
+    }
+}
+
+struct SseConnection {
+    client: Client,
+    url: String,
+    endpoint: Url,
+    events: EventStream,
+}
+
+#[async_trait]
+impl Connection for SseConnection {
+    async fn send(&mut self, message: &JsonValue) -> Result<(), GenerationError> {
+        self.client
+            .post(self.endpoint.clone())
+            .json(message)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|e| {
+                GenerationError::LoadError(format!(
+                    "Failed to send a message to {}: {e}",
+                    self.endpoint
+                ))
+            })?;
+        Ok(())
+    }
+
+    async fn receive(&mut self) -> Result<JsonValue, GenerationError> {
+        loop {
+            let event = self.events.next(&self.url).await?;
+            if event.name != "message" {
+                continue;
+            }
+            match serde_json::from_str(&event.data) {
+                Ok(message @ JsonValue::Object(_)) => return Ok(message),
+                _ => tracing::debug!("Ignoring MCP event from {}: {}", self.url, event.data),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_lists_tools_over_sse() {
+        let server = MockServer::start().await;
+        // The whole stream is sent at once; the client still has to post each
+        // request to the endpoint before reading its response
+        let stream = concat!(
+            ": keep-alive\r\n\r\n",
+            "event: endpoint\r\ndata: /message?sessionId=abc\r\n\r\n",
+            "event: message\ndata: {\"jsonrpc\":\"2.0\",\"id\":1,\"result\":{}}\n\n",
+            "data: {\"jsonrpc\":\"2.0\",\"id\":2,\n",
+            "data: \"result\":{\"tools\":[{\"name\":\"echo\",\"description\":\"Echoes — text\"}]}}\n\n",
+        );
+        Mock::given(method("GET"))
+            .and(path("/sse"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/event-stream")
+                    .set_body_string(stream),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/message"))
+            .and(query_param("sessionId", "abc"))
+            .respond_with(ResponseTemplate::new(202))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let tools = list_tools(&format!("{}/sse", server.uri())).await.unwrap();
+        assert_eq!(tools.len(), 1);
+        assert_eq!(tools[0].name, "echo");
+        assert_eq!(tools[0].description.as_deref(), Some("Echoes — text"));
+    }
+
+    #[tokio::test]
+    async fn test_reports_streams_without_endpoint() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(": nothing here\n\n"))
+            .mount(&server)
+            .await;
+
+        let error = list_tools(&format!("{}/sse", server.uri()))
+            .await
+            .unwrap_err();
+        assert!(
+            error.to_string().contains("closed its event stream"),
+            "{error}"
+        );
+    }
+}
@@ -1,10 +1,13 @@
 //! Infrastructure layer - concrete implementations of domain ports
 
 pub mod generation;
+pub mod mcp;
 pub mod openapi;
 pub mod output;
+#[cfg(feature = "shell-hooks")]
 pub mod shell;
 pub mod templates;
 
+#[cfg(feature = "shell-hooks")]
 pub use shell::*;
 pub use templates::*;
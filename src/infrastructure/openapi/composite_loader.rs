@@ -3,18 +3,21 @@
 use crate::generation::{GenerationError, OpenApiContext, OpenApiLoader};
 use async_trait::async_trait;
 
-/// Composite loader that tries multiple loaders in sequence
+/// Composite loader that picks a loader based on the source
+///
+/// URLs need the `http-loader` feature; without it they are rejected.
 pub struct CompositeOpenApiLoader {
-    loaders: Vec<Box<dyn OpenApiLoader>>,
+    #[cfg(feature = "http-loader")]
+    http_loader: super::HttpOpenApiLoader,
+    file_loader: super::FileOpenApiLoader,
 }
 
 impl CompositeOpenApiLoader {
     pub fn new() -> Self {
         Self {
-            loaders: vec![
-                Box::new(super::HttpOpenApiLoader::new()),
-                Box::new(super::FileOpenApiLoader::new()),
-            ],
+            #[cfg(feature = "http-loader")]
+            http_loader: super::HttpOpenApiLoader::new(),
+            file_loader: super::FileOpenApiLoader::new(),
         }
     }
 }
@@ -33,12 +36,19 @@ impl OpenApiLoader for CompositeOpenApiLoader {
         // Intelligently detect the source type and use the appropriate loader
         if source.starts_with("http://") || source.starts_with("https://") {
             // Use HTTP loader for URLs
-            tracing::debug!("CompositeOpenApiLoader: Using HTTP loader");
-            self.loaders[0].load(source).await
+            #[cfg(feature = "http-loader")]
+            {
+                tracing::debug!("CompositeOpenApiLoader: Using HTTP loader");
+                self.http_loader.load(source).await
+            }
+            #[cfg(not(feature = "http-loader"))]
+            Err(GenerationError::LoadError(format!(
+                "Cannot load {source}: loading specs from URLs requires the `http-loader` feature"
+            )))
         } else {
             // Use file loader for file paths
             tracing::debug!("CompositeOpenApiLoader: Using file loader");
-            self.loaders[1].load(source).await
+            self.file_loader.load(source).await
         }
     }
 }
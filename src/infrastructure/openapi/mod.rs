@@ -2,12 +2,14 @@
 
 pub mod composite_loader;
 pub mod file_loader;
+#[cfg(feature = "http-loader")]
 pub mod http_loader;
 pub mod parser;
 pub mod types;
 
 pub use composite_loader::CompositeOpenApiLoader;
 pub use file_loader::FileOpenApiLoader;
+#[cfg(feature = "http-loader")]
 pub use http_loader::HttpOpenApiLoader;
 pub use types::*;
 
@@ -107,6 +109,7 @@ paths: {}"#;
         assert!(result.is_ok());
     }
 
+    #[cfg(feature = "http-loader")]
     #[tokio::test]
     async fn test_composite_loader_http() {
         use wiremock::matchers::{method, path};
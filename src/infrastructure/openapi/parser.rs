@@ -9,11 +9,15 @@
 //! - Security definitions
 //! - Callbacks and vendor extensions
 
+use indexmap::IndexMap;
 use serde_json::Value as JsonValue;
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
 
 use crate::generation::{
-    ApiInfo, Components, GenerationError, OpenApiContext, Operation, Parameter, ParameterLocation,
-    RequestBody, Response, Schema, Server,
+    ApiInfo, Components, Diagnostics, GenerationError, OpenApiContext, Operation, Parameter,
+    ParameterLocation, RequestBody, Response, Schema, Server, Tag,
+    diagnostics::{codes, json_pointer, operation_pointer},
 };
 
 /// HTTP methods supported by OpenAPI (copied from core)
@@ -57,17 +61,74 @@ impl std::fmt::Display for HttpMethod {
     }
 }
 
+/// Memoized results for `$ref` targets, keyed by reference string
+///
+/// Large specs point thousands of operations at the same few components, so each
+/// target is resolved once. A reference met again while its own target is still
+/// being resolved is a cycle; `on_cycle` supplies the value to use there instead.
+struct RefCache<T> {
+    resolved: Mutex<HashMap<String, T>>,
+    in_progress: Mutex<HashSet<String>>,
+}
+
+impl<T: Clone> RefCache<T> {
+    fn new() -> Self {
+        Self {
+            resolved: Mutex::new(HashMap::new()),
+            in_progress: Mutex::new(HashSet::new()),
+        }
+    }
+
+    fn get_or_resolve(
+        &self,
+        ref_str: &str,
+        on_cycle: impl FnOnce() -> T,
+        resolve: impl FnOnce() -> Result<T, GenerationError>,
+    ) -> Result<T, GenerationError> {
+        if let Some(value) = lock(&self.resolved).get(ref_str) {
+            return Ok(value.clone());
+        }
+        if !lock(&self.in_progress).insert(ref_str.to_string()) {
+            return Ok(on_cycle());
+        }
+
+        let result = resolve();
+        lock(&self.in_progress).remove(ref_str);
+
+        let value = result?;
+        lock(&self.resolved).insert(ref_str.to_string(), value.clone());
+        Ok(value)
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
 /// OpenAPI specification parser with comprehensive parsing capabilities
 /// This is a complete port of the core::openapi::OpenApiContext implementation
 pub struct OpenApiParser {
     /// The raw JSON value of the OpenAPI spec
     pub json: JsonValue,
+    /// Problems found while parsing
+    diagnostics: Diagnostics,
+    /// Parsed schemas per `$ref`
+    schemas: RefCache<Schema>,
+    /// Fully resolved JSON per `$ref`
+    resolved_refs: RefCache<JsonValue>,
 }
 
 impl OpenApiParser {
     /// Create a new parser from JSON content
     pub fn new(json: JsonValue) -> Self {
-        Self { json }
+        Self {
+            json,
+            diagnostics: Diagnostics::new(),
+            schemas: RefCache::new(),
+            resolved_refs: RefCache::new(),
+        }
     }
 
     /// Parse the complete OpenAPI specification to our domain model
@@ -102,7 +163,7 @@ impl OpenApiParser {
         };
 
         // Extract servers
-        let servers = self
+        let servers: Vec<Server> = self
             .json
             .get("servers")
             .and_then(|v| v.as_array())
@@ -119,21 +180,51 @@ impl OpenApiParser {
                     })
                     .collect()
             })
-            .unwrap_or_default();
+            .unwrap_or_else(|| self.swagger_servers());
+        if servers.is_empty() {
+            self.diagnostics.warning(
+                codes::NO_SERVERS,
+                "/servers",
+                "The spec declares no servers; pass --base-url to set the backend API URL",
+            );
+        }
 
         // Parse operations using the comprehensive implementation
         let operations = self.parse_operations().await?;
         tracing::debug!("OpenAPI parser found {} operations", operations.len());
 
-        // Extract components if present
-        let components = self
+        // Extract components if present; Swagger 2.0 declares them at the top
+        // level as `definitions` and `securityDefinitions`
+        let (schemas, security_schemes) = match self.json.get("components") {
+            Some(comp) => (comp.get("schemas"), comp.get("securitySchemes")),
+            None => (
+                self.json.get("definitions"),
+                self.json.get("securityDefinitions"),
+            ),
+        };
+        let components = (schemas.is_some() || security_schemes.is_some()).then(|| Components {
+            schemas: schemas.cloned().unwrap_or_default(),
+            security_schemes: security_schemes.cloned().unwrap_or_default(),
+        });
+
+        let tags = self
             .json
-            .get("components")
-            .and_then(|comp| comp.as_object())
-            .and_then(|comp_obj| comp_obj.get("schemas"))
-            .map(|schemas| Components {
-                schemas: schemas.clone(),
-            });
+            .get("tags")
+            .and_then(|v| v.as_array())
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|t| {
+                        Some(Tag {
+                            name: t.get("name").and_then(|v| v.as_str())?.to_string(),
+                            description: t
+                                .get("description")
+                                .and_then(|v| v.as_str())
+                                .map(|s| s.to_string()),
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
 
         Ok(OpenApiContext {
             version,
@@ -141,6 +232,8 @@ impl OpenApiParser {
             servers,
             operations,
             components,
+            tags,
+            diagnostics: self.diagnostics.snapshot(),
         })
     }
 
@@ -149,6 +242,40 @@ impl OpenApiParser {
         self.json.get("info")?.get("title")?.as_str()
     }
 
+    /// Servers of a Swagger 2.0 spec, one per scheme of `host` and `basePath`
+    ///
+    /// Without `schemes` the server uses HTTPS. Without `host` the URL is just
+    /// the base path, relative to wherever the spec is served from.
+    fn swagger_servers(&self) -> Vec<Server> {
+        let host = self.json.get("host").and_then(JsonValue::as_str);
+        let base_path = self.json.get("basePath").and_then(JsonValue::as_str);
+        if host.is_none() && base_path.is_none() {
+            return Vec::new();
+        }
+        let base_path = base_path.unwrap_or_default().trim_end_matches('/');
+
+        let Some(host) = host else {
+            return vec![Server {
+                url: base_path.to_string(),
+                description: None,
+            }];
+        };
+        let schemes: Vec<&str> = self
+            .json
+            .get("schemes")
+            .and_then(JsonValue::as_array)
+            .map(|schemes| schemes.iter().filter_map(JsonValue::as_str).collect())
+            .filter(|schemes: &Vec<&str>| !schemes.is_empty())
+            .unwrap_or_else(|| vec!["https"]);
+        schemes
+            .into_iter()
+            .map(|scheme| Server {
+                url: format!("{scheme}://{host}{base_path}"),
+                description: None,
+            })
+            .collect()
+    }
+
     /// Get the version of the API
     pub fn version(&self) -> Option<&str> {
         self.json.get("info")?.get("version")?.as_str()
@@ -196,16 +323,23 @@ impl OpenApiParser {
         path_item: &JsonValue,
         method_item: &serde_json::Map<String, JsonValue>,
     ) -> Result<Operation, GenerationError> {
+        let pointer = operation_pointer(path, &method.to_string());
         let operation_id = method_item
             .get("operationId")
             .and_then(JsonValue::as_str)
             .map(String::from)
             .unwrap_or_else(|| {
-                format!(
+                let generated = format!(
                     "{}_{}",
                     method,
                     path.trim_start_matches('/').replace('/', "_")
-                )
+                );
+                self.diagnostics.info(
+                    codes::OPERATION_ID_GENERATED,
+                    &pointer,
+                    format!("Operation has no operationId; using '{generated}'"),
+                );
+                generated
             });
 
         let summary = method_item
@@ -219,9 +353,11 @@ impl OpenApiParser {
         let external_docs = method_item.get("externalDocs").cloned();
 
         // Extract typed parameters - merge path-level and method-level parameters
-        let mut parameters = self.extract_parameters(path_item).unwrap_or_default();
+        let mut parameters = self
+            .extract_parameters(path_item.get("parameters"), &json_pointer(["paths", path]))
+            .unwrap_or_default();
         let method_params = self
-            .extract_parameters(&JsonValue::Object(method_item.clone()))
+            .extract_parameters(method_item.get("parameters"), &pointer)
             .unwrap_or_default();
         parameters.extend(method_params);
 
@@ -274,25 +410,44 @@ impl OpenApiParser {
         })
     }
 
-    /// Extracts parameters from an OpenAPI path item, resolving any $ref references
+    /// Extracts the `parameters` array of a path item or operation, resolving any $ref references
     /// This is a complete port from core::openapi::OpenApiContext::extract_parameters
-    fn extract_parameters(&self, path_item: &JsonValue) -> Option<Vec<Parameter>> {
-        path_item
-            .get("parameters")
-            .and_then(JsonValue::as_array)
-            .map(|arr| {
-                arr.iter()
-                    .filter_map(|param| {
+    ///
+    /// Parameters that cannot be parsed are skipped and reported as diagnostics
+    /// against `pointer`, the location of the object holding the `parameters` array.
+    fn extract_parameters(
+        &self,
+        parameters: Option<&JsonValue>,
+        pointer: &str,
+    ) -> Option<Vec<Parameter>> {
+        parameters.and_then(JsonValue::as_array).map(|arr| {
+            arr.iter()
+                .enumerate()
+                .filter_map(|(index, param)| {
+                    let parsed =
                         if let Some(ref_str) = param.get("$ref").and_then(JsonValue::as_str) {
-                            self.json
-                                .pointer(&ref_str[1..])
-                                .and_then(|p| self.parse_parameter(p).ok())
+                            self.resolve_ref(ref_str)
+                                .and_then(|resolved| self.parse_parameter(resolved))
                         } else {
-                            self.parse_parameter(param).ok()
-                        }
-                    })
-                    .collect::<Vec<Parameter>>()
-            })
+                            self.parse_parameter(param)
+                        };
+
+                    parsed
+                        .map_err(|e| {
+                            let name = param
+                                .get("name")
+                                .and_then(JsonValue::as_str)
+                                .unwrap_or("<unnamed>");
+                            self.diagnostics.warning(
+                                codes::PARAMETER_DROPPED,
+                                format!("{pointer}/parameters/{index}"),
+                                format!("Parameter '{name}' was skipped: {e}"),
+                            );
+                        })
+                        .ok()
+                })
+                .collect::<Vec<Parameter>>()
+        })
     }
 
     /// Parse a single parameter
@@ -363,7 +518,7 @@ impl OpenApiParser {
         {
             self.resolve_ref(ref_str)?
         } else {
-            response.clone()
+            response
         };
 
         // Process content to resolve any $ref in schemas
@@ -409,7 +564,7 @@ impl OpenApiParser {
         let resolved_body = if let Some(ref_str) = body.get("$ref").and_then(|v| v.as_str()) {
             self.resolve_ref(ref_str)?
         } else {
-            body.clone()
+            body
         };
 
         // Process content to resolve any $ref in schemas
@@ -433,14 +588,13 @@ impl OpenApiParser {
     }
 
     /// Parse a schema object
-    #[allow(clippy::only_used_in_recursion)]
     fn parse_schema(&self, schema: &JsonValue) -> Result<Schema, GenerationError> {
-        // First check if this is a $ref
+        // First check if this is a $ref; each target is parsed once, and a
+        // recursive reference back into itself is left untyped
         if let Some(ref_str) = schema.get("$ref").and_then(|v| v.as_str()) {
-            // Resolve the reference
-            let resolved_schema = self.resolve_ref(ref_str)?;
-            // Parse the resolved schema
-            return self.parse_schema(&resolved_schema);
+            return self.schemas.get_or_resolve(ref_str, Schema::default, || {
+                self.parse_schema(self.resolve_ref(ref_str)?)
+            });
         }
 
         let schema_type = schema
@@ -461,7 +615,7 @@ impl OpenApiParser {
         // Parse properties recursively to resolve any nested schemas
         let properties = if let Some(props) = schema.get("properties") {
             if let Some(props_obj) = props.as_object() {
-                let mut parsed_props = std::collections::HashMap::new();
+                let mut parsed_props = IndexMap::new();
                 for (key, value) in props_obj {
                     let parsed_schema = self.parse_schema(value)?;
                     parsed_props.insert(key.clone(), parsed_schema);
@@ -661,9 +815,13 @@ impl OpenApiParser {
             JsonValue::Object(obj) => {
                 // Check if this object has a $ref
                 if let Some(ref_str) = obj.get("$ref").and_then(|v| v.as_str()) {
-                    // Resolve the reference and recursively resolve any nested refs
-                    let resolved = self.resolve_ref(ref_str)?;
-                    return self.resolve_schema_refs(&resolved);
+                    // Resolve the reference and recursively resolve any nested refs,
+                    // keeping a recursive reference back into itself as a `$ref`
+                    return self.resolved_refs.get_or_resolve(
+                        ref_str,
+                        || value.clone(),
+                        || self.resolve_schema_refs(self.resolve_ref(ref_str)?),
+                    );
                 }
 
                 // Otherwise, recursively process all fields
@@ -687,10 +845,10 @@ impl OpenApiParser {
     }
 
     /// Resolve a $ref reference
-    fn resolve_ref(&self, ref_str: &str) -> Result<JsonValue, GenerationError> {
+    fn resolve_ref(&self, ref_str: &str) -> Result<&JsonValue, GenerationError> {
         // Handle JSON pointer references (e.g., "#/components/schemas/Pet")
         if let Some(pointer) = ref_str.strip_prefix('#') {
-            self.json.pointer(pointer).cloned().ok_or_else(|| {
+            self.json.pointer(pointer).ok_or_else(|| {
                 GenerationError::ValidationError(format!("Unable to resolve reference: {ref_str}"))
             })
         } else {
@@ -705,7 +863,7 @@ impl OpenApiParser {
     fn extract_vendor_extensions(
         &self,
         method_item: &serde_json::Map<String, JsonValue>,
-    ) -> std::collections::HashMap<String, JsonValue> {
+    ) -> IndexMap<String, JsonValue> {
         method_item
             .iter()
             .filter(|(k, _)| k.starts_with("x-"))
@@ -717,6 +875,7 @@ impl OpenApiParser {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::generation::Severity;
     use serde_json::json;
 
     #[tokio::test]
@@ -843,6 +1002,238 @@ mod tests {
         assert_eq!(name_prop.get("type"), Some(&json!("string")));
     }
 
+    #[tokio::test]
+    async fn test_schema_properties_keep_spec_order() {
+        let spec_json: JsonValue = serde_json::from_str(
+            r#"{
+                "openapi": "3.0.0",
+                "info": { "title": "Order API", "version": "1.0.0" },
+                "paths": {
+                    "/search": {
+                        "get": {
+                            "operationId": "search",
+                            "parameters": [{
+                                "name": "filter",
+                                "in": "query",
+                                "schema": {
+                                    "type": "object",
+                                    "properties": {
+                                        "zeta": { "type": "string" },
+                                        "alpha": { "type": "integer" },
+                                        "mid": { "type": "boolean" }
+                                    }
+                                }
+                            }],
+                            "responses": { "200": { "description": "ok" } }
+                        }
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let spec = OpenApiParser::new(spec_json).parse().await.unwrap();
+        let properties = spec.operations[0].parameters[0]
+            .schema
+            .properties
+            .as_ref()
+            .unwrap();
+
+        let names: Vec<_> = properties.keys().map(String::as_str).collect();
+        assert_eq!(names, vec!["zeta", "alpha", "mid"]);
+    }
+
+    #[tokio::test]
+    async fn test_parse_reports_diagnostics() {
+        let spec_json = json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Lossy API", "version": "1.0.0" },
+            "paths": {
+                "/pets/{petId}": {
+                    "get": {
+                        "parameters": [
+                            { "name": "petId", "in": "path", "required": true, "schema": { "type": "string" } },
+                            { "name": "trace", "in": "body" }
+                        ],
+                        "responses": { "200": { "description": "ok" } }
+                    }
+                }
+            }
+        });
+
+        let spec = OpenApiParser::new(spec_json).parse().await.unwrap();
+        assert_eq!(spec.operations[0].parameters.len(), 1);
+
+        let found: Vec<_> = spec
+            .diagnostics
+            .iter()
+            .map(|d| (d.severity, d.code.as_str(), d.pointer.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (Severity::Warning, codes::NO_SERVERS, "/servers"),
+                (
+                    Severity::Info,
+                    codes::OPERATION_ID_GENERATED,
+                    "/paths/~1pets~1{petId}/get"
+                ),
+                (
+                    Severity::Warning,
+                    codes::PARAMETER_DROPPED,
+                    "/paths/~1pets~1{petId}/get/parameters/1"
+                ),
+            ]
+        );
+
+        // Swagger 2.0 declares its server with `host` and `basePath`
+        let spec_json = json!({
+            "swagger": "2.0",
+            "info": { "title": "Legacy API", "version": "1.0.0" },
+            "host": "api.example.com",
+            "basePath": "/v1",
+            "paths": {}
+        });
+        let spec = OpenApiParser::new(spec_json).parse().await.unwrap();
+        assert!(spec.diagnostics.iter().all(|d| d.code != codes::NO_SERVERS));
+    }
+
+    #[tokio::test]
+    async fn test_parse_swagger_servers() {
+        let spec = |extra: JsonValue| {
+            let mut spec_json = json!({
+                "swagger": "2.0",
+                "info": { "title": "Legacy API", "version": "1.0.0" },
+                "paths": {}
+            });
+            spec_json
+                .as_object_mut()
+                .unwrap()
+                .extend(extra.as_object().unwrap().clone());
+            OpenApiParser::new(spec_json)
+        };
+        let urls = |spec: &OpenApiContext| -> Vec<String> {
+            spec.servers.iter().map(|s| s.url.clone()).collect()
+        };
+
+        let parsed = spec(json!({
+            "host": "petstore.swagger.io",
+            "basePath": "/v2",
+            "schemes": ["https", "http"]
+        }))
+        .parse()
+        .await
+        .unwrap();
+        assert_eq!(
+            urls(&parsed),
+            vec![
+                "https://petstore.swagger.io/v2",
+                "http://petstore.swagger.io/v2"
+            ]
+        );
+
+        let parsed = spec(json!({ "host": "api.example.com" }))
+            .parse()
+            .await
+            .unwrap();
+        assert_eq!(urls(&parsed), vec!["https://api.example.com"]);
+
+        let parsed = spec(json!({ "basePath": "/api/" })).parse().await.unwrap();
+        assert_eq!(urls(&parsed), vec!["/api"]);
+
+        let parsed = spec(json!({})).parse().await.unwrap();
+        assert!(parsed.servers.is_empty());
+        assert_eq!(parsed.diagnostics[0].code, codes::NO_SERVERS);
+    }
+
+    #[tokio::test]
+    async fn test_parse_tags_and_security_schemes() {
+        let spec_json = json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Secure API", "version": "1.0.0" },
+            "tags": [
+                { "name": "pet", "description": "Everything about pets" },
+                { "name": "store" }
+            ],
+            "paths": {},
+            "components": {
+                "securitySchemes": {
+                    "bearer": { "type": "http", "scheme": "bearer" }
+                }
+            }
+        });
+
+        let spec = OpenApiParser::new(spec_json).parse().await.unwrap();
+        let tags: Vec<_> = spec
+            .tags
+            .iter()
+            .map(|t| (t.name.as_str(), t.description.as_deref()))
+            .collect();
+        assert_eq!(
+            tags,
+            vec![("pet", Some("Everything about pets")), ("store", None)]
+        );
+
+        let components = spec.components.unwrap();
+        assert!(components.schemas.is_null());
+        assert_eq!(components.security_schemes["bearer"]["scheme"], "bearer");
+    }
+
+    #[tokio::test]
+    async fn test_recursive_refs_terminate() {
+        let spec_json = json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Tree API", "version": "1.0.0" },
+            "paths": {
+                "/nodes": {
+                    "post": {
+                        "operationId": "createNode",
+                        "requestBody": {
+                            "content": {
+                                "application/json": { "schema": { "$ref": "#/components/schemas/Node" } }
+                            }
+                        },
+                        "responses": {
+                            "200": {
+                                "description": "ok",
+                                "content": {
+                                    "application/json": { "schema": { "$ref": "#/components/schemas/Node" } }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "Node": {
+                        "type": "object",
+                        "properties": {
+                            "name": { "type": "string" },
+                            "children": { "type": "array", "items": { "$ref": "#/components/schemas/Node" } }
+                        }
+                    }
+                }
+            }
+        });
+
+        let spec = OpenApiParser::new(spec_json).parse().await.unwrap();
+        let operation = &spec.operations[0];
+
+        // The nested reference back to Node stays a $ref instead of recursing forever
+        let schema =
+            &operation.responses[0].content.as_ref().unwrap()["application/json"]["schema"];
+        assert_eq!(schema["properties"]["name"]["type"], "string");
+        assert_eq!(
+            schema["properties"]["children"]["items"],
+            json!({ "$ref": "#/components/schemas/Node" })
+        );
+        assert_eq!(
+            operation.request_body.as_ref().unwrap().content["application/json"]["schema"],
+            *schema
+        );
+    }
+
     #[tokio::test]
     async fn test_petstore_parsing_parity() {
         // Load the petstore spec
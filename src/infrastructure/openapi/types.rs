@@ -1,6 +1,7 @@
 //! OpenAPI types matching the existing generation types
 //! This is a temporary compatibility layer during migration
 
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
 /// OpenAPI operation representation
@@ -38,7 +39,7 @@ pub struct Operation {
     pub servers: Option<Vec<serde_json::Value>>,
     /// Specification extensions (fields starting with `x-`)
     #[serde(flatten)]
-    pub vendor_extensions: std::collections::HashMap<String, serde_json::Value>,
+    pub vendor_extensions: IndexMap<String, serde_json::Value>,
 }
 
 /// Operation parameter
@@ -78,13 +79,13 @@ pub struct Response {
 }
 
 /// Schema representation
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Schema {
     #[serde(rename = "type")]
     pub schema_type: Option<String>,
     pub format: Option<String>,
     pub items: Option<Box<Schema>>,
-    pub properties: Option<std::collections::HashMap<String, Schema>>,
+    pub properties: Option<IndexMap<String, Schema>>,
     pub required: Option<Vec<String>>,
     // Additional OpenAPI schema fields
     pub description: Option<String>,
@@ -127,7 +128,7 @@ pub enum AdditionalProperties {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Discriminator {
     pub property_name: String,
-    pub mapping: Option<std::collections::HashMap<String, String>>,
+    pub mapping: Option<IndexMap<String, String>>,
 }
 
 /// External documentation
@@ -145,6 +146,12 @@ pub struct OpenApiContext {
     pub servers: Vec<Server>,
     pub operations: Vec<Operation>,
     pub components: Option<Components>,
+    /// Top-level tag declarations, in spec order
+    #[serde(default)]
+    pub tags: Vec<Tag>,
+    /// Problems found while parsing, handed on to the generation context
+    #[serde(skip)]
+    pub diagnostics: Vec<crate::generation::Diagnostic>,
 }
 
 /// API information
@@ -162,8 +169,17 @@ pub struct Server {
     pub description: Option<String>,
 }
 
+/// Tag declaration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tag {
+    pub name: String,
+    pub description: Option<String>,
+}
+
 /// Components section
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Components {
     pub schemas: serde_json::Value,
+    #[serde(default)]
+    pub security_schemes: serde_json::Value,
 }
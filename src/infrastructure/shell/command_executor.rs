@@ -4,8 +4,10 @@
 //! as part of the code generation post-processing pipeline.
 
 use async_trait::async_trait;
+use std::collections::BTreeMap;
 use std::path::Path;
 use std::process::Stdio;
+use std::time::Duration;
 use tokio::process::Command;
 
 use crate::generation::GenerationError;
@@ -18,9 +20,19 @@ pub trait CommandExecutor: Send + Sync {
         &self,
         command: &str,
         working_dir: &Path,
+        options: &CommandOptions,
     ) -> Result<CommandResult, GenerationError>;
 }
 
+/// Environment and limits for a single command
+#[derive(Debug, Clone, Default)]
+pub struct CommandOptions {
+    /// Variables added to the inherited environment
+    pub env: BTreeMap<String, String>,
+    /// Kill the command if it runs longer than this
+    pub timeout: Option<Duration>,
+}
+
 /// Result of command execution
 #[derive(Debug, Clone)]
 pub struct CommandResult {
@@ -57,6 +69,7 @@ impl CommandExecutor for ShellCommandExecutor {
         &self,
         command: &str,
         working_dir: &Path,
+        options: &CommandOptions,
     ) -> Result<CommandResult, GenerationError> {
         let shell = if cfg!(target_os = "windows") {
             "cmd"
@@ -70,19 +83,46 @@ impl CommandExecutor for ShellCommandExecutor {
             "-c"
         };
 
-        let output = Command::new(shell)
+        let mut command_builder = Command::new(shell);
+        command_builder
             .arg(shell_arg)
             .arg(command)
             .current_dir(working_dir)
+            .envs(&options.env)
+            .stdin(Stdio::null())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
-            .output()
-            .await
-            .map_err(|e| {
-                GenerationError::PostProcessingError(format!(
-                    "Failed to execute command '{command}': {e:?}"
-                ))
-            })?;
+            // Dropping the output future on timeout must not leave the command running
+            .kill_on_drop(true);
+        // Compound commands such as `npm install && npm run build` do their work
+        // in grandchildren of the shell; a group of their own lets a timeout
+        // kill all of them
+        #[cfg(unix)]
+        command_builder.process_group(0);
+
+        let spawn_error = |e: std::io::Error| {
+            GenerationError::PostProcessingError(format!(
+                "Failed to execute command '{command}': {e:?}"
+            ))
+        };
+        let child = command_builder.spawn().map_err(spawn_error)?;
+        let pid = child.id();
+        let output = child.wait_with_output();
+        tokio::pin!(output);
+
+        let output = match options.timeout {
+            Some(timeout) => match tokio::time::timeout(timeout, &mut output).await {
+                Ok(output) => output,
+                Err(_) => {
+                    kill_process_group(pid).await;
+                    return Err(GenerationError::PostProcessingError(format!(
+                        "Command '{command}' timed out after {timeout:?}"
+                    )));
+                }
+            },
+            None => output.await,
+        }
+        .map_err(spawn_error)?;
 
         Ok(CommandResult {
             exit_code: output.status.code().unwrap_or(-1),
@@ -92,10 +132,35 @@ impl CommandExecutor for ShellCommandExecutor {
     }
 }
 
+/// Kill every process in the group led by `pid`
+///
+/// The shell leads the group, so this reaches whatever it started, even
+/// after the shell itself has exited.
+#[cfg(unix)]
+async fn kill_process_group(pid: Option<u32>) {
+    let Some(pid) = pid else {
+        return;
+    };
+    let killed = Command::new("kill")
+        .args(["-KILL", "--", &format!("-{pid}")])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .await;
+    if let Err(e) = killed {
+        tracing::warn!("Failed to kill process group {pid}: {e}");
+    }
+}
+
+#[cfg(not(unix))]
+async fn kill_process_group(_pid: Option<u32>) {}
+
 /// Mock command executor for testing
 #[cfg(test)]
 pub struct MockCommandExecutor {
     pub results: std::collections::HashMap<String, CommandResult>,
+    /// Every executed command with its working directory and options
+    pub calls: std::sync::Mutex<Vec<(String, std::path::PathBuf, CommandOptions)>>,
 }
 
 #[cfg(test)]
@@ -103,6 +168,7 @@ impl MockCommandExecutor {
     pub fn new() -> Self {
         Self {
             results: std::collections::HashMap::new(),
+            calls: std::sync::Mutex::new(Vec::new()),
         }
     }
 
@@ -125,14 +191,27 @@ impl MockCommandExecutor {
     }
 }
 
+#[cfg(test)]
+impl Default for MockCommandExecutor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 #[async_trait]
 impl CommandExecutor for MockCommandExecutor {
     async fn execute(
         &self,
         command: &str,
-        _working_dir: &Path,
+        working_dir: &Path,
+        options: &CommandOptions,
     ) -> Result<CommandResult, GenerationError> {
+        self.calls.lock().unwrap().push((
+            command.to_string(),
+            working_dir.to_path_buf(),
+            options.clone(),
+        ));
         self.results.get(command).cloned().ok_or_else(|| {
             GenerationError::PostProcessingError(format!(
                 "Mock executor has no result for command: {command}"
@@ -152,7 +231,10 @@ mod tests {
         let dir = tempdir().unwrap();
 
         // Test a simple echo command
-        let result = executor.execute("echo hello", dir.path()).await.unwrap();
+        let result = executor
+            .execute("echo hello", dir.path(), &CommandOptions::default())
+            .await
+            .unwrap();
 
         assert!(result.is_success());
         assert_eq!(result.exit_code, 0);
@@ -166,12 +248,81 @@ mod tests {
         let dir = tempdir().unwrap();
 
         // Test a command that should fail
-        let result = executor.execute("exit 1", dir.path()).await.unwrap();
+        let result = executor
+            .execute("exit 1", dir.path(), &CommandOptions::default())
+            .await
+            .unwrap();
 
         assert!(!result.is_success());
         assert_eq!(result.exit_code, 1);
     }
 
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_shell_command_executor_env_and_working_dir() {
+        let executor = ShellCommandExecutor::new();
+        let dir = tempdir().unwrap();
+        let options = CommandOptions {
+            env: BTreeMap::from([("GREETING".to_string(), "hi".to_string())]),
+            timeout: None,
+        };
+
+        let result = executor
+            .execute("echo $GREETING; pwd", dir.path(), &options)
+            .await
+            .unwrap();
+
+        assert!(result.stdout.starts_with("hi\n"));
+        let cwd = std::path::PathBuf::from(result.stdout.lines().nth(1).unwrap());
+        assert_eq!(
+            cwd.canonicalize().unwrap(),
+            dir.path().canonicalize().unwrap()
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_shell_command_executor_timeout() {
+        let executor = ShellCommandExecutor::new();
+        let dir = tempdir().unwrap();
+        let options = CommandOptions {
+            timeout: Some(Duration::from_millis(200)),
+            ..Default::default()
+        };
+
+        let err = executor
+            .execute("sleep 5", dir.path(), &options)
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("timed out after 200ms"));
+
+        // Processes the shell started are killed along with it
+        let err = executor
+            .execute(
+                "true && sleep 5 & echo $! > pid; wait",
+                dir.path(),
+                &options,
+            )
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("timed out after 200ms"));
+
+        let pid = std::fs::read_to_string(dir.path().join("pid")).unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let state = std::process::Command::new("ps")
+            .args(["-o", "stat=", "-p", pid.trim()])
+            .output()
+            .unwrap();
+        let state = String::from_utf8_lossy(&state.stdout);
+        // Gone, or a zombie waiting to be reaped
+        assert!(
+            state.trim().is_empty() || state.trim().starts_with('Z'),
+            "process {} still running: {state}",
+            pid.trim()
+        );
+    }
+
     #[tokio::test]
     async fn test_mock_command_executor() {
         let executor = MockCommandExecutor::new()
@@ -180,11 +331,17 @@ mod tests {
 
         let dir = tempdir().unwrap();
 
-        let result1 = executor.execute("test1", dir.path()).await.unwrap();
+        let result1 = executor
+            .execute("test1", dir.path(), &CommandOptions::default())
+            .await
+            .unwrap();
         assert!(result1.is_success());
         assert_eq!(result1.stdout, "output1");
 
-        let result2 = executor.execute("test2", dir.path()).await.unwrap();
+        let result2 = executor
+            .execute("test2", dir.path(), &CommandOptions::default())
+            .await
+            .unwrap();
         assert!(!result2.is_success());
         assert_eq!(result2.stderr, "error");
     }
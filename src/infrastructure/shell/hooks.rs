@@ -0,0 +1,264 @@
+//! Hook runner that executes template hooks through a shell
+
+use async_trait::async_trait;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::{CommandExecutor, CommandOptions, CommandResult, ShellCommandExecutor};
+use crate::application::{ApplicationError, HookRunner};
+use crate::generation::{Diagnostic, GenerationError, Severity, diagnostics::codes};
+use crate::infrastructure::{HookStage, TemplateHook};
+
+/// Lines of hook output kept in a failure report
+const REPORTED_OUTPUT_LINES: usize = 20;
+
+/// Hook runner that executes each hook with a [`CommandExecutor`]
+pub struct ShellHookRunner {
+    executor: Arc<dyn CommandExecutor>,
+}
+
+impl ShellHookRunner {
+    pub fn new() -> Self {
+        Self::with_executor(Arc::new(ShellCommandExecutor::new()))
+    }
+
+    pub fn with_executor(executor: Arc<dyn CommandExecutor>) -> Self {
+        Self { executor }
+    }
+}
+
+impl Default for ShellHookRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl HookRunner for ShellHookRunner {
+    async fn run_hooks(
+        &self,
+        stage: HookStage,
+        hooks: &[TemplateHook],
+        working_dir: &Path,
+    ) -> Result<Vec<Diagnostic>, ApplicationError> {
+        let mut warnings = Vec::new();
+
+        for hook in hooks {
+            if !hook.runs_on_current_os() {
+                tracing::debug!(
+                    %stage,
+                    command = %hook.command,
+                    os = ?hook.os,
+                    "Skipping hook for another operating system"
+                );
+                continue;
+            }
+
+            tracing::info!(
+                %stage,
+                command = %hook.command,
+                working_dir = %working_dir.display(),
+                "Running template hook"
+            );
+
+            let options = CommandOptions {
+                env: hook.env.clone(),
+                timeout: Some(Duration::from_secs(hook.timeout_secs)),
+            };
+            let reason = match self
+                .executor
+                .execute(&hook.command, working_dir, &options)
+                .await
+            {
+                Ok(result) if result.is_success() => {
+                    if !result.stdout.trim().is_empty() {
+                        tracing::debug!(
+                            %stage,
+                            command = %hook.command,
+                            output = %result.stdout.trim(),
+                            "Template hook output"
+                        );
+                    }
+                    continue;
+                }
+                Ok(result) => failure_reason(&result),
+                Err(GenerationError::PostProcessingError(message)) => message,
+                Err(e) => e.to_string(),
+            };
+
+            if !hook.continue_on_error {
+                return Err(ApplicationError::HookFailed {
+                    stage,
+                    command: hook.command.clone(),
+                    reason,
+                });
+            }
+
+            tracing::warn!(%stage, command = %hook.command, %reason, "Template hook failed");
+            warnings.push(Diagnostic::new(
+                Severity::Warning,
+                codes::HOOK_FAILED,
+                "",
+                format!("{stage} hook '{}' failed: {reason}", hook.command),
+            ));
+        }
+
+        Ok(warnings)
+    }
+}
+
+/// Exit code and the tail of the output, stderr first
+fn failure_reason(result: &CommandResult) -> String {
+    let output = if result.stderr.trim().is_empty() {
+        result.stdout.trim()
+    } else {
+        result.stderr.trim()
+    };
+    let lines: Vec<_> = output.lines().collect();
+    let tail = lines[lines.len().saturating_sub(REPORTED_OUTPUT_LINES)..].join("\n");
+
+    if tail.is_empty() {
+        format!("exited with code {}", result.exit_code)
+    } else {
+        format!("exited with code {}:\n{tail}", result.exit_code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::MockCommandExecutor;
+    use std::collections::BTreeMap;
+    use std::path::PathBuf;
+
+    fn hook(command: &str) -> TemplateHook {
+        TemplateHook::new(command)
+    }
+
+    #[tokio::test]
+    async fn test_hooks_run_in_working_dir_with_options() {
+        let executor = Arc::new(
+            MockCommandExecutor::new()
+                .with_result("cargo fmt", 0, "", "")
+                .with_result("cargo check", 0, "", ""),
+        );
+        let runner = ShellHookRunner::with_executor(executor.clone());
+
+        let mut check = hook("cargo check");
+        check.timeout_secs = 60;
+        check.env = BTreeMap::from([("RUSTFLAGS".to_string(), "-Dwarnings".to_string())]);
+
+        let warnings = runner
+            .run_hooks(
+                HookStage::PostGenerate,
+                &[hook("cargo fmt"), check],
+                Path::new("/out/petstore"),
+            )
+            .await
+            .unwrap();
+        assert!(warnings.is_empty());
+
+        let calls = executor.calls.lock().unwrap();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].0, "cargo fmt");
+        assert_eq!(calls[0].1, PathBuf::from("/out/petstore"));
+        assert_eq!(calls[1].2.timeout, Some(Duration::from_secs(60)));
+        assert_eq!(calls[1].2.env["RUSTFLAGS"], "-Dwarnings");
+    }
+
+    #[tokio::test]
+    async fn test_required_hook_failure_stops_the_run() {
+        let executor = Arc::new(
+            MockCommandExecutor::new()
+                .with_result("npm install", 1, "", "npm ERR! missing package.json")
+                .with_result("npm test", 0, "", ""),
+        );
+        let runner = ShellHookRunner::with_executor(executor.clone());
+
+        let err = runner
+            .run_hooks(
+                HookStage::PreGenerate,
+                &[hook("npm install"), hook("npm test")],
+                Path::new("."),
+            )
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "pre_generate hook 'npm install' failed: exited with code 1:\nnpm ERR! missing package.json"
+        );
+        assert_eq!(executor.calls.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_optional_hook_failure_is_a_warning() {
+        let executor = Arc::new(
+            MockCommandExecutor::new()
+                .with_result("cargo check", 101, "", "error[E0432]: unresolved import")
+                .with_result("cargo fmt", 0, "", ""),
+        );
+        let runner = ShellHookRunner::with_executor(executor.clone());
+
+        let mut check = hook("cargo check");
+        check.continue_on_error = true;
+
+        let warnings = runner
+            .run_hooks(
+                HookStage::PostGenerate,
+                &[check, hook("cargo fmt")],
+                Path::new("."),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, codes::HOOK_FAILED);
+        assert!(warnings[0].message.contains("exited with code 101"));
+        assert_eq!(executor.calls.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_hooks_for_other_os_are_skipped() {
+        let executor = Arc::new(MockCommandExecutor::new());
+        let runner = ShellHookRunner::with_executor(executor.clone());
+
+        let mut other = hook("never-run");
+        other.os = vec!["plan9".to_string()];
+
+        runner
+            .run_hooks(HookStage::PostGenerate, &[other], Path::new("."))
+            .await
+            .unwrap();
+        assert!(executor.calls.lock().unwrap().is_empty());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_hook_timeout_fails_the_run() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut slow = hook("sleep 5");
+        slow.timeout_secs = 1;
+
+        let err = ShellHookRunner::new()
+            .run_hooks(HookStage::PostGenerate, &[slow], dir.path())
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("timed out after 1s"), "{err}");
+    }
+
+    #[test]
+    fn test_failure_reason_keeps_output_tail() {
+        let stdout: Vec<_> = (1..=30).map(|n| format!("line {n}")).collect();
+        let reason = failure_reason(&CommandResult {
+            exit_code: 2,
+            stdout: stdout.join("\n"),
+            stderr: String::new(),
+        });
+
+        assert!(reason.starts_with("exited with code 2:\nline 11\n"));
+        assert!(reason.ends_with("line 30"));
+    }
+}
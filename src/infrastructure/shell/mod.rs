@@ -1,5 +1,7 @@
 //! Shell command execution infrastructure
 
 pub mod command_executor;
+pub mod hooks;
 
 pub use command_executor::*;
+pub use hooks::*;
@@ -4,8 +4,9 @@ use crate::generation::Language;
 use crate::protocols::types::{Protocol, Role};
 
 use super::{
-    RawTemplateFile, Template, TemplateDiscovery, TemplateError, TemplateExporter, TemplateFile,
-    TemplateFileType, TemplateManifest, TemplateRepository, TemplateSource,
+    MAX_INHERITANCE_DEPTH, RawTemplateFile, Template, TemplateDiscovery, TemplateError,
+    TemplateExporter, TemplateFile, TemplateFileType, TemplateManifest, TemplateRepository,
+    TemplateSource,
 };
 use async_trait::async_trait;
 use rust_embed::RustEmbed;
@@ -51,11 +52,11 @@ impl TemplateRepository for EmbeddedTemplateRepository {
     }
 
     fn get_manifest(&self, manifest_path: &str) -> Result<Option<TemplateManifest>, TemplateError> {
-        let path = if manifest_path.ends_with("/manifest.yml") {
-            manifest_path.to_string()
-        } else {
-            format!("{manifest_path}/manifest.yml")
-        };
+        // The manifest path is the template directory, with or without the file name
+        let template_path = manifest_path
+            .strip_suffix("/manifest.yml")
+            .unwrap_or(manifest_path);
+        let path = format!("{template_path}/manifest.yml");
 
         // Load manifest content
         let file = match EmbeddedTemplates::get(&path) {
@@ -65,10 +66,10 @@ impl TemplateRepository for EmbeddedTemplateRepository {
 
         let content = match std::str::from_utf8(file.data.as_ref()) {
             Ok(content) => content,
-            Err(e) => return Err(TemplateError::manifest_parse_error(manifest_path, e)),
+            Err(e) => return Err(TemplateError::manifest_parse_error(template_path, e)),
         };
 
-        let manifest = TemplateManifest::from_yaml(content, manifest_path)?;
+        let manifest = TemplateManifest::from_yaml(content, template_path)?;
 
         Ok(Some(manifest))
     }
@@ -107,24 +108,47 @@ impl TemplateRepository for EmbeddedTemplateRepository {
     }
 }
 
-#[async_trait]
-impl TemplateDiscovery for EmbeddedTemplateRepository {
-    async fn discover(
+impl EmbeddedTemplateRepository {
+    /// Load the embedded template at `template_path`, resolving `extends`
+    pub fn load(&self, template_path: &str) -> Result<Template, TemplateError> {
+        self.load_at_depth(template_path, 0)
+    }
+
+    /// Manifests of every embedded variant for `protocol` and `role`
+    pub fn variants(&self, protocol: Protocol, role: &Role) -> Vec<TemplateManifest> {
+        self.list_manifests()
+            .into_iter()
+            .filter(|manifest| manifest.protocol == protocol && &manifest.role == role)
+            .collect()
+    }
+
+    /// Find the variant of `protocol` and `role` named `name`, either its
+    /// directory (`rust-minimal`) or its manifest name (`mcp-rust-minimal-server`)
+    pub fn find_variant(
         &self,
         protocol: Protocol,
-        role: Role,
-        language: Language,
-    ) -> Result<Template, TemplateError> {
-        // Build the template path from attributes
-        let template_path = format!("{protocol}/{role}/{language}");
+        role: &Role,
+        name: &str,
+    ) -> Option<TemplateManifest> {
+        self.variants(protocol, role)
+            .into_iter()
+            .find(|manifest| manifest.variant() == name || manifest.name == name)
+    }
+
+    fn load_at_depth(&self, template_path: &str, depth: usize) -> Result<Template, TemplateError> {
+        if depth > MAX_INHERITANCE_DEPTH {
+            return Err(TemplateError::InvalidManifest(format!(
+                "Template '{template_path}' exceeds {MAX_INHERITANCE_DEPTH} levels of 'extends'; is there a cycle?"
+            )));
+        }
 
         // Check if template exists
-        if !self.has_template(&template_path) {
-            return Err(TemplateError::not_found(&template_path));
+        if !self.has_template(template_path) {
+            return Err(TemplateError::not_found(template_path));
         }
 
         // Get template files
-        let template_files = self.get_template_files(&template_path);
+        let template_files = self.get_template_files(template_path);
 
         // Find and parse the manifest file
         let manifest_file = template_files
@@ -135,7 +159,7 @@ impl TemplateDiscovery for EmbeddedTemplateRepository {
             Some(file) => {
                 // Parse the actual manifest YAML
                 let content = String::from_utf8_lossy(&file.contents);
-                TemplateManifest::from_yaml(&content, &template_path)?
+                TemplateManifest::from_yaml(&content, template_path)?
             }
             None => {
                 return Err(TemplateError::InvalidManifest(format!(
@@ -161,7 +185,9 @@ impl TemplateDiscovery for EmbeddedTemplateRepository {
                     Some(mf) => mf.file_type.clone(),
                     None => {
                         // Fallback logic for files not in manifest
-                        if file.relative_path.ends_with(".tera") {
+                        if manifest.is_partial(&file.relative_path) {
+                            TemplateFileType::Partial
+                        } else if file.relative_path.ends_with(".tera") {
                             TemplateFileType::Template { for_each: None }
                         } else {
                             TemplateFileType::Static
@@ -177,12 +203,24 @@ impl TemplateDiscovery for EmbeddedTemplateRepository {
             })
             .collect();
 
-        let template = Template {
+        let mut template = Template {
             manifest,
             files,
             source: TemplateSource::Embedded,
         };
 
+        if let Some(parent) = template.manifest.extends.clone() {
+            if parent.is_filesystem() {
+                return Err(TemplateError::InvalidManifest(format!(
+                    "Embedded template '{template_path}' cannot extend filesystem template '{}'",
+                    parent.path
+                )));
+            }
+            let base = self.load_at_depth(&parent.path, depth + 1)?;
+            parent.check_version(&base.manifest)?;
+            template = template.inherit(base);
+        }
+
         debug!(
             template_path = %template_path,
             source = %template.source,
@@ -194,6 +232,32 @@ impl TemplateDiscovery for EmbeddedTemplateRepository {
     }
 }
 
+#[async_trait]
+impl TemplateDiscovery for EmbeddedTemplateRepository {
+    async fn discover(
+        &self,
+        protocol: Protocol,
+        role: Role,
+        language: Language,
+    ) -> Result<Template, TemplateError> {
+        self.load(&format!("{protocol}/{role}/{language}"))
+    }
+
+    async fn discover_named(
+        &self,
+        protocol: Protocol,
+        role: Role,
+        name: &str,
+    ) -> Result<Template, TemplateError> {
+        match self.find_variant(protocol, &role, name) {
+            Some(manifest) => self.load(&manifest.path),
+            None => Err(TemplateError::not_found(format!(
+                "{protocol}/{role}/{name}"
+            ))),
+        }
+    }
+}
+
 /// Template exporter for embedded templates
 pub struct EmbeddedTemplateExporter {
     repository: EmbeddedTemplateRepository,
@@ -283,8 +347,55 @@ mod tests {
         let repo = EmbeddedTemplateRepository;
         let manifests = repo.list_manifests();
 
-        // We only support MCP Client and Server at this time.
-        assert!(manifests.len() == 2);
+        let paths: Vec<_> = manifests.iter().map(|m| m.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "a2a/agent/rust",
+                "a2a/bridge/rust",
+                "acp/client/rust",
+                "acp/server/rust",
+                "anp/agent/rust",
+                "mcp/client/python",
+                "mcp/client/rust",
+                "mcp/client/typescript",
+                "mcp/server/csharp",
+                "mcp/server/go",
+                "mcp/server/python",
+                "mcp/server/rust",
+                "mcp/server/rust-minimal",
+                "mcp/server/typescript"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_variant() {
+        let repo = EmbeddedTemplateRepository;
+
+        let minimal = repo
+            .find_variant(Protocol::Mcp, &Role::Server, "rust-minimal")
+            .unwrap();
+        assert_eq!(minimal.name, "mcp-rust-minimal-server");
+        assert_eq!(minimal.language, Language::Rust);
+
+        let by_name = repo
+            .find_variant(Protocol::Mcp, &Role::Server, "mcp-rust-minimal-server")
+            .unwrap();
+        assert_eq!(by_name.path, "mcp/server/rust-minimal");
+
+        // Variants belong to a protocol and role
+        assert!(
+            repo.find_variant(Protocol::Mcp, &Role::Client, "rust-minimal")
+                .is_none()
+        );
+        assert_eq!(repo.variants(Protocol::Mcp, &Role::Server).len(), 6);
+        assert_eq!(repo.variants(Protocol::Mcp, &Role::Client).len(), 3);
+        assert_eq!(repo.variants(Protocol::A2a, &Role::Agent).len(), 1);
+        assert_eq!(repo.variants(Protocol::A2a, &Role::Bridge).len(), 1);
+        assert_eq!(repo.variants(Protocol::Acp, &Role::Server).len(), 1);
+        assert_eq!(repo.variants(Protocol::Acp, &Role::Client).len(), 1);
+        assert_eq!(repo.variants(Protocol::Anp, &Role::Agent).len(), 1);
     }
 
     #[test]
@@ -20,6 +20,14 @@ pub enum TemplateError {
     /// YAML parsing error
     #[error("YAML parsing error: {0}")]
     YamlError(#[from] serde_yaml::Error),
+
+    /// Supplied variables do not satisfy the manifest variable schema
+    #[error("Invalid template variables: {0}")]
+    InvalidVariables(String),
+
+    /// An archive or git template source could not be fetched
+    #[error("Failed to fetch template: {0}")]
+    FetchError(String),
 }
 
 impl TemplateError {
@@ -8,9 +8,14 @@ use std::path::{Path, PathBuf};
 use tokio::fs;
 
 use crate::infrastructure::{
-    Template, TemplateError, TemplateFile, TemplateLoader, TemplateManifest, TemplateSource,
+    EmbeddedTemplateRepository, MAX_INHERITANCE_DEPTH, Template, TemplateError, TemplateFile,
+    TemplateFileType, TemplateLoader, TemplateManifest, TemplateSource,
 };
 
+/// Directories [`list_files`] never descends into: VCS metadata and the
+/// build and dependency output a template checkout may carry
+const SKIPPED_DIRS: &[&str] = &[".git", ".hg", ".svn", "target", "node_modules"];
+
 /// Template loader that loads a single template bundle from filesystem
 pub struct FileSystemTemplateLoader;
 
@@ -26,9 +31,15 @@ impl Default for FileSystemTemplateLoader {
     }
 }
 
-#[async_trait]
-impl TemplateLoader for FileSystemTemplateLoader {
-    async fn load_template(&self, path: &Path) -> Result<Template, TemplateError> {
+impl FileSystemTemplateLoader {
+    async fn load_at_depth(&self, path: &Path, depth: usize) -> Result<Template, TemplateError> {
+        if depth > MAX_INHERITANCE_DEPTH {
+            return Err(TemplateError::InvalidManifest(format!(
+                "Template '{}' exceeds {MAX_INHERITANCE_DEPTH} levels of 'extends'; is there a cycle?",
+                path.display()
+            )));
+        }
+
         // Verify the directory exists
         if !path.exists() {
             return Err(TemplateError::not_found(path.to_string_lossy().as_ref()));
@@ -38,13 +49,45 @@ impl TemplateLoader for FileSystemTemplateLoader {
         let manifest = load_manifest_from_dir(path).await?;
 
         // Load all template files
-        let files = load_template_files(path, &manifest).await?;
-
-        let template = Template {
-            manifest,
-            files,
-            source: TemplateSource::FileSystem(path.to_path_buf()),
+        let mut files = load_template_files(path, &manifest).await?;
+
+        let mut template = match manifest.extends.clone() {
+            Some(parent) => {
+                let base = if parent.is_filesystem() {
+                    Box::pin(self.load_at_depth(&parent.resolve_dir(path), depth + 1)).await?
+                } else {
+                    EmbeddedTemplateRepository::new().load(&parent.path)?
+                };
+                parent.check_version(&base.manifest)?;
+
+                // Files that shadow a parent file replace it without being listed
+                for base_file in &base.files {
+                    let shadow = path.join(&base_file.path);
+                    if files.iter().all(|f| f.path != base_file.path) && shadow.is_file() {
+                        files.push(TemplateFile {
+                            path: base_file.path.clone(),
+                            content: fs::read_to_string(&shadow)
+                                .await
+                                .map_err(TemplateError::IoError)?,
+                            file_type: base_file.file_type.clone(),
+                        });
+                    }
+                }
+
+                Template {
+                    manifest,
+                    files,
+                    source: TemplateSource::FileSystem(path.to_path_buf()),
+                }
+                .inherit(base)
+            }
+            None => Template {
+                manifest,
+                files,
+                source: TemplateSource::FileSystem(path.to_path_buf()),
+            },
         };
+        template.source = TemplateSource::FileSystem(path.to_path_buf());
 
         tracing::debug!(
             template_path = %path.display(),
@@ -57,6 +100,13 @@ impl TemplateLoader for FileSystemTemplateLoader {
     }
 }
 
+#[async_trait]
+impl TemplateLoader for FileSystemTemplateLoader {
+    async fn load_template(&self, path: &Path) -> Result<Template, TemplateError> {
+        self.load_at_depth(path, 0).await
+    }
+}
+
 /// Load a template manifest from a directory
 async fn load_manifest_from_dir(dir: &Path) -> Result<TemplateManifest, TemplateError> {
     let manifest_path = dir.join("manifest.yml");
@@ -92,6 +142,12 @@ async fn load_template_files(
     for manifest_file in &manifest.files {
         let file_path = dir.join(&manifest_file.source);
 
+        // An entry that only changes the destination of a parent file has no
+        // source of its own; the parent's content is used
+        if manifest.extends.is_some() && !file_path.exists() {
+            continue;
+        }
+
         let content = fs::read_to_string(&file_path).await.map_err(|e| {
             tracing::error!(
                 "Failed to read template file '{}': {}",
@@ -111,6 +167,71 @@ async fn load_template_files(
         });
     }
 
+    for source in find_partials(dir, manifest).await? {
+        let content = fs::read_to_string(dir.join(&source))
+            .await
+            .map_err(TemplateError::IoError)?;
+        files.push(TemplateFile {
+            path: PathBuf::from(source),
+            content,
+            file_type: TemplateFileType::Partial,
+        });
+    }
+
+    Ok(files)
+}
+
+/// Find the partials under `dir`, as `/`-separated relative paths in sorted order
+async fn find_partials(
+    dir: &Path,
+    manifest: &TemplateManifest,
+) -> Result<Vec<String>, TemplateError> {
+    Ok(list_files(dir)
+        .await?
+        .into_iter()
+        .filter(|source| manifest.is_partial(source))
+        .collect())
+}
+
+/// List every file under `dir` as `/`-separated relative paths in sorted order
+///
+/// Skips the contents of [`SKIPPED_DIRS`].
+pub async fn list_files(dir: &Path) -> Result<Vec<String>, TemplateError> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+
+    while let Some(current) = pending.pop() {
+        let mut entries = fs::read_dir(&current)
+            .await
+            .map_err(TemplateError::IoError)?;
+        while let Some(entry) = entries.next_entry().await.map_err(TemplateError::IoError)? {
+            let path = entry.path();
+            if entry
+                .file_type()
+                .await
+                .map_err(TemplateError::IoError)?
+                .is_dir()
+            {
+                if !SKIPPED_DIRS.contains(&entry.file_name().to_string_lossy().as_ref()) {
+                    pending.push(path);
+                }
+                continue;
+            }
+
+            let Ok(relative) = path.strip_prefix(dir) else {
+                continue;
+            };
+            files.push(
+                relative
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/"),
+            );
+        }
+    }
+
+    files.sort();
     Ok(files)
 }
 
@@ -119,7 +240,7 @@ async fn load_template_files(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::infrastructure::TemplateFileType;
+    use crate::infrastructure::TemplateHook;
     use tempfile::TempDir;
     use tokio::fs;
 
@@ -195,6 +316,11 @@ hooks:
         assert_eq!(template.manifest.name, "test-template");
         assert_eq!(template.manifest.version, "1.0.0");
         assert_eq!(template.files.len(), 3);
+        assert_eq!(
+            template.manifest.pre_generate_hooks,
+            vec![TemplateHook::new("echo 'Starting generation'")]
+        );
+        assert_eq!(template.manifest.post_generate_hooks.len(), 2);
 
         // Check file types
         let main_file = &template.files[0];
@@ -210,6 +336,48 @@ hooks:
         ));
     }
 
+    #[tokio::test]
+    async fn test_loads_partials() {
+        let temp_dir = TempDir::new().unwrap();
+        create_test_template(temp_dir.path()).await;
+        fs::create_dir_all(temp_dir.path().join("_partials/nested"))
+            .await
+            .unwrap();
+        fs::write(temp_dir.path().join("_partials/struct.tera"), "struct")
+            .await
+            .unwrap();
+        fs::write(temp_dir.path().join("_partials/nested/field.tera"), "field")
+            .await
+            .unwrap();
+        fs::write(temp_dir.path().join("unused.tera"), "not a partial")
+            .await
+            .unwrap();
+        for skipped in [".git/_hooks", "node_modules/_pkg", "target/_debug"] {
+            fs::create_dir_all(temp_dir.path().join(skipped))
+                .await
+                .unwrap();
+            fs::write(temp_dir.path().join(skipped).join("skipped.tera"), "")
+                .await
+                .unwrap();
+        }
+
+        let template = FileSystemTemplateLoader::new()
+            .load_template(temp_dir.path())
+            .await
+            .unwrap();
+
+        let partials: Vec<_> = template
+            .files
+            .iter()
+            .filter(|f| f.file_type == TemplateFileType::Partial)
+            .map(|f| f.path.to_string_lossy().to_string())
+            .collect();
+        assert_eq!(
+            partials,
+            vec!["_partials/nested/field.tera", "_partials/struct.tera"]
+        );
+    }
+
     #[tokio::test]
     async fn test_discover_at_missing_directory() {
         let loader = FileSystemTemplateLoader::new();
@@ -241,6 +409,93 @@ hooks:
         }
     }
 
+    #[tokio::test]
+    async fn test_extends_filesystem_parent() {
+        let temp_dir = TempDir::new().unwrap();
+        let base = temp_dir.path().join("base");
+        let child = temp_dir.path().join("child");
+        fs::create_dir_all(&base).await.unwrap();
+        fs::create_dir_all(&child).await.unwrap();
+        create_test_template(&base).await;
+
+        fs::write(
+            child.join("manifest.yml"),
+            r#"
+name: "child-template"
+version: "2.0.0"
+protocol: mcp
+role: server
+language: rust
+extends: "../base@1.0"
+files:
+  - source: "lib.rs.tera"
+    destination: "src/ops.rs"
+remove:
+  files: ["Cargo.toml"]
+  hooks: ["cargo check"]
+"#,
+        )
+        .await
+        .unwrap();
+        fs::write(child.join("main.rs.tera"), "fn main() {}")
+            .await
+            .unwrap();
+
+        let template = FileSystemTemplateLoader::new()
+            .load_template(&child)
+            .await
+            .unwrap();
+
+        assert_eq!(template.manifest.name, "child-template");
+        assert_eq!(template.source, TemplateSource::FileSystem(child.clone()));
+        assert_eq!(
+            template.manifest.post_generate_hooks,
+            vec![TemplateHook::new("cargo fmt")]
+        );
+
+        let targets: Vec<_> = template
+            .manifest
+            .files
+            .iter()
+            .map(|f| f.target.as_str())
+            .collect();
+        assert_eq!(targets, vec!["src/main.rs", "src/ops.rs"]);
+
+        // Shadowed content comes from the child, the rest from the parent
+        let content = |path: &str| {
+            template
+                .files
+                .iter()
+                .find(|f| f.path == Path::new(path))
+                .map(|f| f.content.clone())
+        };
+        assert_eq!(content("main.rs.tera").as_deref(), Some("fn main() {}"));
+        assert!(content("lib.rs.tera").unwrap().contains("operation.name"));
+        assert_eq!(content("Cargo.toml.tera"), None);
+        // The child's entry replaces the parent's, `for_each` included
+        assert!(matches!(
+            template.manifest.files[1].file_type,
+            TemplateFileType::Template { for_each: None }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_extends_cycle_is_rejected() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("manifest.yml"),
+            "name: loop\nversion: \"1.0.0\"\nprotocol: mcp\nrole: server\nlanguage: rust\nextends: ./\n",
+        )
+        .await
+        .unwrap();
+
+        let err = FileSystemTemplateLoader::new()
+            .load_template(temp_dir.path())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("levels of 'extends'"));
+    }
+
     #[tokio::test]
     async fn test_manifest_with_variables() {
         let temp_dir = TempDir::new().unwrap();
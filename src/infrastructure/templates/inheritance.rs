@@ -0,0 +1,406 @@
+//! Template inheritance
+//!
+//! A manifest with `extends: mcp/server/rust@0.1` starts from the files,
+//! variables and hooks of its parent and layers its own entries on top.
+//! Entries are matched by file source or destination, variable name and hook
+//! command; anything listed under `remove:` is dropped from the parent first.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use super::{Template, TemplateError, TemplateManifest};
+
+/// Longest chain of `extends` followed before giving up, which also stops cycles
+pub const MAX_INHERITANCE_DEPTH: usize = 8;
+
+/// Parent declared by `extends:`, plus the parent entries the child removes
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TemplateParent {
+    /// Embedded template path such as `mcp/server/rust`, or a filesystem path
+    /// starting with `./`, `../` or `/`, resolved against the child directory
+    pub path: String,
+    /// Version prefix the parent must match, from the `@0.1` suffix
+    pub version: Option<String>,
+    pub remove: TemplateRemovals,
+}
+
+/// Parent entries dropped by the child's `remove:` section
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TemplateRemovals {
+    /// File sources or destinations
+    pub files: Vec<String>,
+    pub variables: Vec<String>,
+    pub hooks: Vec<String>,
+}
+
+impl TemplateParent {
+    /// Parse an `extends` reference of the form `path[@version]`
+    pub fn parse(reference: &str) -> Result<Self, String> {
+        let (path, version) = match reference.rsplit_once('@') {
+            Some((path, version)) => (path, Some(version)),
+            None => (reference, None),
+        };
+        let path = path.trim_end_matches('/');
+        if path.is_empty() {
+            return Err(format!("'extends' reference '{reference}' has no path"));
+        }
+        if version.is_some_and(|v| v.is_empty()) {
+            return Err(format!(
+                "'extends' reference '{reference}' has an empty version"
+            ));
+        }
+
+        Ok(Self {
+            path: path.to_string(),
+            version: version.map(str::to_string),
+            remove: TemplateRemovals::default(),
+        })
+    }
+
+    /// Whether the parent lives on the filesystem rather than in the binary
+    pub fn is_filesystem(&self) -> bool {
+        matches!(self.path.as_str(), "." | "..")
+            || self.path.starts_with("./")
+            || self.path.starts_with("../")
+            || Path::new(&self.path).is_absolute()
+    }
+
+    /// Resolve a filesystem parent against the directory of the child template
+    pub fn resolve_dir(&self, child_dir: &Path) -> PathBuf {
+        child_dir.join(&self.path)
+    }
+
+    /// Check the resolved parent against the requested version
+    pub fn check_version(&self, parent: &TemplateManifest) -> Result<(), TemplateError> {
+        match &self.version {
+            Some(version) if !version_matches(version, &parent.version) => {
+                Err(TemplateError::InvalidManifest(format!(
+                    "template '{}' extends '{}@{version}', but found version {}",
+                    parent.path, self.path, parent.version
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Whether `version` starts with every component of `requested`
+///
+/// `0.1` accepts `0.1.0` and `0.1.7` but not `0.10.0`.
+fn version_matches(requested: &str, version: &str) -> bool {
+    let requested = requested.trim_start_matches(['^', '=', 'v']);
+    let mut actual = version.trim_start_matches('v').split('.');
+    requested
+        .split('.')
+        .all(|component| actual.next() == Some(component))
+}
+
+impl Template {
+    /// Layer this template over its resolved parent
+    ///
+    /// The result keeps the child's name, version, path and source. Parent
+    /// files whose manifest entry was removed or replaced by an entry with a
+    /// different source are dropped, and child files override parent files
+    /// with the same path.
+    pub fn inherit(self, parent: Template) -> Template {
+        let Template {
+            manifest: child,
+            files: child_files,
+            source,
+        } = self;
+        let remove = child
+            .extends
+            .as_ref()
+            .map(|parent| parent.remove.clone())
+            .unwrap_or_default();
+
+        let mut manifest = parent.manifest;
+        let mut dropped = HashSet::new();
+
+        manifest.files.retain(|file| {
+            let removed = remove
+                .files
+                .iter()
+                .any(|r| *r == file.source || *r == file.target);
+            if removed {
+                dropped.insert(file.source.clone());
+            }
+            !removed
+        });
+        for file in child.files {
+            match manifest
+                .files
+                .iter_mut()
+                .find(|f| f.source == file.source || f.target == file.target)
+            {
+                Some(existing) => {
+                    if existing.source != file.source {
+                        dropped.insert(existing.source.clone());
+                    }
+                    *existing = file;
+                }
+                None => manifest.files.push(file),
+            }
+        }
+
+        manifest
+            .variable_schema
+            .retain(|var| !remove.variables.contains(&var.name));
+        for var in child.variable_schema {
+            match manifest
+                .variable_schema
+                .iter_mut()
+                .find(|v| v.name == var.name)
+            {
+                Some(existing) => *existing = var,
+                None => manifest.variable_schema.push(var),
+            }
+        }
+        manifest.variables = manifest
+            .variable_schema
+            .iter()
+            .filter_map(|var| var.default.clone().map(|value| (var.name.clone(), value)))
+            .collect();
+
+        for (hooks, child_hooks) in [
+            (&mut manifest.pre_generate_hooks, child.pre_generate_hooks),
+            (&mut manifest.post_generate_hooks, child.post_generate_hooks),
+        ] {
+            hooks.retain(|hook| !remove.hooks.contains(&hook.command));
+            for hook in child_hooks {
+                // A child hook replaces the base hook running the same command
+                match hooks.iter_mut().find(|h| h.command == hook.command) {
+                    Some(existing) => *existing = hook,
+                    None => hooks.push(hook),
+                }
+            }
+        }
+
+        for partial in child.partials {
+            if !manifest.partials.contains(&partial) {
+                manifest.partials.push(partial);
+            }
+        }
+        for dir in child.required_directories {
+            if !manifest.required_directories.contains(&dir) {
+                manifest.required_directories.push(dir);
+            }
+        }
+
+        manifest.name = child.name;
+        manifest.version = child.version;
+        manifest.description = child.description.or(manifest.description);
+        manifest.path = child.path;
+        manifest.protocol = child.protocol;
+        manifest.role = child.role;
+        manifest.language = child.language;
+        manifest.extends = None;
+
+        let mut files: Vec<_> = parent
+            .files
+            .into_iter()
+            .filter(|file| {
+                let source = file.path.to_string_lossy();
+                !dropped.contains(source.as_ref())
+                    || manifest.files.iter().any(|f| f.source == source)
+            })
+            .collect();
+        for file in child_files {
+            match files.iter_mut().find(|f| f.path == file.path) {
+                Some(existing) => *existing = file,
+                None => files.push(file),
+            }
+        }
+        for file in &mut files {
+            let source = file.path.to_string_lossy();
+            if let Some(entry) = manifest.files.iter().find(|f| f.source == source) {
+                file.file_type = entry.file_type.clone();
+            }
+        }
+
+        Template {
+            manifest,
+            files,
+            source,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::{TemplateFile, TemplateFileType, TemplateSource};
+
+    const PARENT: &str = r#"
+name: base
+version: "0.1.3"
+description: "Base template"
+protocol: mcp
+role: server
+language: rust
+files:
+  - source: main.rs.tera
+    destination: src/main.rs
+  - source: Dockerfile.tera
+    destination: Dockerfile
+  - source: handler.rs.tera
+    destination: src/handler.rs
+variables:
+  port: 8080
+  docker: false
+hooks:
+  post_generate:
+    - cargo fmt
+    - cargo check
+"#;
+
+    const CHILD: &str = r#"
+name: child
+version: "1.0.0"
+extends: mcp/server/rust@0.1
+files:
+  - source: custom_handler.rs.tera
+    destination: src/handler.rs
+  - source: extra.rs.tera
+    destination: src/extra.rs
+variables:
+  port: 9000
+  region: eu
+hooks:
+  pre_generate:
+    - command: ./hooks/check-tools.sh
+      os: unix
+  post_generate:
+    - cargo clippy
+    - command: cargo fmt
+      timeout: 60
+remove:
+  files: [Dockerfile]
+  variables: [docker]
+  hooks: [cargo check]
+"#;
+
+    fn template(manifest: TemplateManifest, files: &[(&str, &str)]) -> Template {
+        Template {
+            files: files
+                .iter()
+                .map(|(path, content)| TemplateFile {
+                    path: PathBuf::from(path),
+                    content: content.to_string(),
+                    file_type: TemplateFileType::Template { for_each: None },
+                })
+                .collect(),
+            manifest,
+            source: TemplateSource::Embedded,
+        }
+    }
+
+    #[test]
+    fn test_parse_reference() {
+        let parent = TemplateParent::parse("mcp/server/rust@0.1").unwrap();
+        assert_eq!(parent.path, "mcp/server/rust");
+        assert_eq!(parent.version.as_deref(), Some("0.1"));
+        assert!(!parent.is_filesystem());
+
+        let parent = TemplateParent::parse("../base/").unwrap();
+        assert_eq!(parent.path, "../base");
+        assert_eq!(parent.version, None);
+        assert!(parent.is_filesystem());
+
+        assert!(TemplateParent::parse("@0.1").is_err());
+        assert!(TemplateParent::parse("mcp/server/rust@").is_err());
+    }
+
+    #[test]
+    fn test_version_matches() {
+        assert!(version_matches("0.1", "0.1.0"));
+        assert!(version_matches("0.1.3", "0.1.3"));
+        assert!(version_matches("^1", "1.4.0"));
+        assert!(!version_matches("0.1", "0.10.0"));
+        assert!(!version_matches("0.1.3", "0.1"));
+    }
+
+    #[test]
+    fn test_inherit_overrides_adds_and_removes() {
+        let parent_manifest = TemplateManifest::from_yaml(PARENT, "mcp/server/rust").unwrap();
+        let child_manifest = TemplateManifest::from_yaml(CHILD, "custom").unwrap();
+        let extends = child_manifest.extends.clone().unwrap();
+        assert!(extends.check_version(&parent_manifest).is_ok());
+
+        let parent = template(
+            parent_manifest,
+            &[
+                ("main.rs.tera", "parent main"),
+                ("Dockerfile.tera", "FROM rust"),
+                ("handler.rs.tera", "parent handler"),
+                ("_partials/header.tera", "header"),
+            ],
+        );
+        let child = template(
+            child_manifest,
+            &[
+                ("main.rs.tera", "child main"),
+                ("custom_handler.rs.tera", "child handler"),
+                ("extra.rs.tera", "extra"),
+            ],
+        );
+
+        let merged = child.inherit(parent);
+        let manifest = &merged.manifest;
+        assert_eq!(manifest.name, "child");
+        assert_eq!(manifest.description.as_deref(), Some("Base template"));
+        assert_eq!(manifest.language, crate::generation::Language::Rust);
+        assert_eq!(manifest.extends, None);
+
+        let entries: Vec<_> = manifest
+            .files
+            .iter()
+            .map(|f| (f.source.as_str(), f.target.as_str()))
+            .collect();
+        assert_eq!(
+            entries,
+            vec![
+                ("main.rs.tera", "src/main.rs"),
+                ("custom_handler.rs.tera", "src/handler.rs"),
+                ("extra.rs.tera", "src/extra.rs"),
+            ]
+        );
+
+        let files: Vec<_> = merged
+            .files
+            .iter()
+            .map(|f| (f.path.to_string_lossy().to_string(), f.content.as_str()))
+            .collect();
+        assert_eq!(
+            files,
+            vec![
+                ("main.rs.tera".to_string(), "child main"),
+                ("_partials/header.tera".to_string(), "header"),
+                ("custom_handler.rs.tera".to_string(), "child handler"),
+                ("extra.rs.tera".to_string(), "extra"),
+            ]
+        );
+
+        assert_eq!(manifest.variables.len(), 2);
+        assert_eq!(manifest.variables["port"], 9000);
+        assert_eq!(manifest.variables["region"], "eu");
+        let commands: Vec<_> = manifest
+            .post_generate_hooks
+            .iter()
+            .map(|hook| hook.command.as_str())
+            .collect();
+        assert_eq!(commands, vec!["cargo fmt", "cargo clippy"]);
+        // The child's `cargo fmt` replaces the parent's in place
+        assert_eq!(manifest.post_generate_hooks[0].timeout_secs, 60);
+        assert_eq!(manifest.pre_generate_hooks.len(), 1);
+        assert_eq!(manifest.pre_generate_hooks[0].os, vec!["unix"]);
+    }
+
+    #[test]
+    fn test_check_version_mismatch() {
+        let parent = TemplateManifest::from_yaml(PARENT, "mcp/server/rust").unwrap();
+        let extends = TemplateParent::parse("mcp/server/rust@0.2").unwrap();
+        let err = extends.check_version(&parent).unwrap_err().to_string();
+        assert!(err.contains("found version 0.1.3"));
+    }
+}
@@ -73,7 +73,12 @@ mod tests {
             language: Language::Go,
             files: vec![],
             variables: HashMap::new(),
+            variable_schema: vec![],
+            pre_generate_hooks: vec![],
             post_generate_hooks: vec![],
+            partials: vec![],
+            required_directories: vec![],
+            extends: None,
         };
 
         let template = Template {
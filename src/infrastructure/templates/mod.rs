@@ -3,13 +3,19 @@
 pub mod embedded_repository;
 pub mod errors;
 pub mod filesystem_loader;
+pub mod inheritance;
 pub mod loader_adapter;
+pub mod sources;
 pub mod traits;
 pub mod types;
+pub mod variant_adapter;
 
 pub use embedded_repository::*;
 pub use errors::*;
 pub use filesystem_loader::*;
+pub use inheritance::*;
 pub use loader_adapter::*;
+pub use sources::*;
 pub use traits::*;
 pub use types::*;
+pub use variant_adapter::*;
@@ -0,0 +1,655 @@
+//! Templates fetched from archives and git repositories
+//!
+//! [`TemplateFetcher`] turns a [`TemplateSource::Archive`] or
+//! [`TemplateSource::Git`] into a directory the filesystem loader can read.
+//! Archives are unpacked once per content digest and repositories are
+//! mirrored once per URL and checked out once per commit, all under the user
+//! cache dir, so repeated scaffolds from the same source work offline.
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use sha2::{Digest, Sha256};
+
+use crate::protocols::{Protocol, Role};
+
+use super::{TemplateError, TemplateSource};
+
+/// Overrides the cache root, which defaults to `<user cache dir>/agenterra`
+pub const CACHE_DIR_ENV: &str = "AGENTERRA_CACHE_DIR";
+
+/// Prefix of archive digests
+const DIGEST_PREFIX: &str = "sha256:";
+
+/// What a fetched source resolved to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplatePin {
+    /// Commit a git ref resolved to
+    Commit(String),
+    /// `sha256:` digest of an archive
+    Digest(String),
+}
+
+impl std::fmt::Display for TemplatePin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TemplatePin::Commit(commit) => write!(f, "{commit}"),
+            TemplatePin::Digest(digest) => write!(f, "{digest}"),
+        }
+    }
+}
+
+/// A source unpacked into the cache
+#[derive(Debug, Clone)]
+pub struct FetchedTemplate {
+    /// Root of the unpacked archive or checkout
+    pub root: PathBuf,
+    pub pin: TemplatePin,
+}
+
+impl FetchedTemplate {
+    /// Directory holding the template for a protocol, role and variant
+    ///
+    /// That is the root when it has a `manifest.yml`, else the
+    /// `<protocol>/<role>/<variant>` directory written by `templates export`,
+    /// where the variant is a language or a name such as `rust-minimal`.
+    /// When the root holds a single directory, as most release tarballs do,
+    /// the same is tried inside it.
+    pub fn template_dir(
+        &self,
+        protocol: Protocol,
+        role: &Role,
+        variant: &str,
+    ) -> Result<PathBuf, TemplateError> {
+        let nested = format!("{protocol}/{role}/{variant}");
+        let mut roots = vec![self.root.clone()];
+        roots.extend(single_subdirectory(&self.root)?);
+        for root in roots {
+            for dir in [root.clone(), root.join(&nested)] {
+                if dir.join("manifest.yml").is_file() {
+                    return Ok(dir);
+                }
+            }
+        }
+        Err(TemplateError::TemplateNotFound(format!(
+            "no manifest.yml at the root of the fetched template or under {protocol}/{role}/{variant}"
+        )))
+    }
+}
+
+/// Fetches archive and git template sources into a cache directory
+#[derive(Debug, Clone)]
+pub struct TemplateFetcher {
+    cache_dir: PathBuf,
+}
+
+impl TemplateFetcher {
+    /// Fetcher using [`CACHE_DIR_ENV`] or the user cache dir
+    pub fn new() -> Self {
+        let cache_dir = std::env::var_os(CACHE_DIR_ENV)
+            .map(PathBuf::from)
+            .or_else(|| dirs::cache_dir().map(|dir| dir.join("agenterra")))
+            .unwrap_or_else(|| std::env::temp_dir().join("agenterra-cache"));
+        Self::with_cache_dir(cache_dir)
+    }
+
+    pub fn with_cache_dir(cache_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            cache_dir: cache_dir.into(),
+        }
+    }
+
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    /// Fetch `source` into the cache
+    ///
+    /// With a `pinned` commit or digest, from a lockfile, a git source is
+    /// checked out at that commit instead of its ref, and an archive must
+    /// match that digest.
+    pub async fn fetch(
+        &self,
+        source: &TemplateSource,
+        pinned: Option<&TemplatePin>,
+    ) -> Result<FetchedTemplate, TemplateError> {
+        match source {
+            TemplateSource::Archive(location) => {
+                let bytes = self.read_archive(location).await?;
+                let fetcher = self.clone();
+                let location = location.clone();
+                let pinned = pinned.cloned();
+                blocking(move || fetcher.unpack_archive(&location, &bytes, pinned.as_ref())).await
+            }
+            TemplateSource::Git { url, reference } => {
+                let fetcher = self.clone();
+                let url = url.clone();
+                let reference = reference.clone();
+                let pinned = pinned.cloned();
+                blocking(move || fetcher.checkout(&url, reference.as_deref(), pinned.as_ref()))
+                    .await
+            }
+            TemplateSource::Embedded | TemplateSource::FileSystem(_) => Err(fetch_error(format!(
+                "{source} is not fetched; load it directly"
+            ))),
+        }
+    }
+
+    /// Archive bytes from a local path, or from a URL through the download cache
+    async fn read_archive(&self, location: &str) -> Result<Vec<u8>, TemplateError> {
+        if !is_url(location) {
+            let path = location.strip_prefix("file://").unwrap_or(location);
+            return tokio::fs::read(path)
+                .await
+                .map_err(|e| fetch_error(format!("cannot read archive {location}: {e}")));
+        }
+
+        let cached = self
+            .cache_dir
+            .join("downloads")
+            .join(short_hash(location.as_bytes()))
+            .join(archive_file_name(location));
+        if let Ok(bytes) = tokio::fs::read(&cached).await {
+            return Ok(bytes);
+        }
+        let bytes = download(location).await?;
+        if let Some(parent) = cached.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(&cached, &bytes).await?;
+        Ok(bytes)
+    }
+
+    /// Unpack an archive into `archives/<digest>`, once per digest
+    fn unpack_archive(
+        &self,
+        location: &str,
+        bytes: &[u8],
+        pinned: Option<&TemplatePin>,
+    ) -> Result<FetchedTemplate, TemplateError> {
+        let hex = format!("{:x}", Sha256::digest(bytes));
+        let digest = format!("{DIGEST_PREFIX}{hex}");
+        if let Some(pinned) = pinned
+            && *pinned != TemplatePin::Digest(digest.clone())
+        {
+            return Err(fetch_error(format!(
+                "archive {location} has digest {digest}, but {pinned} is pinned"
+            )));
+        }
+
+        let root = self.cache_dir.join("archives").join(&hex);
+        self.populate(&root, |dir| {
+            if is_zip(location) {
+                let mut archive = zip::ZipArchive::new(std::io::Cursor::new(bytes))
+                    .map_err(|e| fetch_error(format!("invalid zip archive {location}: {e}")))?;
+                archive
+                    .extract(dir)
+                    .map_err(|e| fetch_error(format!("cannot unpack {location}: {e}")))
+            } else {
+                let decoder = flate2::read::GzDecoder::new(bytes);
+                tar::Archive::new(decoder)
+                    .unpack(dir)
+                    .map_err(|e| fetch_error(format!("cannot unpack {location}: {e}")))
+            }
+        })?;
+
+        Ok(FetchedTemplate {
+            root,
+            pin: TemplatePin::Digest(digest),
+        })
+    }
+
+    /// Mirror the repository and check out the pinned commit or the ref
+    fn checkout(
+        &self,
+        url: &str,
+        reference: Option<&str>,
+        pinned: Option<&TemplatePin>,
+    ) -> Result<FetchedTemplate, TemplateError> {
+        let mirror = self.cache_dir.join("git").join(short_hash(url.as_bytes()));
+        let pinned = match pinned {
+            Some(TemplatePin::Commit(commit)) => Some(commit.as_str()),
+            Some(pin) => {
+                return Err(fetch_error(format!(
+                    "git source {url} cannot be pinned to {pin}"
+                )));
+            }
+            None => None,
+        };
+
+        if !mirror.join("HEAD").is_file() {
+            // git would take a leading `-` as an option such as `--upload-pack`
+            if url.starts_with('-') {
+                return Err(fetch_error(format!("invalid git URL '{url}'")));
+            }
+            std::fs::create_dir_all(mirror.parent().unwrap_or(&self.cache_dir))?;
+            git(
+                None,
+                &[
+                    "clone",
+                    "--mirror",
+                    "--quiet",
+                    "--",
+                    url,
+                    &mirror.to_string_lossy(),
+                ],
+            )?;
+        } else if pinned.is_none_or(|commit| resolve(&mirror, commit).is_err()) {
+            // Offline runs fall back to what the mirror already has
+            if let Err(e) = git(Some(&mirror), &["fetch", "--quiet", "--prune", "origin"]) {
+                tracing::warn!("Using cached mirror of {url}: {e}");
+            }
+        }
+
+        let commit = match pinned {
+            Some(commit) => resolve(&mirror, commit)?,
+            None => resolve(&mirror, reference.unwrap_or("HEAD"))?,
+        };
+
+        let root = self.cache_dir.join("checkouts").join(&commit);
+        self.populate(&root, |dir| {
+            let tar = git(Some(&mirror), &["archive", "--format=tar", &commit])?;
+            tar::Archive::new(tar.as_slice())
+                .unpack(dir)
+                .map_err(|e| fetch_error(format!("cannot check out {commit}: {e}")))
+        })?;
+
+        Ok(FetchedTemplate {
+            root,
+            pin: TemplatePin::Commit(commit),
+        })
+    }
+
+    /// Fill `root` once, through a temporary sibling renamed into place, so an
+    /// interrupted fetch never leaves a half-written cache entry
+    fn populate(
+        &self,
+        root: &Path,
+        fill: impl FnOnce(&Path) -> Result<(), TemplateError>,
+    ) -> Result<(), TemplateError> {
+        if root.is_dir() {
+            return Ok(());
+        }
+        let parent = root.parent().unwrap_or(&self.cache_dir);
+        std::fs::create_dir_all(parent)?;
+        let staging = tempfile::TempDir::new_in(parent)?;
+        fill(staging.path())?;
+
+        let staged = staging.keep();
+        if let Err(e) = std::fs::rename(&staged, root) {
+            let _ = std::fs::remove_dir_all(&staged);
+            // Another run may have filled it first
+            if !root.is_dir() {
+                return Err(e.into());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for TemplateFetcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::str::FromStr for TemplateSource {
+    type Err = TemplateError;
+
+    /// Parse a `--template-source` value
+    ///
+    /// - `git+<url>[#<ref>]`, or a URL ending in `.git`, is a git repository
+    /// - a path or URL ending in `.tar.gz`, `.tgz` or `.zip` is an archive
+    /// - anything else is a template directory
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(TemplateError::TemplateNotFound(
+                "empty template source".to_string(),
+            ));
+        }
+
+        let (location, reference) = match s.rsplit_once('#') {
+            Some((location, reference)) => (location, Some(reference)),
+            None => (s, None),
+        };
+        if let Some(url) = location.strip_prefix("git+") {
+            return Ok(TemplateSource::Git {
+                url: url.to_string(),
+                reference: reference.filter(|r| !r.is_empty()).map(str::to_string),
+            });
+        }
+        if is_url(location) && location.trim_end_matches('/').ends_with(".git") {
+            return Ok(TemplateSource::Git {
+                url: location.to_string(),
+                reference: reference.filter(|r| !r.is_empty()).map(str::to_string),
+            });
+        }
+        if is_archive(s) {
+            return Ok(TemplateSource::Archive(s.to_string()));
+        }
+        Ok(TemplateSource::FileSystem(PathBuf::from(s)))
+    }
+}
+
+/// Run blocking fetch work off the async runtime
+async fn blocking<T: Send + 'static>(
+    work: impl FnOnce() -> Result<T, TemplateError> + Send + 'static,
+) -> Result<T, TemplateError> {
+    tokio::task::spawn_blocking(work)
+        .await
+        .map_err(|e| fetch_error(format!("fetch task failed: {e}")))?
+}
+
+#[cfg(feature = "http-loader")]
+async fn download(url: &str) -> Result<Vec<u8>, TemplateError> {
+    let response = reqwest::get(url)
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| fetch_error(format!("cannot download {url}: {e}")))?;
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|e| fetch_error(format!("cannot download {url}: {e}")))?;
+    Ok(bytes.to_vec())
+}
+
+#[cfg(not(feature = "http-loader"))]
+async fn download(url: &str) -> Result<Vec<u8>, TemplateError> {
+    Err(fetch_error(format!(
+        "cannot download {url}: built without the http-loader feature"
+    )))
+}
+
+/// Run git, returning its stdout
+fn git(dir: Option<&Path>, args: &[&str]) -> Result<Vec<u8>, TemplateError> {
+    let mut command = Command::new("git");
+    if let Some(dir) = dir {
+        command.arg("--git-dir").arg(dir);
+    }
+    let output = command
+        .args(args)
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .output()
+        .map_err(|e| fetch_error(format!("cannot run git: {e}")))?;
+    if !output.status.success() {
+        return Err(fetch_error(format!(
+            "git {} failed: {}",
+            args.first().unwrap_or(&""),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(output.stdout)
+}
+
+/// Commit a ref or commit prefix names in the mirror
+fn resolve(mirror: &Path, reference: &str) -> Result<String, TemplateError> {
+    let spec = format!("{reference}^{{commit}}");
+    let stdout = git(Some(mirror), &["rev-parse", "--verify", "--quiet", &spec])
+        .map_err(|_| fetch_error(format!("unknown git ref '{reference}'")))?;
+    Ok(String::from_utf8_lossy(&stdout).trim().to_string())
+}
+
+fn single_subdirectory(dir: &Path) -> Result<Option<PathBuf>, TemplateError> {
+    let mut entries = std::fs::read_dir(dir)?
+        .filter_map(Result::ok)
+        .filter(|entry| !entry.file_name().to_string_lossy().starts_with('.'));
+    match (entries.next(), entries.next()) {
+        (Some(entry), None) if entry.path().is_dir() => Ok(Some(entry.path())),
+        _ => Ok(None),
+    }
+}
+
+fn fetch_error(message: String) -> TemplateError {
+    TemplateError::FetchError(message)
+}
+
+fn is_url(location: &str) -> bool {
+    ["http://", "https://", "file://", "ssh://", "git://"]
+        .iter()
+        .any(|scheme| location.starts_with(scheme))
+}
+
+/// Path of a location without its query string, lowercased for extension checks
+fn archive_path(location: &str) -> String {
+    location
+        .split(['?', '#'])
+        .next()
+        .unwrap_or(location)
+        .to_ascii_lowercase()
+}
+
+fn is_archive(location: &str) -> bool {
+    let path = archive_path(location);
+    path.ends_with(".tar.gz") || path.ends_with(".tgz") || path.ends_with(".zip")
+}
+
+fn is_zip(location: &str) -> bool {
+    archive_path(location).ends_with(".zip")
+}
+
+fn archive_file_name(location: &str) -> String {
+    let path = archive_path(location);
+    let name = path.rsplit('/').next().unwrap_or_default();
+    if name.is_empty() {
+        "template.tar.gz".to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+/// First 16 hex digits of a SHA-256, for cache directory names
+fn short_hash(bytes: &[u8]) -> String {
+    let mut hex = format!("{:x}", Sha256::digest(bytes));
+    hex.truncate(16);
+    hex
+}
+
+/// Read the digest of an archive already on disk, for tests and tooling
+pub fn archive_digest(path: &Path) -> Result<TemplatePin, TemplateError> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = [0; 8192];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(TemplatePin::Digest(format!(
+        "{DIGEST_PREFIX}{:x}",
+        hasher.finalize()
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const MANIFEST: &str = "name: fetched\nversion: \"1.0.0\"\nprotocol: mcp\nrole: client\nlanguage: rust\nfiles: []\n";
+
+    fn tarball(dir: &Path, files: &[(&str, &str)]) -> PathBuf {
+        let path = dir.join("template.tar.gz");
+        let encoder = flate2::write::GzEncoder::new(
+            std::fs::File::create(&path).unwrap(),
+            flate2::Compression::default(),
+        );
+        let mut builder = tar::Builder::new(encoder);
+        for (name, content) in files {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder
+                .append_data(&mut header, name, content.as_bytes())
+                .unwrap();
+        }
+        builder.into_inner().unwrap().finish().unwrap();
+        path
+    }
+
+    #[test]
+    fn test_parse_sources() {
+        let parse = |s: &str| s.parse::<TemplateSource>().unwrap();
+        assert_eq!(
+            parse("git+file:///srv/templates.git#v1.2"),
+            TemplateSource::Git {
+                url: "file:///srv/templates.git".into(),
+                reference: Some("v1.2".into()),
+            }
+        );
+        assert_eq!(
+            parse("https://example.com/templates.git"),
+            TemplateSource::Git {
+                url: "https://example.com/templates.git".into(),
+                reference: None,
+            }
+        );
+        assert_eq!(
+            parse("https://example.com/rust-1.0.tar.gz?token=x"),
+            TemplateSource::Archive("https://example.com/rust-1.0.tar.gz?token=x".into())
+        );
+        assert_eq!(
+            parse("./dist/templates.zip"),
+            TemplateSource::Archive("./dist/templates.zip".into())
+        );
+        assert_eq!(
+            parse("./templates/mcp/server/rust"),
+            TemplateSource::FileSystem(PathBuf::from("./templates/mcp/server/rust"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_tarball_with_top_level_directory() {
+        let work = TempDir::new().unwrap();
+        let archive = tarball(
+            work.path(),
+            &[
+                ("templates-1.0/manifest.yml", MANIFEST),
+                ("templates-1.0/main.rs.tera", "fn main() {}"),
+            ],
+        );
+        let fetcher = TemplateFetcher::with_cache_dir(work.path().join("cache"));
+        let source = TemplateSource::Archive(archive.to_string_lossy().to_string());
+
+        let fetched = fetcher.fetch(&source, None).await.unwrap();
+        assert_eq!(fetched.pin, archive_digest(&archive).unwrap());
+        let dir = fetched
+            .template_dir(Protocol::Mcp, &Role::Client, "rust")
+            .unwrap();
+        assert!(dir.join("main.rs.tera").is_file());
+        assert!(dir.starts_with(fetcher.cache_dir().join("archives")));
+
+        // The pinned digest is checked
+        let wrong = TemplatePin::Digest(format!("{DIGEST_PREFIX}{}", "0".repeat(64)));
+        let err = fetcher.fetch(&source, Some(&wrong)).await.unwrap_err();
+        assert!(err.to_string().contains("is pinned"));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_zip_in_export_layout() {
+        let work = TempDir::new().unwrap();
+        let path = work.path().join("templates.zip");
+        let mut zip = zip::ZipWriter::new(std::fs::File::create(&path).unwrap());
+        let options = zip::write::SimpleFileOptions::default();
+        zip.start_file("mcp/client/rust/manifest.yml", options)
+            .unwrap();
+        std::io::Write::write_all(&mut zip, MANIFEST.as_bytes()).unwrap();
+        zip.start_file("mcp/server/rust/manifest.yml", options)
+            .unwrap();
+        std::io::Write::write_all(&mut zip, MANIFEST.as_bytes()).unwrap();
+        zip.finish().unwrap();
+
+        let fetcher = TemplateFetcher::with_cache_dir(work.path().join("cache"));
+        let fetched = fetcher
+            .fetch(
+                &TemplateSource::Archive(path.to_string_lossy().to_string()),
+                None,
+            )
+            .await
+            .unwrap();
+        let dir = fetched
+            .template_dir(Protocol::Mcp, &Role::Client, "rust")
+            .unwrap();
+        assert!(dir.ends_with("mcp/client/rust"));
+        assert!(
+            fetched
+                .template_dir(Protocol::Mcp, &Role::Client, "python")
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_git_ref_and_pinned_commit() {
+        let work = TempDir::new().unwrap();
+        let repo = work.path().join("repo");
+        std::fs::create_dir_all(&repo).unwrap();
+        let run = |args: &[&str]| {
+            let status = Command::new("git")
+                .arg("-C")
+                .arg(&repo)
+                .args(["-c", "user.name=t", "-c", "user.email=t@example.com"])
+                .args(args)
+                .output()
+                .unwrap();
+            assert!(status.status.success(), "{args:?}: {status:?}");
+            String::from_utf8(status.stdout).unwrap().trim().to_string()
+        };
+        run(&["init", "--quiet"]);
+        std::fs::write(repo.join("manifest.yml"), MANIFEST).unwrap();
+        std::fs::write(repo.join("main.rs.tera"), "v1").unwrap();
+        run(&["add", "."]);
+        run(&["commit", "--quiet", "-m", "v1"]);
+        run(&["tag", "v1"]);
+        let v1 = run(&["rev-parse", "HEAD"]);
+        std::fs::write(repo.join("main.rs.tera"), "v2").unwrap();
+        run(&["commit", "--quiet", "-am", "v2"]);
+
+        let url = format!("file://{}", repo.display());
+        let fetcher = TemplateFetcher::with_cache_dir(work.path().join("cache"));
+        let at = |reference: Option<&str>| TemplateSource::Git {
+            url: url.clone(),
+            reference: reference.map(str::to_string),
+        };
+
+        let head = fetcher.fetch(&at(None), None).await.unwrap();
+        assert_eq!(
+            std::fs::read_to_string(head.root.join("main.rs.tera")).unwrap(),
+            "v2"
+        );
+
+        let tagged = fetcher.fetch(&at(Some("v1")), None).await.unwrap();
+        assert_eq!(tagged.pin, TemplatePin::Commit(v1.clone()));
+        assert_eq!(
+            std::fs::read_to_string(tagged.root.join("main.rs.tera")).unwrap(),
+            "v1"
+        );
+
+        // A pinned commit wins over the ref
+        let pinned = fetcher
+            .fetch(&at(None), Some(&TemplatePin::Commit(v1.clone())))
+            .await
+            .unwrap();
+        assert_eq!(pinned.root, tagged.root);
+
+        let err = fetcher.fetch(&at(Some("nope")), None).await.unwrap_err();
+        assert!(err.to_string().contains("unknown git ref 'nope'"));
+    }
+
+    #[tokio::test]
+    async fn test_git_url_is_never_an_option() {
+        let work = TempDir::new().unwrap();
+        let marker = work.path().join("pwned");
+        let source: TemplateSource = format!("git+--upload-pack=touch {}", marker.display())
+            .parse()
+            .unwrap();
+
+        let fetcher = TemplateFetcher::with_cache_dir(work.path().join("cache"));
+        let err = fetcher.fetch(&source, None).await.unwrap_err();
+        assert!(err.to_string().contains("invalid git URL"), "{err}");
+        assert!(!marker.exists());
+    }
+}
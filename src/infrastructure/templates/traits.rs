@@ -35,6 +35,21 @@ pub trait TemplateDiscovery: Send + Sync {
         role: crate::protocols::Role,
         language: crate::generation::Language,
     ) -> Result<Template, TemplateError>;
+
+    /// Find a template variant by name, such as `rust-minimal`
+    ///
+    /// Sources without variants only know the templates named after a language.
+    async fn discover_named(
+        &self,
+        protocol: crate::protocols::Protocol,
+        role: crate::protocols::Role,
+        name: &str,
+    ) -> Result<Template, TemplateError> {
+        let language = name
+            .parse()
+            .map_err(|_| TemplateError::not_found(format!("{protocol}/{role}/{name}")))?;
+        self.discover(protocol, role, language).await
+    }
 }
 
 /// Trait for exporting templates from a repository to the filesystem
@@ -4,7 +4,7 @@
 //! implementation (embedded, filesystem, remote, etc.)
 
 use serde_json::Value as JsonValue;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
 
 use crate::{
@@ -12,13 +12,21 @@ use crate::{
     protocols::{Protocol, Role},
 };
 
-use super::TemplateError;
+use super::{TemplateError, TemplateParent};
 
 /// Source of a template
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TemplateSource {
     Embedded,
     FileSystem(PathBuf),
+    /// `.tar.gz`, `.tgz` or `.zip` archive at a local path or URL
+    Archive(String),
+    /// Git repository checked out at `reference`, a branch, tag or commit;
+    /// the remote's `HEAD` when unset
+    Git {
+        url: String,
+        reference: Option<String>,
+    },
 }
 
 impl std::fmt::Display for TemplateSource {
@@ -26,6 +34,15 @@ impl std::fmt::Display for TemplateSource {
         match self {
             TemplateSource::Embedded => write!(f, "Embedded"),
             TemplateSource::FileSystem(path) => write!(f, "FileSystem({})", path.display()),
+            TemplateSource::Archive(location) => write!(f, "Archive({location})"),
+            TemplateSource::Git {
+                url,
+                reference: Some(reference),
+            } => write!(f, "Git({url}#{reference})"),
+            TemplateSource::Git {
+                url,
+                reference: None,
+            } => write!(f, "Git({url})"),
         }
     }
 }
@@ -55,7 +72,17 @@ pub struct TemplateManifest {
     // Template details
     pub files: Vec<ManifestFile>,
     pub variables: HashMap<String, JsonValue>,
-    pub post_generate_hooks: Vec<String>,
+    pub variable_schema: Vec<TemplateVariable>,
+    /// Hooks run in the output directory before any file is written
+    pub pre_generate_hooks: Vec<TemplateHook>,
+    /// Hooks run in the output directory once every file is written
+    pub post_generate_hooks: Vec<TemplateHook>,
+    /// Helper template files or directories declared under `partials:`
+    pub partials: Vec<String>,
+    /// Directories created in the output even when no file lands in them
+    pub required_directories: Vec<String>,
+    /// Template this one extends; `None` once inheritance has been resolved
+    pub extends: Option<TemplateParent>,
 }
 
 /// File entry in manifest
@@ -64,14 +91,159 @@ pub struct ManifestFile {
     pub source: String,
     pub target: String,
     pub file_type: TemplateFileType,
+    /// Tera expression; the file is only emitted when it is truthy
+    pub when: Option<String>,
+}
+
+/// Variable declared in the manifest `variables:` section
+#[derive(Debug, Clone, PartialEq)]
+pub struct TemplateVariable {
+    pub name: String,
+    pub var_type: VariableType,
+    pub default: Option<JsonValue>,
+    pub description: Option<String>,
+    pub required: bool,
+}
+
+/// JSON type accepted by a template variable
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VariableType {
+    Any,
+    String,
+    Integer,
+    Number,
+    Boolean,
+    Array,
+    Object,
+}
+
+impl VariableType {
+    /// Infer the variable type from a default value
+    pub fn of(value: &JsonValue) -> Self {
+        match value {
+            JsonValue::Null => VariableType::Any,
+            JsonValue::Bool(_) => VariableType::Boolean,
+            JsonValue::Number(n) if n.is_f64() => VariableType::Number,
+            JsonValue::Number(_) => VariableType::Integer,
+            JsonValue::String(_) => VariableType::String,
+            JsonValue::Array(_) => VariableType::Array,
+            JsonValue::Object(_) => VariableType::Object,
+        }
+    }
+
+    /// Check whether a value is acceptable for this type
+    pub fn accepts(&self, value: &JsonValue) -> bool {
+        match self {
+            VariableType::Any => true,
+            VariableType::String => value.is_string(),
+            VariableType::Integer => value.is_i64() || value.is_u64(),
+            VariableType::Number => value.is_number(),
+            VariableType::Boolean => value.is_boolean(),
+            VariableType::Array => value.is_array(),
+            VariableType::Object => value.is_object(),
+        }
+    }
+}
+
+impl std::fmt::Display for VariableType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            VariableType::Any => "any",
+            VariableType::String => "string",
+            VariableType::Integer => "integer",
+            VariableType::Number => "number",
+            VariableType::Boolean => "boolean",
+            VariableType::Array => "array",
+            VariableType::Object => "object",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl std::str::FromStr for VariableType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "any" => Ok(VariableType::Any),
+            "string" => Ok(VariableType::String),
+            "integer" | "int" => Ok(VariableType::Integer),
+            "number" | "float" => Ok(VariableType::Number),
+            "boolean" | "bool" => Ok(VariableType::Boolean),
+            "array" | "list" => Ok(VariableType::Array),
+            "object" | "map" => Ok(VariableType::Object),
+            _ => Err(format!("Unknown variable type: {s}")),
+        }
+    }
+}
+
+/// Seconds a hook may run before it is killed, unless it sets `timeout`
+pub const DEFAULT_HOOK_TIMEOUT_SECS: u64 = 300;
+
+/// Command declared under `hooks.pre_generate` or `hooks.post_generate`
+///
+/// A plain string is shorthand for a hook with only a `command`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateHook {
+    pub command: String,
+    /// Seconds the command may run before it is killed
+    pub timeout_secs: u64,
+    /// Environment variables set for the command
+    pub env: BTreeMap<String, String>,
+    /// Report a failure as a warning instead of failing the generation
+    pub continue_on_error: bool,
+    /// Operating systems the hook runs on, as in `std::env::consts::OS`,
+    /// or `unix`; every system when empty
+    pub os: Vec<String>,
+}
+
+impl TemplateHook {
+    pub fn new(command: impl Into<String>) -> Self {
+        Self {
+            command: command.into(),
+            timeout_secs: DEFAULT_HOOK_TIMEOUT_SECS,
+            env: BTreeMap::new(),
+            continue_on_error: false,
+            os: Vec::new(),
+        }
+    }
+
+    /// Whether the hook runs on this operating system
+    pub fn runs_on_current_os(&self) -> bool {
+        self.os.is_empty()
+            || self
+                .os
+                .iter()
+                .any(|os| os == std::env::consts::OS || (os == "unix" && cfg!(unix)))
+    }
+}
+
+/// When a hook runs relative to writing the generated files
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookStage {
+    PreGenerate,
+    PostGenerate,
+}
+
+impl std::fmt::Display for HookStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HookStage::PreGenerate => write!(f, "pre_generate"),
+            HookStage::PostGenerate => write!(f, "post_generate"),
+        }
+    }
 }
 
 /// Type of template file
 #[derive(Debug, Clone, PartialEq)]
 pub enum TemplateFileType {
-    Template { for_each: Option<String> },
+    Template {
+        for_each: Option<String>,
+    },
     Static,
     Configuration,
+    /// Helper for `include`, `import` and `extends`; never rendered to its own file
+    Partial,
 }
 
 /// A single template file - storage agnostic representation
@@ -123,26 +295,30 @@ impl TemplateManifest {
             .and_then(|v| v.as_str())
             .map(|s| s.to_string());
 
-        // Convert string fields to enums
-        let protocol_str = yaml
-            .get("protocol")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| TemplateError::manifest_parse_error(path, "missing 'protocol' field"))?;
-        let protocol = Protocol::from_str(protocol_str)
-            .map_err(|e| TemplateError::manifest_parse_error(path, e))?;
-
-        let role_str = yaml
-            .get("role")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| TemplateError::manifest_parse_error(path, "missing 'role' field"))?;
-        let role =
-            Role::from_str(role_str).map_err(|e| TemplateError::manifest_parse_error(path, e))?;
+        let extends = parse_extends(&yaml, path)?;
 
-        let language_str = yaml
-            .get("language")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| TemplateError::manifest_parse_error(path, "missing 'language' field"))?;
-        let language = Language::from_str(language_str)
+        // Convert string fields to enums. A template extending an embedded
+        // template may omit them and take them from the parent path instead.
+        let identity = |key: &str, index: usize| -> Result<&str, TemplateError> {
+            let inherited = extends
+                .as_ref()
+                .filter(|parent| !parent.is_filesystem())
+                .and_then(|parent| {
+                    let segments: Vec<_> = parent.path.split('/').collect();
+                    (segments.len() == 3).then(|| segments[index])
+                });
+            yaml.get(key)
+                .and_then(|v| v.as_str())
+                .or(inherited)
+                .ok_or_else(|| {
+                    TemplateError::manifest_parse_error(path, format!("missing '{key}' field"))
+                })
+        };
+        let protocol = Protocol::from_str(identity("protocol", 0)?)
+            .map_err(|e| TemplateError::manifest_parse_error(path, e))?;
+        let role = Role::from_str(identity("role", 1)?)
+            .map_err(|e| TemplateError::manifest_parse_error(path, e))?;
+        let language = Language::from_str(identity("language", 2)?)
             .map_err(|e| TemplateError::manifest_parse_error(path, e))?;
 
         // Parse files array
@@ -153,18 +329,46 @@ impl TemplateManifest {
         };
 
         // Parse variables (default to empty if not present)
-        let variables = if let Some(vars_yaml) = yaml.get("variables") {
-            serde_yaml::from_value(vars_yaml.clone()).map_err(|e| {
-                TemplateError::manifest_parse_error(path, format!("invalid variables: {e}"))
-            })?
+        let variable_schema = if let Some(vars_yaml) = yaml.get("variables") {
+            parse_variables(vars_yaml, path)?
         } else {
-            HashMap::new()
+            Vec::new()
+        };
+        let variables = variable_schema
+            .iter()
+            .filter_map(|var| var.default.clone().map(|value| (var.name.clone(), value)))
+            .collect();
+
+        // Parse hooks; `post_generate_hooks` is the older spelling of `hooks.post_generate`
+        let hooks = yaml.get("hooks");
+        let pre_generate_hooks = parse_hooks(
+            hooks.and_then(|h| h.get("pre_generate")),
+            "hooks.pre_generate",
+            path,
+        )?;
+        let post_generate_hooks = match hooks.and_then(|h| h.get("post_generate")) {
+            Some(value) => parse_hooks(Some(value), "hooks.post_generate", path)?,
+            None => parse_hooks(yaml.get("post_generate_hooks"), "post_generate_hooks", path)?,
         };
 
-        // Parse hooks
-        let post_generate_hooks = parse_hooks(&yaml, "hooks", "post_generate")
-            .or_else(|_| parse_hooks(&yaml, "post_generate_hooks", ""))
-            .unwrap_or_default();
+        let partials = match yaml.get("partials") {
+            Some(partials) => parse_string_list(partials, "partials", path)?,
+            None => Vec::new(),
+        };
+
+        let required_directories = match yaml.get("required_directories") {
+            Some(dirs) => parse_string_list(dirs, "required_directories", path)?,
+            None => Vec::new(),
+        };
+        if let Some(dir) = required_directories
+            .iter()
+            .find(|dir| !is_relative_within(dir))
+        {
+            return Err(TemplateError::manifest_parse_error(
+                path,
+                format!("required directory '{dir}' must be a relative path inside the project"),
+            ));
+        }
 
         Ok(TemplateManifest {
             name,
@@ -176,9 +380,171 @@ impl TemplateManifest {
             language,
             files,
             variables,
+            variable_schema,
+            pre_generate_hooks,
             post_generate_hooks,
+            partials,
+            required_directories,
+            extends,
         })
     }
+
+    /// Variant name of the template, the last component of its path
+    ///
+    /// `mcp/server/rust-minimal` is the `rust-minimal` variant; the default
+    /// template of a language is the variant named after it.
+    pub fn variant(&self) -> &str {
+        let path = self.path.trim_end_matches('/');
+        path.rsplit('/').next().unwrap_or(path)
+    }
+
+    /// Whether `source` is a partial rather than an output file
+    ///
+    /// Partials are `.tera` files not listed under `files:` that are either
+    /// declared under `partials:`, directly or through a parent directory, or
+    /// have a path segment starting with `_`, such as `_partials/struct.tera`
+    /// or `_macros.tera`.
+    pub fn is_partial(&self, source: &str) -> bool {
+        if !source.ends_with(".tera") || self.files.iter().any(|f| f.source == source) {
+            return false;
+        }
+
+        source.split('/').any(|segment| segment.starts_with('_'))
+            || self.partials.iter().any(|declared| {
+                let declared = declared.trim_end_matches('/');
+                source == declared
+                    || source
+                        .strip_prefix(declared)
+                        .is_some_and(|rest| rest.starts_with('/'))
+            })
+    }
+
+    /// Check variables against the declared schema and fill in defaults
+    pub fn resolve_variables(
+        &self,
+        variables: &mut HashMap<String, JsonValue>,
+    ) -> Result<(), TemplateError> {
+        let mut problems = Vec::new();
+
+        for var in &self.variable_schema {
+            match variables.get(&var.name) {
+                Some(value) if !var.var_type.accepts(value) => problems.push(format!(
+                    "'{}' must be {}, got {}",
+                    var.name, var.var_type, value
+                )),
+                Some(_) => {}
+                None => match &var.default {
+                    Some(default) => {
+                        variables.insert(var.name.clone(), default.clone());
+                    }
+                    None if var.required => problems.push(format!("'{}' is required", var.name)),
+                    None => {}
+                },
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(TemplateError::InvalidVariables(problems.join("; ")))
+        }
+    }
+}
+
+// Keys that mark a `variables:` entry as a schema declaration rather than a bare default
+const VARIABLE_SCHEMA_KEYS: &[&str] = &["type", "default", "description", "required"];
+
+// Helper function to parse the variable schema
+fn parse_variables(
+    vars_yaml: &serde_yaml::Value,
+    manifest_path: &str,
+) -> Result<Vec<TemplateVariable>, TemplateError> {
+    let mapping = vars_yaml.as_mapping().ok_or_else(|| {
+        TemplateError::manifest_parse_error(manifest_path, "'variables' must be a mapping")
+    })?;
+
+    let mut variables = Vec::new();
+    for (key, value) in mapping {
+        let name = key
+            .as_str()
+            .ok_or_else(|| {
+                TemplateError::manifest_parse_error(manifest_path, "variable names must be strings")
+            })?
+            .to_string();
+        let invalid = |reason: String| {
+            TemplateError::manifest_parse_error(
+                manifest_path,
+                format!("invalid variable '{name}': {reason}"),
+            )
+        };
+
+        let is_schema = value.as_mapping().is_some_and(|m| {
+            !m.is_empty()
+                && m.keys().all(|k| {
+                    k.as_str()
+                        .is_some_and(|k| VARIABLE_SCHEMA_KEYS.contains(&k))
+                })
+        });
+
+        let variable = if is_schema {
+            let default = value
+                .get("default")
+                .map(|v| serde_yaml::from_value::<JsonValue>(v.clone()))
+                .transpose()
+                .map_err(|e| invalid(e.to_string()))?;
+            let var_type = match value.get("type") {
+                Some(t) => t
+                    .as_str()
+                    .ok_or_else(|| invalid("'type' must be a string".to_string()))?
+                    .parse()
+                    .map_err(invalid)?,
+                None => default
+                    .as_ref()
+                    .map(VariableType::of)
+                    .unwrap_or(VariableType::Any),
+            };
+            if let Some(default) = &default
+                && !var_type.accepts(default)
+            {
+                return Err(invalid(format!("default {default} is not a {var_type}")));
+            }
+            let description = match value.get("description") {
+                Some(d) => Some(
+                    d.as_str()
+                        .ok_or_else(|| invalid("'description' must be a string".to_string()))?
+                        .to_string(),
+                ),
+                None => None,
+            };
+            let required = match value.get("required") {
+                Some(r) => r
+                    .as_bool()
+                    .ok_or_else(|| invalid("'required' must be a boolean".to_string()))?,
+                None => false,
+            };
+            TemplateVariable {
+                name,
+                var_type,
+                default,
+                description,
+                required,
+            }
+        } else {
+            let default: JsonValue =
+                serde_yaml::from_value(value.clone()).map_err(|e| invalid(e.to_string()))?;
+            TemplateVariable {
+                name,
+                var_type: VariableType::of(&default),
+                default: Some(default),
+                description: None,
+                required: false,
+            }
+        };
+
+        variables.push(variable);
+    }
+
+    Ok(variables)
 }
 
 // Helper function to parse manifest files
@@ -225,42 +591,184 @@ fn parse_manifest_files(
             TemplateFileType::Static
         };
 
+        let when = match file_yaml.get("when") {
+            Some(serde_yaml::Value::String(expression)) => Some(expression.clone()),
+            Some(_) => {
+                return Err(TemplateError::manifest_parse_error(
+                    manifest_path,
+                    format!("'when' of '{source}' must be a string expression"),
+                ));
+            }
+            None => None,
+        };
+
         files.push(ManifestFile {
             source,
             target,
             file_type,
+            when,
         });
     }
 
     Ok(files)
 }
 
-// Helper function to parse hooks
-fn parse_hooks(
+// Helper function to parse `extends:` and the `remove:` section that goes with it
+fn parse_extends(
     yaml: &serde_yaml::Value,
-    parent_key: &str,
-    child_key: &str,
+    manifest_path: &str,
+) -> Result<Option<TemplateParent>, TemplateError> {
+    let remove = yaml.get("remove");
+    let Some(reference) = yaml.get("extends") else {
+        return match remove {
+            Some(_) => Err(TemplateError::manifest_parse_error(
+                manifest_path,
+                "'remove' is only allowed together with 'extends'",
+            )),
+            None => Ok(None),
+        };
+    };
+
+    let reference = reference.as_str().ok_or_else(|| {
+        TemplateError::manifest_parse_error(manifest_path, "'extends' must be a string")
+    })?;
+    let mut parent = TemplateParent::parse(reference)
+        .map_err(|e| TemplateError::manifest_parse_error(manifest_path, e))?;
+
+    if let Some(remove) = remove {
+        if !remove.is_mapping() {
+            return Err(TemplateError::manifest_parse_error(
+                manifest_path,
+                "'remove' must be a mapping of 'files', 'variables' and 'hooks'",
+            ));
+        }
+        let list = |key: &str| match remove.get(key) {
+            Some(value) => parse_string_list(value, &format!("remove.{key}"), manifest_path),
+            None => Ok(Vec::new()),
+        };
+        parent.remove.files = list("files")?;
+        parent.remove.variables = list("variables")?;
+        parent.remove.hooks = list("hooks")?;
+    }
+
+    Ok(Some(parent))
+}
+
+// Helper function to parse a list of strings
+fn parse_string_list(
+    value: &serde_yaml::Value,
+    key: &str,
+    manifest_path: &str,
 ) -> Result<Vec<String>, TemplateError> {
-    let hooks_value = if child_key.is_empty() {
-        yaml.get(parent_key)
-    } else {
-        yaml.get(parent_key).and_then(|p| p.get(child_key))
+    let invalid = || {
+        TemplateError::manifest_parse_error(
+            manifest_path,
+            format!("'{key}' must be a list of strings"),
+        )
     };
+    value
+        .as_sequence()
+        .ok_or_else(invalid)?
+        .iter()
+        .map(|item| item.as_str().map(str::to_string).ok_or_else(invalid))
+        .collect()
+}
 
-    match hooks_value {
-        Some(serde_yaml::Value::String(s)) => Ok(vec![s.clone()]),
+// Helper function to parse a hook list: a command, or a list of commands
+// and hook mappings
+fn parse_hooks(
+    value: Option<&serde_yaml::Value>,
+    key: &str,
+    manifest_path: &str,
+) -> Result<Vec<TemplateHook>, TemplateError> {
+    match value {
+        None | Some(serde_yaml::Value::Null) => Ok(Vec::new()),
         Some(serde_yaml::Value::Sequence(seq)) => seq
             .iter()
-            .map(|v| {
-                v.as_str().map(|s| s.to_string()).ok_or_else(|| {
-                    TemplateError::InvalidManifest("hook must be a string".to_string())
-                })
-            })
+            .map(|hook| parse_hook(hook, key, manifest_path))
             .collect(),
-        _ => Ok(Vec::new()),
+        Some(hook) => Ok(vec![parse_hook(hook, key, manifest_path)?]),
     }
 }
 
+// Helper function to parse a single hook
+fn parse_hook(
+    value: &serde_yaml::Value,
+    key: &str,
+    manifest_path: &str,
+) -> Result<TemplateHook, TemplateError> {
+    let invalid = |reason: String| TemplateError::manifest_parse_error(manifest_path, reason);
+
+    if let Some(command) = value.as_str() {
+        return Ok(TemplateHook::new(command));
+    }
+    if !value.is_mapping() {
+        return Err(invalid(format!(
+            "'{key}' entries must be commands or mappings with a 'command'"
+        )));
+    }
+
+    let command = value
+        .get("command")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| invalid(format!("'{key}' entry is missing 'command'")))?;
+    let mut hook = TemplateHook::new(command);
+
+    if let Some(timeout) = value.get("timeout") {
+        hook.timeout_secs = timeout.as_u64().filter(|secs| *secs > 0).ok_or_else(|| {
+            invalid(format!(
+                "'timeout' of hook '{command}' must be a positive number of seconds"
+            ))
+        })?;
+    }
+    if let Some(continue_on_error) = value.get("continue_on_error") {
+        hook.continue_on_error = continue_on_error.as_bool().ok_or_else(|| {
+            invalid(format!(
+                "'continue_on_error' of hook '{command}' must be true or false"
+            ))
+        })?;
+    }
+    if let Some(env) = value.get("env") {
+        let env = env
+            .as_mapping()
+            .ok_or_else(|| invalid(format!("'env' of hook '{command}' must be a mapping")))?;
+        for (name, value) in env {
+            let name = name.as_str().ok_or_else(|| {
+                invalid(format!("'env' of hook '{command}' must have string names"))
+            })?;
+            let value = match value {
+                serde_yaml::Value::String(s) => s.clone(),
+                serde_yaml::Value::Number(n) => n.to_string(),
+                serde_yaml::Value::Bool(b) => b.to_string(),
+                _ => {
+                    return Err(invalid(format!(
+                        "'env.{name}' of hook '{command}' must be a string, number or boolean"
+                    )));
+                }
+            };
+            hook.env.insert(name.to_string(), value);
+        }
+    }
+    match value.get("os") {
+        Some(serde_yaml::Value::String(os)) => hook.os = vec![os.clone()],
+        Some(os) => {
+            hook.os = parse_string_list(os, &format!("os of hook '{command}'"), manifest_path)?
+        }
+        None => {}
+    }
+
+    Ok(hook)
+}
+
+// Check that a manifest path stays inside the output directory
+fn is_relative_within(path: &str) -> bool {
+    use std::path::{Component, Path};
+
+    Path::new(path)
+        .components()
+        .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+}
+
 // Check if a file is a configuration file based on its name/extension
 fn is_configuration_file(source: &str) -> bool {
     use std::path::Path;
@@ -302,3 +810,272 @@ fn is_configuration_file(source: &str) -> bool {
 
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const MANIFEST_HEADER: &str = r#"
+name: test-template
+version: "1.0.0"
+protocol: mcp
+role: server
+language: rust
+"#;
+
+    fn manifest_with(extra: &str) -> Result<TemplateManifest, TemplateError> {
+        TemplateManifest::from_yaml(&format!("{MANIFEST_HEADER}{extra}"), "mcp/server/rust")
+    }
+
+    #[test]
+    fn test_variable_schema_parsing() {
+        let manifest = manifest_with(
+            r#"
+variables:
+  default_port: 3000
+  docker:
+    type: boolean
+    default: false
+    description: "Emit a Dockerfile"
+  api_key_env:
+    type: string
+    required: true
+"#,
+        )
+        .unwrap();
+
+        let names: Vec<_> = manifest
+            .variable_schema
+            .iter()
+            .map(|v| v.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["default_port", "docker", "api_key_env"]);
+
+        let port = &manifest.variable_schema[0];
+        assert_eq!(port.var_type, VariableType::Integer);
+        assert_eq!(port.default, Some(json!(3000)));
+        assert!(!port.required);
+
+        let docker = &manifest.variable_schema[1];
+        assert_eq!(docker.var_type, VariableType::Boolean);
+        assert_eq!(docker.description.as_deref(), Some("Emit a Dockerfile"));
+
+        let api_key = &manifest.variable_schema[2];
+        assert!(api_key.required);
+        assert_eq!(api_key.default, None);
+
+        // Only variables with defaults populate the defaults map
+        assert_eq!(manifest.variables.len(), 2);
+        assert_eq!(manifest.variables["docker"], json!(false));
+    }
+
+    #[test]
+    fn test_variable_schema_rejects_bad_declarations() {
+        assert!(manifest_with("variables:\n  port:\n    type: colour\n").is_err());
+        assert!(
+            manifest_with("variables:\n  port:\n    type: integer\n    default: abc\n").is_err()
+        );
+        assert!(manifest_with("variables:\n  flag:\n    required: \"yes\"\n").is_err());
+        assert!(manifest_with("variables:\n  - not-a-mapping\n").is_err());
+    }
+
+    #[test]
+    fn test_mapping_default_without_schema_keys_is_shorthand() {
+        let manifest = manifest_with("variables:\n  labels:\n    team: core\n").unwrap();
+
+        let labels = &manifest.variable_schema[0];
+        assert_eq!(labels.var_type, VariableType::Object);
+        assert_eq!(labels.default, Some(json!({"team": "core"})));
+    }
+
+    #[test]
+    fn test_resolve_variables() {
+        let manifest = manifest_with(
+            r#"
+variables:
+  port:
+    type: integer
+    default: 8080
+  name:
+    type: string
+    required: true
+"#,
+        )
+        .unwrap();
+
+        // Defaults fill gaps and supplied values are kept
+        let mut vars = HashMap::from([("name".to_string(), json!("petstore"))]);
+        manifest.resolve_variables(&mut vars).unwrap();
+        assert_eq!(vars["port"], json!(8080));
+        assert_eq!(vars["name"], json!("petstore"));
+
+        // Missing required variables and type mismatches are both reported
+        let mut vars = HashMap::from([("port".to_string(), json!("eighty"))]);
+        let err = manifest
+            .resolve_variables(&mut vars)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("'port' must be integer"));
+        assert!(err.contains("'name' is required"));
+    }
+
+    #[test]
+    fn test_variable_type_accepts() {
+        assert!(VariableType::Number.accepts(&json!(1)));
+        assert!(VariableType::Number.accepts(&json!(1.5)));
+        assert!(!VariableType::Integer.accepts(&json!(1.5)));
+        assert!(VariableType::Any.accepts(&json!(null)));
+        assert!(!VariableType::Array.accepts(&json!({})));
+    }
+
+    #[test]
+    fn test_partials() {
+        let manifest = manifest_with(
+            r#"
+files:
+  - source: "_listed.rs.tera"
+    destination: "src/listed.rs"
+partials:
+  - shared/
+  - macros.tera
+"#,
+        )
+        .unwrap();
+        assert_eq!(manifest.partials, vec!["shared/", "macros.tera"]);
+
+        assert!(manifest.is_partial("_partials/struct.tera"));
+        assert!(manifest.is_partial("src/_macros.tera"));
+        assert!(manifest.is_partial("shared/struct.tera"));
+        assert!(manifest.is_partial("macros.tera"));
+        assert!(!manifest.is_partial("shared_utils/struct.tera"));
+        assert!(!manifest.is_partial("_partials/README.md"));
+        // Listed files are always output files
+        assert!(!manifest.is_partial("_listed.rs.tera"));
+
+        assert!(manifest_with("partials: shared\n").is_err());
+    }
+
+    #[test]
+    fn test_when_and_required_directories() {
+        let manifest = manifest_with(
+            r#"
+files:
+  - source: "auth.rs.tera"
+    destination: "src/auth.rs"
+    when: "api_security_schemes"
+  - source: "main.rs.tera"
+    destination: "src/main.rs"
+required_directories:
+  - src
+  - src/handlers
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            manifest.files[0].when.as_deref(),
+            Some("api_security_schemes")
+        );
+        assert_eq!(manifest.files[1].when, None);
+        assert_eq!(manifest.required_directories, vec!["src", "src/handlers"]);
+
+        let when_bool = "files:\n  - source: a.tera\n    destination: a\n    when: true\n";
+        assert!(manifest_with(when_bool).is_err());
+        assert!(manifest_with("required_directories:\n  - ../outside\n").is_err());
+        assert!(manifest_with("required_directories:\n  - /etc\n").is_err());
+    }
+
+    #[test]
+    fn test_extends() {
+        // Identity comes from an embedded parent path when omitted
+        let manifest = TemplateManifest::from_yaml(
+            r#"
+name: custom
+version: "1.0.0"
+extends: mcp/client/rust@0.1
+remove:
+  files: [Dockerfile]
+"#,
+            "custom",
+        )
+        .unwrap();
+        assert_eq!(manifest.protocol, Protocol::Mcp);
+        assert_eq!(manifest.role, Role::Client);
+        assert_eq!(manifest.language, Language::Rust);
+        let parent = manifest.extends.unwrap();
+        assert_eq!(parent.path, "mcp/client/rust");
+        assert_eq!(parent.remove.files, vec!["Dockerfile"]);
+
+        // Filesystem parents need an explicit identity
+        let fs_parent = "name: custom\nversion: \"1.0.0\"\nextends: ../base\n";
+        assert!(TemplateManifest::from_yaml(fs_parent, "custom").is_err());
+
+        assert!(manifest_with("remove:\n  files: [a]\n").is_err());
+        assert!(manifest_with("extends: mcp/server/rust\nremove: [a]\n").is_err());
+        assert!(manifest_with("extends: [mcp]\n").is_err());
+    }
+
+    #[test]
+    fn test_hooks() {
+        let manifest = manifest_with(
+            r#"
+hooks:
+  pre_generate: ./hooks/check-tools.sh
+  post_generate:
+    - cargo fmt
+    - command: cargo check
+      timeout: 120
+      continue_on_error: true
+      env:
+        RUSTFLAGS: -Dwarnings
+        CARGO_TERM_COLOR: never
+    - command: chmod +x run.sh
+      os: [linux, macos]
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            manifest.pre_generate_hooks,
+            vec![TemplateHook::new("./hooks/check-tools.sh")]
+        );
+
+        let [fmt, check, chmod] = manifest.post_generate_hooks.as_slice() else {
+            panic!("expected three post_generate hooks");
+        };
+        assert_eq!(fmt, &TemplateHook::new("cargo fmt"));
+        assert_eq!(fmt.timeout_secs, DEFAULT_HOOK_TIMEOUT_SECS);
+        assert_eq!(check.timeout_secs, 120);
+        assert!(check.continue_on_error);
+        assert_eq!(check.env["RUSTFLAGS"], "-Dwarnings");
+        assert_eq!(chmod.os, vec!["linux", "macos"]);
+
+        // The older top-level key still works
+        let legacy = manifest_with("post_generate_hooks: [cargo fmt]\n").unwrap();
+        assert_eq!(legacy.post_generate_hooks[0].command, "cargo fmt");
+
+        assert!(manifest_with("hooks:\n  post_generate:\n    - timeout: 5\n").is_err());
+        assert!(
+            manifest_with("hooks:\n  post_generate:\n    - command: x\n      timeout: 0\n")
+                .is_err()
+        );
+        assert!(
+            manifest_with("hooks:\n  pre_generate:\n    - command: x\n      env: [A]\n").is_err()
+        );
+    }
+
+    #[test]
+    fn test_hook_os_condition() {
+        let mut hook = TemplateHook::new("make");
+        assert!(hook.runs_on_current_os());
+
+        hook.os = vec![std::env::consts::OS.to_string()];
+        assert!(hook.runs_on_current_os());
+
+        hook.os = vec!["unix".to_string()];
+        assert_eq!(hook.runs_on_current_os(), cfg!(unix));
+
+        hook.os = vec!["plan9".to_string()];
+        assert!(!hook.runs_on_current_os());
+    }
+}
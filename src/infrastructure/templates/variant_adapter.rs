@@ -0,0 +1,83 @@
+//! Adapter that pins a TemplateDiscovery to a named variant
+//!
+//! This adapter is used when `--template` names a variant such as
+//! `rust-minimal` rather than a language. The generation domain still asks
+//! for a template by protocol, role and language; the variant name decides
+//! which of the templates for that protocol and role is loaded.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::generation::Language;
+use crate::infrastructure::{Template, TemplateDiscovery, TemplateError};
+use crate::protocols::{Protocol, Role};
+
+/// Adapter that discovers the variant `name` whatever language is requested
+pub struct TemplateVariantDiscoveryAdapter<T: TemplateDiscovery> {
+    inner: Arc<T>,
+    name: String,
+}
+
+impl<T: TemplateDiscovery> TemplateVariantDiscoveryAdapter<T> {
+    pub fn new(inner: Arc<T>, name: impl Into<String>) -> Self {
+        Self {
+            inner,
+            name: name.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl<T: TemplateDiscovery> TemplateDiscovery for TemplateVariantDiscoveryAdapter<T> {
+    async fn discover(
+        &self,
+        protocol: Protocol,
+        role: Role,
+        _language: Language,
+    ) -> Result<Template, TemplateError> {
+        // The language was taken from the variant's manifest in the first place
+        self.inner.discover_named(protocol, role, &self.name).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::EmbeddedTemplateRepository;
+
+    #[tokio::test]
+    async fn test_adapter_loads_named_variant() {
+        let adapter = TemplateVariantDiscoveryAdapter::new(
+            Arc::new(EmbeddedTemplateRepository::new()),
+            "rust-minimal",
+        );
+        let template = adapter
+            .discover(Protocol::Mcp, Role::Server, Language::Rust)
+            .await
+            .unwrap();
+        assert_eq!(template.manifest.path, "mcp/server/rust-minimal");
+        assert_eq!(template.manifest.language, Language::Rust);
+
+        // Manifest names work as well
+        let by_name = TemplateVariantDiscoveryAdapter::new(
+            Arc::new(EmbeddedTemplateRepository::new()),
+            "mcp-rust-server",
+        );
+        let template = by_name
+            .discover(Protocol::Mcp, Role::Server, Language::Rust)
+            .await
+            .unwrap();
+        assert_eq!(template.manifest.path, "mcp/server/rust");
+
+        let missing = TemplateVariantDiscoveryAdapter::new(
+            Arc::new(EmbeddedTemplateRepository::new()),
+            "rust-nonexistent",
+        );
+        assert!(matches!(
+            missing
+                .discover(Protocol::Mcp, Role::Server, Language::Rust)
+                .await,
+            Err(TemplateError::TemplateNotFound(_))
+        ));
+    }
+}
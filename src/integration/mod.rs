@@ -4,236 +4,492 @@
 //! with the new domain-driven architecture.
 
 use crate::application::{
-    GenerateClientRequest, GenerateServerRequest, generate_client::GenerateClientUseCase,
+    GenerateAgentRequest, GenerateBridgeRequest, GenerateClientRequest, GenerateCustomRequest,
+    GenerateServerRequest, GenerationLock, GenerationReport, HookRunner, LOCKFILE_NAME,
+    generate_agent::GenerateAgentUseCase, generate_bridge::GenerateBridgeUseCase,
+    generate_client::GenerateClientUseCase, generate_custom::GenerateCustomUseCase,
     generate_server::GenerateServerUseCase,
 };
-use crate::generation::Language;
-use crate::protocols::Protocol;
-use std::path::PathBuf;
+use crate::generation::{Language, McpServerSource, TemplateDiscoveryAdapter};
+use crate::infrastructure::{
+    EmbeddedTemplateRepository, FileSystemTemplateLoader, TemplateFetcher, TemplateLoader,
+    TemplateLoaderDiscoveryAdapter, TemplateSource, TemplateVariantDiscoveryAdapter,
+};
+use crate::protocols::{Protocol, Role};
+
+mod scaffold_command;
+pub use scaffold_command::{scaffold_command, scaffold_params, template_dirs_from_args};
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Runner for the template's hooks, unless `--no-hooks` was given
+fn hook_runner(no_hooks: bool) -> Option<Arc<dyn HookRunner>> {
+    if no_hooks {
+        return None;
+    }
+    #[cfg(feature = "shell-hooks")]
+    {
+        Some(Arc::new(crate::infrastructure::ShellHookRunner::new()))
+    }
+    #[cfg(not(feature = "shell-hooks"))]
+    {
+        tracing::debug!("Built without the shell-hooks feature; template hooks are skipped");
+        None
+    }
+}
 
-/// Server generation parameters from CLI
-pub struct ServerParams {
+/// Scaffold parameters from the CLI, for any protocol and role
+pub struct ScaffoldParams {
+    pub protocol: Protocol,
+    pub role: Role,
     pub project_name: String,
-    pub schema_path: String,
     pub template: String,
     pub template_dir: Option<PathBuf>,
+    /// Archive or git repository to fetch the template from, see [`TemplateSource`]
+    pub template_source: Option<String>,
     pub output_dir: Option<PathBuf>,
-    pub port: Option<u16>,
-    pub log_file: Option<String>,
-    pub base_url: Option<reqwest::Url>,
+    /// Path or URL to an OpenAPI schema; required for MCP servers
+    pub schema_path: Option<String>,
+    /// MCP server a bridge lists the tools of and connects to
+    pub mcp_server: Option<McpServerSource>,
+    /// Template variables, including those set by the role's options
+    pub variables: HashMap<String, JsonValue>,
+    /// Skip the template's pre- and post-generation hooks
+    pub no_hooks: bool,
 }
 
-/// Client generation parameters from CLI
-pub struct ClientParams {
-    pub project_name: String,
-    pub template: String,
-    pub template_dir: Option<PathBuf>,
-    pub output_dir: Option<PathBuf>,
+/// Parse a `--var KEY=VALUE` argument, reading the value as JSON when possible
+pub fn parse_template_var(arg: &str) -> Result<(String, JsonValue), String> {
+    let (key, raw) = arg
+        .split_once('=')
+        .ok_or_else(|| format!("expected KEY=VALUE, got '{arg}'"))?;
+    let key = key.trim();
+    if key.is_empty() {
+        return Err(format!("missing variable name in '{arg}'"));
+    }
+
+    Ok((key.to_string(), parse_template_value(raw)))
 }
 
-/// Integration service for MCP server generation
-pub struct McpServerIntegration;
-
-impl McpServerIntegration {
-    pub async fn generate(params: ServerParams) -> anyhow::Result<()> {
-        // Parse language
-        let language = params
-            .template
-            .parse::<Language>()
-            .map_err(|_| anyhow::anyhow!("Invalid language: {}", params.template))?;
-
-        // Resolve output directory
-        let output_dir = if let Some(dir) = params.output_dir {
-            dir.join(&params.project_name)
-        } else {
-            std::env::current_dir()?.join(&params.project_name)
-        };
-
-        // Create request with options
-        let mut options = std::collections::HashMap::new();
-
-        // Add base_url as base_api_url for template compatibility
-        if let Some(ref base_url) = params.base_url {
-            options.insert(
-                "base_api_url".to_string(),
-                serde_json::json!(base_url.to_string()),
-            );
-        }
+/// Read a template variable value as JSON, falling back to a plain string
+pub fn parse_template_value(raw: &str) -> JsonValue {
+    // Bare words such as `--var name=petstore` fall back to plain strings
+    serde_json::from_str(raw).unwrap_or_else(|_| JsonValue::String(raw.to_string()))
+}
 
-        // Add port if provided
-        if let Some(port) = params.port {
-            options.insert("server_port".to_string(), serde_json::json!(port));
-        }
+/// Merge variables from an optional vars file with `--var` arguments, which take precedence
+pub fn collect_template_vars(
+    vars: &[(String, JsonValue)],
+    vars_file: Option<&Path>,
+) -> anyhow::Result<HashMap<String, JsonValue>> {
+    let mut variables = HashMap::new();
+
+    if let Some(path) = vars_file {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("Failed to read vars file {}: {}", path.display(), e))?;
+        // JSON is a subset of YAML, so one parser covers both formats
+        let file_vars: Option<HashMap<String, JsonValue>> = serde_yaml::from_str(&content)
+            .map_err(|e| anyhow::anyhow!("Invalid vars file {}: {}", path.display(), e))?;
+        variables.extend(file_vars.unwrap_or_default());
+    }
 
-        // Add log file if provided
-        if let Some(ref log_file) = params.log_file {
-            options.insert("log_file".to_string(), serde_json::json!(log_file));
-        }
+    variables.extend(vars.iter().cloned());
+    Ok(variables)
+}
 
-        let request = GenerateServerRequest {
-            protocol: Protocol::Mcp,
-            language,
-            project_name: params.project_name,
-            schema_path: Some(params.schema_path),
-            output_dir,
-            options,
-        };
-
-        // Create dependencies
-        let protocol_registry = std::sync::Arc::new(
-            crate::protocols::ProtocolRegistry::with_defaults()
-                .map_err(|e| anyhow::anyhow!("Failed to create protocol registry: {}", e))?,
-        );
+/// Resolve `--template-source` to a template directory
+///
+/// Archives and git repositories are fetched into the template cache, pinned
+/// by the project's lockfile when it was written for the same source; the
+/// lockfile to write afterwards is returned alongside the directory.
+async fn fetch_template_source(
+    source: &str,
+    project_dir: &Path,
+    protocol: Protocol,
+    role: &Role,
+    template: &str,
+) -> anyhow::Result<(PathBuf, Option<GenerationLock>)> {
+    let parsed: TemplateSource = source.parse()?;
+    if let TemplateSource::FileSystem(dir) = parsed {
+        return Ok((dir, None));
+    }
 
-        let openapi_loader =
-            std::sync::Arc::new(crate::infrastructure::openapi::CompositeOpenApiLoader::new())
-                as std::sync::Arc<dyn crate::generation::OpenApiLoader>;
-
-        // Create template discovery based on whether template_dir is provided
-        let template_discovery: std::sync::Arc<dyn crate::generation::TemplateDiscovery> =
-            if let Some(ref template_dir) = params.template_dir {
-                // Use the new TemplateLoader approach for filesystem templates
-                let loader =
-                    std::sync::Arc::new(crate::infrastructure::FileSystemTemplateLoader::new());
-                let infrastructure_discovery = std::sync::Arc::new(
-                    crate::infrastructure::TemplateLoaderDiscoveryAdapter::new(
-                        loader,
-                        template_dir.clone(),
-                    ),
-                );
-                // Wrap with the generation adapter
-                std::sync::Arc::new(crate::generation::TemplateDiscoveryAdapter::new(
-                    infrastructure_discovery,
-                ))
-            } else {
-                // Use embedded templates with adapter
-                std::sync::Arc::new(crate::generation::TemplateDiscoveryAdapter::new(
-                    std::sync::Arc::new(crate::infrastructure::EmbeddedTemplateRepository::new()),
-                ))
-            };
+    let pinned = GenerationLock::read(project_dir)?.and_then(|lock| lock.pin_for(source));
+    let fetched = TemplateFetcher::new()
+        .fetch(&parsed, pinned.as_ref())
+        .await?;
+    let template_dir = fetched.template_dir(protocol, role, template)?;
+    tracing::info!(
+        "Using template {} from {source} at {}",
+        template_dir.display(),
+        fetched.pin
+    );
+    Ok((
+        template_dir,
+        Some(GenerationLock::new(source, &fetched.pin)),
+    ))
+}
 
-        // Select appropriate renderer based on protocol and role
-        let template_renderer = crate::infrastructure::generation::select_renderer(
-            Protocol::Mcp,
-            crate::protocols::Role::Server,
-        );
+/// Resolve `--template` to the language to generate and, for embedded
+/// templates, the variant to load
+///
+/// A language name selects the template named after it. Any other name is a
+/// variant such as `rust-minimal`, looked up by directory or manifest name
+/// among the embedded templates, and the language comes from its manifest;
+/// with a template directory, from that directory's manifest.
+async fn select_template(
+    template: &str,
+    protocol: Protocol,
+    role: &Role,
+    template_dir: Option<&Path>,
+) -> anyhow::Result<(Language, Option<String>)> {
+    if let Ok(language) = template.parse::<Language>() {
+        return Ok((language, None));
+    }
 
-        let generation_orchestrator = std::sync::Arc::new(
-            crate::generation::GenerationOrchestrator::new(
-                template_discovery,
-                std::sync::Arc::new(crate::infrastructure::generation::context_builders::registry::CompositeContextBuilder::default()),
-                template_renderer,
-                std::sync::Arc::new(crate::infrastructure::generation::CompositePostProcessor::new()),
-            )
-        );
+    if let Some(dir) = template_dir {
+        let loaded = FileSystemTemplateLoader::new().load_template(dir).await?;
+        return Ok((loaded.manifest.language, None));
+    }
 
-        let output_service =
-            std::sync::Arc::new(crate::infrastructure::output::FileSystemOutputService::new())
-                as std::sync::Arc<dyn crate::application::OutputService>;
+    let repository = EmbeddedTemplateRepository::new();
+    match repository.find_variant(protocol, role, template) {
+        Some(manifest) => Ok((manifest.language, Some(template.to_string()))),
+        None => {
+            let available: Vec<_> = repository
+                .variants(protocol, role)
+                .iter()
+                .map(|manifest| manifest.variant().to_string())
+                .collect();
+            anyhow::bail!(
+                "Unknown template '{template}' for {protocol} {role}; available: {}",
+                available.join(", ")
+            )
+        }
+    }
+}
 
-        // Execute use case
-        let use_case = GenerateServerUseCase::new(
-            protocol_registry,
-            openapi_loader,
-            generation_orchestrator,
-            output_service,
-        );
+/// Template discovery for a template directory, an embedded variant, or the
+/// embedded template named after the requested language
+fn template_discovery(
+    template_dir: Option<PathBuf>,
+    variant: Option<String>,
+) -> Arc<dyn crate::generation::TemplateDiscovery> {
+    let embedded = Arc::new(EmbeddedTemplateRepository::new());
+    match (template_dir, variant) {
+        (Some(template_dir), _) => {
+            // Use the TemplateLoader approach for filesystem templates
+            let loader = Arc::new(FileSystemTemplateLoader::new());
+            Arc::new(TemplateDiscoveryAdapter::new(Arc::new(
+                TemplateLoaderDiscoveryAdapter::new(loader, template_dir),
+            )))
+        }
+        (None, Some(variant)) => Arc::new(TemplateDiscoveryAdapter::new(Arc::new(
+            TemplateVariantDiscoveryAdapter::new(embedded, variant),
+        ))),
+        (None, None) => Arc::new(TemplateDiscoveryAdapter::new(embedded)),
+    }
+}
 
-        use_case
-            .execute(request)
-            .await
-            .map_err(|e| anyhow::anyhow!("Failed to generate server: {}", e))?;
+/// Generate a project for `params.protocol` and `params.role` with the role's use case
+///
+/// Roles without a dedicated use case, such as custom roles defined by a
+/// template, are generated with the custom use case.
+pub async fn scaffold(params: ScaffoldParams) -> anyhow::Result<GenerationReport> {
+    let protocol = params.protocol;
+    let role = params.role;
+
+    // Resolve output directory
+    let output_dir = if let Some(dir) = params.output_dir {
+        dir.join(&params.project_name)
+    } else {
+        std::env::current_dir()?.join(&params.project_name)
+    };
+
+    let (template_dir, lock) = match &params.template_source {
+        Some(source) => {
+            let (dir, lock) =
+                fetch_template_source(source, &output_dir, protocol, &role, &params.template)
+                    .await?;
+            (Some(dir), lock)
+        }
+        None => (params.template_dir, None),
+    };
+    let (language, variant) =
+        select_template(&params.template, protocol, &role, template_dir.as_deref()).await?;
+
+    // Create dependencies
+    let protocol_registry = std::sync::Arc::new(
+        crate::protocols::ProtocolRegistry::with_defaults()
+            .map_err(|e| anyhow::anyhow!("Failed to create protocol registry: {}", e))?,
+    );
+
+    let openapi_loader =
+        std::sync::Arc::new(crate::infrastructure::openapi::CompositeOpenApiLoader::new())
+            as std::sync::Arc<dyn crate::generation::OpenApiLoader>;
+
+    let template_discovery = template_discovery(template_dir, variant);
+
+    // Select appropriate renderer based on protocol and role
+    let template_renderer =
+        crate::infrastructure::generation::select_renderer(protocol, role.clone());
+
+    let generation_orchestrator = std::sync::Arc::new(
+        crate::generation::GenerationOrchestrator::new(
+            template_discovery,
+            std::sync::Arc::new(crate::infrastructure::generation::context_builders::registry::CompositeContextBuilder::default()),
+            template_renderer,
+            std::sync::Arc::new(crate::infrastructure::generation::CompositePostProcessor::new()),
+        )
+    );
+
+    let output_service =
+        std::sync::Arc::new(crate::infrastructure::output::FileSystemOutputService::new())
+            as std::sync::Arc<dyn crate::application::OutputService>;
+
+    let hook_runner = hook_runner(params.no_hooks);
+    let options = params.variables;
+    let project_name = params.project_name;
+    let failed = |e: crate::application::ApplicationError| {
+        anyhow::anyhow!("Failed to generate {}: {}", role, e)
+    };
+
+    // Execute the role's use case
+    let (metadata, output_path, mut artifacts, diagnostics) = match &role {
+        Role::Server => {
+            let use_case = GenerateServerUseCase::new(
+                protocol_registry,
+                openapi_loader,
+                generation_orchestrator,
+                output_service,
+            );
+            let use_case = match hook_runner {
+                Some(runner) => use_case.with_hook_runner(runner),
+                None => use_case,
+            };
+            let response = use_case
+                .execute(GenerateServerRequest {
+                    protocol,
+                    language,
+                    project_name,
+                    schema_path: params.schema_path,
+                    output_dir,
+                    options,
+                })
+                .await
+                .map_err(failed)?;
+            (
+                response.metadata,
+                response.output_path,
+                response.artifacts,
+                response.diagnostics,
+            )
+        }
+        Role::Client => {
+            let use_case = GenerateClientUseCase::new(
+                protocol_registry,
+                generation_orchestrator,
+                output_service,
+            );
+            let use_case = match hook_runner {
+                Some(runner) => use_case.with_hook_runner(runner),
+                None => use_case,
+            };
+            let response = use_case
+                .execute(GenerateClientRequest {
+                    protocol,
+                    language,
+                    project_name,
+                    output_dir,
+                    options,
+                })
+                .await
+                .map_err(failed)?;
+            (
+                response.metadata,
+                response.output_path,
+                response.artifacts,
+                response.diagnostics,
+            )
+        }
+        Role::Agent => {
+            let use_case = GenerateAgentUseCase::new(
+                protocol_registry,
+                generation_orchestrator,
+                output_service,
+            );
+            let use_case = match hook_runner {
+                Some(runner) => use_case.with_hook_runner(runner),
+                None => use_case,
+            };
+            let response = use_case
+                .execute(GenerateAgentRequest {
+                    protocol,
+                    language,
+                    project_name,
+                    output_dir,
+                    options,
+                })
+                .await
+                .map_err(failed)?;
+            (
+                response.metadata,
+                response.output_path,
+                response.artifacts,
+                response.diagnostics,
+            )
+        }
+        Role::Bridge => {
+            let mcp_tool_loader =
+                std::sync::Arc::new(crate::infrastructure::mcp::McpToolLister::new())
+                    as std::sync::Arc<dyn crate::generation::McpToolLoader>;
+            let use_case = GenerateBridgeUseCase::new(
+                protocol_registry,
+                openapi_loader,
+                mcp_tool_loader,
+                generation_orchestrator,
+                output_service,
+            );
+            let use_case = match hook_runner {
+                Some(runner) => use_case.with_hook_runner(runner),
+                None => use_case,
+            };
+            let response = use_case
+                .execute(GenerateBridgeRequest {
+                    protocol,
+                    language,
+                    project_name,
+                    mcp_server: params.mcp_server,
+                    schema_path: params.schema_path,
+                    output_dir,
+                    options,
+                })
+                .await
+                .map_err(failed)?;
+            tracing::info!("Exposed {} MCP tools as skills", response.tools_count);
+            (
+                response.metadata,
+                response.output_path,
+                response.artifacts,
+                response.diagnostics,
+            )
+        }
+        _ => {
+            let use_case = GenerateCustomUseCase::new(
+                protocol_registry,
+                openapi_loader,
+                generation_orchestrator,
+                output_service,
+            );
+            let use_case = match hook_runner {
+                Some(runner) => use_case.with_hook_runner(runner),
+                None => use_case,
+            };
+            let response = use_case
+                .execute(GenerateCustomRequest {
+                    protocol,
+                    role: role.clone(),
+                    language,
+                    project_name,
+                    schema_path: params.schema_path,
+                    output_dir,
+                    options,
+                })
+                .await
+                .map_err(failed)?;
+            (
+                response.metadata,
+                response.output_path,
+                response.artifacts,
+                response.diagnostics,
+            )
+        }
+    };
 
-        Ok(())
+    if let Some(lock) = lock {
+        lock.write(&output_path)?;
+        artifacts.push(PathBuf::from(LOCKFILE_NAME));
     }
+
+    Ok(GenerationReport::new(
+        metadata.project_name,
+        protocol,
+        role,
+        language,
+        output_path,
+        artifacts,
+        diagnostics,
+    ))
 }
 
-/// Integration service for MCP client generation
-pub struct McpClientIntegration;
-
-impl McpClientIntegration {
-    pub async fn generate(params: ClientParams) -> anyhow::Result<()> {
-        // Parse language
-        let language = params
-            .template
-            .parse::<Language>()
-            .map_err(|_| anyhow::anyhow!("Invalid language: {}", params.template))?;
-
-        // Resolve output directory
-        let output_dir = if let Some(dir) = params.output_dir {
-            dir.join(&params.project_name)
-        } else {
-            std::env::current_dir()?.join(&params.project_name)
-        };
-
-        // Create request
-        let request = GenerateClientRequest {
-            protocol: Protocol::Mcp,
-            language,
-            project_name: params.project_name,
-            output_dir,
-            options: std::collections::HashMap::new(),
-        };
-
-        // Create dependencies
-        let protocol_registry = std::sync::Arc::new(
-            crate::protocols::ProtocolRegistry::with_defaults()
-                .map_err(|e| anyhow::anyhow!("Failed to create protocol registry: {}", e))?,
-        );
+// CustomDirTemplateDiscovery has been removed in favor of TemplateLoaderDiscoveryAdapter
 
-        // Create template discovery based on whether template_dir is provided
-        let template_discovery: std::sync::Arc<dyn crate::generation::TemplateDiscovery> =
-            if let Some(ref template_dir) = params.template_dir {
-                // Use the new TemplateLoader approach for filesystem templates
-                let loader =
-                    std::sync::Arc::new(crate::infrastructure::FileSystemTemplateLoader::new());
-                let infrastructure_discovery = std::sync::Arc::new(
-                    crate::infrastructure::TemplateLoaderDiscoveryAdapter::new(
-                        loader,
-                        template_dir.clone(),
-                    ),
-                );
-                // Wrap with the generation adapter
-                std::sync::Arc::new(crate::generation::TemplateDiscoveryAdapter::new(
-                    infrastructure_discovery,
-                ))
-            } else {
-                // Use embedded templates with adapter
-                std::sync::Arc::new(crate::generation::TemplateDiscoveryAdapter::new(
-                    std::sync::Arc::new(crate::infrastructure::EmbeddedTemplateRepository::new()),
-                ))
-            };
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
 
-        // Select appropriate renderer for client (uses default renderer)
-        let template_renderer = crate::infrastructure::generation::select_renderer(
-            Protocol::Mcp,
-            crate::protocols::Role::Client,
+    #[test]
+    fn test_parse_template_var_json_values() {
+        assert_eq!(
+            parse_template_var("port=8080").unwrap(),
+            ("port".to_string(), json!(8080))
         );
+        assert_eq!(
+            parse_template_var("docker=true").unwrap(),
+            ("docker".to_string(), json!(true))
+        );
+        assert_eq!(
+            parse_template_var(r#"features=["auth","metrics"]"#).unwrap(),
+            ("features".to_string(), json!(["auth", "metrics"]))
+        );
+    }
 
-        let generation_orchestrator = std::sync::Arc::new(
-            crate::generation::GenerationOrchestrator::new(
-                template_discovery,
-                std::sync::Arc::new(crate::infrastructure::generation::context_builders::registry::CompositeContextBuilder::default()),
-                template_renderer,
-                std::sync::Arc::new(crate::infrastructure::generation::CompositePostProcessor::new()),
-            )
+    #[test]
+    fn test_parse_template_var_string_fallback() {
+        assert_eq!(
+            parse_template_var("name=petstore").unwrap(),
+            ("name".to_string(), json!("petstore"))
         );
+        // Only the first '=' separates key and value
+        assert_eq!(
+            parse_template_var("query=a=b").unwrap(),
+            ("query".to_string(), json!("a=b"))
+        );
+        assert_eq!(
+            parse_template_var("empty=").unwrap(),
+            ("empty".to_string(), json!(""))
+        );
+    }
 
-        let output_service =
-            std::sync::Arc::new(crate::infrastructure::output::FileSystemOutputService::new())
-                as std::sync::Arc<dyn crate::application::OutputService>;
+    #[test]
+    fn test_parse_template_var_rejects_malformed() {
+        assert!(parse_template_var("no_equals").is_err());
+        assert!(parse_template_var("=value").is_err());
+    }
 
-        // Execute use case
-        let use_case =
-            GenerateClientUseCase::new(protocol_registry, generation_orchestrator, output_service);
+    #[test]
+    fn test_collect_template_vars_cli_overrides_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let vars_file = dir.path().join("vars.yaml");
+        std::fs::write(&vars_file, "port: 3000\nauthor: Jane\ntags: [a, b]\n").unwrap();
 
-        use_case
-            .execute(request)
-            .await
-            .map_err(|e| anyhow::anyhow!("Failed to generate client: {}", e))?;
+        let cli_vars = vec![("port".to_string(), json!(9000))];
+        let vars = collect_template_vars(&cli_vars, Some(&vars_file)).unwrap();
 
-        Ok(())
+        assert_eq!(vars["port"], json!(9000));
+        assert_eq!(vars["author"], json!("Jane"));
+        assert_eq!(vars["tags"], json!(["a", "b"]));
     }
-}
 
-// CustomDirTemplateDiscovery has been removed in favor of TemplateLoaderDiscoveryAdapter
+    #[test]
+    fn test_collect_template_vars_rejects_non_mapping_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let vars_file = dir.path().join("vars.yaml");
+        std::fs::write(&vars_file, "- just\n- a list\n").unwrap();
+
+        assert!(collect_template_vars(&[], Some(&vars_file)).is_err());
+    }
+}
@@ -0,0 +1,688 @@
+//! The `scaffold` command tree, built from the registered protocol handlers
+//! and the templates they can generate
+//!
+//! Each protocol with a handler gets a subcommand, with a subcommand for
+//! every role it has a template for: the embedded templates, and those in
+//! the `--template-dir` given on the command line. A role's options are the
+//! handler's [`ScaffoldOption`]s followed by a flag for each variable its
+//! templates declare, so a new protocol, role or template variable needs no
+//! change here.
+
+use super::{ScaffoldParams, collect_template_vars, parse_template_value, parse_template_var};
+use crate::generation::McpServerSource;
+use crate::infrastructure::{
+    EmbeddedTemplateRepository, FileSystemTemplateLoader, TemplateLoader, TemplateManifest,
+    TemplateRepository, VariableType,
+};
+use crate::protocols::{
+    Protocol, ProtocolHandler, ProtocolRegistry, Role, ScaffoldOption, ScaffoldTarget,
+    ScaffoldValue,
+};
+use anyhow::Context;
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use serde_json::Value as JsonValue;
+use std::collections::{BTreeMap, HashMap};
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+/// Id prefix of the arguments setting template variables
+const VARIABLE_ID_PREFIX: &str = "variable:";
+
+/// Flags every role has, which template variables cannot take over
+const COMMON_FLAGS: &[&str] = &[
+    "project-name",
+    "template",
+    "template-dir",
+    "template-source",
+    "output-dir",
+    "var",
+    "vars-file",
+    "no-hooks",
+    "report",
+    "help",
+];
+
+/// A template a role can be generated from
+struct RoleTemplate {
+    /// Value of `--template` selecting it
+    name: String,
+    manifest: TemplateManifest,
+}
+
+/// The `--template-dir` values on the command line
+///
+/// The command tree depends on them, so they are read before it is parsed.
+pub fn template_dirs_from_args(args: impl IntoIterator<Item = OsString>) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == "--template-dir" {
+            if let Some(dir) = args.next() {
+                dirs.push(PathBuf::from(dir));
+            }
+        } else if let Some(dir) = arg.to_str().and_then(|a| a.strip_prefix("--template-dir=")) {
+            dirs.push(PathBuf::from(dir));
+        }
+    }
+    dirs
+}
+
+/// Build the `scaffold` command for the default protocol handlers, the
+/// embedded templates and the templates in `template_dirs`
+pub async fn scaffold_command(template_dirs: &[PathBuf]) -> anyhow::Result<Command> {
+    let registry = ProtocolRegistry::with_defaults()
+        .map_err(|e| anyhow::anyhow!("Failed to create protocol registry: {}", e))?;
+
+    let mut templates = embedded_templates();
+    for dir in template_dirs {
+        match FileSystemTemplateLoader::new().load_template(dir).await {
+            Ok(template) => templates.push(RoleTemplate {
+                name: template.manifest.language.to_string(),
+                manifest: template.manifest,
+            }),
+            // Generating from the directory reports the error in full
+            Err(e) => tracing::warn!("Skipping template {}: {}", dir.display(), e),
+        }
+    }
+
+    let mut command = Command::new("scaffold")
+        .about("Scaffold servers and clients for various targets")
+        .subcommand_required(true)
+        .arg_required_else_help(true);
+    for handler in registry.handlers() {
+        let roles = roles_of(handler.as_ref(), &templates);
+        if roles.is_empty() {
+            continue;
+        }
+        let protocol = handler.protocol();
+        let mut protocol_command = Command::new(protocol.to_string())
+            .about(handler.description())
+            .subcommand_required(true)
+            .arg_required_else_help(true);
+        for (role, role_templates) in roles {
+            protocol_command =
+                protocol_command.subcommand(role_command(handler.as_ref(), &role, &role_templates));
+        }
+        command = command.subcommand(protocol_command);
+    }
+    Ok(command)
+}
+
+/// Read the parameters of the protocol and role chosen in `matches` of the
+/// `scaffold` command
+pub fn scaffold_params(matches: &ArgMatches) -> anyhow::Result<ScaffoldParams> {
+    let (protocol_name, protocol_matches) = matches.subcommand().context("Missing protocol")?;
+    let (role_name, matches) = protocol_matches.subcommand().context("Missing role")?;
+    let protocol: Protocol = protocol_name.parse()?;
+    let role: Role = role_name.parse()?;
+
+    let registry = ProtocolRegistry::with_defaults()
+        .map_err(|e| anyhow::anyhow!("Failed to create protocol registry: {}", e))?;
+    let handler = registry
+        .get(protocol)
+        .with_context(|| format!("No handler for {protocol}"))?;
+
+    // Options override `--var`, which overrides the vars file
+    let vars: Vec<(String, JsonValue)> = matches
+        .get_many::<(String, JsonValue)>("var")
+        .map(|vars| vars.cloned().collect())
+        .unwrap_or_default();
+    let mut variables = collect_template_vars(
+        &vars,
+        matches
+            .get_one::<PathBuf>("vars-file")
+            .map(PathBuf::as_path),
+    )?;
+    for id in matches.ids() {
+        if let Some(name) = id.as_str().strip_prefix(VARIABLE_ID_PREFIX)
+            && let Some(value) = matches.get_one::<JsonValue>(id.as_str())
+        {
+            variables.insert(name.to_string(), value.clone());
+        }
+    }
+
+    let mut schema_path = matches
+        .try_get_one::<String>("schema-path")
+        .ok()
+        .flatten()
+        .cloned();
+    let mut mcp_command = None;
+    let mut mcp_args = Vec::new();
+    let mut mcp_url = None;
+    for option in handler.scaffold_options(&role) {
+        match option.target {
+            ScaffoldTarget::Variable { name, kind } => {
+                let value = match kind {
+                    ScaffoldValue::Port => matches.get_one::<u16>(option.flag).map(|p| (*p).into()),
+                    ScaffoldValue::String | ScaffoldValue::Url => matches
+                        .get_one::<String>(option.flag)
+                        .map(|s| JsonValue::String(s.clone())),
+                };
+                if let Some(value) = value {
+                    variables.insert(name.to_string(), value);
+                }
+            }
+            ScaffoldTarget::SchemaPath { .. } => {
+                schema_path = matches.get_one::<String>(option.flag).cloned();
+            }
+            ScaffoldTarget::McpCommand => {
+                mcp_command = matches.get_one::<String>(option.flag).cloned();
+            }
+            ScaffoldTarget::McpArg => {
+                mcp_args = matches
+                    .get_many::<String>(option.flag)
+                    .map(|args| args.cloned().collect())
+                    .unwrap_or_default();
+            }
+            ScaffoldTarget::McpUrl => {
+                mcp_url = matches.get_one::<String>(option.flag).cloned();
+            }
+        }
+    }
+    let mcp_server = match (mcp_command, mcp_url) {
+        (Some(command), _) => Some(McpServerSource::Stdio {
+            command,
+            args: mcp_args,
+        }),
+        (None, Some(url)) => Some(McpServerSource::Sse { url }),
+        (None, None) => None,
+    };
+
+    Ok(ScaffoldParams {
+        protocol,
+        role,
+        project_name: matches
+            .get_one::<String>("project-name")
+            .cloned()
+            .context("Missing project name")?,
+        template: matches
+            .get_one::<String>("template")
+            .cloned()
+            .context("Missing template")?,
+        template_dir: matches.get_one::<PathBuf>("template-dir").cloned(),
+        template_source: matches.get_one::<String>("template-source").cloned(),
+        output_dir: matches.get_one::<PathBuf>("output-dir").cloned(),
+        schema_path,
+        mcp_server,
+        variables,
+        no_hooks: matches.get_flag("no-hooks"),
+    })
+}
+
+/// Every embedded template, with `extends` resolved so inherited variables
+/// get their flags too
+fn embedded_templates() -> Vec<RoleTemplate> {
+    let repository = EmbeddedTemplateRepository::new();
+    repository
+        .list_manifests()
+        .into_iter()
+        .filter_map(|manifest| {
+            let manifest = if manifest.extends.is_some() {
+                match repository.load(&manifest.path) {
+                    Ok(template) => template.manifest,
+                    Err(e) => {
+                        tracing::warn!("Skipping template {}: {}", manifest.path, e);
+                        return None;
+                    }
+                }
+            } else {
+                manifest
+            };
+            Some(RoleTemplate {
+                name: manifest.variant().to_string(),
+                manifest,
+            })
+        })
+        .collect()
+}
+
+/// The roles of `handler`'s protocol that have templates, the protocol's own
+/// roles first and then custom roles by name
+fn roles_of<'a>(
+    handler: &dyn ProtocolHandler,
+    templates: &'a [RoleTemplate],
+) -> Vec<(Role, Vec<&'a RoleTemplate>)> {
+    let protocol = handler.protocol();
+    let supported_roles = protocol.capabilities().supported_roles;
+
+    let mut roles: Vec<(Role, Vec<&RoleTemplate>)> = Vec::new();
+    for template in templates {
+        let manifest = &template.manifest;
+        if manifest.protocol != protocol || protocol.validate_role(&manifest.role).is_err() {
+            continue;
+        }
+        match roles.iter_mut().find(|(role, _)| role == &manifest.role) {
+            Some((_, role_templates)) => role_templates.push(template),
+            None => roles.push((manifest.role.clone(), vec![template])),
+        }
+    }
+    roles.sort_by_key(|(role, _)| {
+        match supported_roles
+            .iter()
+            .position(|supported| supported == role)
+        {
+            Some(position) => (position, String::new()),
+            None => (supported_roles.len(), role.to_string()),
+        }
+    });
+    roles
+}
+
+/// The command generating `role`, with the handler's options and a flag for
+/// each variable of its templates
+fn role_command(
+    handler: &dyn ProtocolHandler,
+    role: &Role,
+    templates: &[&RoleTemplate],
+) -> Command {
+    let protocol = handler.protocol();
+    let about = handler
+        .role_description(role)
+        .map(str::to_string)
+        .or_else(|| {
+            templates
+                .iter()
+                .find_map(|t| t.manifest.description.clone())
+        })
+        .unwrap_or_else(|| format!("Generate {} {role}", protocol.to_string().to_uppercase()));
+
+    let names: Vec<&str> = templates.iter().map(|t| t.name.as_str()).collect();
+    let default_template = if names.contains(&"rust") {
+        "rust"
+    } else {
+        names[0]
+    };
+
+    let mut command = Command::new(role.to_string())
+        .about(about)
+        .arg(
+            Arg::new("project-name")
+                .long("project-name")
+                .value_name("PROJECT_NAME")
+                .default_value(format!("agenterra_{protocol}_{role}").replace('-', "_"))
+                .help(format!(
+                    "Project name for the generated {} {role}",
+                    protocol.to_string().to_uppercase()
+                )),
+        )
+        .arg(
+            Arg::new("template")
+                .long("template")
+                .value_name("TEMPLATE")
+                .default_value(default_template.to_string())
+                .help(format!(
+                    "Template variant ({}) or manifest name; see 'templates list'",
+                    names.join(", ")
+                )),
+        )
+        .arg(
+            Arg::new("template-dir")
+                .long("template-dir")
+                .value_name("TEMPLATE_DIR")
+                .value_parser(clap::value_parser!(PathBuf))
+                .help("Custom template directory"),
+        )
+        .arg(
+            Arg::new("template-source")
+                .long("template-source")
+                .value_name("TEMPLATE_SOURCE")
+                .conflicts_with("template-dir")
+                .help("Template archive (.tar.gz, .tgz, .zip) or git repository (git+URL#REF) to fetch"),
+        )
+        .arg(
+            Arg::new("output-dir")
+                .long("output-dir")
+                .value_name("OUTPUT_DIR")
+                .value_parser(clap::value_parser!(PathBuf))
+                .help("Output directory for generated code"),
+        );
+
+    let mut options = handler.scaffold_options(role);
+    if matches!(role, Role::Custom(_))
+        && !options
+            .iter()
+            .any(|o| matches!(o.target, ScaffoldTarget::SchemaPath { .. }))
+    {
+        options.push(ScaffoldOption::new(
+            "schema-path",
+            ScaffoldTarget::SchemaPath { required: false },
+            "Path or URL to an OpenAPI schema passed to the template",
+        ));
+    }
+    for option in &options {
+        command = command.arg(option_arg(option));
+    }
+
+    for arg in variable_args(templates, &options) {
+        command = command.arg(arg);
+    }
+
+    command
+        .arg(
+            Arg::new("var")
+                .long("var")
+                .value_name("KEY=VALUE")
+                .action(ArgAction::Append)
+                .value_parser(parse_template_var)
+                .help("Template variable as KEY=VALUE, with JSON-typed values (repeatable)"),
+        )
+        .arg(
+            Arg::new("vars-file")
+                .long("vars-file")
+                .value_name("VARS_FILE")
+                .value_parser(clap::value_parser!(PathBuf))
+                .help("YAML or JSON file of template variables"),
+        )
+        .arg(
+            Arg::new("no-hooks")
+                .long("no-hooks")
+                .action(ArgAction::SetTrue)
+                .help("Skip the template's pre- and post-generation hooks"),
+        )
+}
+
+/// The argument of a handler option
+fn option_arg(option: &ScaffoldOption) -> Arg {
+    let arg = Arg::new(option.flag)
+        .long(option.flag)
+        .value_name(option.flag.to_uppercase().replace('-', "_"))
+        .help(option.help);
+    match option.target {
+        ScaffoldTarget::Variable { kind, .. } => match kind {
+            ScaffoldValue::String => arg,
+            ScaffoldValue::Port => arg.value_parser(clap::value_parser!(u16)),
+            ScaffoldValue::Url => arg.value_parser(parse_url),
+        },
+        ScaffoldTarget::SchemaPath { required } => arg.required(required),
+        ScaffoldTarget::McpCommand => arg
+            .conflicts_with("mcp-url")
+            .required_unless_present_any(["mcp-url", "schema-path"]),
+        ScaffoldTarget::McpArg => arg
+            .value_name("ARG")
+            .action(ArgAction::Append)
+            .allow_hyphen_values(true)
+            .requires("mcp-command"),
+        ScaffoldTarget::McpUrl => arg.value_parser(parse_url),
+    }
+}
+
+/// A flag for each variable the templates declare, unless a handler option
+/// already sets it or its flag is taken
+fn variable_args(templates: &[&RoleTemplate], options: &[ScaffoldOption]) -> Vec<Arg> {
+    let mut variables: BTreeMap<&str, (&crate::infrastructure::TemplateVariable, Vec<&str>)> =
+        BTreeMap::new();
+    for template in templates {
+        for variable in &template.manifest.variable_schema {
+            variables
+                .entry(variable.name.as_str())
+                .or_insert_with(|| (variable, Vec::new()))
+                .1
+                .push(template.name.as_str());
+        }
+    }
+
+    let mut flags: HashMap<String, &str> = HashMap::new();
+    let mut args = Vec::new();
+    for (name, (variable, declared_by)) in variables {
+        let flag = name.replace('_', "-");
+        let covered = options.iter().any(|option| {
+            option.flag == flag
+                || matches!(option.target, ScaffoldTarget::Variable { name: set, .. } if set == name)
+        });
+        if covered || COMMON_FLAGS.contains(&flag.as_str()) {
+            continue;
+        }
+        if let Some(other) = flags.insert(flag.clone(), name) {
+            tracing::debug!("Template variables {other} and {name} share the flag --{flag}");
+            continue;
+        }
+
+        let mut help = variable
+            .description
+            .clone()
+            .unwrap_or_else(|| format!("Template variable {name}"));
+        if let Some(default) = &variable.default {
+            help.push_str(&format!(" (default: {default})"));
+        }
+        if declared_by.len() < templates.len() {
+            help.push_str(&format!(" [templates: {}]", declared_by.join(", ")));
+        }
+
+        let arg = Arg::new(format!("{VARIABLE_ID_PREFIX}{name}"))
+            .long(flag.clone())
+            .value_name(name.to_uppercase())
+            .help(help);
+        args.push(variable_arg(arg, variable.var_type));
+    }
+    args
+}
+
+/// Parse the value of a template variable flag as its declared type
+fn variable_arg(arg: Arg, var_type: VariableType) -> Arg {
+    match var_type {
+        VariableType::String => arg.value_parser(|raw: &str| {
+            Ok::<_, std::convert::Infallible>(JsonValue::String(raw.to_string()))
+        }),
+        VariableType::Integer => {
+            arg.value_parser(|raw: &str| raw.parse::<i64>().map(JsonValue::from))
+        }
+        VariableType::Number => {
+            arg.value_parser(|raw: &str| raw.parse::<f64>().map(JsonValue::from))
+        }
+        VariableType::Boolean => arg
+            .num_args(0..=1)
+            .default_missing_value("true")
+            .value_parser(|raw: &str| raw.parse::<bool>().map(JsonValue::Bool)),
+        VariableType::Any | VariableType::Array | VariableType::Object => arg
+            .value_parser(|raw: &str| Ok::<_, std::convert::Infallible>(parse_template_value(raw))),
+    }
+}
+
+/// Check a URL option, keeping it as the normalized string
+fn parse_url(raw: &str) -> Result<String, url::ParseError> {
+    url::Url::parse(raw).map(|url| url.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    async fn command() -> Command {
+        scaffold_command(&[]).await.unwrap()
+    }
+
+    fn parse(command: Command, args: &[&str]) -> ScaffoldParams {
+        let mut argv = vec!["scaffold"];
+        argv.extend_from_slice(args);
+        scaffold_params(&command.try_get_matches_from(argv).unwrap()).unwrap()
+    }
+
+    fn subcommand_names(command: &Command) -> Vec<String> {
+        command
+            .get_subcommands()
+            .map(|c| c.get_name().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_template_dirs_from_args() {
+        let args = [
+            "agenterra",
+            "scaffold",
+            "--template-dir",
+            "a",
+            "--template-dir=b",
+        ];
+        assert_eq!(
+            template_dirs_from_args(args.iter().map(OsString::from)),
+            vec![PathBuf::from("a"), PathBuf::from("b")]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_protocols_and_roles_come_from_handlers_and_templates() {
+        let command = command().await;
+        assert_eq!(subcommand_names(&command), ["a2a", "acp", "anp", "mcp"]);
+
+        let roles = |protocol: &str| subcommand_names(command.find_subcommand(protocol).unwrap());
+        assert_eq!(roles("a2a"), ["agent", "bridge"]);
+        // ACP has a broker role but no template for it
+        assert_eq!(roles("acp"), ["server", "client"]);
+        assert_eq!(roles("anp"), ["agent"]);
+        assert_eq!(roles("mcp"), ["server", "client"]);
+    }
+
+    #[tokio::test]
+    async fn test_handler_options() {
+        let params = parse(
+            command().await,
+            &[
+                "mcp",
+                "server",
+                "--schema-path",
+                "petstore.yaml",
+                "--port",
+                "3000",
+                "--base-url",
+                "https://api.example.com",
+                "--var",
+                "server_port=1",
+            ],
+        );
+        assert_eq!(params.protocol, Protocol::Mcp);
+        assert_eq!(params.role, Role::Server);
+        assert_eq!(params.project_name, "agenterra_mcp_server");
+        assert_eq!(params.template, "rust");
+        assert_eq!(params.schema_path.as_deref(), Some("petstore.yaml"));
+        assert_eq!(params.variables["server_port"], json!(3000));
+        assert_eq!(
+            params.variables["base_api_url"],
+            json!("https://api.example.com/")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_required_and_conflicting_options() {
+        let command = command().await;
+        assert!(
+            command
+                .clone()
+                .try_get_matches_from(["scaffold", "mcp", "server"])
+                .is_err()
+        );
+        assert!(
+            command
+                .clone()
+                .try_get_matches_from(["scaffold", "a2a", "bridge"])
+                .is_err()
+        );
+        assert!(
+            command
+                .clone()
+                .try_get_matches_from([
+                    "scaffold",
+                    "mcp",
+                    "server",
+                    "--schema-path",
+                    "a.yaml",
+                    "--port",
+                    "http"
+                ])
+                .is_err()
+        );
+
+        let params = parse(
+            command,
+            &[
+                "a2a",
+                "bridge",
+                "--mcp-command",
+                "weather-mcp",
+                "--mcp-arg",
+                "--stdio",
+            ],
+        );
+        assert_eq!(
+            params.mcp_server,
+            Some(McpServerSource::Stdio {
+                command: "weather-mcp".to_string(),
+                args: vec!["--stdio".to_string()],
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_template_variable_flags() {
+        let command = command().await;
+        let agent = command
+            .find_subcommand("a2a")
+            .and_then(|a2a| a2a.find_subcommand("agent"))
+            .unwrap();
+        let flags: Vec<_> = agent.get_arguments().filter_map(Arg::get_long).collect();
+        assert!(flags.contains(&"streaming"), "{flags:?}");
+        // server_port is set by --port, not a flag of its own
+        assert!(!flags.contains(&"server-port"), "{flags:?}");
+
+        let params = parse(
+            command,
+            &[
+                "a2a",
+                "agent",
+                "--var",
+                "auth=\"api_key\"",
+                "--auth",
+                "bearer",
+                "--streaming",
+                "false",
+                "--skills",
+                r#"[{"id": "echo"}]"#,
+            ],
+        );
+        assert_eq!(params.variables["auth"], json!("bearer"));
+        assert_eq!(params.variables["streaming"], json!(false));
+        assert_eq!(params.variables["skills"], json!([{ "id": "echo" }]));
+    }
+
+    #[tokio::test]
+    async fn test_custom_role_from_template_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("manifest.yml"),
+            "name: mcp-reviewer\nversion: 0.1.0\ndescription: Review MCP servers\n\
+             protocol: mcp\nrole: reviewer\nlanguage: python\nfiles: []\n\
+             variables:\n  severity:\n    type: string\n    default: low\n",
+        )
+        .unwrap();
+
+        let command = scaffold_command(&[dir.path().to_path_buf()]).await.unwrap();
+        let mcp = command.find_subcommand("mcp").unwrap();
+        assert_eq!(subcommand_names(mcp), ["server", "client", "reviewer"]);
+        let reviewer = mcp.find_subcommand("reviewer").unwrap();
+        assert_eq!(
+            reviewer
+                .get_about()
+                .map(|about| about.to_string())
+                .as_deref(),
+            Some("Review MCP servers")
+        );
+
+        let template_dir = dir.path().to_str().unwrap();
+        let params = parse(
+            command,
+            &[
+                "mcp",
+                "reviewer",
+                "--template-dir",
+                template_dir,
+                "--severity",
+                "high",
+            ],
+        );
+        assert_eq!(params.role, Role::Custom("reviewer".to_string()));
+        assert_eq!(params.project_name, "agenterra_mcp_reviewer");
+        assert_eq!(params.template, "python");
+        assert_eq!(params.schema_path, None);
+        assert_eq!(params.variables["severity"], json!("high"));
+    }
+}
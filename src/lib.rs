@@ -0,0 +1,58 @@
+//! Generate MCP servers and clients from OpenAPI specs
+//!
+//! The `agenterra` binary is a thin layer over this crate. To generate code
+//! from Rust, start with [`Generator`]:
+//!
+//! ```no_run
+//! use agenterra::{Generator, Language};
+//!
+//! # async fn run() -> Result<(), agenterra::application::ApplicationError> {
+//! let result = Generator::builder()
+//!     .language(Language::Rust)
+//!     .project_name("petstore_server")
+//!     .spec_location("petstore.json")
+//!     .variable("server_port", serde_json::json!(9000))
+//!     .build()?
+//!     .generate()
+//!     .await?;
+//!
+//! for artifact in &result.artifacts {
+//!     println!("{}", artifact.path.display());
+//! }
+//! for diagnostic in &result.diagnostics {
+//!     eprintln!("{diagnostic}");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The layered modules stay public for callers that need to swap out a
+//! single piece of the pipeline, such as a custom [`generation::TemplateDiscovery`]
+//! or [`generation::PostProcessor`] passed to [`generation::GenerationOrchestrator`].
+//!
+//! # Features
+//!
+//! - `cli` *(default)*: the `agenterra` binary
+//! - `http-loader` *(default)*: load specs from `http(s)` URLs
+//! - `shell-hooks` *(default)*: run the hooks declared in template manifests
+//!   with `infrastructure::ShellHookRunner`; [`Generator::generate`] only
+//!   returns them, since they need the files on disk
+#![deny(unsafe_code)]
+
+pub mod application;
+pub mod generation;
+pub mod generator;
+pub mod infrastructure;
+pub mod protocols;
+
+/// Adapters between the CLI arguments and the application layer
+///
+/// Not part of the stable API.
+#[cfg(feature = "cli")]
+#[doc(hidden)]
+pub mod integration;
+
+pub use generation::{Artifact, Diagnostic, GenerationResult, Language, Severity};
+pub use generator::{Generator, GeneratorBuilder, SpecSource};
+pub use infrastructure::TemplateSource;
+pub use protocols::{Protocol, Role};
@@ -2,17 +2,11 @@
 //! Parses command-line arguments and dispatches to the integration layer.
 #![deny(unsafe_code)]
 
-mod application;
-mod generation;
-mod infrastructure;
-mod integration;
-mod protocols;
-
+use agenterra::application;
+use agenterra::infrastructure::{EmbeddedTemplateExporter, EmbeddedTemplateRepository};
+use agenterra::integration;
 use anyhow::Context;
-use clap::Parser;
-use infrastructure::{EmbeddedTemplateExporter, EmbeddedTemplateRepository};
-use integration::{ClientParams, McpClientIntegration, McpServerIntegration, ServerParams};
-use reqwest::Url;
+use clap::{Arg, CommandFactory, FromArgMatches, Parser};
 use std::path::PathBuf;
 use tracing::{Level, info};
 use tracing_subscriber::EnvFilter;
@@ -27,11 +21,6 @@ struct Cli {
 
 #[derive(clap::Subcommand, Debug)]
 pub enum Commands {
-    /// Scaffold servers and clients for various targets
-    Scaffold {
-        #[command(subcommand)]
-        target: TargetCommands,
-    },
     /// Manage embedded templates
     Templates {
         #[command(subcommand)]
@@ -39,114 +28,11 @@ pub enum Commands {
     },
 }
 
-#[derive(clap::Subcommand, Debug)]
-pub enum TargetCommands {
-    /// Agent to Agent Protocol (A2A) - by Google
-    A2a {
-        #[command(subcommand)]
-        role: A2aCommands,
-    },
-    /// Agent Communication Protocol (ACP) - by IBM
-    Acp {
-        #[command(subcommand)]
-        role: AcpCommands,
-    },
-    /// Agent Network Protocol (ANP) - by Cisco
-    Anp {
-        #[command(subcommand)]
-        role: AnpCommands,
-    },
-    /// Model Context Protocol (MCP) servers and clients
-    Mcp {
-        #[command(subcommand)]
-        role: McpCommands,
-    },
-}
-
-#[derive(clap::Subcommand, Debug)]
-pub enum McpCommands {
-    /// Generate MCP server from OpenAPI specification
-    Server {
-        /// Project name for the generated MCP server
-        #[arg(long, default_value = "agenterra_mcp_server")]
-        project_name: String,
-        /// Path or URL to OpenAPI schema (YAML or JSON)
-        #[arg(long)]
-        schema_path: String,
-        /// Template to use for code generation
-        #[arg(long, default_value = "rust")]
-        template: String,
-        /// Custom template directory
-        #[arg(long)]
-        template_dir: Option<PathBuf>,
-        /// Output directory for generated code
-        #[arg(long)]
-        output_dir: Option<PathBuf>,
-        /// Log file name without extension
-        #[arg(long)]
-        log_file: Option<String>,
-        /// Server port
-        #[arg(long)]
-        port: Option<u16>,
-        /// Base URL of the OpenAPI specification
-        #[arg(long)]
-        base_url: Option<Url>,
-    },
-    /// Generate MCP client
-    Client {
-        /// Project name for the generated MCP client
-        #[arg(long, default_value = "agenterra_mcp_client")]
-        project_name: String,
-        /// Template to use for client generation
-        #[arg(long, default_value = "rust")]
-        template: String,
-        /// Custom template directory
-        #[arg(long)]
-        template_dir: Option<PathBuf>,
-        /// Output directory for generated code
-        #[arg(long)]
-        output_dir: Option<PathBuf>,
-    },
-}
-
-// Placeholder enums for unimplemented protocols
-#[derive(clap::Subcommand, Debug)]
-pub enum A2aCommands {
-    /// Generate A2A agent
-    Agent {
-        /// Project name
-        #[arg(long, default_value = "agenterra_a2a_agent")]
-        project_name: String,
-        /// Template to use
-        #[arg(long, default_value = "rust")]
-        template: String,
-        /// Custom template directory
-        #[arg(long)]
-        template_dir: Option<PathBuf>,
-        /// Output directory
-        #[arg(long)]
-        output_dir: Option<PathBuf>,
-    },
-}
-
-#[derive(clap::Subcommand, Debug)]
-pub enum AcpCommands {
-    /// Generate ACP server
-    Server {
-        /// Project name
-        #[arg(long, default_value = "agenterra_acp_server")]
-        project_name: String,
-    },
-}
-
-#[derive(clap::Subcommand, Debug)]
-pub enum AnpCommands {
-    /// Generate ANP broker
-    Broker {
-        /// Project name
-        #[arg(long, default_value = "agenterra_anp_broker")]
-        project_name: String,
-    },
+/// Output format of the report printed after a scaffold
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportFormat {
+    Text,
+    Json,
 }
 
 #[derive(clap::Subcommand, Debug)]
@@ -166,105 +52,91 @@ pub enum TemplateCommands {
         /// Template path (e.g., "mcp/server/rust")
         template: String,
     },
+    /// Check a template for errors without generating anything
+    Validate {
+        /// Template directory, or embedded template path (e.g., "mcp/server/rust")
+        template: String,
+        /// Report format; `json` prints the issues to stdout
+        #[arg(long, value_enum, default_value_t = ReportFormat::Text)]
+        report: ReportFormat,
+    },
+    /// Create a starter template for a new protocol, role or language
+    New {
+        /// Template path to create (e.g., "mcp/server/python")
+        template: String,
+        /// Directory the template path is created under
+        #[arg(long, default_value = ".")]
+        output_dir: PathBuf,
+    },
 }
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     // Initialize logging
+    // Logs go to stderr so stdout stays clean for `--report json`
     tracing_subscriber::fmt()
+        .with_writer(std::io::stderr)
         .with_env_filter(EnvFilter::from_default_env().add_directive(Level::INFO.into()))
         .init();
 
     info!("Starting Agenterra CLI");
-    let cli = Cli::parse();
 
-    match &cli.command {
-        Commands::Scaffold { target } => match target {
-            TargetCommands::A2a { .. } => {
-                // TODO: Implement A2A (Agent to Agent) protocol handler
-                // Protocol by: Google
-                // Architecture: Centralized peer-to-peer
-                // See: https://github.com/agenterra/agenterra/issues/XXX
-                anyhow::bail!(
-                    "A2A protocol is not yet implemented. Currently only MCP is supported."
-                );
-            }
-            TargetCommands::Acp { .. } => {
-                // TODO: Implement ACP (Agent Communication Protocol) protocol handler
-                // Protocol by: IBM
-                // Architecture: Brokered client-server
-                // See: https://github.com/agenterra/agenterra/issues/XXX
-                anyhow::bail!(
-                    "ACP protocol is not yet implemented. Currently only MCP is supported."
-                );
-            }
-            TargetCommands::Anp { .. } => {
-                // TODO: Implement ANP (Agent Network Protocol) protocol handler
-                // Protocol by: Cisco
-                // Architecture: Decentralized peer-to-peer
-                // See: https://github.com/agenterra/agenterra/issues/XXX
-                anyhow::bail!(
-                    "ANP protocol is not yet implemented. Currently only MCP is supported."
-                );
-            }
-            TargetCommands::Mcp { role } => handle_mcp_command(role).await?,
+    // The scaffold commands come from the protocol handlers and templates,
+    // including those of any `--template-dir`
+    let template_dirs = integration::template_dirs_from_args(std::env::args_os());
+    let scaffold = integration::scaffold_command(&template_dirs)
+        .await?
+        .display_order(0)
+        .arg(
+            Arg::new("report")
+                .long("report")
+                .value_name("REPORT")
+                .value_parser(clap::value_parser!(ReportFormat))
+                .default_value("text")
+                .global(true)
+                .help("Generation report format; `json` prints the report to stdout"),
+        );
+    let matches = Cli::command().subcommand(scaffold).get_matches();
+
+    match matches.subcommand() {
+        Some(("scaffold", matches)) => {
+            let params = integration::scaffold_params(matches)?;
+            let target = format!(
+                "{} {}",
+                params.protocol.to_string().to_uppercase(),
+                params.role
+            );
+            let generation_report = integration::scaffold(params)
+                .await
+                .with_context(|| format!("Failed to generate {target}"))?;
+            info!("Successfully generated {target}");
+
+            let report = matches
+                .get_one::<ReportFormat>("report")
+                .copied()
+                .unwrap_or(ReportFormat::Text);
+            print_report(&generation_report, report)?;
+        }
+        _ => match Cli::from_arg_matches(&matches)?.command {
+            Commands::Templates { action } => handle_template_command(&action).await?,
         },
-        Commands::Templates { action } => handle_template_command(action).await?,
     }
 
     Ok(())
 }
 
-async fn handle_mcp_command(role: &McpCommands) -> anyhow::Result<()> {
-    match role {
-        McpCommands::Server {
-            project_name,
-            schema_path,
-            template,
-            template_dir,
-            output_dir,
-            log_file,
-            port,
-            base_url,
-        } => {
-            let params = ServerParams {
-                project_name: project_name.clone(),
-                schema_path: schema_path.clone(),
-                template: template.clone(),
-                template_dir: template_dir.clone(),
-                output_dir: output_dir.clone(),
-                port: *port,
-                log_file: log_file.clone(),
-                base_url: base_url.clone(),
-            };
-
-            McpServerIntegration::generate(params)
-                .await
-                .context("Failed to generate MCP server")?;
-
-            info!("Successfully generated MCP server");
-        }
-        McpCommands::Client {
-            project_name,
-            template,
-            template_dir,
-            output_dir,
-        } => {
-            let params = ClientParams {
-                project_name: project_name.clone(),
-                template: template.clone(),
-                template_dir: template_dir.clone(),
-                output_dir: output_dir.clone(),
-            };
-
-            McpClientIntegration::generate(params)
-                .await
-                .context("Failed to generate MCP client")?;
-
-            info!("Successfully generated MCP client");
+/// Print the generation report; the human summary goes to stderr when stdout carries JSON
+fn print_report(
+    report: &application::GenerationReport,
+    format: ReportFormat,
+) -> anyhow::Result<()> {
+    match format {
+        ReportFormat::Text => println!("{}", report.to_text()),
+        ReportFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(report)?);
+            eprintln!("{}", report.to_text());
         }
     }
-
     Ok(())
 }
 
@@ -310,6 +182,49 @@ async fn handle_template_command(action: &TemplateCommands) -> anyhow::Result<()
                 Err(e) => return Err(e.into()),
             }
         }
+        TemplateCommands::Validate { template, report } => {
+            let use_case = application::ValidateTemplateUseCase::new();
+            let validation = match use_case.execute(template).await {
+                Ok(validation) => validation,
+                Err(application::ApplicationError::TemplateNotFound(_)) => {
+                    eprintln!("Template not found: {template}");
+                    eprintln!(
+                        "\nPass a template directory or a path from 'agenterra templates list'."
+                    );
+                    std::process::exit(1);
+                }
+                Err(e) => return Err(e.into()),
+            };
+
+            match report {
+                ReportFormat::Text => println!("{}", validation.to_text()),
+                ReportFormat::Json => println!("{}", serde_json::to_string_pretty(&validation)?),
+            }
+            if !validation.is_valid() {
+                std::process::exit(1);
+            }
+        }
+        TemplateCommands::New {
+            template,
+            output_dir,
+        } => {
+            let use_case = application::NewTemplateUseCase::new();
+            let created = use_case.execute(template, output_dir).await?;
+
+            println!(
+                "Created template {} at {}",
+                template,
+                created.directory.display()
+            );
+            for file in &created.files {
+                println!("  {file}");
+            }
+            println!("\n{}", created.variables.to_text());
+            println!(
+                "Check it with: agenterra templates validate {}",
+                created.directory.display()
+            );
+        }
     }
 
     Ok(())
@@ -0,0 +1,923 @@
+//! A2A (Agent-to-Agent) handler implementation
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value as JsonValue, json};
+
+use crate::generation::{Diagnostic, McpTool, Operation, ProtocolContext, Schema};
+use crate::protocols::handlers::mcp::McpProtocolHandler;
+use crate::protocols::{
+    Protocol, ProtocolConfig, ProtocolError, ProtocolHandler, ProtocolInput, Role, ScaffoldOption,
+    ScaffoldTarget, ScaffoldValue,
+};
+
+/// A2A protocol version the generated Agent Card declares
+pub const A2A_PROTOCOL_VERSION: &str = "0.3.0";
+
+/// Port the generated agent listens on unless `server_port` is set
+const DEFAULT_PORT: u64 = 8080;
+
+/// The Agent Card an A2A agent publishes at `/.well-known/agent-card.json`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentCard {
+    pub protocol_version: String,
+    pub name: String,
+    pub description: String,
+    pub url: String,
+    pub version: String,
+    pub preferred_transport: String,
+    pub capabilities: AgentCapabilities,
+    pub default_input_modes: Vec<String>,
+    pub default_output_modes: Vec<String>,
+    pub skills: Vec<AgentSkill>,
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub security_schemes: serde_json::Map<String, JsonValue>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub security: Vec<JsonValue>,
+}
+
+/// Optional A2A features the agent supports
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentCapabilities {
+    pub streaming: bool,
+    pub push_notifications: bool,
+    pub state_transition_history: bool,
+}
+
+/// A capability the agent advertises to callers
+///
+/// Only `id` is required in the `skills` option; `name` defaults to the id
+/// and `description` to the name.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentSkill {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<String>,
+    /// Media types the skill accepts, when they differ from the card's defaults
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub input_modes: Vec<String>,
+}
+
+/// How callers authenticate to the generated agent
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum A2aAuth {
+    None,
+    Bearer,
+    ApiKey,
+}
+
+impl A2aAuth {
+    fn parse(value: &str) -> Result<Self, ProtocolError> {
+        match value {
+            "none" => Ok(Self::None),
+            "bearer" => Ok(Self::Bearer),
+            "api_key" => Ok(Self::ApiKey),
+            other => Err(ProtocolError::InvalidConfiguration(format!(
+                "Invalid auth scheme: {other}. Must be one of: none, bearer, api_key"
+            ))),
+        }
+    }
+
+    /// `securitySchemes` and `security` entries for the Agent Card
+    fn security(self) -> (serde_json::Map<String, JsonValue>, Vec<JsonValue>) {
+        let (name, scheme) = match self {
+            Self::None => return (serde_json::Map::new(), Vec::new()),
+            Self::Bearer => ("bearer", json!({ "type": "http", "scheme": "bearer" })),
+            Self::ApiKey => (
+                "apiKey",
+                json!({ "type": "apiKey", "in": "header", "name": "X-API-Key" }),
+            ),
+        };
+        let mut schemes = serde_json::Map::new();
+        schemes.insert(name.to_string(), scheme);
+        (schemes, vec![json!({ name: [] })])
+    }
+}
+
+/// The skill generated agents start with when no `skills` are configured
+fn default_skills() -> Vec<AgentSkill> {
+    vec![AgentSkill {
+        id: "echo".to_string(),
+        name: "Echo".to_string(),
+        description: "Replies with the text of the message it receives".to_string(),
+        tags: vec!["echo".to_string()],
+        examples: vec!["Hello, agent!".to_string()],
+        input_modes: Vec::new(),
+    }]
+}
+
+/// Parse the `skills` option, filling in names and descriptions
+fn parse_skills(value: Option<&JsonValue>) -> Result<Vec<AgentSkill>, ProtocolError> {
+    let Some(value) = value else {
+        return Ok(default_skills());
+    };
+    let mut skills: Vec<AgentSkill> = serde_json::from_value(value.clone()).map_err(|e| {
+        ProtocolError::InvalidConfiguration(format!(
+            "Invalid skills: {e}. Expected a list of objects with at least an `id`"
+        ))
+    })?;
+    if skills.is_empty() {
+        return Err(ProtocolError::InvalidConfiguration(
+            "An A2A agent needs at least one skill".to_string(),
+        ));
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    for skill in &mut skills {
+        if skill.id.trim().is_empty() {
+            return Err(ProtocolError::InvalidConfiguration(
+                "Skill ids cannot be empty".to_string(),
+            ));
+        }
+        if !seen.insert(skill.id.clone()) {
+            return Err(ProtocolError::InvalidConfiguration(format!(
+                "Duplicate skill id: {}",
+                skill.id
+            )));
+        }
+        if skill.name.is_empty() {
+            skill.name = skill.id.clone();
+        }
+        if skill.description.is_empty() {
+            skill.description = skill.name.clone();
+        }
+    }
+    Ok(skills)
+}
+
+fn string_option<'a>(config: &'a ProtocolConfig, key: &str) -> Option<&'a str> {
+    config.options.get(key).and_then(JsonValue::as_str)
+}
+
+/// Build the Agent Card from the project configuration
+pub fn build_agent_card(config: &ProtocolConfig) -> Result<AgentCard, ProtocolError> {
+    let skills = parse_skills(config.options.get("skills"))?;
+    card_with_skills(config, skills, "A2A agent")
+}
+
+/// Build the Agent Card of a bridge, with a skill for each MCP tool
+pub fn build_bridge_card(
+    config: &ProtocolConfig,
+    tools: &[McpTool],
+) -> Result<AgentCard, ProtocolError> {
+    if config.options.contains_key("skills") {
+        return Err(ProtocolError::InvalidConfiguration(
+            "A bridge's skills are the tools of its MCP server and cannot be set with `skills`"
+                .to_string(),
+        ));
+    }
+    if tools.is_empty() {
+        return Err(ProtocolError::InvalidConfiguration(
+            "The MCP server has no tools to expose as skills".to_string(),
+        ));
+    }
+    let skills = tools.iter().map(bridge_skill).collect();
+    card_with_skills(config, skills, "A2A bridge to an MCP server")
+}
+
+fn card_with_skills(
+    config: &ProtocolConfig,
+    skills: Vec<AgentSkill>,
+    kind: &str,
+) -> Result<AgentCard, ProtocolError> {
+    let port = config
+        .options
+        .get("server_port")
+        .and_then(JsonValue::as_u64)
+        .unwrap_or(DEFAULT_PORT);
+    let auth = A2aAuth::parse(string_option(config, "auth").unwrap_or("none"))?;
+    let (security_schemes, security) = auth.security();
+    let streaming = config
+        .options
+        .get("streaming")
+        .and_then(JsonValue::as_bool)
+        .unwrap_or(true);
+
+    Ok(AgentCard {
+        protocol_version: A2A_PROTOCOL_VERSION.to_string(),
+        name: string_option(config, "agent_name")
+            .unwrap_or(&config.project_name)
+            .to_string(),
+        description: string_option(config, "agent_description")
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("{} {kind}", config.project_name)),
+        url: string_option(config, "agent_url")
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("http://localhost:{port}/")),
+        version: config
+            .version
+            .clone()
+            .unwrap_or_else(|| "0.1.0".to_string()),
+        preferred_transport: "JSONRPC".to_string(),
+        capabilities: AgentCapabilities {
+            streaming,
+            push_notifications: false,
+            state_transition_history: false,
+        },
+        default_input_modes: vec!["text/plain".to_string(), "application/json".to_string()],
+        default_output_modes: vec!["text/plain".to_string(), "application/json".to_string()],
+        skills,
+        security_schemes,
+        security,
+    })
+}
+
+/// The skill exposing an MCP tool
+///
+/// The description lists the tool's arguments, and the example shows them
+/// as the JSON object the bridge passes on.
+fn bridge_skill(tool: &McpTool) -> AgentSkill {
+    let name = tool.title.clone().unwrap_or_else(|| tool.name.clone());
+    let mut description = tool
+        .description
+        .as_deref()
+        .map(str::trim)
+        .filter(|description| !description.is_empty())
+        .unwrap_or(&name)
+        .to_string();
+
+    let properties = schema_properties(&tool.input_schema);
+    let required = required_arguments(&tool.input_schema);
+    if !properties.is_empty() {
+        description.push_str("\n\nArguments:");
+        for (argument, schema) in &properties {
+            description.push_str(&format!("\n- `{argument}`"));
+            let mut notes = Vec::new();
+            if let Some(kind) = schema.get("type").and_then(JsonValue::as_str) {
+                notes.push(kind);
+            }
+            if required.contains(argument) {
+                notes.push("required");
+            }
+            if !notes.is_empty() {
+                description.push_str(&format!(" ({})", notes.join(", ")));
+            }
+            if let Some(text) = schema.get("description").and_then(JsonValue::as_str) {
+                description.push_str(&format!(": {}", text.trim()));
+            }
+        }
+    }
+
+    // Tools without arguments, or with a single string, also take plain text
+    let input_modes = if bridge_accepts_text(&tool.input_schema) {
+        vec!["text/plain".to_string(), "application/json".to_string()]
+    } else {
+        vec!["application/json".to_string()]
+    };
+
+    AgentSkill {
+        id: tool.name.clone(),
+        name,
+        description,
+        tags: vec!["mcp".to_string()],
+        examples: example_arguments(&properties, &required)
+            .into_iter()
+            .collect(),
+        input_modes,
+    }
+}
+
+fn schema_properties(schema: &JsonValue) -> Vec<(String, JsonValue)> {
+    schema
+        .get("properties")
+        .and_then(JsonValue::as_object)
+        .map(|properties| {
+            properties
+                .iter()
+                .map(|(name, schema)| (name.clone(), schema.clone()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn required_arguments(schema: &JsonValue) -> Vec<String> {
+    schema
+        .get("required")
+        .and_then(JsonValue::as_array)
+        .map(|names| {
+            names
+                .iter()
+                .filter_map(JsonValue::as_str)
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Whether the generated bridge fills the tool's arguments from message text
+fn bridge_accepts_text(schema: &JsonValue) -> bool {
+    let properties = schema_properties(schema);
+    match properties.as_slice() {
+        [] => true,
+        [(_, schema)] => schema.get("type").and_then(JsonValue::as_str) == Some("string"),
+        _ => false,
+    }
+}
+
+/// Example arguments for the required arguments, or all of them when none is
+/// required, from each schema's example, default or first enum value
+fn example_arguments(properties: &[(String, JsonValue)], required: &[String]) -> Option<String> {
+    let mut example = serde_json::Map::new();
+    for (name, schema) in properties {
+        if !required.is_empty() && !required.contains(name) {
+            continue;
+        }
+        let value = schema
+            .get("example")
+            .or_else(|| schema.get("default"))
+            .or_else(|| schema.get("enum").and_then(|values| values.get(0)))
+            .cloned()
+            .unwrap_or_else(|| match schema.get("type").and_then(JsonValue::as_str) {
+                Some("integer" | "number") => json!(0),
+                Some("boolean") => json!(false),
+                Some("array") => json!([]),
+                Some("object") => json!({}),
+                _ => json!(""),
+            });
+        example.insert(name.clone(), value);
+    }
+    (!example.is_empty()).then(|| JsonValue::Object(example).to_string())
+}
+
+/// The tools of the MCP server generated from `input`'s OpenAPI spec
+///
+/// The spec goes through the MCP server handler, and each operation becomes
+/// a tool named after its operation id as the generated server names it,
+/// taking the operation's parameters as arguments.
+async fn tools_from_openapi(
+    input: &ProtocolInput,
+) -> Result<(Vec<McpTool>, Vec<Diagnostic>), ProtocolError> {
+    let server_input = ProtocolInput {
+        openapi_spec: input.openapi_spec.clone(),
+        config: ProtocolConfig {
+            options: Default::default(),
+            ..input.config.clone()
+        },
+        role: Role::Server,
+        language: input.language,
+        mcp_server: None,
+        mcp_tools: None,
+    };
+    let context = McpProtocolHandler::new()
+        .prepare_context(server_input)
+        .await?;
+    let Some(ProtocolContext::McpServer { endpoints, .. }) = &context.protocol_context else {
+        return Err(ProtocolError::InternalError(
+            "The MCP server handler produced no endpoints".to_string(),
+        ));
+    };
+    Ok((
+        endpoints.iter().map(operation_tool).collect(),
+        context.diagnostics.snapshot(),
+    ))
+}
+
+/// The JSON Schema keywords of a parameter schema that describe an argument
+fn argument_schema(schema: &Schema) -> serde_json::Map<String, JsonValue> {
+    let mut json = serde_json::Map::new();
+    let mut insert = |key: &str, value: Option<JsonValue>| {
+        if let Some(value) = value {
+            json.insert(key.to_string(), value);
+        }
+    };
+    insert("type", schema.schema_type.as_ref().map(|kind| json!(kind)));
+    insert("format", schema.format.as_ref().map(|format| json!(format)));
+    insert(
+        "description",
+        schema.description.as_ref().map(|text| json!(text)),
+    );
+    insert(
+        "enum",
+        schema.enum_values.as_ref().map(|values| json!(values)),
+    );
+    insert("default", schema.default.clone());
+    insert("example", schema.example.clone());
+    insert("minimum", schema.minimum.map(|minimum| json!(minimum)));
+    insert("maximum", schema.maximum.map(|maximum| json!(maximum)));
+    insert(
+        "items",
+        schema
+            .items
+            .as_ref()
+            .map(|items| JsonValue::Object(argument_schema(items))),
+    );
+    json
+}
+
+fn operation_tool(operation: &Operation) -> McpTool {
+    let mut properties = serde_json::Map::new();
+    let mut required = Vec::new();
+    for parameter in &operation.parameters {
+        let name = crate::generation::utils::to_snake_case(&parameter.name);
+        let mut schema = argument_schema(&parameter.schema);
+        if let Some(description) = &parameter.description {
+            schema.insert("description".to_string(), json!(description));
+        }
+        if parameter.required {
+            required.push(json!(name));
+        }
+        properties.insert(name, JsonValue::Object(schema));
+    }
+
+    let mut input_schema = json!({ "type": "object", "properties": properties });
+    if !required.is_empty() {
+        input_schema["required"] = JsonValue::Array(required);
+    }
+    let description = [&operation.summary, &operation.description]
+        .into_iter()
+        .flatten()
+        .map(|text| text.trim())
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>()
+        .join(" - ");
+
+    McpTool {
+        name: crate::generation::utils::to_snake_case(&operation.id),
+        title: None,
+        description: (!description.is_empty()).then_some(description),
+        input_schema,
+    }
+}
+
+/// Handler for the Agent-to-Agent protocol (A2A)
+#[derive(Debug, Clone)]
+pub struct A2aProtocolHandler;
+
+impl A2aProtocolHandler {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for A2aProtocolHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ProtocolHandler for A2aProtocolHandler {
+    fn protocol(&self) -> Protocol {
+        Protocol::A2a
+    }
+
+    fn description(&self) -> &'static str {
+        "Agent to Agent Protocol (A2A) - by Google"
+    }
+
+    fn role_description(&self, role: &Role) -> Option<&'static str> {
+        match role {
+            Role::Agent => {
+                Some("Generate A2A agent with an Agent Card, JSON-RPC endpoint and task store")
+            }
+            Role::Bridge => {
+                Some("Generate A2A agent exposing the tools of an MCP server as skills")
+            }
+            _ => None,
+        }
+    }
+
+    fn scaffold_options(&self, role: &Role) -> Vec<ScaffoldOption> {
+        let agent_url = ScaffoldOption::variable(
+            "agent-url",
+            "agent_url",
+            ScaffoldValue::Url,
+            "Public URL advertised in the Agent Card (defaults to http://localhost:<port>/)",
+        );
+        match role {
+            Role::Agent => vec![
+                ScaffoldOption::variable(
+                    "port",
+                    "server_port",
+                    ScaffoldValue::Port,
+                    "Port the agent listens on",
+                ),
+                agent_url,
+            ],
+            Role::Bridge => vec![
+                ScaffoldOption::new(
+                    "mcp-command",
+                    ScaffoldTarget::McpCommand,
+                    "Command starting the MCP server over stdio",
+                ),
+                ScaffoldOption::new(
+                    "mcp-arg",
+                    ScaffoldTarget::McpArg,
+                    "Argument passed to --mcp-command (repeatable)",
+                ),
+                ScaffoldOption::new(
+                    "mcp-url",
+                    ScaffoldTarget::McpUrl,
+                    "SSE endpoint of a running MCP server (e.g. http://localhost:8080/sse)",
+                ),
+                ScaffoldOption::new(
+                    "schema-path",
+                    ScaffoldTarget::SchemaPath { required: false },
+                    "Path or URL to the OpenAPI schema an MCP server was generated from; its operations become the skills instead of the tools the server lists",
+                ),
+                ScaffoldOption::variable(
+                    "port",
+                    "server_port",
+                    ScaffoldValue::Port,
+                    "Port the bridge listens on",
+                ),
+                agent_url,
+            ],
+            _ => Vec::new(),
+        }
+    }
+
+    async fn prepare_context(
+        &self,
+        input: ProtocolInput,
+    ) -> Result<crate::generation::GenerationContext, ProtocolError> {
+        self.protocol().validate_role(&input.role)?;
+        self.validate_configuration(&input.config)?;
+
+        crate::generation::rules::validate_language_support(
+            Protocol::A2a,
+            &input.role,
+            input.language,
+        )
+        .map_err(|e| match e {
+            crate::generation::GenerationError::UnsupportedLanguageForProtocol {
+                language,
+                protocol,
+            } => ProtocolError::InvalidConfiguration(format!(
+                "Language {:?} is not supported for {:?}/{:?}",
+                language, protocol, input.role
+            )),
+            _ => ProtocolError::InternalError(e.to_string()),
+        })?;
+
+        let bridged = match input.role {
+            Role::Bridge => Some(match &input.mcp_tools {
+                Some(tools) => (tools.clone(), Vec::new()),
+                None if input.openapi_spec.is_some() => tools_from_openapi(&input).await?,
+                None => {
+                    return Err(ProtocolError::InvalidConfiguration(
+                        "An A2A bridge needs the tools of an MCP server or an OpenAPI spec"
+                            .to_string(),
+                    ));
+                }
+            }),
+            _ => None,
+        };
+        let agent_card = match &bridged {
+            Some((tools, _)) => build_bridge_card(&input.config, tools)?,
+            None => build_agent_card(&input.config)?,
+        };
+
+        let mut context = crate::generation::GenerationContext::new(
+            Protocol::A2a,
+            input.role.clone(),
+            input.language,
+        );
+        context.metadata.project_name = input.config.project_name.clone();
+        context.metadata.version = agent_card.version.clone();
+        context.metadata.description = Some(agent_card.description.clone());
+
+        // Custom options first, so the normalized values below replace them
+        for (key, value) in &input.config.options {
+            context.add_variable(key.clone(), value.clone());
+        }
+
+        context.add_variable("project_name".to_string(), json!(input.config.project_name));
+        context.add_variable("version".to_string(), json!(agent_card.version));
+        context.add_variable("requires_openapi".to_string(), json!(false));
+        context.add_variable("skills".to_string(), json!(agent_card.skills));
+        context.add_variable(
+            "streaming".to_string(),
+            json!(agent_card.capabilities.streaming),
+        );
+        context.add_variable(
+            "auth".to_string(),
+            json!(string_option(&input.config, "auth").unwrap_or("none")),
+        );
+        context.add_variable(
+            "agent_card".to_string(),
+            serde_json::to_value(&agent_card)
+                .map_err(|e| ProtocolError::InternalError(e.to_string()))?,
+        );
+
+        if let Some((tools, diagnostics)) = bridged {
+            context.diagnostics.extend(diagnostics);
+            context.protocol_context = Some(ProtocolContext::McpBridge {
+                server: input.mcp_server.clone(),
+                tools,
+            });
+        }
+
+        Ok(context)
+    }
+
+    fn validate_configuration(&self, config: &ProtocolConfig) -> Result<(), ProtocolError> {
+        if config.project_name.is_empty() {
+            return Err(ProtocolError::InvalidConfiguration(
+                "Project name is required".to_string(),
+            ));
+        }
+
+        if !config
+            .project_name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(ProtocolError::InvalidConfiguration(
+                "Project name must be alphanumeric with optional dashes or underscores".to_string(),
+            ));
+        }
+
+        // Catch malformed skills and auth before any template is rendered
+        build_agent_card(config)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocols::Role;
+    use std::collections::HashMap;
+
+    fn input(options: HashMap<String, JsonValue>) -> ProtocolInput {
+        ProtocolInput {
+            openapi_spec: None,
+            config: ProtocolConfig {
+                project_name: "travel-agent".to_string(),
+                version: None,
+                options,
+            },
+            role: Role::Agent,
+            language: crate::generation::Language::Rust,
+            mcp_server: None,
+            mcp_tools: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_a2a_agent_context_has_default_card() {
+        let context = A2aProtocolHandler::new()
+            .prepare_context(input(HashMap::new()))
+            .await
+            .unwrap();
+
+        assert_eq!(context.protocol, Protocol::A2a);
+        assert_eq!(context.role, Role::Agent);
+        let card = &context.variables["agent_card"];
+        assert_eq!(card["name"], "travel-agent");
+        assert_eq!(card["url"], "http://localhost:8080/");
+        assert_eq!(card["protocolVersion"], A2A_PROTOCOL_VERSION);
+        assert_eq!(card["capabilities"]["streaming"], true);
+        assert_eq!(card["skills"][0]["id"], "echo");
+        assert!(card.get("securitySchemes").is_none());
+        assert_eq!(context.variables["auth"], "none");
+    }
+
+    #[tokio::test]
+    async fn test_a2a_agent_card_from_options() {
+        let mut options = HashMap::new();
+        options.insert("server_port".to_string(), json!(9000));
+        options.insert("auth".to_string(), json!("bearer"));
+        options.insert("streaming".to_string(), json!(false));
+        options.insert(
+            "skills".to_string(),
+            json!([
+                { "id": "book_flight", "tags": ["travel"] },
+                { "id": "find_hotel", "name": "Find hotel", "description": "Search hotels" }
+            ]),
+        );
+
+        let context = A2aProtocolHandler::new()
+            .prepare_context(input(options))
+            .await
+            .unwrap();
+
+        let card = &context.variables["agent_card"];
+        assert_eq!(card["url"], "http://localhost:9000/");
+        assert_eq!(card["capabilities"]["streaming"], false);
+        assert_eq!(card["securitySchemes"]["bearer"]["scheme"], "bearer");
+        assert_eq!(card["security"], json!([{ "bearer": [] }]));
+        assert_eq!(card["skills"][0]["name"], "book_flight");
+        assert_eq!(card["skills"][0]["description"], "book_flight");
+        assert_eq!(card["skills"][1]["description"], "Search hotels");
+        assert_eq!(context.variables["skills"][1]["id"], "find_hotel");
+    }
+
+    #[tokio::test]
+    async fn test_a2a_rejects_invalid_options() {
+        let handler = A2aProtocolHandler::new();
+        for (key, value, message) in [
+            ("auth", json!("oauth"), "Invalid auth scheme"),
+            ("skills", json!([]), "at least one skill"),
+            ("skills", json!([{ "name": "no id" }]), "Invalid skills"),
+            (
+                "skills",
+                json!([{ "id": "a" }, { "id": "a" }]),
+                "Duplicate skill id",
+            ),
+        ] {
+            let mut options = HashMap::new();
+            options.insert(key.to_string(), value);
+            match handler.prepare_context(input(options)).await {
+                Err(ProtocolError::InvalidConfiguration(msg)) => {
+                    assert!(msg.contains(message), "{msg}")
+                }
+                other => panic!("Expected InvalidConfiguration, got {other:?}"),
+            }
+        }
+    }
+
+    fn weather_tools() -> Vec<McpTool> {
+        serde_json::from_value(json!([
+            {
+                "name": "get_forecast",
+                "description": "Weather forecast for a city",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "city": { "type": "string", "description": "City name" },
+                        "days": { "type": "integer", "default": 3 }
+                    },
+                    "required": ["city"]
+                }
+            },
+            { "name": "list_alerts", "title": "Weather alerts" }
+        ]))
+        .unwrap()
+    }
+
+    fn bridge_input(options: HashMap<String, JsonValue>) -> ProtocolInput {
+        let mut input = input(options);
+        input.role = Role::Bridge;
+        input.mcp_server = Some(crate::generation::McpServerSource::Stdio {
+            command: "weather-mcp".to_string(),
+            args: vec!["--stdio".to_string()],
+        });
+        input.mcp_tools = Some(weather_tools());
+        input
+    }
+
+    #[tokio::test]
+    async fn test_a2a_bridge_skills_from_mcp_tools() {
+        let context = A2aProtocolHandler::new()
+            .prepare_context(bridge_input(HashMap::new()))
+            .await
+            .unwrap();
+
+        assert_eq!(context.role, Role::Bridge);
+        let card = &context.variables["agent_card"];
+        assert_eq!(
+            card["description"],
+            "travel-agent A2A bridge to an MCP server"
+        );
+        let forecast = &card["skills"][0];
+        assert_eq!(forecast["id"], "get_forecast");
+        assert_eq!(forecast["name"], "get_forecast");
+        assert_eq!(
+            forecast["description"],
+            "Weather forecast for a city\n\nArguments:\n- `city` (string, required): City name\n- `days` (integer)"
+        );
+        assert_eq!(forecast["examples"], json!([r#"{"city":""}"#]));
+        assert_eq!(forecast["inputModes"], json!(["application/json"]));
+        assert_eq!(forecast["tags"], json!(["mcp"]));
+
+        // Tools without arguments take any message
+        let alerts = &card["skills"][1];
+        assert_eq!(alerts["name"], "Weather alerts");
+        assert_eq!(alerts["description"], "Weather alerts");
+        assert!(alerts.get("examples").is_none());
+        assert_eq!(
+            alerts["inputModes"],
+            json!(["text/plain", "application/json"])
+        );
+
+        match context.protocol_context {
+            Some(ProtocolContext::McpBridge { server, tools }) => {
+                assert_eq!(server.unwrap().to_string(), "weather-mcp --stdio");
+                assert_eq!(tools, weather_tools());
+            }
+            other => panic!("Expected an MCP bridge context, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_a2a_bridge_tools_from_openapi() {
+        let operation: Operation = serde_json::from_value(json!({
+            "operationId": "findPetsByStatus",
+            "path": "/pet/findByStatus",
+            "method": "get",
+            "summary": "Finds pets by status",
+            "parameters": [{
+                "name": "status",
+                "location": "query",
+                "required": true,
+                "description": "Status values to filter by",
+                "schema": { "type": "string", "enum": ["available", "sold"] }
+            }],
+            "request_body": null,
+            "responses": []
+        }))
+        .unwrap();
+        let mut input = input(HashMap::new());
+        input.role = Role::Bridge;
+        input.openapi_spec = Some(crate::generation::OpenApiContext {
+            version: "3.0.0".to_string(),
+            info: crate::generation::ApiInfo {
+                title: "Petstore".to_string(),
+                version: "1.0.0".to_string(),
+                description: None,
+            },
+            servers: vec![],
+            operations: vec![operation],
+            components: None,
+            tags: vec![],
+            diagnostics: vec![],
+        });
+
+        let context = A2aProtocolHandler::new()
+            .prepare_context(input)
+            .await
+            .unwrap();
+
+        let Some(ProtocolContext::McpBridge { server, tools }) = context.protocol_context else {
+            panic!("Expected an MCP bridge context");
+        };
+        assert!(server.is_none());
+        assert_eq!(tools.len(), 1);
+        assert_eq!(tools[0].name, "find_pets_by_status");
+        assert_eq!(
+            tools[0].description.as_deref(),
+            Some("Finds pets by status")
+        );
+        assert_eq!(
+            tools[0].input_schema,
+            json!({
+                "type": "object",
+                "properties": {
+                    "status": {
+                        "type": "string",
+                        "enum": ["available", "sold"],
+                        "description": "Status values to filter by"
+                    }
+                },
+                "required": ["status"]
+            })
+        );
+        let skill = &context.variables["agent_card"]["skills"][0];
+        assert_eq!(skill["examples"], json!([r#"{"status":"available"}"#]));
+        assert_eq!(
+            skill["inputModes"],
+            json!(["text/plain", "application/json"])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_a2a_bridge_rejects_missing_tools_and_skills() {
+        let handler = A2aProtocolHandler::new();
+
+        let mut without_tools = bridge_input(HashMap::new());
+        without_tools.mcp_tools = None;
+        let mut no_tools = bridge_input(HashMap::new());
+        no_tools.mcp_tools = Some(Vec::new());
+        let mut options = HashMap::new();
+        options.insert("skills".to_string(), json!([{ "id": "echo" }]));
+
+        for (input, message) in [
+            (without_tools, "needs the tools of an MCP server"),
+            (no_tools, "no tools"),
+            (bridge_input(options), "cannot be set with `skills`"),
+        ] {
+            match handler.prepare_context(input).await {
+                Err(ProtocolError::InvalidConfiguration(msg)) => {
+                    assert!(msg.contains(message), "{msg}")
+                }
+                other => panic!("Expected InvalidConfiguration, got {other:?}"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_a2a_unsupported_role() {
+        let mut input = input(HashMap::new());
+        input.role = Role::Server;
+
+        let result = A2aProtocolHandler::new().prepare_context(input).await;
+        assert!(matches!(
+            result,
+            Err(ProtocolError::UnsupportedRole {
+                protocol: Protocol::A2a,
+                role: Role::Server
+            })
+        ));
+    }
+}
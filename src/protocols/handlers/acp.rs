@@ -0,0 +1,587 @@
+//! ACP (Agent Communication Protocol) handler implementation
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value as JsonValue, json};
+
+use crate::generation::OpenApiContext;
+use crate::infrastructure::openapi::{Operation, ParameterLocation};
+use crate::protocols::{
+    Protocol, ProtocolConfig, ProtocolError, ProtocolHandler, ProtocolInput, Role, ScaffoldOption,
+    ScaffoldTarget, ScaffoldValue,
+};
+
+/// Port the generated server listens on unless `server_port` is set
+const DEFAULT_PORT: u64 = 8000;
+
+/// ACP agent names are RFC 1123 DNS labels
+const MAX_AGENT_NAME_LEN: usize = 63;
+
+fn default_content_types() -> Vec<String> {
+    vec!["text/plain".to_string(), "application/json".to_string()]
+}
+
+/// The manifest an ACP server publishes for each agent at `/agents/{name}`
+///
+/// Only `name` is required in the `agents` option; `description` defaults to
+/// the name.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AgentManifest {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default = "default_content_types")]
+    pub input_content_types: Vec<String>,
+    #[serde(default = "default_content_types")]
+    pub output_content_types: Vec<String>,
+    #[serde(default, skip_serializing_if = "JsonValue::is_null")]
+    pub metadata: JsonValue,
+    /// The API operation the agent calls, for agents derived from OpenAPI
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub openapi: Option<OpenApiBinding>,
+}
+
+/// How an agent derived from an OpenAPI operation builds its API request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OpenApiBinding {
+    pub operation_id: String,
+    /// Upper-case HTTP method
+    pub method: String,
+    pub path: String,
+    pub path_params: Vec<String>,
+    pub query_params: Vec<String>,
+    pub header_params: Vec<String>,
+    pub has_body: bool,
+}
+
+/// The agent generated servers start with when no `agents` are configured
+fn default_agents() -> Vec<AgentManifest> {
+    vec![AgentManifest {
+        name: "echo".to_string(),
+        description: "Replies with the text of its input, asking for some when the input is empty"
+            .to_string(),
+        input_content_types: vec!["text/plain".to_string()],
+        output_content_types: vec!["text/plain".to_string()],
+        metadata: JsonValue::Null,
+        openapi: None,
+    }]
+}
+
+fn is_agent_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= MAX_AGENT_NAME_LEN
+        && !name.starts_with('-')
+        && !name.ends_with('-')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+/// `getPetById` -> `get-pet-by-id`, trimmed to a valid agent name
+fn agent_name_for(operation_id: &str) -> String {
+    let words: String = operation_id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    let name = crate::generation::utils::to_snake_case(&words)
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect::<String>()
+        .split('-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    name.chars()
+        .take(MAX_AGENT_NAME_LEN)
+        .collect::<String>()
+        .trim_end_matches('-')
+        .to_string()
+}
+
+/// An agent that calls one API operation with the JSON object it receives
+fn openapi_agent(operation: &Operation) -> AgentManifest {
+    let description = operation
+        .summary
+        .clone()
+        .or_else(|| operation.description.clone())
+        .unwrap_or_else(|| format!("{} {}", operation.method.to_uppercase(), operation.path));
+
+    let params_in = |location: ParameterLocation| {
+        operation
+            .parameters
+            .iter()
+            .filter(|p| p.location == location)
+            .map(|p| p.name.clone())
+            .collect::<Vec<_>>()
+    };
+
+    let mut documentation = vec![
+        format!(
+            "Calls `{} {}`. Send a JSON object with the arguments:",
+            operation.method.to_uppercase(),
+            operation.path
+        ),
+        String::new(),
+    ];
+    for param in &operation.parameters {
+        let location = serde_json::to_value(&param.location)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_default();
+        let required = if param.required { ", required" } else { "" };
+        let mut line = format!("- `{}` ({location}{required})", param.name);
+        if let Some(desc) = &param.description {
+            line.push_str(&format!(": {desc}"));
+        }
+        documentation.push(line);
+    }
+    if let Some(body) = &operation.request_body {
+        let required = if body.required { ", required" } else { "" };
+        documentation.push(format!("- `body` (request body{required})"));
+    }
+
+    AgentManifest {
+        name: agent_name_for(&operation.id),
+        description,
+        input_content_types: vec!["application/json".to_string()],
+        output_content_types: vec!["application/json".to_string()],
+        metadata: json!({
+            "documentation": documentation.join("\n"),
+            "tags": operation.tags.clone().unwrap_or_default(),
+        }),
+        openapi: Some(OpenApiBinding {
+            operation_id: operation.id.clone(),
+            method: operation.method.to_uppercase(),
+            path: operation.path.clone(),
+            path_params: params_in(ParameterLocation::Path),
+            query_params: params_in(ParameterLocation::Query),
+            header_params: params_in(ParameterLocation::Header),
+            has_body: operation.request_body.is_some(),
+        }),
+    }
+}
+
+/// Build the agent manifests from the `agents` option and the OpenAPI spec
+///
+/// Configured agents come first, then one agent per API operation. Without
+/// either the server gets the `echo` agent.
+pub fn build_agents(
+    config: &ProtocolConfig,
+    spec: Option<&OpenApiContext>,
+) -> Result<Vec<AgentManifest>, ProtocolError> {
+    let mut agents: Vec<AgentManifest> = match config.options.get("agents") {
+        Some(value) => serde_json::from_value(value.clone()).map_err(|e| {
+            ProtocolError::InvalidConfiguration(format!(
+                "Invalid agents: {e}. Expected a list of objects with at least a `name`"
+            ))
+        })?,
+        None if spec.is_none() => default_agents(),
+        None => Vec::new(),
+    };
+    for agent in &mut agents {
+        if !is_agent_name(&agent.name) {
+            return Err(ProtocolError::InvalidConfiguration(format!(
+                "Invalid agent name '{}': use lower case letters, digits and dashes",
+                agent.name
+            )));
+        }
+        // Bindings only come from the spec
+        agent.openapi = None;
+        if agent.description.is_empty() {
+            agent.description = agent.name.clone();
+        }
+    }
+
+    if let Some(spec) = spec {
+        for operation in &spec.operations {
+            let mut agent = openapi_agent(operation);
+            if agent.name.is_empty() {
+                continue;
+            }
+            // Operation ids that only differ in case or punctuation
+            let base = agent.name.clone();
+            let mut suffix = 2;
+            while agents.iter().any(|a| a.name == agent.name) {
+                agent.name = format!("{base}-{suffix}");
+                suffix += 1;
+            }
+            agents.push(agent);
+        }
+    }
+
+    if agents.is_empty() {
+        return Err(ProtocolError::InvalidConfiguration(
+            "An ACP server needs at least one agent".to_string(),
+        ));
+    }
+    let mut seen = std::collections::HashSet::new();
+    for agent in &agents {
+        if !seen.insert(agent.name.as_str()) {
+            return Err(ProtocolError::InvalidConfiguration(format!(
+                "Duplicate agent name: {}",
+                agent.name
+            )));
+        }
+    }
+    Ok(agents)
+}
+
+fn port(config: &ProtocolConfig) -> u64 {
+    config
+        .options
+        .get("server_port")
+        .and_then(JsonValue::as_u64)
+        .unwrap_or(DEFAULT_PORT)
+}
+
+/// Handler for the Agent Communication Protocol (ACP)
+#[derive(Debug, Clone)]
+pub struct AcpProtocolHandler;
+
+impl AcpProtocolHandler {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for AcpProtocolHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ProtocolHandler for AcpProtocolHandler {
+    fn protocol(&self) -> Protocol {
+        Protocol::Acp
+    }
+
+    fn description(&self) -> &'static str {
+        "Agent Communication Protocol (ACP) - by IBM"
+    }
+
+    fn role_description(&self, role: &Role) -> Option<&'static str> {
+        match role {
+            Role::Server => {
+                Some("Generate ACP server hosting agents with sync, async and streaming runs")
+            }
+            Role::Client => Some("Generate ACP client CLI for discovering agents and running them"),
+            _ => None,
+        }
+    }
+
+    fn scaffold_options(&self, role: &Role) -> Vec<ScaffoldOption> {
+        match role {
+            Role::Server => vec![
+                ScaffoldOption::new(
+                    "schema-path",
+                    ScaffoldTarget::SchemaPath { required: false },
+                    "Path or URL to an OpenAPI schema whose operations become ACP agents",
+                ),
+                ScaffoldOption::variable("port", "server_port", ScaffoldValue::Port, "Server port"),
+                ScaffoldOption::variable(
+                    "base-url",
+                    "base_api_url",
+                    ScaffoldValue::Url,
+                    "Base URL of the API the OpenAPI agents call",
+                ),
+            ],
+            Role::Client => vec![ScaffoldOption::variable(
+                "server-url",
+                "server_url",
+                ScaffoldValue::Url,
+                "ACP server the client connects to by default (defaults to http://localhost:8000)",
+            )],
+            _ => Vec::new(),
+        }
+    }
+
+    async fn prepare_context(
+        &self,
+        input: ProtocolInput,
+    ) -> Result<crate::generation::GenerationContext, ProtocolError> {
+        self.protocol().validate_role(&input.role)?;
+        self.validate_configuration(&input.config)?;
+
+        crate::generation::rules::validate_language_support(
+            Protocol::Acp,
+            &input.role,
+            input.language,
+        )
+        .map_err(|e| match e {
+            crate::generation::GenerationError::UnsupportedLanguageForProtocol {
+                language,
+                protocol,
+            } => ProtocolError::InvalidConfiguration(format!(
+                "Language {:?} is not supported for {:?}/{:?}",
+                language, protocol, input.role
+            )),
+            _ => ProtocolError::InternalError(e.to_string()),
+        })?;
+
+        let mut context = crate::generation::GenerationContext::new(
+            Protocol::Acp,
+            input.role.clone(),
+            input.language,
+        );
+        context.metadata.project_name = input.config.project_name.clone();
+        if let Some(version) = &input.config.version {
+            context.metadata.version = version.clone();
+        }
+
+        // Custom options first, so the normalized values below replace them
+        for (key, value) in &input.config.options {
+            context.add_variable(key.clone(), value.clone());
+        }
+        context.add_variable("project_name".to_string(), json!(input.config.project_name));
+        context.add_variable("requires_openapi".to_string(), json!(false));
+
+        match input.role {
+            Role::Server => {
+                if let Some(spec) = &input.openapi_spec {
+                    context.diagnostics.extend(spec.diagnostics.iter().cloned());
+                    context.add_variable("api_title".to_string(), json!(spec.info.title));
+                    context.add_variable("api_version".to_string(), json!(spec.info.version));
+                    // Relative server URLs leave the base URL to the generated server's options
+                    if !input.config.options.contains_key("base_api_url")
+                        && let Some(server) = spec.servers.first()
+                        && url::Url::parse(&server.url).is_ok()
+                    {
+                        context.add_variable("base_api_url".to_string(), json!(server.url));
+                    }
+                }
+
+                let agents = build_agents(&input.config, input.openapi_spec.as_ref())?;
+                let openapi_agents = agents.iter().any(|agent| agent.openapi.is_some());
+                context.add_variable("agents".to_string(), json!(agents));
+                context.add_variable("openapi_agents".to_string(), json!(openapi_agents));
+                context.add_variable("server_port".to_string(), json!(port(&input.config)));
+            }
+            Role::Client => {
+                let server_url = input
+                    .config
+                    .options
+                    .get("server_url")
+                    .and_then(JsonValue::as_str)
+                    .map(|url| url.trim_end_matches('/').to_string())
+                    .unwrap_or_else(|| format!("http://localhost:{}", port(&input.config)));
+                context.add_variable("server_url".to_string(), json!(server_url));
+            }
+            // Templates of custom roles get the options and nothing more
+            Role::Custom(_) => {}
+            _ => {
+                return Err(ProtocolError::UnsupportedRole {
+                    protocol: self.protocol(),
+                    role: input.role.clone(),
+                });
+            }
+        }
+
+        Ok(context)
+    }
+
+    fn validate_configuration(&self, config: &ProtocolConfig) -> Result<(), ProtocolError> {
+        if config.project_name.is_empty() {
+            return Err(ProtocolError::InvalidConfiguration(
+                "Project name is required".to_string(),
+            ));
+        }
+
+        if !config
+            .project_name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(ProtocolError::InvalidConfiguration(
+                "Project name must be alphanumeric with optional dashes or underscores".to_string(),
+            ));
+        }
+
+        if let Some(url) = config.options.get("server_url")
+            && url.as_str().and_then(|u| url::Url::parse(u).ok()).is_none()
+        {
+            return Err(ProtocolError::InvalidConfiguration(format!(
+                "Invalid server_url: {url}"
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn input(role: Role, options: HashMap<String, JsonValue>) -> ProtocolInput {
+        ProtocolInput {
+            openapi_spec: None,
+            config: ProtocolConfig {
+                project_name: "acp-agents".to_string(),
+                version: None,
+                options,
+            },
+            role,
+            language: crate::generation::Language::Rust,
+            mcp_server: None,
+            mcp_tools: None,
+        }
+    }
+
+    fn operation(id: &str, method: &str, path: &str) -> Operation {
+        serde_json::from_value(json!({
+            "operationId": id,
+            "path": path,
+            "method": method,
+            "tags": ["pet"],
+            "summary": null,
+            "description": null,
+            "externalDocs": null,
+            "parameters": [
+                {"name": "petId", "location": "path", "required": true, "schema": {}, "description": "ID of pet"},
+                {"name": "api_key", "location": "header", "required": false, "schema": {}, "description": null}
+            ],
+            "request_body": null,
+            "responses": [],
+            "callbacks": null,
+            "deprecated": null,
+            "security": null,
+            "servers": null
+        }))
+        .unwrap()
+    }
+
+    fn spec(operations: Vec<Operation>) -> OpenApiContext {
+        serde_json::from_value(json!({
+            "version": "3.0.0",
+            "info": {"title": "Petstore", "version": "1.0.0", "description": null},
+            "servers": [{"url": "https://petstore.example.com/v3", "description": null}],
+            "operations": operations,
+            "components": null
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_acp_server_context_has_default_agent() {
+        let context = AcpProtocolHandler::new()
+            .prepare_context(input(Role::Server, HashMap::new()))
+            .await
+            .unwrap();
+
+        assert_eq!(context.protocol, Protocol::Acp);
+        assert_eq!(context.variables["agents"][0]["name"], "echo");
+        assert_eq!(context.variables["openapi_agents"], false);
+        assert_eq!(context.variables["server_port"], 8000);
+    }
+
+    #[tokio::test]
+    async fn test_acp_server_exposes_openapi_operations_as_agents() {
+        let mut options = HashMap::new();
+        options.insert("agents".to_string(), json!([{ "name": "summarizer" }]));
+        let mut input = input(Role::Server, options);
+        input.openapi_spec = Some(spec(vec![
+            operation("getPetById", "get", "/pet/{petId}"),
+            operation("get_pet_by_id", "delete", "/pet/{petId}"),
+        ]));
+
+        let context = AcpProtocolHandler::new()
+            .prepare_context(input)
+            .await
+            .unwrap();
+
+        let agents = &context.variables["agents"];
+        assert_eq!(agents[0]["name"], "summarizer");
+        assert_eq!(agents[0]["description"], "summarizer");
+        assert_eq!(agents[1]["name"], "get-pet-by-id");
+        assert_eq!(agents[1]["description"], "GET /pet/{petId}");
+        assert_eq!(agents[1]["openapi"]["method"], "GET");
+        assert_eq!(agents[1]["openapi"]["path_params"], json!(["petId"]));
+        assert_eq!(agents[1]["openapi"]["header_params"], json!(["api_key"]));
+        assert!(
+            agents[1]["metadata"]["documentation"]
+                .as_str()
+                .unwrap()
+                .contains("- `petId` (path, required): ID of pet")
+        );
+        assert_eq!(agents[2]["name"], "get-pet-by-id-2");
+        assert_eq!(context.variables["openapi_agents"], true);
+        assert_eq!(
+            context.variables["base_api_url"],
+            "https://petstore.example.com/v3"
+        );
+
+        // A relative server URL is no usable default
+        let mut relative_input = self::input(Role::Server, HashMap::new());
+        let mut relative = spec(vec![operation("getPetById", "get", "/pet/{petId}")]);
+        relative.servers[0].url = "/api/v3".to_string();
+        relative_input.openapi_spec = Some(relative);
+        let context = AcpProtocolHandler::new()
+            .prepare_context(relative_input)
+            .await
+            .unwrap();
+        assert!(!context.variables.contains_key("base_api_url"));
+    }
+
+    #[tokio::test]
+    async fn test_acp_client_server_url() {
+        let handler = AcpProtocolHandler::new();
+        let context = handler
+            .prepare_context(input(Role::Client, HashMap::new()))
+            .await
+            .unwrap();
+        assert_eq!(context.variables["server_url"], "http://localhost:8000");
+
+        let mut options = HashMap::new();
+        options.insert("server_url".to_string(), json!("https://acp.example.com/"));
+        let context = handler
+            .prepare_context(input(Role::Client, options))
+            .await
+            .unwrap();
+        assert_eq!(context.variables["server_url"], "https://acp.example.com");
+    }
+
+    #[tokio::test]
+    async fn test_acp_rejects_invalid_options() {
+        let handler = AcpProtocolHandler::new();
+        for (key, value, message) in [
+            ("agents", json!([]), "at least one agent"),
+            ("agents", json!([{ "name": "Echo" }]), "Invalid agent name"),
+            ("agents", json!([{ "description": "x" }]), "Invalid agents"),
+            (
+                "agents",
+                json!([{ "name": "a" }, { "name": "a" }]),
+                "Duplicate agent name",
+            ),
+            ("server_url", json!("not a url"), "Invalid server_url"),
+        ] {
+            let mut options = HashMap::new();
+            options.insert(key.to_string(), value);
+            match handler.prepare_context(input(Role::Server, options)).await {
+                Err(ProtocolError::InvalidConfiguration(msg)) => {
+                    assert!(msg.contains(message), "{msg}")
+                }
+                other => panic!("Expected InvalidConfiguration, got {other:?}"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_acp_broker_not_generated() {
+        let result = AcpProtocolHandler::new()
+            .prepare_context(input(Role::Broker, HashMap::new()))
+            .await;
+        let message = result.unwrap_err().to_string();
+        assert!(
+            message.contains("not yet implemented for the broker role"),
+            "{message}"
+        );
+    }
+
+    #[test]
+    fn test_agent_name_for() {
+        assert_eq!(agent_name_for("getPetById"), "get-pet-by-id");
+        assert_eq!(agent_name_for("List_Users.v2"), "list-users-v2");
+        assert_eq!(agent_name_for("__"), "");
+    }
+}
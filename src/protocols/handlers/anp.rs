@@ -0,0 +1,424 @@
+//! ANP (Agent Network Protocol) handler implementation
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value as JsonValue, json};
+
+use crate::protocols::{
+    Protocol, ProtocolConfig, ProtocolError, ProtocolHandler, ProtocolInput, Role, ScaffoldOption,
+    ScaffoldValue,
+};
+
+/// Port the generated agent listens on unless `server_port` is set
+const DEFAULT_PORT: u64 = 9000;
+
+/// An application protocol the agent offers during meta-protocol negotiation
+///
+/// Only `id` is required in the `protocols` option; `description` defaults
+/// to the id.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AgentProtocol {
+    pub id: String,
+    #[serde(default)]
+    pub description: String,
+    /// Name of the generated handler function, derived from the id
+    #[serde(default, skip_deserializing)]
+    pub handler: String,
+}
+
+/// The protocol generated agents start with when no `protocols` are configured
+fn default_protocols() -> Vec<AgentProtocol> {
+    vec![AgentProtocol {
+        id: "echo/1.0".to_string(),
+        description: "Replies with the content it receives".to_string(),
+        handler: handler_name("echo/1.0"),
+    }]
+}
+
+/// Function name for the handler of protocol `id`, e.g. `echo_1_0` for `echo/1.0`
+fn handler_name(id: &str) -> String {
+    let words: String = id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    let name = crate::generation::utils::to_snake_case(&words);
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("protocol_{name}")
+    } else {
+        name
+    }
+}
+
+/// Parse the `protocols` option, filling in descriptions and handler names
+fn parse_protocols(value: Option<&JsonValue>) -> Result<Vec<AgentProtocol>, ProtocolError> {
+    let Some(value) = value else {
+        return Ok(default_protocols());
+    };
+    let mut protocols: Vec<AgentProtocol> = serde_json::from_value(value.clone()).map_err(|e| {
+        ProtocolError::InvalidConfiguration(format!(
+            "Invalid protocols: {e}. Expected a list of objects with at least an `id`"
+        ))
+    })?;
+    if protocols.is_empty() {
+        return Err(ProtocolError::InvalidConfiguration(
+            "An ANP agent needs at least one protocol".to_string(),
+        ));
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    let mut handlers = std::collections::HashMap::new();
+    for protocol in &mut protocols {
+        if protocol.id.is_empty() || protocol.id.chars().any(char::is_whitespace) {
+            return Err(ProtocolError::InvalidConfiguration(format!(
+                "Invalid protocol id '{}': ids cannot be empty or contain whitespace",
+                protocol.id
+            )));
+        }
+        if !seen.insert(protocol.id.clone()) {
+            return Err(ProtocolError::InvalidConfiguration(format!(
+                "Duplicate protocol id: {}",
+                protocol.id
+            )));
+        }
+        if protocol.description.is_empty() {
+            protocol.description = protocol.id.clone();
+        }
+        protocol.handler = handler_name(&protocol.id);
+        if let Some(other) = handlers.insert(protocol.handler.clone(), protocol.id.clone()) {
+            return Err(ProtocolError::InvalidConfiguration(format!(
+                "Protocol ids '{other}' and '{}' need distinct handler names",
+                protocol.id
+            )));
+        }
+    }
+    Ok(protocols)
+}
+
+/// The `did:wba` identifier of an agent published at `url`
+///
+/// `https://example.com` is `did:wba:example.com`, a port is percent-encoded
+/// (`did:wba:localhost%3A9000`) and path segments follow as `:user:alice`.
+pub fn did_for_url(url: &url::Url) -> Result<String, ProtocolError> {
+    let host = url.host_str().ok_or_else(|| {
+        ProtocolError::InvalidConfiguration(format!("Agent URL {url} has no host"))
+    })?;
+    let mut did = format!("did:wba:{host}");
+    if let Some(port) = url.port() {
+        did.push_str(&format!("%3A{port}"));
+    }
+    for segment in url.path().split('/').filter(|s| !s.is_empty()) {
+        did.push(':');
+        did.push_str(segment);
+    }
+    Ok(did)
+}
+
+fn agent_url(config: &ProtocolConfig) -> Result<url::Url, ProtocolError> {
+    let port = config
+        .options
+        .get("server_port")
+        .and_then(JsonValue::as_u64)
+        .unwrap_or(DEFAULT_PORT);
+    let raw = match config.options.get("agent_url") {
+        Some(value) => value.as_str().map(str::to_string).ok_or_else(|| {
+            ProtocolError::InvalidConfiguration(format!("Invalid agent_url: {value}"))
+        })?,
+        None => format!("http://localhost:{port}"),
+    };
+    let url = url::Url::parse(&raw)
+        .map_err(|e| ProtocolError::InvalidConfiguration(format!("Invalid agent_url: {e}")))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(ProtocolError::InvalidConfiguration(format!(
+            "Invalid agent_url: {url} must use http or https"
+        )));
+    }
+    Ok(url)
+}
+
+fn string_option<'a>(config: &'a ProtocolConfig, key: &str) -> Option<&'a str> {
+    config.options.get(key).and_then(JsonValue::as_str)
+}
+
+/// Handler for the Agent Network Protocol (ANP)
+#[derive(Debug, Clone)]
+pub struct AnpProtocolHandler;
+
+impl AnpProtocolHandler {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for AnpProtocolHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ProtocolHandler for AnpProtocolHandler {
+    fn protocol(&self) -> Protocol {
+        Protocol::Anp
+    }
+
+    fn description(&self) -> &'static str {
+        "Agent Network Protocol (ANP) - decentralized agents with DID identities"
+    }
+
+    fn role_description(&self, role: &Role) -> Option<&'static str> {
+        (*role == Role::Agent).then_some(
+            "Generate ANP agent with a did:wba identity, agent description and protocol negotiation",
+        )
+    }
+
+    fn scaffold_options(&self, role: &Role) -> Vec<ScaffoldOption> {
+        if *role != Role::Agent {
+            return Vec::new();
+        }
+        vec![
+            ScaffoldOption::variable(
+                "port",
+                "server_port",
+                ScaffoldValue::Port,
+                "Port the agent listens on",
+            ),
+            ScaffoldOption::variable(
+                "agent-url",
+                "agent_url",
+                ScaffoldValue::Url,
+                "Public URL the agent's DID is derived from (defaults to http://localhost:<port>)",
+            ),
+        ]
+    }
+
+    async fn prepare_context(
+        &self,
+        input: ProtocolInput,
+    ) -> Result<crate::generation::GenerationContext, ProtocolError> {
+        self.protocol().validate_role(&input.role)?;
+        self.validate_configuration(&input.config)?;
+
+        crate::generation::rules::validate_language_support(
+            Protocol::Anp,
+            &input.role,
+            input.language,
+        )
+        .map_err(|e| match e {
+            crate::generation::GenerationError::UnsupportedLanguageForProtocol {
+                language,
+                protocol,
+            } => ProtocolError::InvalidConfiguration(format!(
+                "Language {:?} is not supported for {:?}/{:?}",
+                language, protocol, input.role
+            )),
+            _ => ProtocolError::InternalError(e.to_string()),
+        })?;
+
+        let url = agent_url(&input.config)?;
+        let did = did_for_url(&url)?;
+        let protocols = parse_protocols(input.config.options.get("protocols"))?;
+        let description = string_option(&input.config, "agent_description")
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("{} ANP agent", input.config.project_name));
+
+        let mut context = crate::generation::GenerationContext::new(
+            Protocol::Anp,
+            input.role.clone(),
+            input.language,
+        );
+        context.metadata.project_name = input.config.project_name.clone();
+        if let Some(version) = &input.config.version {
+            context.metadata.version = version.clone();
+        }
+        context.metadata.description = Some(description.clone());
+
+        // Custom options first, so the normalized values below replace them
+        for (key, value) in &input.config.options {
+            context.add_variable(key.clone(), value.clone());
+        }
+
+        context.add_variable("project_name".to_string(), json!(input.config.project_name));
+        context.add_variable("requires_openapi".to_string(), json!(false));
+        context.add_variable(
+            "agent_name".to_string(),
+            json!(string_option(&input.config, "agent_name").unwrap_or(&input.config.project_name)),
+        );
+        context.add_variable("agent_description".to_string(), json!(description));
+        context.add_variable(
+            "agent_url".to_string(),
+            json!(url.as_str().trim_end_matches('/')),
+        );
+        context.add_variable("did".to_string(), json!(did));
+        context.add_variable("protocols".to_string(), json!(protocols));
+        context.add_variable(
+            "server_port".to_string(),
+            json!(
+                input
+                    .config
+                    .options
+                    .get("server_port")
+                    .and_then(JsonValue::as_u64)
+                    .unwrap_or(DEFAULT_PORT)
+            ),
+        );
+
+        Ok(context)
+    }
+
+    fn validate_configuration(&self, config: &ProtocolConfig) -> Result<(), ProtocolError> {
+        if config.project_name.is_empty() {
+            return Err(ProtocolError::InvalidConfiguration(
+                "Project name is required".to_string(),
+            ));
+        }
+
+        if !config
+            .project_name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(ProtocolError::InvalidConfiguration(
+                "Project name must be alphanumeric with optional dashes or underscores".to_string(),
+            ));
+        }
+
+        // Catch a malformed URL or protocols before any template is rendered
+        did_for_url(&agent_url(config)?)?;
+        parse_protocols(config.options.get("protocols"))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocols::Role;
+    use std::collections::HashMap;
+
+    fn input(options: HashMap<String, JsonValue>) -> ProtocolInput {
+        ProtocolInput {
+            openapi_spec: None,
+            config: ProtocolConfig {
+                project_name: "hotel-agent".to_string(),
+                version: None,
+                options,
+            },
+            role: Role::Agent,
+            language: crate::generation::Language::Rust,
+            mcp_server: None,
+            mcp_tools: None,
+        }
+    }
+
+    #[test]
+    fn test_did_for_url() {
+        let did = |url: &str| did_for_url(&url::Url::parse(url).unwrap()).unwrap();
+        assert_eq!(did("https://example.com"), "did:wba:example.com");
+        assert_eq!(did("https://example.com:443/"), "did:wba:example.com");
+        assert_eq!(did("http://localhost:9000"), "did:wba:localhost%3A9000");
+        assert_eq!(
+            did("https://example.com/user/alice/"),
+            "did:wba:example.com:user:alice"
+        );
+    }
+
+    #[test]
+    fn test_handler_name() {
+        assert_eq!(handler_name("echo/1.0"), "echo_1_0");
+        assert_eq!(handler_name("hotelBooking/2"), "hotel_booking_2");
+        assert_eq!(handler_name("1.0/chat"), "protocol_1_0_chat");
+    }
+
+    #[tokio::test]
+    async fn test_anp_agent_context_defaults() {
+        let context = AnpProtocolHandler::new()
+            .prepare_context(input(HashMap::new()))
+            .await
+            .unwrap();
+
+        assert_eq!(context.protocol, Protocol::Anp);
+        assert_eq!(context.role, Role::Agent);
+        assert_eq!(context.variables["agent_name"], "hotel-agent");
+        assert_eq!(context.variables["agent_url"], "http://localhost:9000");
+        assert_eq!(context.variables["did"], "did:wba:localhost%3A9000");
+        assert_eq!(context.variables["protocols"][0]["id"], "echo/1.0");
+        assert_eq!(context.variables["server_port"], 9000);
+    }
+
+    #[tokio::test]
+    async fn test_anp_agent_context_from_options() {
+        let mut options = HashMap::new();
+        options.insert("server_port".to_string(), json!(9101));
+        options.insert(
+            "agent_url".to_string(),
+            json!("https://agents.example.com/hotel/"),
+        );
+        options.insert(
+            "protocols".to_string(),
+            json!([{ "id": "hotel-booking/1.0" }]),
+        );
+
+        let context = AnpProtocolHandler::new()
+            .prepare_context(input(options))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            context.variables["agent_url"],
+            "https://agents.example.com/hotel"
+        );
+        assert_eq!(context.variables["did"], "did:wba:agents.example.com:hotel");
+        assert_eq!(
+            context.variables["protocols"][0]["description"],
+            "hotel-booking/1.0"
+        );
+        assert_eq!(
+            context.variables["protocols"][0]["handler"],
+            "hotel_booking_1_0"
+        );
+        assert_eq!(context.variables["server_port"], 9101);
+    }
+
+    #[tokio::test]
+    async fn test_anp_rejects_invalid_options() {
+        let handler = AnpProtocolHandler::new();
+        for (key, value, message) in [
+            ("protocols", json!([]), "at least one protocol"),
+            ("protocols", json!([{ "id": "a b" }]), "Invalid protocol id"),
+            (
+                "protocols",
+                json!([{ "id": "a" }, { "id": "a" }]),
+                "Duplicate protocol id",
+            ),
+            ("protocols", json!("echo"), "Invalid protocols"),
+            (
+                "protocols",
+                json!([{ "id": "echo/1.0" }, { "id": "echo-1.0" }]),
+                "need distinct handler names",
+            ),
+            (
+                "agent_url",
+                json!("ftp://example.com"),
+                "must use http or https",
+            ),
+            ("agent_url", json!("not a url"), "Invalid agent_url"),
+        ] {
+            let mut options = HashMap::new();
+            options.insert(key.to_string(), value);
+            match handler.prepare_context(input(options)).await {
+                Err(ProtocolError::InvalidConfiguration(msg)) => {
+                    assert!(msg.contains(message), "{msg}")
+                }
+                other => panic!("Expected InvalidConfiguration, got {other:?}"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_anp_has_no_server_role() {
+        let mut input = input(HashMap::new());
+        input.role = Role::Server;
+        let result = AnpProtocolHandler::new().prepare_context(input).await;
+        assert!(matches!(result, Err(ProtocolError::UnsupportedRole { .. })));
+    }
+}
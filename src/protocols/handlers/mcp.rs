@@ -4,7 +4,8 @@ use async_trait::async_trait;
 use serde_json::json;
 
 use crate::protocols::{
-    Protocol, ProtocolConfig, ProtocolError, ProtocolHandler, ProtocolInput, Role,
+    Protocol, ProtocolConfig, ProtocolError, ProtocolHandler, ProtocolInput, Role, ScaffoldOption,
+    ScaffoldTarget, ScaffoldValue,
 };
 
 /// Handler for the Model Context Protocol (MCP)
@@ -17,12 +18,56 @@ impl McpProtocolHandler {
     }
 }
 
+impl Default for McpProtocolHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[async_trait]
 impl ProtocolHandler for McpProtocolHandler {
     fn protocol(&self) -> Protocol {
         Protocol::Mcp
     }
 
+    fn description(&self) -> &'static str {
+        "Model Context Protocol (MCP) servers and clients"
+    }
+
+    fn role_description(&self, role: &Role) -> Option<&'static str> {
+        match role {
+            Role::Server => Some("Generate MCP server from OpenAPI specification"),
+            Role::Client => Some("Generate MCP client"),
+            _ => None,
+        }
+    }
+
+    fn scaffold_options(&self, role: &Role) -> Vec<ScaffoldOption> {
+        match role {
+            Role::Server => vec![
+                ScaffoldOption::new(
+                    "schema-path",
+                    ScaffoldTarget::SchemaPath { required: true },
+                    "Path or URL to OpenAPI schema (YAML or JSON)",
+                ),
+                ScaffoldOption::variable(
+                    "log-file",
+                    "log_file",
+                    ScaffoldValue::String,
+                    "Log file name without extension",
+                ),
+                ScaffoldOption::variable("port", "server_port", ScaffoldValue::Port, "Server port"),
+                ScaffoldOption::variable(
+                    "base-url",
+                    "base_api_url",
+                    ScaffoldValue::Url,
+                    "Base URL of the OpenAPI specification",
+                ),
+            ],
+            _ => Vec::new(),
+        }
+    }
+
     async fn prepare_context(
         &self,
         input: ProtocolInput,
@@ -92,6 +137,13 @@ impl ProtocolHandler for McpProtocolHandler {
                 context.add_variable("transport".to_string(), json!("stdio"));
                 context.add_variable("connection_type".to_string(), json!("direct"));
             }
+            // The template decides what to make of the spec, if one is given
+            Role::Custom(_) => {
+                context.add_variable(
+                    "requires_openapi".to_string(),
+                    json!(input.openapi_spec.is_some()),
+                );
+            }
             _ => {
                 return Err(ProtocolError::UnsupportedRole {
                     protocol: self.protocol(),
@@ -102,6 +154,9 @@ impl ProtocolHandler for McpProtocolHandler {
 
         // Add OpenAPI spec if present (for MCP Server)
         if let Some(spec) = input.openapi_spec {
+            // Carry parser diagnostics into the generation run
+            context.diagnostics.extend(spec.diagnostics.iter().cloned());
+
             // Add OpenAPI metadata to context variables
             context.add_variable("api_title".to_string(), json!(spec.info.title));
             context.add_variable("api_version".to_string(), json!(spec.info.version));
@@ -123,7 +178,7 @@ impl ProtocolHandler for McpProtocolHandler {
 
             // Store in protocol-specific context
             context.protocol_context = Some(crate::generation::ProtocolContext::McpServer {
-                openapi_spec: spec,
+                openapi_spec: Box::new(spec),
                 endpoints,
             });
         }
@@ -195,6 +250,8 @@ mod tests {
             },
             role: Role::Server,
             language: crate::generation::Language::Rust,
+            mcp_server: None,
+            mcp_tools: None,
         };
 
         let result = handler.prepare_context(input).await;
@@ -219,6 +276,8 @@ mod tests {
             },
             role: Role::Client,
             language: crate::generation::Language::Rust,
+            mcp_server: None,
+            mcp_tools: None,
         };
 
         let result = handler.prepare_context(input).await;
@@ -250,6 +309,8 @@ mod tests {
                 servers: vec![],
                 operations: vec![],
                 components: None,
+                tags: vec![],
+                diagnostics: vec![],
             }),
             config: ProtocolConfig {
                 project_name: "test-server".to_string(),
@@ -258,6 +319,8 @@ mod tests {
             },
             role: Role::Server,
             language: crate::generation::Language::Rust,
+            mcp_server: None,
+            mcp_tools: None,
         };
 
         let result = handler.prepare_context(input).await;
@@ -358,6 +421,8 @@ mod tests {
             },
             role: Role::Agent,
             language: crate::generation::Language::Rust,
+            mcp_server: None,
+            mcp_tools: None,
         };
 
         let result = handler.prepare_context(input).await;
@@ -370,4 +435,47 @@ mod tests {
             panic!("Expected UnsupportedRole error");
         }
     }
+
+    #[tokio::test]
+    async fn test_mcp_custom_role() {
+        let handler = McpProtocolHandler::new();
+        let mut options = std::collections::HashMap::new();
+        options.insert("severity".to_string(), json!("high"));
+        let input = ProtocolInput {
+            openapi_spec: None,
+            config: ProtocolConfig {
+                project_name: "test-reviewer".to_string(),
+                version: None,
+                options,
+            },
+            role: Role::Custom("reviewer".to_string()),
+            language: crate::generation::Language::Go,
+            mcp_server: None,
+            mcp_tools: None,
+        };
+
+        let context = handler.prepare_context(input).await.unwrap();
+        assert_eq!(context.role, Role::Custom("reviewer".to_string()));
+        assert_eq!(context.variables["requires_openapi"], false);
+        assert_eq!(context.variables["severity"], "high");
+        assert!(context.protocol_context.is_none());
+    }
+
+    #[test]
+    fn test_mcp_scaffold_options() {
+        let handler = McpProtocolHandler::new();
+        let flags: Vec<_> = handler
+            .scaffold_options(&Role::Server)
+            .iter()
+            .map(|option| option.flag)
+            .collect();
+        assert_eq!(flags, ["schema-path", "log-file", "port", "base-url"]);
+        assert!(handler.scaffold_options(&Role::Client).is_empty());
+        assert!(handler.role_description(&Role::Server).is_some());
+        assert!(
+            handler
+                .role_description(&Role::Custom("reviewer".to_string()))
+                .is_none()
+        );
+    }
 }
@@ -1,3 +1,6 @@
 //! Protocol handler implementations
 
+pub mod a2a;
+pub mod acp;
+pub mod anp;
 pub mod mcp;
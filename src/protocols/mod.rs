@@ -36,7 +36,10 @@ mod tests {
     fn test_a2a_protocol_capabilities() {
         let capabilities = Protocol::A2a.capabilities();
         assert_eq!(capabilities.protocol, Protocol::A2a);
-        assert_eq!(capabilities.supported_roles, vec![Role::Agent]);
+        assert_eq!(
+            capabilities.supported_roles,
+            vec![Role::Agent, Role::Bridge]
+        );
         assert!(!capabilities.requires_openapi);
     }
 
@@ -45,6 +48,7 @@ mod tests {
         assert!(Protocol::Mcp.validate_role(&Role::Server).is_ok());
         assert!(Protocol::Mcp.validate_role(&Role::Client).is_ok());
         assert!(Protocol::A2a.validate_role(&Role::Agent).is_ok());
+        assert!(Protocol::A2a.validate_role(&Role::Bridge).is_ok());
         assert!(Protocol::Acp.validate_role(&Role::Broker).is_ok());
     }
 
@@ -108,10 +112,13 @@ mod tests {
         let handler = handler.expect("MCP handler should be registered");
         assert_eq!(handler.protocol(), Protocol::Mcp);
 
-        // Other protocols should not be registered
-        assert!(registry.get(Protocol::A2a).is_none());
-        assert!(registry.get(Protocol::Acp).is_none());
-        assert!(registry.get(Protocol::Anp).is_none());
+        // A2A, ACP and ANP are registered too
+        for protocol in [Protocol::A2a, Protocol::Acp, Protocol::Anp] {
+            assert_eq!(
+                registry.get(protocol).map(|handler| handler.protocol()),
+                Some(protocol)
+            );
+        }
     }
 
     #[test]
@@ -171,6 +178,10 @@ mod tests {
             self.protocol
         }
 
+        fn description(&self) -> &'static str {
+            "Mock protocol"
+        }
+
         async fn prepare_context(
             &self,
             _input: ProtocolInput,
@@ -26,11 +26,23 @@ impl ProtocolRegistry {
             Arc::new(crate::protocols::handlers::mcp::McpProtocolHandler::new()),
         )?;
 
-        // TODO: Register A2A, ACP, ANP protocol handlers when implemented
-        // Currently only MCP is supported
-        // - A2A (Agent to Agent) - Google
-        // - ACP (Agent Communication Protocol) - IBM
-        // - ANP (Agent Network Protocol) - Cisco
+        // Register A2A handler
+        registry.register(
+            crate::protocols::Protocol::A2a,
+            Arc::new(crate::protocols::handlers::a2a::A2aProtocolHandler::new()),
+        )?;
+
+        // Register ACP handler
+        registry.register(
+            crate::protocols::Protocol::Acp,
+            Arc::new(crate::protocols::handlers::acp::AcpProtocolHandler::new()),
+        )?;
+
+        // Register ANP handler
+        registry.register(
+            crate::protocols::Protocol::Anp,
+            Arc::new(crate::protocols::handlers::anp::AnpProtocolHandler::new()),
+        )?;
 
         Ok(registry)
     }
@@ -57,6 +69,23 @@ impl ProtocolRegistry {
     ) -> Option<Arc<dyn crate::protocols::ProtocolHandler>> {
         self.handlers.read().ok()?.get(&protocol).cloned()
     }
+
+    /// Every registered handler, ordered by protocol name
+    pub fn handlers(&self) -> Vec<Arc<dyn crate::protocols::ProtocolHandler>> {
+        let mut handlers: Vec<_> = self
+            .handlers
+            .read()
+            .map(|handlers| handlers.values().cloned().collect())
+            .unwrap_or_default();
+        handlers.sort_by_key(|handler| handler.protocol().to_string());
+        handlers
+    }
+}
+
+impl Default for ProtocolRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[cfg(test)]
@@ -86,12 +115,21 @@ mod tests {
     fn test_with_defaults() {
         let registry = ProtocolRegistry::with_defaults().unwrap();
 
-        // Should have MCP registered
+        // Every protocol has a handler registered
         assert!(registry.get(crate::protocols::Protocol::Mcp).is_some());
+        assert!(registry.get(crate::protocols::Protocol::A2a).is_some());
+        assert!(registry.get(crate::protocols::Protocol::Acp).is_some());
+        assert!(registry.get(crate::protocols::Protocol::Anp).is_some());
+    }
 
-        // Should not have others registered
-        assert!(registry.get(crate::protocols::Protocol::A2a).is_none());
-        assert!(registry.get(crate::protocols::Protocol::Acp).is_none());
-        assert!(registry.get(crate::protocols::Protocol::Anp).is_none());
+    #[test]
+    fn test_handlers_are_ordered_by_protocol_name() {
+        let registry = ProtocolRegistry::with_defaults().unwrap();
+        let protocols: Vec<_> = registry
+            .handlers()
+            .iter()
+            .map(|handler| handler.protocol().to_string())
+            .collect();
+        assert_eq!(protocols, ["a2a", "acp", "anp", "mcp"]);
     }
 }
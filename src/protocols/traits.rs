@@ -7,6 +7,11 @@ use serde_json::Value as JsonValue;
 #[derive(Debug, Clone)]
 pub struct ProtocolInput {
     pub openapi_spec: Option<crate::generation::OpenApiContext>,
+    /// MCP server a bridge connects to
+    pub mcp_server: Option<crate::generation::McpServerSource>,
+    /// Tools the bridged MCP server listed; without them a bridge derives
+    /// its tools from `openapi_spec`
+    pub mcp_tools: Option<Vec<crate::generation::McpTool>>,
     pub config: ProtocolConfig,
     pub role: crate::protocols::Role,
     pub language: crate::generation::Language,
@@ -26,6 +31,24 @@ pub trait ProtocolHandler: Send + Sync {
     /// Returns the protocol this handler implements
     fn protocol(&self) -> crate::protocols::Protocol;
 
+    /// One-line summary of the protocol, for `agenterra scaffold --help`
+    fn description(&self) -> &'static str;
+
+    /// One-line summary of generating `role`, for its scaffold command;
+    /// `None` leaves it to the role's templates
+    fn role_description(&self, _role: &crate::protocols::Role) -> Option<&'static str> {
+        None
+    }
+
+    /// Options the scaffold command of `role` takes on top of the ones every
+    /// role takes
+    fn scaffold_options(
+        &self,
+        _role: &crate::protocols::Role,
+    ) -> Vec<crate::protocols::ScaffoldOption> {
+        Vec::new()
+    }
+
     /// Prepares generation context from input
     async fn prepare_context(
         &self,
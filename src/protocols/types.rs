@@ -25,7 +25,7 @@ impl Protocol {
             },
             Protocol::A2a => ProtocolCapabilities {
                 protocol: Protocol::A2a,
-                supported_roles: vec![Role::Agent],
+                supported_roles: vec![Role::Agent, Role::Bridge],
                 requires_openapi: false,
                 supports_streaming: true,
                 supports_bidirectional: true,
@@ -47,9 +47,12 @@ impl Protocol {
         }
     }
 
+    /// Check the protocol supports `role`
+    ///
+    /// Custom roles are defined by their templates, so every protocol accepts them.
     pub fn validate_role(&self, role: &Role) -> Result<(), ProtocolError> {
         let capabilities = self.capabilities();
-        if capabilities.supported_roles.contains(role) {
+        if matches!(role, Role::Custom(_)) || capabilities.supported_roles.contains(role) {
             Ok(())
         } else {
             Err(ProtocolError::UnsupportedRole {
@@ -99,9 +102,12 @@ pub struct ProtocolCapabilities {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Role {
     Agent,
+    /// Exposes a server of another protocol, such as MCP, through this one
+    Bridge,
     Broker,
     Client,
     Server,
+    /// Role defined by a template rather than by the protocol, such as `reviewer`
     Custom(String),
 }
 
@@ -109,6 +115,7 @@ impl std::fmt::Display for Role {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             Role::Agent => write!(f, "agent"),
+            Role::Bridge => write!(f, "bridge"),
             Role::Broker => write!(f, "broker"),
             Role::Client => write!(f, "client"),
             Role::Server => write!(f, "server"),
@@ -123,15 +130,116 @@ impl FromStr for Role {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "agent" => Ok(Role::Agent),
+            "bridge" => Ok(Role::Bridge),
             "broker" => Ok(Role::Broker),
             "client" => Ok(Role::Client),
             "server" => Ok(Role::Server),
+            other if is_custom_role_name(other) => Ok(Role::Custom(other.to_string())),
             other => Err(ProtocolError::InvalidConfiguration(format!(
-                "Unknown role: {other}"
+                "Invalid role: '{other}'; custom roles start with a letter and contain only letters, digits, dashes and underscores"
             ))),
         }
     }
 }
 
+/// Whether `name` can name a custom role, and so a scaffold command and a
+/// template path segment
+fn is_custom_role_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+}
+
+/// Option of `agenterra scaffold <protocol> <role>` that a protocol handler
+/// declares on top of the options every role takes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScaffoldOption {
+    /// Long flag without the leading dashes, such as `port`
+    pub flag: &'static str,
+    pub help: &'static str,
+    pub target: ScaffoldTarget,
+}
+
+impl ScaffoldOption {
+    /// Option setting the template variable `variable`
+    pub fn variable(
+        flag: &'static str,
+        variable: &'static str,
+        kind: ScaffoldValue,
+        help: &'static str,
+    ) -> Self {
+        Self {
+            flag,
+            help,
+            target: ScaffoldTarget::Variable {
+                name: variable,
+                kind,
+            },
+        }
+    }
+
+    pub fn new(flag: &'static str, target: ScaffoldTarget, help: &'static str) -> Self {
+        Self { flag, help, target }
+    }
+}
+
+/// What a scaffold option sets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScaffoldTarget {
+    /// Template variable `name`, from a value of `kind`
+    Variable {
+        name: &'static str,
+        kind: ScaffoldValue,
+    },
+    /// Path or URL of the OpenAPI schema to generate from
+    SchemaPath { required: bool },
+    /// Command starting the MCP server a bridge lists the tools of; required
+    /// unless the MCP server URL or a schema path is given
+    McpCommand,
+    /// Argument passed to the MCP server command (repeatable)
+    McpArg,
+    /// SSE endpoint of the MCP server a bridge lists the tools of
+    McpUrl,
+}
+
+/// Value a variable option takes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScaffoldValue {
+    String,
+    /// TCP port, stored as a number
+    Port,
+    /// Absolute URL, stored as a string
+    Url,
+}
+
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_role_from_str() {
+        assert_eq!("Server".parse::<Role>().unwrap(), Role::Server);
+        assert_eq!("bridge".parse::<Role>().unwrap(), Role::Bridge);
+        assert_eq!(
+            "code-reviewer".parse::<Role>().unwrap(),
+            Role::Custom("code-reviewer".to_string())
+        );
+        assert_eq!(
+            "Reviewer_2".parse::<Role>().unwrap(),
+            Role::Custom("reviewer_2".to_string())
+        );
+
+        for invalid in ["", "2fa", "-reviewer", "code reviewer", "review/er"] {
+            assert!(invalid.parse::<Role>().is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_custom_roles_are_valid_for_every_protocol() {
+        let role = Role::Custom("reviewer".to_string());
+        for protocol in [Protocol::A2a, Protocol::Acp, Protocol::Anp, Protocol::Mcp] {
+            assert!(protocol.validate_role(&role).is_ok());
+        }
+    }
+}
@@ -0,0 +1,227 @@
+//! Integration tests for scaffolding A2A agents
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn scaffold_agent(work_dir: &Path) -> Command {
+    let mut cmd = Command::cargo_bin("agenterra").unwrap();
+    cmd.args(["scaffold", "a2a", "agent", "--project-name", "trip-agent"])
+        .arg("--output-dir")
+        .arg(work_dir)
+        .arg("--no-hooks");
+    cmd
+}
+
+#[test]
+fn test_scaffold_a2a_agent() {
+    let work_dir = TempDir::new().unwrap();
+
+    scaffold_agent(work_dir.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Successfully generated a2a agent rust 'trip-agent'",
+        ));
+
+    let project = work_dir.path().join("trip-agent");
+    let card: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(project.join("agent-card.json")).unwrap())
+            .unwrap();
+    assert_eq!(card["name"], "trip-agent");
+    assert_eq!(card["url"], "http://localhost:8080/");
+    assert_eq!(card["preferredTransport"], "JSONRPC");
+    assert_eq!(card["capabilities"]["streaming"], true);
+    assert_eq!(card["skills"][0]["id"], "echo");
+    assert!(card.get("securitySchemes").is_none());
+
+    let cargo = fs::read_to_string(project.join("Cargo.toml")).unwrap();
+    assert!(cargo.contains("name = \"trip-agent\""));
+
+    let server = fs::read_to_string(project.join("src/server.rs")).unwrap();
+    for method in [
+        "message/send",
+        "message/stream",
+        "tasks/get",
+        "tasks/cancel",
+    ] {
+        assert!(server.contains(&format!("\"{method}\"")), "{method}");
+    }
+    assert!(server.contains("/.well-known/agent-card.json"));
+
+    let agent = fs::read_to_string(project.join("src/agent.rs")).unwrap();
+    assert!(agent.contains("pub const SKILLS: &[&str] = &[\"echo\"];"));
+    assert!(agent.contains("async fn echo(&self, ctx: &TaskContext)"));
+
+    let main = fs::read_to_string(project.join("src/main.rs")).unwrap();
+    assert!(main.contains("use trip_agent::server::{self, AppState, Auth};"));
+    assert!(main.contains("let auth = Auth::None;"));
+    assert!(project.join("src/store.rs").is_file());
+    assert!(project.join("src/types.rs").is_file());
+}
+
+#[test]
+fn test_scaffold_a2a_agent_with_skills_and_auth() {
+    let work_dir = TempDir::new().unwrap();
+    let vars_file = work_dir.path().join("agent.json");
+    fs::write(
+        &vars_file,
+        r#"{
+            "auth": "bearer",
+            "streaming": false,
+            "skills": [
+                {"id": "book-flight", "name": "Book flight", "tags": ["travel"]},
+                {"id": "type"}
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    scaffold_agent(work_dir.path())
+        .args([
+            "--port",
+            "9100",
+            "--agent-url",
+            "https://agents.example.com/trip",
+        ])
+        .arg("--vars-file")
+        .arg(&vars_file)
+        .assert()
+        .success();
+
+    let project = work_dir.path().join("trip-agent");
+    let card: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(project.join("agent-card.json")).unwrap())
+            .unwrap();
+    assert_eq!(card["url"], "https://agents.example.com/trip");
+    assert_eq!(card["capabilities"]["streaming"], false);
+    assert_eq!(card["skills"][1]["name"], "type");
+    assert_eq!(card["securitySchemes"]["bearer"]["scheme"], "bearer");
+    assert_eq!(card["security"][0]["bearer"], serde_json::json!([]));
+
+    let agent = fs::read_to_string(project.join("src/agent.rs")).unwrap();
+    assert!(agent.contains("\"book-flight\" => self.book_flight(ctx).await,"));
+    assert!(agent.contains("\"type\" => self.type_(ctx).await,"));
+
+    let main = fs::read_to_string(project.join("src/main.rs")).unwrap();
+    assert!(main.contains("env = \"A2A_AUTH_TOKEN\""));
+    assert!(main.contains("default_value_t = 9100"));
+}
+
+#[test]
+fn test_scaffold_a2a_agent_rejects_invalid_skills() {
+    let work_dir = TempDir::new().unwrap();
+
+    scaffold_agent(work_dir.path())
+        .args(["--var", "skills=[]"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("at least one skill"));
+}
+
+fn scaffold_bridge(work_dir: &Path) -> Command {
+    let mut cmd = Command::cargo_bin("agenterra").unwrap();
+    cmd.args(["scaffold", "a2a", "bridge", "--project-name", "pet-bridge"])
+        .arg("--output-dir")
+        .arg(work_dir)
+        .arg("--no-hooks");
+    cmd
+}
+
+#[test]
+fn test_scaffold_a2a_bridge_from_openapi() {
+    let work_dir = TempDir::new().unwrap();
+
+    scaffold_bridge(work_dir.path())
+        .args([
+            "--schema-path",
+            "tests/fixtures/openapi/petstore.openapi.v3.json",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Successfully generated a2a bridge rust 'pet-bridge'",
+        ));
+
+    let project = work_dir.path().join("pet-bridge");
+    let card: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(project.join("agent-card.json")).unwrap())
+            .unwrap();
+    let tools: Vec<serde_json::Value> =
+        serde_json::from_str(&fs::read_to_string(project.join("mcp-tools.json")).unwrap()).unwrap();
+    let skills = card["skills"].as_array().unwrap();
+    assert_eq!(skills.len(), tools.len());
+    for (skill, tool) in skills.iter().zip(&tools) {
+        assert_eq!(skill["id"], tool["name"]);
+        let description = skill["description"].as_str().unwrap();
+        assert!(description.starts_with(tool["description"].as_str().unwrap()));
+    }
+    assert!(
+        skills
+            .iter()
+            .any(|skill| skill["id"] == "find_pets_by_status")
+    );
+
+    let mcp = fs::read_to_string(project.join("src/mcp.rs")).unwrap();
+    assert!(mcp.contains("pub fn generated() -> Option<Self> {\n        None"));
+    let lib = fs::read_to_string(project.join("src/lib.rs")).unwrap();
+    assert!(lib.contains("pub mod mcp;"));
+    let cargo = fs::read_to_string(project.join("Cargo.toml")).unwrap();
+    assert!(cargo.contains("agenterra-rmcp"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_scaffold_a2a_bridge_from_stdio_server() {
+    let work_dir = TempDir::new().unwrap();
+    // A server that answers by request order, ignoring what it is sent
+    let script = r#"
+        read request
+        echo '{"jsonrpc":"2.0","id":1,"result":{"capabilities":{}}}'
+        read notification
+        read request
+        echo '{"jsonrpc":"2.0","id":2,"result":{"tools":[{"name":"forecast","description":"Weather forecast for a city","inputSchema":{"type":"object","properties":{"city":{"type":"string"}},"required":["city"]}}]}}'
+        read eof
+    "#;
+
+    scaffold_bridge(work_dir.path())
+        .args([
+            "--mcp-command",
+            "sh",
+            "--mcp-arg",
+            "-c",
+            "--mcp-arg",
+            script,
+        ])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Exposed 1 MCP tools as skills"));
+
+    let project = work_dir.path().join("pet-bridge");
+    let card: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(project.join("agent-card.json")).unwrap())
+            .unwrap();
+    assert_eq!(card["skills"].as_array().unwrap().len(), 1);
+    assert_eq!(card["skills"][0]["id"], "forecast");
+    assert!(
+        card["skills"][0]["description"]
+            .as_str()
+            .unwrap()
+            .contains("- `city` (string, required)")
+    );
+
+    let mcp = fs::read_to_string(project.join("src/mcp.rs")).unwrap();
+    assert!(mcp.contains("command: \"sh\".to_string(),"));
+}
+
+#[test]
+fn test_scaffold_a2a_bridge_requires_a_source() {
+    let work_dir = TempDir::new().unwrap();
+
+    scaffold_bridge(work_dir.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--mcp-command"));
+}
@@ -0,0 +1,137 @@
+//! Integration tests for scaffolding ACP servers and clients
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn scaffold(work_dir: &Path, role: &str, project_name: &str) -> Command {
+    let mut cmd = Command::cargo_bin("agenterra").unwrap();
+    cmd.args(["scaffold", "acp", role, "--project-name", project_name])
+        .arg("--output-dir")
+        .arg(work_dir)
+        .arg("--no-hooks");
+    cmd
+}
+
+fn read_agents(project: &Path) -> serde_json::Value {
+    serde_json::from_str(&fs::read_to_string(project.join("agents.json")).unwrap()).unwrap()
+}
+
+#[test]
+fn test_scaffold_acp_server() {
+    let work_dir = TempDir::new().unwrap();
+
+    scaffold(work_dir.path(), "server", "acp-agents")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Successfully generated acp server rust 'acp-agents'",
+        ));
+
+    let project = work_dir.path().join("acp-agents");
+    let agents = read_agents(&project);
+    assert_eq!(agents[0]["name"], "echo");
+    assert_eq!(agents.as_array().unwrap().len(), 1);
+
+    let server = fs::read_to_string(project.join("src/server.rs")).unwrap();
+    for route in [
+        "\"/ping\"",
+        "\"/agents\"",
+        "\"/agents/{name}\"",
+        "\"/runs\"",
+        "\"/runs/{run_id}\"",
+        "\"/runs/{run_id}/cancel\"",
+        "\"/runs/{run_id}/events\"",
+    ] {
+        assert!(server.contains(route), "{route}");
+    }
+
+    let agent_handlers = fs::read_to_string(project.join("src/agents.rs")).unwrap();
+    assert!(agent_handlers.contains("\"echo\" => self.echo(ctx).await,"));
+    assert!(agent_handlers.contains("await_message(\"What should I echo?\")"));
+    assert!(!agent_handlers.contains("ApiClient"));
+
+    let main = fs::read_to_string(project.join("src/main.rs")).unwrap();
+    assert!(main.contains("use acp_agents::server::{self, AppState};"));
+    assert!(main.contains("default_value_t = 8000"));
+
+    let cargo = fs::read_to_string(project.join("Cargo.toml")).unwrap();
+    assert!(cargo.contains("name = \"acp-agents\""));
+    assert!(!cargo.contains("reqwest"));
+}
+
+#[test]
+fn test_scaffold_acp_server_from_openapi() {
+    let work_dir = TempDir::new().unwrap();
+    let schema = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/openapi/petstore.openapi.v3.json");
+
+    scaffold(work_dir.path(), "server", "pet-agents")
+        .arg("--schema-path")
+        .arg(&schema)
+        .args(["--base-url", "https://petstore.example.com/api/v3"])
+        .args(["--port", "9200"])
+        .args(["--var", r#"agents=[{"name": "summarize"}]"#])
+        .assert()
+        .success();
+
+    let project = work_dir.path().join("pet-agents");
+    let agents = read_agents(&project);
+    let names: Vec<_> = agents
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|agent| agent["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names[0], "summarize");
+    assert!(names.contains(&"add-pet"));
+    assert!(names.contains(&"get-pet-by-id"));
+    // Manifests do not expose the API binding
+    assert!(agents[1].get("openapi").is_none());
+    assert_eq!(agents[1]["input_content_types"][0], "application/json");
+
+    let agent_handlers = fs::read_to_string(project.join("src/agents.rs")).unwrap();
+    assert!(agent_handlers.contains("\"summarize\" => self.summarize(ctx).await,"));
+    assert!(agent_handlers.contains("path: \"/pet/{petId}\","));
+    assert!(agent_handlers.contains("path_params: &[\"petId\"],"));
+
+    let main = fs::read_to_string(project.join("src/main.rs")).unwrap();
+    assert!(main.contains("default_value = \"https://petstore.example.com/api/v3\""));
+    assert!(main.contains("default_value_t = 9200"));
+
+    let cargo = fs::read_to_string(project.join("Cargo.toml")).unwrap();
+    assert!(cargo.contains("reqwest"));
+}
+
+#[test]
+fn test_scaffold_acp_client() {
+    let work_dir = TempDir::new().unwrap();
+
+    scaffold(work_dir.path(), "client", "acp-cli")
+        .args(["--server-url", "http://127.0.0.1:9200/"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Successfully generated acp client rust 'acp-cli'",
+        ));
+
+    let project = work_dir.path().join("acp-cli");
+    let main = fs::read_to_string(project.join("src/main.rs")).unwrap();
+    assert!(main.contains("default_value = \"http://127.0.0.1:9200\""));
+    assert!(main.contains("use acp_cli::client::AcpClient;"));
+    assert!(project.join("src/client.rs").is_file());
+    assert!(project.join("src/types.rs").is_file());
+}
+
+#[test]
+fn test_scaffold_acp_server_rejects_invalid_agents() {
+    let work_dir = TempDir::new().unwrap();
+
+    scaffold(work_dir.path(), "server", "acp-agents")
+        .args(["--var", r#"agents=[{"name": "Not Valid"}]"#])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid agent name"));
+}
@@ -0,0 +1,110 @@
+//! Integration tests for scaffolding ANP agents
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn scaffold_agent(work_dir: &Path, project_name: &str) -> Command {
+    let mut cmd = Command::cargo_bin("agenterra").unwrap();
+    cmd.args(["scaffold", "anp", "agent", "--project-name", project_name])
+        .arg("--output-dir")
+        .arg(work_dir)
+        .arg("--no-hooks");
+    cmd
+}
+
+#[test]
+fn test_scaffold_anp_agent() {
+    let work_dir = TempDir::new().unwrap();
+
+    scaffold_agent(work_dir.path(), "anp-agent")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Successfully generated anp agent rust 'anp-agent'",
+        ));
+
+    let project = work_dir.path().join("anp-agent");
+    for file in [
+        "src/identity.rs",
+        "src/auth.rs",
+        "src/description.rs",
+        "src/negotiation.rs",
+        "src/protocols.rs",
+        "src/server.rs",
+        "src/peer.rs",
+        "tests/two_agents.rs",
+        "README.md",
+    ] {
+        assert!(project.join(file).exists(), "{file}");
+    }
+
+    let identity = fs::read_to_string(project.join("src/identity.rs")).unwrap();
+    assert!(identity.contains(r#"pub const DEFAULT_PUBLIC_URL: &str = "http://localhost:9000";"#));
+
+    let protocols = fs::read_to_string(project.join("src/protocols.rs")).unwrap();
+    assert!(protocols.contains(r#""echo/1.0" => echo_1_0(caller, content).await,"#));
+
+    let main = fs::read_to_string(project.join("src/main.rs")).unwrap();
+    assert!(main.contains("use anp_agent::server;"));
+    assert!(main.contains("default_value_t = 9000"));
+
+    let readme = fs::read_to_string(project.join("README.md")).unwrap();
+    assert!(readme.contains("Its DID is `did:wba:localhost%3A9000`"));
+
+    let gitignore = fs::read_to_string(project.join(".gitignore")).unwrap();
+    assert!(gitignore.contains("/keys"));
+}
+
+#[test]
+fn test_scaffold_anp_agent_with_url_and_protocols() {
+    let work_dir = TempDir::new().unwrap();
+
+    scaffold_agent(work_dir.path(), "hotel-agent")
+        .args(["--port", "9300"])
+        .args(["--agent-url", "https://agents.example.com/hotel"])
+        .args([
+            "--var",
+            r#"protocols=[{"id": "hotel-booking/1.0", "description": "Books rooms"}]"#,
+        ])
+        .assert()
+        .success();
+
+    let project = work_dir.path().join("hotel-agent");
+    let readme = fs::read_to_string(project.join("README.md")).unwrap();
+    assert!(readme.contains("Its DID is `did:wba:agents.example.com:hotel`"));
+    assert!(readme.contains("- `hotel-booking/1.0`: Books rooms"));
+
+    let protocols = fs::read_to_string(project.join("src/protocols.rs")).unwrap();
+    assert!(protocols.contains(r#"id: "hotel-booking/1.0","#));
+    assert!(!protocols.contains(r#"id: "echo/1.0","#));
+
+    let main = fs::read_to_string(project.join("src/main.rs")).unwrap();
+    assert!(main.contains("default_value_t = 9300"));
+}
+
+#[test]
+fn test_scaffold_anp_agent_rejects_invalid_protocols() {
+    let work_dir = TempDir::new().unwrap();
+
+    scaffold_agent(work_dir.path(), "anp-agent")
+        .args([
+            "--var",
+            r#"protocols=[{"id": "echo/1.0"}, {"id": "echo/1.0"}]"#,
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Duplicate protocol id"));
+}
+
+#[test]
+fn test_anp_has_no_broker_role() {
+    Command::cargo_bin("agenterra")
+        .unwrap()
+        .args(["scaffold", "anp", "broker"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unrecognized subcommand"));
+}
@@ -0,0 +1,75 @@
+//! Integration tests for `when:` conditions and `required_directories` in manifests
+
+use assert_cmd::Command;
+use std::fs;
+use tempfile::TempDir;
+
+const MANIFEST: &str = r#"name: conditional-client
+version: "1.0.0"
+protocol: mcp
+role: client
+language: rust
+files:
+  - source: main.rs.tera
+    destination: src/main.rs
+  - source: Dockerfile.tera
+    destination: Dockerfile
+    when: docker
+  - source: retries.rs.tera
+    destination: src/retries.rs
+    when: "retries > 0"
+variables:
+  docker:
+    type: boolean
+    default: false
+  retries:
+    type: integer
+    default: 0
+required_directories:
+  - data/cache
+"#;
+
+fn scaffold(vars: &[&str]) -> TempDir {
+    let template_dir = TempDir::new().unwrap();
+    let output_dir = TempDir::new().unwrap();
+    let write = |name: &str, content: &str| fs::write(template_dir.path().join(name), content);
+    write("manifest.yml", MANIFEST).unwrap();
+    write("main.rs.tera", "fn main() {}\n").unwrap();
+    write("Dockerfile.tera", "FROM rust\n").unwrap();
+    write("retries.rs.tera", "const RETRIES: u32 = {{ retries }};\n").unwrap();
+
+    let mut cmd = Command::cargo_bin("agenterra").unwrap();
+    cmd.args(["scaffold", "mcp", "client", "--project-name", "cond_client"])
+        .arg("--template-dir")
+        .arg(template_dir.path())
+        .arg("--output-dir")
+        .arg(output_dir.path());
+    for var in vars {
+        cmd.args(["--var", var]);
+    }
+    cmd.assert().success();
+    output_dir
+}
+
+#[test]
+fn test_conditional_files_skipped_by_default() {
+    let output_dir = scaffold(&[]);
+    let root = output_dir.path().join("cond_client");
+
+    assert!(root.join("src/main.rs").exists());
+    assert!(!root.join("Dockerfile").exists());
+    assert!(!root.join("src/retries.rs").exists());
+    assert!(root.join("data/cache").is_dir());
+}
+
+#[test]
+fn test_conditional_files_follow_variables() {
+    let output_dir = scaffold(&["docker=true", "retries=3"]);
+    let root = output_dir.path().join("cond_client");
+
+    assert!(root.join("Dockerfile").exists());
+    assert_eq!(
+        fs::read_to_string(root.join("src/retries.rs")).unwrap(),
+        "const RETRIES: u32 = 3;\n"
+    );
+}
@@ -0,0 +1,140 @@
+//! Integration tests for running template hooks during `scaffold`
+#![cfg(unix)]
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+const README: &str = "# {{ project_name }}\n";
+
+fn write_template(dir: &Path, hooks: &str) {
+    let manifest = format!(
+        r#"name: hooks-client
+version: "1.0.0"
+protocol: mcp
+role: client
+language: rust
+files:
+  - source: README.md.tera
+    destination: README.md
+hooks:
+{hooks}"#
+    );
+    fs::write(dir.join("manifest.yml"), manifest).unwrap();
+    fs::write(dir.join("README.md.tera"), README).unwrap();
+}
+
+fn scaffold_client(template_dir: &Path, output_dir: &Path) -> Command {
+    let mut cmd = Command::cargo_bin("agenterra").unwrap();
+    cmd.args(["scaffold", "mcp", "client", "--project-name", "hooked"])
+        .arg("--template-dir")
+        .arg(template_dir)
+        .arg("--output-dir")
+        .arg(output_dir);
+    cmd
+}
+
+#[test]
+fn test_hooks_run_in_output_directory() {
+    let template_dir = TempDir::new().unwrap();
+    let output_dir = TempDir::new().unwrap();
+    write_template(
+        template_dir.path(),
+        r#"  pre_generate:
+    - test ! -e README.md && pwd > pre.txt
+  post_generate:
+    - command: test -f README.md && echo "$GREETING" > post.txt
+      env:
+        GREETING: hello
+    - command: echo never > skipped.txt
+      os: plan9
+"#,
+    );
+
+    scaffold_client(template_dir.path(), output_dir.path())
+        .assert()
+        .success();
+
+    let project = output_dir.path().join("hooked");
+    let pre_cwd = fs::read_to_string(project.join("pre.txt")).unwrap();
+    assert_eq!(
+        Path::new(pre_cwd.trim()).canonicalize().unwrap(),
+        project.canonicalize().unwrap()
+    );
+    assert_eq!(
+        fs::read_to_string(project.join("post.txt")).unwrap(),
+        "hello\n"
+    );
+    assert!(!project.join("skipped.txt").exists());
+}
+
+#[test]
+fn test_required_hook_failure_fails_scaffold() {
+    let template_dir = TempDir::new().unwrap();
+    let output_dir = TempDir::new().unwrap();
+    write_template(
+        template_dir.path(),
+        "  post_generate:\n    - echo 'lint found 2 problems' >&2; exit 3\n",
+    );
+
+    scaffold_client(template_dir.path(), output_dir.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "post_generate hook 'echo 'lint found 2 problems' >&2; exit 3' failed: exited with code 3",
+        ))
+        .stderr(predicate::str::contains("lint found 2 problems"));
+}
+
+#[test]
+fn test_optional_hook_failure_is_reported() {
+    let template_dir = TempDir::new().unwrap();
+    let output_dir = TempDir::new().unwrap();
+    write_template(
+        template_dir.path(),
+        "  post_generate:\n    - command: exit 1\n      continue_on_error: true\n",
+    );
+
+    scaffold_client(template_dir.path(), output_dir.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "warning[hook-failed]: post_generate hook 'exit 1' failed: exited with code 1",
+        ));
+}
+
+#[test]
+fn test_hook_timeout_fails_scaffold() {
+    let template_dir = TempDir::new().unwrap();
+    let output_dir = TempDir::new().unwrap();
+    write_template(
+        template_dir.path(),
+        "  post_generate:\n    - command: sleep 10\n      timeout: 1\n",
+    );
+
+    scaffold_client(template_dir.path(), output_dir.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("timed out after 1s"));
+}
+
+#[test]
+fn test_no_hooks_skips_hooks() {
+    let template_dir = TempDir::new().unwrap();
+    let output_dir = TempDir::new().unwrap();
+    write_template(
+        template_dir.path(),
+        "  pre_generate: exit 1\n  post_generate: touch post.txt\n",
+    );
+
+    scaffold_client(template_dir.path(), output_dir.path())
+        .arg("--no-hooks")
+        .assert()
+        .success();
+
+    let project = output_dir.path().join("hooked");
+    assert!(project.join("README.md").is_file());
+    assert!(!project.join("post.txt").exists());
+}
@@ -0,0 +1,98 @@
+//! Integration tests for the generation report printed by `scaffold`
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use serde_json::Value;
+use std::path::Path;
+use tempfile::TempDir;
+
+/// Scaffold the Swagger 2.0 petstore with its `host`, `basePath` and
+/// `schemes` removed, so the spec declares no server
+fn scaffold_petstore_v2(work_dir: &Path) -> Command {
+    let fixture = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/openapi/petstore.swagger.v2.json");
+    let mut document: Value =
+        serde_json::from_str(&std::fs::read_to_string(fixture).unwrap()).unwrap();
+    let root = document.as_object_mut().unwrap();
+    for key in ["host", "basePath", "schemes"] {
+        root.remove(key);
+    }
+    let spec = work_dir.join("petstore.json");
+    std::fs::write(&spec, serde_json::to_string(&document).unwrap()).unwrap();
+
+    let mut cmd = Command::cargo_bin("agenterra").unwrap();
+    cmd.args([
+        "scaffold",
+        "mcp",
+        "server",
+        "--project-name",
+        "report_server",
+    ])
+    .arg("--schema-path")
+    .arg(spec)
+    .arg("--output-dir")
+    .arg(work_dir.join("out"))
+    .args(["--base-url", "https://petstore.swagger.io/v2"])
+    .arg("--no-hooks");
+    cmd
+}
+
+#[test]
+fn test_report_json_on_stdout() {
+    let work_dir = TempDir::new().unwrap();
+
+    let output = scaffold_petstore_v2(work_dir.path())
+        .args(["--report", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let report: Value =
+        serde_json::from_slice(&output.stdout).expect("stdout should contain only the JSON report");
+    assert_eq!(report["project_name"], "report_server");
+    assert_eq!(report["protocol"], "mcp");
+    assert_eq!(report["role"], "server");
+    assert_eq!(report["language"], "rust");
+
+    let artifacts = report["artifacts"].as_array().unwrap();
+    assert_eq!(report["summary"]["artifacts"], artifacts.len());
+    assert!(artifacts.iter().any(|a| a == "Cargo.toml"));
+
+    let diagnostics = report["diagnostics"].as_array().unwrap();
+    let no_servers = diagnostics
+        .iter()
+        .find(|d| d["code"] == "no-servers")
+        .expect("missing servers should be reported");
+    assert_eq!(no_servers["severity"], "warning");
+    assert_eq!(no_servers["pointer"], "/servers");
+
+    // The human summary still goes out, on stderr
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Successfully generated mcp server rust 'report_server'"));
+    assert!(stderr.contains("warning[no-servers] /servers"));
+}
+
+#[test]
+fn test_text_summary_by_default() {
+    let work_dir = TempDir::new().unwrap();
+
+    scaffold_petstore_v2(work_dir.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Successfully generated mcp server rust 'report_server'",
+        ))
+        .stdout(predicate::str::contains("warning[no-servers]"))
+        .stdout(predicate::str::contains("warning(s)"));
+}
+
+#[test]
+fn test_invalid_report_format_rejected() {
+    Command::cargo_bin("agenterra")
+        .unwrap()
+        .args(["scaffold", "--report", "yaml", "mcp", "client"])
+        .args(["--project-name", "x"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value 'yaml'"));
+}
@@ -0,0 +1,123 @@
+//! Integration tests for scaffolding roles defined by templates
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+const MANIFEST: &str = r#"name: mcp-reviewer
+version: "0.1.0"
+description: "Reviews the tools of an MCP server"
+protocol: mcp
+role: reviewer
+language: python
+files:
+  - source: review.md.tera
+    destination: REVIEW.md
+variables:
+  severity:
+    type: string
+    default: "low"
+    description: "Lowest severity to report"
+  max_findings:
+    type: integer
+    default: 10
+"#;
+
+const REVIEW: &str =
+    "{{ project_name }} ({{ role }}): severity={{ severity }}, max_findings={{ max_findings }}\n";
+
+fn write_template(dir: &Path) {
+    fs::write(dir.join("manifest.yml"), MANIFEST).unwrap();
+    fs::write(dir.join("review.md.tera"), REVIEW).unwrap();
+}
+
+fn scaffold_reviewer(template_dir: &Path) -> Command {
+    let mut cmd = Command::cargo_bin("agenterra").unwrap();
+    cmd.args(["scaffold", "mcp", "reviewer", "--template-dir"])
+        .arg(template_dir);
+    cmd
+}
+
+#[test]
+fn test_custom_role_is_scaffolded_with_variable_flags() {
+    let template_dir = TempDir::new().unwrap();
+    let output_dir = TempDir::new().unwrap();
+    write_template(template_dir.path());
+
+    scaffold_reviewer(template_dir.path())
+        .arg("--output-dir")
+        .arg(output_dir.path())
+        .args(["--severity", "high", "--var", "max_findings=3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Successfully generated mcp reviewer python 'agenterra_mcp_reviewer'",
+        ));
+
+    let review =
+        fs::read_to_string(output_dir.path().join("agenterra_mcp_reviewer/REVIEW.md")).unwrap();
+    assert_eq!(
+        review,
+        "agenterra_mcp_reviewer (reviewer): severity=high, max_findings=3\n"
+    );
+}
+
+#[test]
+fn test_custom_role_help_lists_template_variables() {
+    let template_dir = TempDir::new().unwrap();
+    write_template(template_dir.path());
+
+    scaffold_reviewer(template_dir.path())
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Reviews the tools of an MCP server",
+        ))
+        .stdout(predicate::str::contains("--severity <SEVERITY>"))
+        .stdout(predicate::str::contains(
+            "Lowest severity to report (default: \"low\")",
+        ))
+        .stdout(predicate::str::contains("--max-findings <MAX_FINDINGS>"));
+}
+
+#[test]
+fn test_variable_flags_are_typed() {
+    let template_dir = TempDir::new().unwrap();
+    write_template(template_dir.path());
+
+    scaffold_reviewer(template_dir.path())
+        .args(["--max-findings", "many"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value 'many'"));
+}
+
+#[test]
+fn test_custom_role_needs_its_template() {
+    Command::cargo_bin("agenterra")
+        .unwrap()
+        .args(["scaffold", "mcp", "reviewer"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "unrecognized subcommand 'reviewer'",
+        ));
+}
+
+#[test]
+fn test_protocols_list_their_roles() {
+    Command::cargo_bin("agenterra")
+        .unwrap()
+        .args(["scaffold", "a2a", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "agent   Generate A2A agent with an Agent Card, JSON-RPC endpoint and task store",
+        ))
+        .stdout(predicate::str::contains(
+            "bridge  Generate A2A agent exposing the tools of an MCP server as skills",
+        ));
+}
@@ -0,0 +1,90 @@
+//! Integration tests for passing template variables to `scaffold`
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+const MANIFEST: &str = r#"name: vars-client
+version: "1.0.0"
+protocol: mcp
+role: client
+language: rust
+files:
+  - source: settings.txt.tera
+    destination: settings.txt
+variables:
+  greeting:
+    type: string
+    required: true
+    description: "Greeting written to settings.txt"
+  retries:
+    type: integer
+    default: 3
+  verbose:
+    type: boolean
+    default: false
+"#;
+
+const SETTINGS: &str = "greeting={{ greeting }}\nretries={{ retries }}\nverbose={{ verbose }}\n";
+
+fn write_template(dir: &Path) {
+    fs::write(dir.join("manifest.yml"), MANIFEST).unwrap();
+    fs::write(dir.join("settings.txt.tera"), SETTINGS).unwrap();
+}
+
+fn scaffold_client(template_dir: &Path, output_dir: &Path) -> Command {
+    let mut cmd = Command::cargo_bin("agenterra").unwrap();
+    cmd.args(["scaffold", "mcp", "client", "--project-name", "vars_client"])
+        .arg("--template-dir")
+        .arg(template_dir)
+        .arg("--output-dir")
+        .arg(output_dir);
+    cmd
+}
+
+#[test]
+fn test_vars_file_and_cli_vars_reach_templates() {
+    let template_dir = TempDir::new().unwrap();
+    let output_dir = TempDir::new().unwrap();
+    write_template(template_dir.path());
+
+    let vars_file = template_dir.path().join("vars.yaml");
+    fs::write(&vars_file, "greeting: hello\nretries: 5\n").unwrap();
+
+    scaffold_client(template_dir.path(), output_dir.path())
+        .arg("--vars-file")
+        .arg(&vars_file)
+        .args(["--var", "retries=7", "--var", "verbose=true"])
+        .assert()
+        .success();
+
+    let settings = fs::read_to_string(output_dir.path().join("vars_client/settings.txt")).unwrap();
+    assert_eq!(settings, "greeting=hello\nretries=7\nverbose=true\n");
+}
+
+#[test]
+fn test_missing_required_var_fails() {
+    let template_dir = TempDir::new().unwrap();
+    let output_dir = TempDir::new().unwrap();
+    write_template(template_dir.path());
+
+    scaffold_client(template_dir.path(), output_dir.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("'greeting' is required"));
+}
+
+#[test]
+fn test_mistyped_var_fails() {
+    let template_dir = TempDir::new().unwrap();
+    let output_dir = TempDir::new().unwrap();
+    write_template(template_dir.path());
+
+    scaffold_client(template_dir.path(), output_dir.path())
+        .args(["--var", "greeting=hi", "--var", "retries=many"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("'retries' must be integer"));
+}
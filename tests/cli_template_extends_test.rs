@@ -0,0 +1,89 @@
+//! Integration tests for templates that `extends` an embedded template
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+const MANIFEST: &str = r#"name: custom-client
+version: "1.0.0"
+extends: mcp/client/rust@0.1
+files:
+  - source: NOTES.md.tera
+    destination: NOTES.md
+remove:
+  files:
+    - tests/sse_integration_tests.rs
+"#;
+
+fn scaffold_client(template_dir: &Path, output_dir: &Path) -> Command {
+    let mut cmd = Command::cargo_bin("agenterra").unwrap();
+    cmd.args([
+        "scaffold",
+        "mcp",
+        "client",
+        "--project-name",
+        "custom_client",
+    ])
+    .arg("--template-dir")
+    .arg(template_dir)
+    .arg("--output-dir")
+    .arg(output_dir)
+    // The inherited `cargo check` hook would compile the whole client
+    .arg("--no-hooks");
+    cmd
+}
+
+#[test]
+fn test_child_template_overrides_embedded_parent() {
+    let template_dir = TempDir::new().unwrap();
+    let output_dir = TempDir::new().unwrap();
+    fs::write(template_dir.path().join("manifest.yml"), MANIFEST).unwrap();
+    fs::write(
+        template_dir.path().join("NOTES.md.tera"),
+        "Notes for {{ project_name }}\n",
+    )
+    .unwrap();
+    // Shadows the parent's file of the same name without listing it
+    fs::write(
+        template_dir.path().join("README.md.tera"),
+        "# {{ project_name }}, customised\n",
+    )
+    .unwrap();
+
+    scaffold_client(template_dir.path(), output_dir.path())
+        .assert()
+        .success();
+
+    let project = output_dir.path().join("custom_client");
+    assert_eq!(
+        fs::read_to_string(project.join("README.md")).unwrap(),
+        "# custom_client, customised\n"
+    );
+    assert_eq!(
+        fs::read_to_string(project.join("NOTES.md")).unwrap(),
+        "Notes for custom_client\n"
+    );
+    assert!(project.join("Cargo.toml").exists());
+    assert!(project.join("src/domain/client.rs").exists());
+    assert!(project.join("tests/test_with_mock_server.rs").exists());
+    assert!(!project.join("tests/sse_integration_tests.rs").exists());
+}
+
+#[test]
+fn test_version_mismatch_is_reported() {
+    let template_dir = TempDir::new().unwrap();
+    let output_dir = TempDir::new().unwrap();
+    fs::write(
+        template_dir.path().join("manifest.yml"),
+        MANIFEST.replace("@0.1", "@2"),
+    )
+    .unwrap();
+    fs::write(template_dir.path().join("NOTES.md.tera"), "notes\n").unwrap();
+
+    scaffold_client(template_dir.path(), output_dir.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("found version 0.1.0"));
+}
@@ -0,0 +1,180 @@
+//! Integration tests for `--template-source` archives and git repositories
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+const MANIFEST: &str = r#"name: blessed-client
+version: "1.0.0"
+protocol: mcp
+role: client
+language: rust
+files:
+  - source: README.md.tera
+    destination: README.md
+"#;
+
+fn scaffold_client(source: &str, output_dir: &Path, cache_dir: &Path) -> Command {
+    let mut cmd = Command::cargo_bin("agenterra").unwrap();
+    cmd.env("AGENTERRA_CACHE_DIR", cache_dir)
+        .args([
+            "scaffold",
+            "mcp",
+            "client",
+            "--project-name",
+            "blessed",
+            "--template-source",
+            source,
+        ])
+        .arg("--output-dir")
+        .arg(output_dir);
+    cmd
+}
+
+fn git(repo: &Path, args: &[&str]) -> String {
+    let output = std::process::Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "git {args:?} failed: {output:?}");
+    String::from_utf8(output.stdout).unwrap().trim().to_string()
+}
+
+fn write_template(dir: &Path, readme: &str) {
+    fs::create_dir_all(dir).unwrap();
+    fs::write(dir.join("manifest.yml"), MANIFEST).unwrap();
+    fs::write(dir.join("README.md.tera"), readme).unwrap();
+}
+
+#[test]
+fn test_git_source_is_pinned_by_lockfile() {
+    let repo = TempDir::new().unwrap();
+    let output_dir = TempDir::new().unwrap();
+    let cache_dir = TempDir::new().unwrap();
+
+    // Templates in the layout `templates export` writes
+    let template = repo.path().join("mcp/client/rust");
+    write_template(&template, "v1 {{ project_name }}\n");
+    git(repo.path(), &["init", "--quiet", "--initial-branch=main"]);
+    git(repo.path(), &["add", "."]);
+    git(repo.path(), &["commit", "--quiet", "-m", "v1"]);
+    let v1 = git(repo.path(), &["rev-parse", "HEAD"]);
+
+    let source = format!("git+file://{}#main", repo.path().display());
+    scaffold_client(&source, output_dir.path(), cache_dir.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Successfully generated mcp client rust 'blessed'",
+        ));
+
+    let project = output_dir.path().join("blessed");
+    assert_eq!(
+        fs::read_to_string(project.join("README.md")).unwrap(),
+        "v1 blessed\n"
+    );
+    let lock = fs::read_to_string(project.join("agenterra.lock")).unwrap();
+    assert!(lock.contains(&format!("source = \"{source}\"")));
+    assert!(lock.contains(&format!("commit = \"{v1}\"")));
+    assert!(cache_dir.path().join("checkouts").join(&v1).is_dir());
+
+    // Moving the branch does not change a locked project
+    fs::write(template.join("README.md.tera"), "v2 {{ project_name }}\n").unwrap();
+    git(repo.path(), &["commit", "--quiet", "-am", "v2"]);
+    scaffold_client(&source, output_dir.path(), cache_dir.path())
+        .assert()
+        .success();
+    assert_eq!(
+        fs::read_to_string(project.join("README.md")).unwrap(),
+        "v1 blessed\n"
+    );
+
+    // Deleting the lockfile picks up the new commit
+    fs::remove_file(project.join("agenterra.lock")).unwrap();
+    scaffold_client(&source, output_dir.path(), cache_dir.path())
+        .assert()
+        .success();
+    assert_eq!(
+        fs::read_to_string(project.join("README.md")).unwrap(),
+        "v2 blessed\n"
+    );
+}
+
+#[test]
+fn test_tarball_source_records_digest() {
+    let work = TempDir::new().unwrap();
+    let output_dir = TempDir::new().unwrap();
+    let cache_dir = TempDir::new().unwrap();
+
+    let template = work.path().join("blessed-client-1.0.0");
+    write_template(&template, "from a tarball\n");
+    let archive = work.path().join("blessed-client-1.0.0.tar.gz");
+    let encoder = flate2::write::GzEncoder::new(
+        fs::File::create(&archive).unwrap(),
+        flate2::Compression::default(),
+    );
+    let mut builder = tar::Builder::new(encoder);
+    builder
+        .append_dir_all("blessed-client-1.0.0", &template)
+        .unwrap();
+    builder.into_inner().unwrap().finish().unwrap();
+
+    scaffold_client(
+        archive.to_str().unwrap(),
+        output_dir.path(),
+        cache_dir.path(),
+    )
+    .assert()
+    .success();
+
+    let project = output_dir.path().join("blessed");
+    assert_eq!(
+        fs::read_to_string(project.join("README.md")).unwrap(),
+        "from a tarball\n"
+    );
+    let lock = fs::read_to_string(project.join("agenterra.lock")).unwrap();
+    assert!(lock.contains("digest = \"sha256:"));
+
+    // A different archive at the same path no longer matches the pin
+    fs::write(template.join("README.md.tera"), "republished\n").unwrap();
+    let encoder = flate2::write::GzEncoder::new(
+        fs::File::create(&archive).unwrap(),
+        flate2::Compression::default(),
+    );
+    let mut builder = tar::Builder::new(encoder);
+    builder
+        .append_dir_all("blessed-client-1.0.0", &template)
+        .unwrap();
+    builder.into_inner().unwrap().finish().unwrap();
+
+    scaffold_client(
+        archive.to_str().unwrap(),
+        output_dir.path(),
+        cache_dir.path(),
+    )
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains("is pinned"));
+}
+
+#[test]
+fn test_template_source_conflicts_with_template_dir() {
+    let mut cmd = Command::cargo_bin("agenterra").unwrap();
+    cmd.args([
+        "scaffold",
+        "mcp",
+        "client",
+        "--template-dir",
+        "templates",
+        "--template-source",
+        "templates.tar.gz",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains("cannot be used with"));
+}
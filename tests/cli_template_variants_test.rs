@@ -0,0 +1,273 @@
+//! Integration tests for selecting template variants with `--template`
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+fn petstore_spec() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/openapi/petstore.openapi.v3.json")
+}
+
+fn scaffold_server(template: &str, work_dir: &Path) -> Command {
+    let mut cmd = Command::cargo_bin("agenterra").unwrap();
+    cmd.args(["scaffold", "mcp", "server", "--project-name", "petstore"])
+        .arg("--schema-path")
+        .arg(petstore_spec())
+        .arg("--output-dir")
+        .arg(work_dir)
+        .args(["--template", template])
+        .arg("--no-hooks");
+    cmd
+}
+
+fn scaffold_client(template: &str, work_dir: &Path) -> Command {
+    let mut cmd = Command::cargo_bin("agenterra").unwrap();
+    cmd.args(["scaffold", "mcp", "client", "--project-name", "pet-client"])
+        .arg("--output-dir")
+        .arg(work_dir)
+        .args(["--template", template])
+        .arg("--no-hooks");
+    cmd
+}
+
+#[test]
+fn test_scaffold_named_variant() {
+    let work_dir = TempDir::new().unwrap();
+
+    scaffold_server("rust-minimal", work_dir.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Successfully generated mcp server rust 'petstore'",
+        ));
+
+    let project = work_dir.path().join("petstore");
+    let main = fs::read_to_string(project.join("src/main.rs")).unwrap();
+    assert!(main.contains(".serve(stdio())"));
+    assert!(project.join("src/handlers/mod.rs").is_file());
+    assert!(!project.join("src/transport.rs").exists());
+}
+
+#[test]
+fn test_scaffold_variant_by_manifest_name() {
+    let work_dir = TempDir::new().unwrap();
+
+    scaffold_server("mcp-rust-minimal-server", work_dir.path())
+        .assert()
+        .success();
+
+    let project = work_dir.path().join("petstore");
+    assert!(!project.join("src/transport.rs").exists());
+}
+
+#[test]
+fn test_scaffold_unknown_variant_lists_available() {
+    let work_dir = TempDir::new().unwrap();
+
+    scaffold_server("rust-axum-http", work_dir.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Unknown template 'rust-axum-http' for mcp server; available: csharp, go, python, rust, rust-minimal, typescript",
+        ));
+}
+
+#[test]
+fn test_scaffold_python_server() {
+    let work_dir = TempDir::new().unwrap();
+
+    scaffold_server("python", work_dir.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Successfully generated mcp server python 'petstore'",
+        ));
+
+    let project = work_dir.path().join("petstore");
+    let pyproject = fs::read_to_string(project.join("pyproject.toml")).unwrap();
+    assert!(pyproject.contains("\"mcp>=1.9\""));
+    assert!(pyproject.contains("petstore = \"mcp_server.__main__:main\""));
+
+    let models = fs::read_to_string(project.join("src/mcp_server/models.py")).unwrap();
+    assert!(models.contains("class Pet(BaseModel):"));
+    assert!(models.contains("photo_urls: List[str] = Field(alias=\"photoUrls\")"));
+
+    let server = fs::read_to_string(project.join("src/mcp_server/server.py")).unwrap();
+    assert!(server.contains("from .models import Order, Pet, User"));
+    assert!(server.contains("async def add_pet("));
+    assert!(server.contains("\"petId\": pet_id,"));
+    assert!(project.join("schemas/add_pet.json").is_file());
+}
+
+#[test]
+fn test_scaffold_typescript_server() {
+    let work_dir = TempDir::new().unwrap();
+
+    scaffold_server("typescript", work_dir.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Successfully generated mcp server typescript 'petstore'",
+        ));
+
+    let project = work_dir.path().join("petstore");
+    let package: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(project.join("package.json")).unwrap()).unwrap();
+    assert_eq!(package["name"], "petstore");
+    assert!(package["dependencies"]["@modelcontextprotocol/sdk"].is_string());
+    assert!(package["dependencies"]["zod"].is_string());
+    assert_eq!(package["scripts"]["typecheck"], "tsc --noEmit");
+    assert!(project.join("tsconfig.json").is_file());
+
+    let schemas = fs::read_to_string(project.join("src/schemas.ts")).unwrap();
+    assert!(schemas.contains("export const PetSchema = z.object({"));
+    assert!(schemas.contains("export type Pet = z.infer<typeof PetSchema>;"));
+
+    let server = fs::read_to_string(project.join("src/server.ts")).unwrap();
+    assert!(
+        server.contains("import { OrderSchema, PetSchema, UserSchema } from \"./schemas.js\";")
+    );
+    assert!(server.contains("\"add_pet\","));
+    assert!(server.contains("\"petId\": args[\"petId\"],"));
+    assert!(project.join("schemas/add_pet.json").is_file());
+}
+
+#[test]
+fn test_scaffold_go_server() {
+    let work_dir = TempDir::new().unwrap();
+
+    scaffold_server("go", work_dir.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Successfully generated mcp server go 'petstore'",
+        ));
+
+    let project = work_dir.path().join("petstore");
+    let go_mod = fs::read_to_string(project.join("go.mod")).unwrap();
+    assert!(go_mod.starts_with("module petstore\n"));
+    assert!(go_mod.contains("github.com/modelcontextprotocol/go-sdk"));
+
+    let main = fs::read_to_string(project.join("main.go")).unwrap();
+    assert!(main.contains("\"petstore/internal/server\""));
+
+    let models = fs::read_to_string(project.join("internal/server/models.go")).unwrap();
+    assert!(models.contains("type Pet struct {"));
+    assert!(models.contains("PhotoURLs []string `json:\"photoUrls\"`"));
+    assert!(models.contains("Category *Category `json:\"category,omitempty\"`"));
+
+    let inputs = fs::read_to_string(project.join("internal/server/inputs.go")).unwrap();
+    assert!(inputs.contains("PetID int64 `json:\"petId\" jsonschema:\"ID of pet to return\"`"));
+
+    let server = fs::read_to_string(project.join("internal/server/server.go")).unwrap();
+    assert!(server.contains("Name:        \"add_pet\","));
+    assert!(server.contains("\"petId\": args.PetID,"));
+    assert!(project.join("schemas/add_pet.json").is_file());
+}
+
+#[test]
+fn test_scaffold_csharp_server() {
+    let work_dir = TempDir::new().unwrap();
+
+    scaffold_server("csharp", work_dir.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Successfully generated mcp server csharp 'petstore'",
+        ));
+
+    let project = work_dir.path().join("petstore");
+    let csproj = fs::read_to_string(project.join("McpServer.csproj")).unwrap();
+    assert!(csproj.contains("<Nullable>enable</Nullable>"));
+    assert!(csproj.contains("<RootNamespace>Petstore</RootNamespace>"));
+    assert!(csproj.contains("Include=\"ModelContextProtocol.AspNetCore\""));
+
+    let models = fs::read_to_string(project.join("Models.cs")).unwrap();
+    assert!(models.contains("public sealed record Pet"));
+    assert!(models.contains("[JsonPropertyName(\"photoUrls\")]"));
+    assert!(models.contains("public required IReadOnlyList<string> PhotoUrls { get; init; }"));
+    assert!(models.contains("public Category? Category { get; init; }"));
+
+    let tools = fs::read_to_string(project.join("ApiTools.cs")).unwrap();
+    assert!(tools.contains("[McpServerTool(Name = \"add_pet\")"));
+    assert!(tools.contains("[Description(\"ID of pet to return\")] long petId,"));
+    assert!(tools.contains("[\"petId\"] = petId,"));
+
+    let program = fs::read_to_string(project.join("Program.cs")).unwrap();
+    assert!(program.contains(".WithStdioServerTransport()"));
+    assert!(program.contains(".WithHttpTransport()"));
+    assert!(project.join("schemas/add_pet.json").is_file());
+}
+
+#[test]
+fn test_scaffold_unknown_client_variant_lists_available() {
+    let work_dir = TempDir::new().unwrap();
+
+    scaffold_client("rust-tui", work_dir.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Unknown template 'rust-tui' for mcp client; available: python, rust, typescript",
+        ));
+}
+
+#[test]
+fn test_scaffold_python_client() {
+    let work_dir = TempDir::new().unwrap();
+
+    scaffold_client("python", work_dir.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Successfully generated mcp client python 'pet-client'",
+        ));
+
+    let project = work_dir.path().join("pet-client");
+    let pyproject = fs::read_to_string(project.join("pyproject.toml")).unwrap();
+    assert!(pyproject.contains("\"mcp>=1.9\""));
+    assert!(pyproject.contains("pet_client = \"mcp_client.__main__:main\""));
+
+    let main = fs::read_to_string(project.join("src/mcp_client/__main__.py")).unwrap();
+    assert!(main.contains("prog=\"pet_client\""));
+    assert!(main.contains("\"set-default\""));
+
+    let profiles = fs::read_to_string(project.join("src/mcp_client/profiles.py")).unwrap();
+    assert!(profiles.contains("Path(base) / \"pet-client\" / \"servers.json\""));
+
+    for module in ["connection.py", "operations.py", "shell.py"] {
+        assert!(
+            project.join("src/mcp_client").join(module).is_file(),
+            "{module}"
+        );
+    }
+}
+
+#[test]
+fn test_scaffold_typescript_client() {
+    let work_dir = TempDir::new().unwrap();
+
+    scaffold_client("typescript", work_dir.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Successfully generated mcp client typescript 'pet-client'",
+        ));
+
+    let project = work_dir.path().join("pet-client");
+    let package: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(project.join("package.json")).unwrap()).unwrap();
+    assert_eq!(package["name"], "pet-client");
+    assert_eq!(package["bin"]["pet-client"], "dist/index.js");
+    assert!(package["dependencies"]["@modelcontextprotocol/sdk"].is_string());
+    assert!(project.join("tsconfig.json").is_file());
+
+    let connection = fs::read_to_string(project.join("src/connection.ts")).unwrap();
+    assert!(connection.contains("new StreamableHTTPClientTransport("));
+    assert!(connection.contains("name: \"pet-client\""));
+
+    for module in ["index.ts", "profiles.ts", "operations.ts", "shell.ts"] {
+        assert!(project.join("src").join(module).is_file(), "{module}");
+    }
+}
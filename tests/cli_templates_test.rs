@@ -15,8 +15,28 @@ fn test_templates_list_command() {
         .stdout(predicate::str::contains("Available embedded templates"))
         .stdout(predicate::str::contains("Server Templates"))
         .stdout(predicate::str::contains("mcp/server/rust"))
+        .stdout(predicate::str::contains(
+            "mcp/server/rust-minimal (mcp-rust-minimal-server, --template rust-minimal)",
+        ))
         .stdout(predicate::str::contains("Client Templates"))
-        .stdout(predicate::str::contains("mcp/client/rust"));
+        .stdout(predicate::str::contains("mcp/client/rust"))
+        .stdout(predicate::str::contains(
+            "acp/server/rust (acp-rust-server, --template rust)",
+        ))
+        .stdout(predicate::str::contains(
+            "acp/client/rust (acp-rust-client, --template rust)",
+        ))
+        .stdout(predicate::str::contains("Agent Templates"))
+        .stdout(predicate::str::contains(
+            "a2a/agent/rust (a2a-rust-agent, --template rust)",
+        ))
+        .stdout(predicate::str::contains(
+            "anp/agent/rust (anp-rust-agent, --template rust)",
+        ))
+        .stdout(predicate::str::contains("Bridge Templates"))
+        .stdout(predicate::str::contains(
+            "a2a/bridge/rust (a2a-rust-mcp-bridge, --template rust)",
+        ));
 }
 
 #[test]
@@ -45,6 +65,12 @@ fn test_templates_export_command() {
             .join("mcp/client/rust/manifest.yml")
             .exists()
     );
+    assert!(
+        temp_dir
+            .path()
+            .join("mcp/server/rust-minimal/main.rs.tera")
+            .is_file()
+    );
 }
 
 #[test]
@@ -88,6 +114,22 @@ fn test_templates_info_command() {
         ));
 }
 
+#[test]
+fn test_templates_info_lists_variables() {
+    let mut cmd = Command::cargo_bin("agenterra").unwrap();
+
+    cmd.arg("templates")
+        .arg("info")
+        .arg("mcp/server/rust")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Variables:"))
+        .stdout(predicate::str::contains(
+            "- server_port (integer, default: 8080): Port the SSE transport binds to",
+        ))
+        .stdout(predicate::str::contains("- base_api_url (string)"));
+}
+
 #[test]
 fn test_templates_info_nonexistent() {
     let mut cmd = Command::cargo_bin("agenterra").unwrap();
@@ -162,3 +204,97 @@ fn test_templates_export_nonexistent_template() {
         .failure()
         .stderr(predicate::str::contains("Template not found"));
 }
+
+#[test]
+fn test_templates_validate_embedded_template() {
+    let mut cmd = Command::cargo_bin("agenterra").unwrap();
+
+    cmd.args(["templates", "validate", "mcp/client/rust"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("0 error(s), 0 warning(s)"));
+}
+
+#[test]
+fn test_templates_validate_reports_positions() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join("manifest.yml"),
+        "name: broken\nversion: \"1.0.0\"\nprotocol: mcp\nrole: client\nlanguage: rust\nfiles:\n  - source: main.rs.tera\n    destination: src/main.rs\n",
+    )
+    .unwrap();
+    std::fs::write(
+        temp_dir.path().join("main.rs.tera"),
+        "fn main() {}\n// {{ project_name }}\n// {{ undefined_thing }}\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("agenterra").unwrap();
+    let output = cmd
+        .args(["templates", "validate", "--report", "json"])
+        .arg(temp_dir.path())
+        .assert()
+        .failure()
+        .get_output()
+        .stdout
+        .clone();
+
+    let report: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let issue = &report["issues"][0];
+    assert_eq!(issue["code"], "undefined-variable");
+    assert_eq!(issue["file"], "main.rs.tera");
+    assert_eq!(issue["line"], 3);
+}
+
+#[test]
+fn test_templates_validate_nonexistent() {
+    let mut cmd = Command::cargo_bin("agenterra").unwrap();
+
+    cmd.args(["templates", "validate", "nonexistent/template"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Template not found"));
+}
+
+#[test]
+fn test_templates_new_creates_valid_starter() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("agenterra").unwrap();
+    cmd.args(["templates", "new", "mcp/server/rust", "--output-dir"])
+        .arg(temp_dir.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Variables from the Rust context builder (mcp server):",
+        ))
+        .stdout(predicate::str::contains(
+            "Per endpoint (for_each: endpoint):",
+        ));
+
+    let template_dir = temp_dir.path().join("mcp/server/rust");
+    for file in [
+        "manifest.yml",
+        "README.md.tera",
+        "src/main.rs.tera",
+        "src/handlers/endpoint.rs.tera",
+        "template_tests/run.sh",
+    ] {
+        assert!(template_dir.join(file).is_file(), "missing {file}");
+    }
+
+    let mut cmd = Command::cargo_bin("agenterra").unwrap();
+    cmd.args(["templates", "validate"])
+        .arg(&template_dir)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("0 error(s), 0 warning(s)"));
+
+    // A second run must not overwrite the author's work
+    let mut cmd = Command::cargo_bin("agenterra").unwrap();
+    cmd.args(["templates", "new", "mcp/server/rust", "--output-dir"])
+        .arg(temp_dir.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already exists"));
+}
@@ -53,6 +53,7 @@ fn test_cli_flag_combinations() -> Result<()> {
             "scaffold",
             "mcp",
             "server",
+            "--no-hooks",
             "--project-name",
             "test_schema_path_required",
         ])
@@ -80,6 +81,7 @@ fn test_cli_flag_combinations() -> Result<()> {
             "scaffold",
             "mcp",
             "client",
+            "--no-hooks",
             "--template",
             "rust",
             "--template-dir",
@@ -110,6 +112,7 @@ fn test_cli_flag_combinations() -> Result<()> {
             "scaffold",
             "mcp",
             "client",
+            "--no-hooks",
             "--project-name",
             "test_schema_path_rejected",
             "--schema-path",
@@ -138,6 +141,7 @@ fn test_cli_flag_combinations() -> Result<()> {
             "scaffold",
             "mcp",
             "server",
+            "--no-hooks",
             "--schema-path",
             "/nonexistent/schema.yaml",
             "--project-name",
@@ -179,6 +183,7 @@ fn test_cli_flag_combinations() -> Result<()> {
             "scaffold",
             "mcp",
             "client",
+            "--no-hooks",
             "--project-name",
             "test-client",
             "--template",
@@ -358,6 +363,7 @@ fn test_custom_template_scaffolding() -> Result<()> {
             "scaffold",
             "mcp",
             "server",
+            "--no-hooks",
             "--project-name",
             server_name,
             "--output-dir",
@@ -414,6 +420,7 @@ fn test_custom_template_scaffolding() -> Result<()> {
             "scaffold",
             "mcp",
             "client",
+            "--no-hooks",
             "--project-name",
             client_name,
             "--output-dir",
@@ -497,6 +504,7 @@ async fn scaffold_mcp_server(
             "scaffold",
             "mcp",
             "server",
+            "--no-hooks",
             "--project-name",
             server_name,
             "--output-dir",
@@ -537,6 +545,7 @@ async fn scaffold_mcp_client(
             "scaffold",
             "mcp",
             "client",
+            "--no-hooks",
             "--project-name",
             client_name,
             "--output-dir",
@@ -929,6 +938,7 @@ async fn test_mcp_sse_transport() -> Result<()> {
             "scaffold",
             "mcp",
             "client",
+            "--no-hooks",
             "--project-name",
             client_name,
             "--template",
@@ -0,0 +1,121 @@
+//! Auto-generated handler for `/create_user` endpoint.
+
+// Internal imports (std, crate)
+use crate::common::*;
+use crate::config::Config;
+
+// External imports (alphabetized)
+use agenterra_rmcp::handler::server::tool::IntoCallToolResult;
+use agenterra_rmcp::model::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::{debug, error, info, warn};
+use utoipa::ToSchema;
+
+/// Auto-generated parameters struct for `/create_user` endpoint.
+/// Spec: 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, ToSchema)]
+pub struct CreateUserParams {
+}
+
+// Implement Endpoint for generic handler
+impl Endpoint for CreateUserParams {
+    fn path() -> &'static str {
+        "/user"
+    }
+
+    fn get_params(&self) -> HashMap<String, String> {
+        HashMap::new()
+    }
+}
+
+/// Auto-generated properties struct for `/create_user` endpoint.
+/// Spec: 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, ToSchema)]
+pub struct CreateUserProperties {
+}
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct CreateUserResponse(pub serde_json::Value);
+
+impl IntoContents for CreateUserResponse {
+    fn into_contents(self) -> Vec<Content> {
+        // Convert the response into a Vec<Content> as expected by MCP
+        // Panics only if serialization fails, which should be impossible for valid structs
+        vec![Content::json(self).expect("Failed to serialize CreateUserResponse to Content")]
+    }
+}
+
+/// `/user` endpoint handler
+/// Create user
+/// This can only be done by the logged in user.
+#[doc = r#"Verb: GET
+Path: /user
+Parameters: CreateUserParams
+Responses:
+    200: Successful Operation
+    400: Bad input parameter
+    500: Internal Server Error
+    502: Bad Gateway
+    503: Service Unavailable
+    504: Gateway Timeout
+Tag: user"#]
+pub async fn create_user_handler(
+    config: &Config,
+    params: &CreateUserParams,
+) -> Result<CallToolResult, agenterra_rmcp::Error> {
+    // Log incoming request parameters and request details as structured JSON
+    info!(
+        target = "handler",
+        event = "incoming_request",
+        endpoint = "create_user",
+        method = "GET",
+        path = "/user",
+        params = serde_json::to_string(params).unwrap_or_else(|e| {
+            warn!("Failed to serialize request params: {e}");
+            "{}".to_string()
+        })
+    );
+    debug!(
+        target = "handler",
+        event = "before_api_call",
+        endpoint = "create_user"
+    );
+    let resp = get_endpoint_response::<_, CreateUserResponse>(config, params).await;
+
+    match &resp {
+        Ok(r) => {
+            info!(
+                target = "handler",
+                event = "api_response",
+                endpoint = "create_user",
+                response = ?r
+            );
+        }
+        Err(e) => {
+            error!(target = "handler", event = "api_error", endpoint = "create_user", error = ?e);
+        }
+    }
+
+    // Log outgoing API request as structured JSON
+    resp.and_then(|r| r.into_call_tool_result())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+    #[test]
+    fn test_parameters_struct_serialization() {
+        let params = CreateUserParams {
+        };
+        let _ = serde_json::to_string(&params).expect("Serializing test params should not fail");
+    }
+
+    #[test]
+    fn test_properties_struct_serialization() {
+        let props = CreateUserProperties {
+        };
+        let _ = serde_json::to_string(&props).expect("Serializing test properties should not fail");
+    }
+}
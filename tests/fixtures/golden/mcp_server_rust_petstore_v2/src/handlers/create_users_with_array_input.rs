@@ -0,0 +1,120 @@
+//! Auto-generated handler for `/create_users_with_array_input` endpoint.
+
+// Internal imports (std, crate)
+use crate::common::*;
+use crate::config::Config;
+
+// External imports (alphabetized)
+use agenterra_rmcp::handler::server::tool::IntoCallToolResult;
+use agenterra_rmcp::model::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::{debug, error, info, warn};
+use utoipa::ToSchema;
+
+/// Auto-generated parameters struct for `/create_users_with_array_input` endpoint.
+/// Spec: 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, ToSchema)]
+pub struct CreateUsersWithArrayInputParams {
+}
+
+// Implement Endpoint for generic handler
+impl Endpoint for CreateUsersWithArrayInputParams {
+    fn path() -> &'static str {
+        "/user/createWithArray"
+    }
+
+    fn get_params(&self) -> HashMap<String, String> {
+        HashMap::new()
+    }
+}
+
+/// Auto-generated properties struct for `/create_users_with_array_input` endpoint.
+/// Spec: 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, ToSchema)]
+pub struct CreateUsersWithArrayInputProperties {
+}
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct CreateUsersWithArrayInputResponse(pub serde_json::Value);
+
+impl IntoContents for CreateUsersWithArrayInputResponse {
+    fn into_contents(self) -> Vec<Content> {
+        // Convert the response into a Vec<Content> as expected by MCP
+        // Panics only if serialization fails, which should be impossible for valid structs
+        vec![Content::json(self).expect("Failed to serialize CreateUsersWithArrayInputResponse to Content")]
+    }
+}
+
+/// `/user/createWithArray` endpoint handler
+/// Creates list of users with given input array
+#[doc = r#"Verb: GET
+Path: /user/createWithArray
+Parameters: CreateUsersWithArrayInputParams
+Responses:
+    200: Successful Operation
+    400: Bad input parameter
+    500: Internal Server Error
+    502: Bad Gateway
+    503: Service Unavailable
+    504: Gateway Timeout
+Tag: user"#]
+pub async fn create_users_with_array_input_handler(
+    config: &Config,
+    params: &CreateUsersWithArrayInputParams,
+) -> Result<CallToolResult, agenterra_rmcp::Error> {
+    // Log incoming request parameters and request details as structured JSON
+    info!(
+        target = "handler",
+        event = "incoming_request",
+        endpoint = "create_users_with_array_input",
+        method = "GET",
+        path = "/user/createWithArray",
+        params = serde_json::to_string(params).unwrap_or_else(|e| {
+            warn!("Failed to serialize request params: {e}");
+            "{}".to_string()
+        })
+    );
+    debug!(
+        target = "handler",
+        event = "before_api_call",
+        endpoint = "create_users_with_array_input"
+    );
+    let resp = get_endpoint_response::<_, CreateUsersWithArrayInputResponse>(config, params).await;
+
+    match &resp {
+        Ok(r) => {
+            info!(
+                target = "handler",
+                event = "api_response",
+                endpoint = "create_users_with_array_input",
+                response = ?r
+            );
+        }
+        Err(e) => {
+            error!(target = "handler", event = "api_error", endpoint = "create_users_with_array_input", error = ?e);
+        }
+    }
+
+    // Log outgoing API request as structured JSON
+    resp.and_then(|r| r.into_call_tool_result())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+    #[test]
+    fn test_parameters_struct_serialization() {
+        let params = CreateUsersWithArrayInputParams {
+        };
+        let _ = serde_json::to_string(&params).expect("Serializing test params should not fail");
+    }
+
+    #[test]
+    fn test_properties_struct_serialization() {
+        let props = CreateUsersWithArrayInputProperties {
+        };
+        let _ = serde_json::to_string(&props).expect("Serializing test properties should not fail");
+    }
+}
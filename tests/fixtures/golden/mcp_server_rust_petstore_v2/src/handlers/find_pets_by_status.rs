@@ -0,0 +1,131 @@
+//! Auto-generated handler for `/find_pets_by_status` endpoint.
+
+// Internal imports (std, crate)
+use crate::common::*;
+use crate::config::Config;
+
+// External imports (alphabetized)
+use agenterra_rmcp::handler::server::tool::IntoCallToolResult;
+use agenterra_rmcp::model::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::{debug, error, info, warn};
+use utoipa::ToSchema;
+
+/// Auto-generated parameters struct for `/find_pets_by_status` endpoint.
+/// Spec: 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, ToSchema)]
+pub struct FindPetsByStatusParams {
+#[schemars(description = r#"Status values that need to be considered for filter"#)]
+    pub status: Option<String>,
+    }
+
+// Implement Endpoint for generic handler
+impl Endpoint for FindPetsByStatusParams {
+    fn path() -> &'static str {
+        "/pet/findByStatus"
+    }
+
+    fn get_params(&self) -> HashMap<String, String> {
+        let mut params = HashMap::new();
+        
+        if let Some(val) = &self.status {
+            params.insert("status".to_string(), val.to_string());
+        }
+        
+        params
+    }
+}
+
+/// Auto-generated properties struct for `/find_pets_by_status` endpoint.
+/// Spec: 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, ToSchema)]
+pub struct FindPetsByStatusProperties {
+}
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct FindPetsByStatusResponse(pub serde_json::Value);
+
+impl IntoContents for FindPetsByStatusResponse {
+    fn into_contents(self) -> Vec<Content> {
+        // Convert the response into a Vec<Content> as expected by MCP
+        // Panics only if serialization fails, which should be impossible for valid structs
+        vec![Content::json(self).expect("Failed to serialize FindPetsByStatusResponse to Content")]
+    }
+}
+
+/// `/pet/findByStatus` endpoint handler
+/// Finds Pets by status
+/// Multiple status values can be provided with comma separated strings
+#[doc = r#" - `status` (String, optional): Status values that need to be considered for filter"#]
+#[doc = r#"Verb: GET
+Path: /pet/findByStatus
+Parameters: FindPetsByStatusParams
+Responses:
+    200: Successful Operation
+    400: Bad input parameter
+    500: Internal Server Error
+    502: Bad Gateway
+    503: Service Unavailable
+    504: Gateway Timeout
+Tag: pet"#]
+pub async fn find_pets_by_status_handler(
+    config: &Config,
+    params: &FindPetsByStatusParams,
+) -> Result<CallToolResult, agenterra_rmcp::Error> {
+    // Log incoming request parameters and request details as structured JSON
+    info!(
+        target = "handler",
+        event = "incoming_request",
+        endpoint = "find_pets_by_status",
+        method = "GET",
+        path = "/pet/findByStatus",
+        params = serde_json::to_string(params).unwrap_or_else(|e| {
+            warn!("Failed to serialize request params: {e}");
+            "{}".to_string()
+        })
+    );
+    debug!(
+        target = "handler",
+        event = "before_api_call",
+        endpoint = "find_pets_by_status"
+    );
+    let resp = get_endpoint_response::<_, FindPetsByStatusResponse>(config, params).await;
+
+    match &resp {
+        Ok(r) => {
+            info!(
+                target = "handler",
+                event = "api_response",
+                endpoint = "find_pets_by_status",
+                response = ?r
+            );
+        }
+        Err(e) => {
+            error!(target = "handler", event = "api_error", endpoint = "find_pets_by_status", error = ?e);
+        }
+    }
+
+    // Log outgoing API request as structured JSON
+    resp.and_then(|r| r.into_call_tool_result())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+    #[test]
+    fn test_parameters_struct_serialization() {
+        let params = FindPetsByStatusParams {
+        status: None,
+            };
+        let _ = serde_json::to_string(&params).expect("Serializing test params should not fail");
+    }
+
+    #[test]
+    fn test_properties_struct_serialization() {
+        let props = FindPetsByStatusProperties {
+        };
+        let _ = serde_json::to_string(&props).expect("Serializing test properties should not fail");
+    }
+}
@@ -0,0 +1,577 @@
+//! Do not edit by hand.
+//! Auto-generated handler stubs for MCP endpoints.
+// MCP auto-generated: Endpoint handler modules
+pub mod upload_file;
+pub mod add_pet;
+pub mod update_pet;
+pub mod find_pets_by_status;
+pub mod find_pets_by_tags;
+pub mod get_pet_by_id;
+pub mod update_pet_with_form;
+pub mod delete_pet;
+pub mod get_inventory;
+pub mod place_order;
+pub mod get_order_by_id;
+pub mod delete_order;
+pub mod create_users_with_list_input;
+pub mod get_user_by_name;
+pub mod update_user;
+pub mod delete_user;
+pub mod login_user;
+pub mod logout_user;
+pub mod create_users_with_array_input;
+pub mod create_user;
+
+// Internal dependencies
+use crate::config::Config;
+
+// External dependencies
+use log::debug;
+use agenterra_rmcp::{
+    handler::server::tool::Parameters, model::*, service::*, tool, Error as McpError,
+    ServerHandler,
+};
+
+#[derive(Clone)]
+pub struct McpServer {
+    tool_router: agenterra_rmcp::handler::server::router::tool::ToolRouter<McpServer>,
+    config: Config,
+}
+
+impl McpServer {
+    /// Create a new MCP server instance with default configuration
+    pub fn new(config: Config) -> Self {
+        Self {
+            tool_router: Self::tool_router(),
+            config,
+        }
+    }
+}
+
+#[agenterra_rmcp::tool_router]
+impl McpServer {
+    /// Returns MCP server status for Inspector/health validation
+    #[tool(description = "Returns MCP server status for Inspector/health validation")]
+    pub async fn ping(&self) -> Result<CallToolResult, McpError> {
+        Ok(CallToolResult::success(vec![Content::text(
+            "The MCP server is alive!",
+        )]))
+    }
+    /// MCP API `/upload_file` endpoint handler
+    #[tool(description = r#"uploads an image - pet"#)]
+    pub async fn upload_file(
+        &self,
+        Parameters(params): Parameters<upload_file::UploadFileParams>,
+    ) -> Result<CallToolResult, McpError> {
+        upload_file::upload_file_handler(&self.config, &params).await
+    }
+    /// MCP API `/add_pet` endpoint handler
+    #[tool(description = r#"Add a new pet to the store - pet"#)]
+    pub async fn add_pet(
+        &self,
+        Parameters(params): Parameters<add_pet::AddPetParams>,
+    ) -> Result<CallToolResult, McpError> {
+        add_pet::add_pet_handler(&self.config, &params).await
+    }
+    /// MCP API `/update_pet` endpoint handler
+    #[tool(description = r#"Update an existing pet - pet"#)]
+    pub async fn update_pet(
+        &self,
+        Parameters(params): Parameters<update_pet::UpdatePetParams>,
+    ) -> Result<CallToolResult, McpError> {
+        update_pet::update_pet_handler(&self.config, &params).await
+    }
+    /// MCP API `/find_pets_by_status` endpoint handler
+    #[tool(description = r#"Finds Pets by status - Multiple status values can be provided with comma separated strings - pet"#)]
+    pub async fn find_pets_by_status(
+        &self,
+        Parameters(params): Parameters<find_pets_by_status::FindPetsByStatusParams>,
+    ) -> Result<CallToolResult, McpError> {
+        find_pets_by_status::find_pets_by_status_handler(&self.config, &params).await
+    }
+    /// MCP API `/find_pets_by_tags` endpoint handler
+    #[tool(description = r#"Finds Pets by tags - Multiple tags can be provided with comma separated strings. Use tag1, tag2, tag3 for testing. - pet"#)]
+    pub async fn find_pets_by_tags(
+        &self,
+        Parameters(params): Parameters<find_pets_by_tags::FindPetsByTagsParams>,
+    ) -> Result<CallToolResult, McpError> {
+        find_pets_by_tags::find_pets_by_tags_handler(&self.config, &params).await
+    }
+    /// MCP API `/get_pet_by_id` endpoint handler
+    #[tool(description = r#"Find pet by ID - Returns a single pet - pet"#)]
+    pub async fn get_pet_by_id(
+        &self,
+        Parameters(params): Parameters<get_pet_by_id::GetPetByIdParams>,
+    ) -> Result<CallToolResult, McpError> {
+        get_pet_by_id::get_pet_by_id_handler(&self.config, &params).await
+    }
+    /// MCP API `/update_pet_with_form` endpoint handler
+    #[tool(description = r#"Updates a pet in the store with form data - pet"#)]
+    pub async fn update_pet_with_form(
+        &self,
+        Parameters(params): Parameters<update_pet_with_form::UpdatePetWithFormParams>,
+    ) -> Result<CallToolResult, McpError> {
+        update_pet_with_form::update_pet_with_form_handler(&self.config, &params).await
+    }
+    /// MCP API `/delete_pet` endpoint handler
+    #[tool(description = r#"Deletes a pet - pet"#)]
+    pub async fn delete_pet(
+        &self,
+        Parameters(params): Parameters<delete_pet::DeletePetParams>,
+    ) -> Result<CallToolResult, McpError> {
+        delete_pet::delete_pet_handler(&self.config, &params).await
+    }
+    /// MCP API `/get_inventory` endpoint handler
+    #[tool(description = r#"Returns pet inventories by status - Returns a map of status codes to quantities - store"#)]
+    pub async fn get_inventory(
+        &self,
+        Parameters(params): Parameters<get_inventory::GetInventoryParams>,
+    ) -> Result<CallToolResult, McpError> {
+        get_inventory::get_inventory_handler(&self.config, &params).await
+    }
+    /// MCP API `/place_order` endpoint handler
+    #[tool(description = r#"Place an order for a pet - store"#)]
+    pub async fn place_order(
+        &self,
+        Parameters(params): Parameters<place_order::PlaceOrderParams>,
+    ) -> Result<CallToolResult, McpError> {
+        place_order::place_order_handler(&self.config, &params).await
+    }
+    /// MCP API `/get_order_by_id` endpoint handler
+    #[tool(description = r#"Find purchase order by ID - For valid response try integer IDs with value >= 1 and <= 10. Other values will generated exceptions - store"#)]
+    pub async fn get_order_by_id(
+        &self,
+        Parameters(params): Parameters<get_order_by_id::GetOrderByIdParams>,
+    ) -> Result<CallToolResult, McpError> {
+        get_order_by_id::get_order_by_id_handler(&self.config, &params).await
+    }
+    /// MCP API `/delete_order` endpoint handler
+    #[tool(description = r#"Delete purchase order by ID - For valid response try integer IDs with positive integer value. Negative or non-integer values will generate API errors - store"#)]
+    pub async fn delete_order(
+        &self,
+        Parameters(params): Parameters<delete_order::DeleteOrderParams>,
+    ) -> Result<CallToolResult, McpError> {
+        delete_order::delete_order_handler(&self.config, &params).await
+    }
+    /// MCP API `/create_users_with_list_input` endpoint handler
+    #[tool(description = r#"Creates list of users with given input array - user"#)]
+    pub async fn create_users_with_list_input(
+        &self,
+        Parameters(params): Parameters<create_users_with_list_input::CreateUsersWithListInputParams>,
+    ) -> Result<CallToolResult, McpError> {
+        create_users_with_list_input::create_users_with_list_input_handler(&self.config, &params).await
+    }
+    /// MCP API `/get_user_by_name` endpoint handler
+    #[tool(description = r#"Get user by user name - user"#)]
+    pub async fn get_user_by_name(
+        &self,
+        Parameters(params): Parameters<get_user_by_name::GetUserByNameParams>,
+    ) -> Result<CallToolResult, McpError> {
+        get_user_by_name::get_user_by_name_handler(&self.config, &params).await
+    }
+    /// MCP API `/update_user` endpoint handler
+    #[tool(description = r#"Updated user - This can only be done by the logged in user. - user"#)]
+    pub async fn update_user(
+        &self,
+        Parameters(params): Parameters<update_user::UpdateUserParams>,
+    ) -> Result<CallToolResult, McpError> {
+        update_user::update_user_handler(&self.config, &params).await
+    }
+    /// MCP API `/delete_user` endpoint handler
+    #[tool(description = r#"Delete user - This can only be done by the logged in user. - user"#)]
+    pub async fn delete_user(
+        &self,
+        Parameters(params): Parameters<delete_user::DeleteUserParams>,
+    ) -> Result<CallToolResult, McpError> {
+        delete_user::delete_user_handler(&self.config, &params).await
+    }
+    /// MCP API `/login_user` endpoint handler
+    #[tool(description = r#"Logs user into the system - user"#)]
+    pub async fn login_user(
+        &self,
+        Parameters(params): Parameters<login_user::LoginUserParams>,
+    ) -> Result<CallToolResult, McpError> {
+        login_user::login_user_handler(&self.config, &params).await
+    }
+    /// MCP API `/logout_user` endpoint handler
+    #[tool(description = r#"Logs out current logged in user session - user"#)]
+    pub async fn logout_user(
+        &self,
+        Parameters(params): Parameters<logout_user::LogoutUserParams>,
+    ) -> Result<CallToolResult, McpError> {
+        logout_user::logout_user_handler(&self.config, &params).await
+    }
+    /// MCP API `/create_users_with_array_input` endpoint handler
+    #[tool(description = r#"Creates list of users with given input array - user"#)]
+    pub async fn create_users_with_array_input(
+        &self,
+        Parameters(params): Parameters<create_users_with_array_input::CreateUsersWithArrayInputParams>,
+    ) -> Result<CallToolResult, McpError> {
+        create_users_with_array_input::create_users_with_array_input_handler(&self.config, &params).await
+    }
+    /// MCP API `/create_user` endpoint handler
+    #[tool(description = r#"Create user - This can only be done by the logged in user. - user"#)]
+    pub async fn create_user(
+        &self,
+        Parameters(params): Parameters<create_user::CreateUserParams>,
+    ) -> Result<CallToolResult, McpError> {
+        create_user::create_user_handler(&self.config, &params).await
+    }
+}
+
+#[agenterra_rmcp::tool_handler]
+impl ServerHandler for McpServer {
+    fn get_info(&self) -> ServerInfo {
+        debug!("[MCP] get_info() called - should show tools!");
+
+        // Set up explicit capabilities for tools and resources
+        let tools_capability = ToolsCapability {
+            list_changed: Some(true),
+        };
+
+        let resources_capability = ResourcesCapability {
+            list_changed: Some(true),
+            ..ResourcesCapability::default()
+        };
+
+        let info = ServerInfo {
+            protocol_version: ProtocolVersion::V_2024_11_05,
+            capabilities: ServerCapabilities {
+                experimental: None,
+                logging: None,
+                completions: None,
+                prompts: None,
+                resources: Some(resources_capability),
+                tools: Some(tools_capability),
+            },
+            server_info: Implementation::from_build_env(),
+            
+            instructions: None,
+            
+        };
+
+        debug!("[MCP] Returning ServerInfo with enabled tools and resources: {:?}", info);
+        info
+    }
+
+    /// Implements MCP resource enumeration for all schema resources (one per endpoint)
+    fn list_resources(
+        &self, _request: Option<PaginatedRequestParam>, _context: RequestContext<RoleServer>,
+    ) -> impl Future<Output = Result<ListResourcesResult, McpError>> + Send + '_ {
+        use agenterra_rmcp::model::{Annotated, RawResource};
+        let resources = vec![
+            Annotated {
+                raw: RawResource {
+                    uri: format!("/schema/{}", "upload_file"),
+                    name: "upload_file".to_string(),
+                    description: Some(
+                        "JSON schema for the /upload_file endpoint (fields, types, docs, envelope)"
+                            .to_string(),
+                    ),
+                    mime_type: Some("application/json".to_string()),
+                    size: None,
+                },
+                annotations: Default::default(),
+            },
+            Annotated {
+                raw: RawResource {
+                    uri: format!("/schema/{}", "add_pet"),
+                    name: "add_pet".to_string(),
+                    description: Some(
+                        "JSON schema for the /add_pet endpoint (fields, types, docs, envelope)"
+                            .to_string(),
+                    ),
+                    mime_type: Some("application/json".to_string()),
+                    size: None,
+                },
+                annotations: Default::default(),
+            },
+            Annotated {
+                raw: RawResource {
+                    uri: format!("/schema/{}", "update_pet"),
+                    name: "update_pet".to_string(),
+                    description: Some(
+                        "JSON schema for the /update_pet endpoint (fields, types, docs, envelope)"
+                            .to_string(),
+                    ),
+                    mime_type: Some("application/json".to_string()),
+                    size: None,
+                },
+                annotations: Default::default(),
+            },
+            Annotated {
+                raw: RawResource {
+                    uri: format!("/schema/{}", "find_pets_by_status"),
+                    name: "find_pets_by_status".to_string(),
+                    description: Some(
+                        "JSON schema for the /find_pets_by_status endpoint (fields, types, docs, envelope)"
+                            .to_string(),
+                    ),
+                    mime_type: Some("application/json".to_string()),
+                    size: None,
+                },
+                annotations: Default::default(),
+            },
+            Annotated {
+                raw: RawResource {
+                    uri: format!("/schema/{}", "find_pets_by_tags"),
+                    name: "find_pets_by_tags".to_string(),
+                    description: Some(
+                        "JSON schema for the /find_pets_by_tags endpoint (fields, types, docs, envelope)"
+                            .to_string(),
+                    ),
+                    mime_type: Some("application/json".to_string()),
+                    size: None,
+                },
+                annotations: Default::default(),
+            },
+            Annotated {
+                raw: RawResource {
+                    uri: format!("/schema/{}", "get_pet_by_id"),
+                    name: "get_pet_by_id".to_string(),
+                    description: Some(
+                        "JSON schema for the /get_pet_by_id endpoint (fields, types, docs, envelope)"
+                            .to_string(),
+                    ),
+                    mime_type: Some("application/json".to_string()),
+                    size: None,
+                },
+                annotations: Default::default(),
+            },
+            Annotated {
+                raw: RawResource {
+                    uri: format!("/schema/{}", "update_pet_with_form"),
+                    name: "update_pet_with_form".to_string(),
+                    description: Some(
+                        "JSON schema for the /update_pet_with_form endpoint (fields, types, docs, envelope)"
+                            .to_string(),
+                    ),
+                    mime_type: Some("application/json".to_string()),
+                    size: None,
+                },
+                annotations: Default::default(),
+            },
+            Annotated {
+                raw: RawResource {
+                    uri: format!("/schema/{}", "delete_pet"),
+                    name: "delete_pet".to_string(),
+                    description: Some(
+                        "JSON schema for the /delete_pet endpoint (fields, types, docs, envelope)"
+                            .to_string(),
+                    ),
+                    mime_type: Some("application/json".to_string()),
+                    size: None,
+                },
+                annotations: Default::default(),
+            },
+            Annotated {
+                raw: RawResource {
+                    uri: format!("/schema/{}", "get_inventory"),
+                    name: "get_inventory".to_string(),
+                    description: Some(
+                        "JSON schema for the /get_inventory endpoint (fields, types, docs, envelope)"
+                            .to_string(),
+                    ),
+                    mime_type: Some("application/json".to_string()),
+                    size: None,
+                },
+                annotations: Default::default(),
+            },
+            Annotated {
+                raw: RawResource {
+                    uri: format!("/schema/{}", "place_order"),
+                    name: "place_order".to_string(),
+                    description: Some(
+                        "JSON schema for the /place_order endpoint (fields, types, docs, envelope)"
+                            .to_string(),
+                    ),
+                    mime_type: Some("application/json".to_string()),
+                    size: None,
+                },
+                annotations: Default::default(),
+            },
+            Annotated {
+                raw: RawResource {
+                    uri: format!("/schema/{}", "get_order_by_id"),
+                    name: "get_order_by_id".to_string(),
+                    description: Some(
+                        "JSON schema for the /get_order_by_id endpoint (fields, types, docs, envelope)"
+                            .to_string(),
+                    ),
+                    mime_type: Some("application/json".to_string()),
+                    size: None,
+                },
+                annotations: Default::default(),
+            },
+            Annotated {
+                raw: RawResource {
+                    uri: format!("/schema/{}", "delete_order"),
+                    name: "delete_order".to_string(),
+                    description: Some(
+                        "JSON schema for the /delete_order endpoint (fields, types, docs, envelope)"
+                            .to_string(),
+                    ),
+                    mime_type: Some("application/json".to_string()),
+                    size: None,
+                },
+                annotations: Default::default(),
+            },
+            Annotated {
+                raw: RawResource {
+                    uri: format!("/schema/{}", "create_users_with_list_input"),
+                    name: "create_users_with_list_input".to_string(),
+                    description: Some(
+                        "JSON schema for the /create_users_with_list_input endpoint (fields, types, docs, envelope)"
+                            .to_string(),
+                    ),
+                    mime_type: Some("application/json".to_string()),
+                    size: None,
+                },
+                annotations: Default::default(),
+            },
+            Annotated {
+                raw: RawResource {
+                    uri: format!("/schema/{}", "get_user_by_name"),
+                    name: "get_user_by_name".to_string(),
+                    description: Some(
+                        "JSON schema for the /get_user_by_name endpoint (fields, types, docs, envelope)"
+                            .to_string(),
+                    ),
+                    mime_type: Some("application/json".to_string()),
+                    size: None,
+                },
+                annotations: Default::default(),
+            },
+            Annotated {
+                raw: RawResource {
+                    uri: format!("/schema/{}", "update_user"),
+                    name: "update_user".to_string(),
+                    description: Some(
+                        "JSON schema for the /update_user endpoint (fields, types, docs, envelope)"
+                            .to_string(),
+                    ),
+                    mime_type: Some("application/json".to_string()),
+                    size: None,
+                },
+                annotations: Default::default(),
+            },
+            Annotated {
+                raw: RawResource {
+                    uri: format!("/schema/{}", "delete_user"),
+                    name: "delete_user".to_string(),
+                    description: Some(
+                        "JSON schema for the /delete_user endpoint (fields, types, docs, envelope)"
+                            .to_string(),
+                    ),
+                    mime_type: Some("application/json".to_string()),
+                    size: None,
+                },
+                annotations: Default::default(),
+            },
+            Annotated {
+                raw: RawResource {
+                    uri: format!("/schema/{}", "login_user"),
+                    name: "login_user".to_string(),
+                    description: Some(
+                        "JSON schema for the /login_user endpoint (fields, types, docs, envelope)"
+                            .to_string(),
+                    ),
+                    mime_type: Some("application/json".to_string()),
+                    size: None,
+                },
+                annotations: Default::default(),
+            },
+            Annotated {
+                raw: RawResource {
+                    uri: format!("/schema/{}", "logout_user"),
+                    name: "logout_user".to_string(),
+                    description: Some(
+                        "JSON schema for the /logout_user endpoint (fields, types, docs, envelope)"
+                            .to_string(),
+                    ),
+                    mime_type: Some("application/json".to_string()),
+                    size: None,
+                },
+                annotations: Default::default(),
+            },
+            Annotated {
+                raw: RawResource {
+                    uri: format!("/schema/{}", "create_users_with_array_input"),
+                    name: "create_users_with_array_input".to_string(),
+                    description: Some(
+                        "JSON schema for the /create_users_with_array_input endpoint (fields, types, docs, envelope)"
+                            .to_string(),
+                    ),
+                    mime_type: Some("application/json".to_string()),
+                    size: None,
+                },
+                annotations: Default::default(),
+            },
+            Annotated {
+                raw: RawResource {
+                    uri: format!("/schema/{}", "create_user"),
+                    name: "create_user".to_string(),
+                    description: Some(
+                        "JSON schema for the /create_user endpoint (fields, types, docs, envelope)"
+                            .to_string(),
+                    ),
+                    mime_type: Some("application/json".to_string()),
+                    size: None,
+                },
+                annotations: Default::default(),
+            },
+        ];
+        std::future::ready(Ok(ListResourcesResult { resources, next_cursor: None }))
+    }
+
+    /// Implements MCP resource fetching for schema resources by URI
+    fn read_resource(
+        &self,
+        request: ReadResourceRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> impl Future<Output = Result<ReadResourceResult, McpError>> + Send + '_ {
+        use agenterra_rmcp::model::{ResourceContents, ReadResourceResult};
+        let uri = request.uri;
+        let prefix = "/schema/";
+        let result = if let Some(endpoint) = uri.strip_prefix(prefix) {
+            let ep_lower = endpoint.to_lowercase();
+            let schema_json = match ep_lower.as_str() {
+                "upload_file" => include_str!("../../schemas/upload_file.json"),
+                "add_pet" => include_str!("../../schemas/add_pet.json"),
+                "update_pet" => include_str!("../../schemas/update_pet.json"),
+                "find_pets_by_status" => include_str!("../../schemas/find_pets_by_status.json"),
+                "find_pets_by_tags" => include_str!("../../schemas/find_pets_by_tags.json"),
+                "get_pet_by_id" => include_str!("../../schemas/get_pet_by_id.json"),
+                "update_pet_with_form" => include_str!("../../schemas/update_pet_with_form.json"),
+                "delete_pet" => include_str!("../../schemas/delete_pet.json"),
+                "get_inventory" => include_str!("../../schemas/get_inventory.json"),
+                "place_order" => include_str!("../../schemas/place_order.json"),
+                "get_order_by_id" => include_str!("../../schemas/get_order_by_id.json"),
+                "delete_order" => include_str!("../../schemas/delete_order.json"),
+                "create_users_with_list_input" => include_str!("../../schemas/create_users_with_list_input.json"),
+                "get_user_by_name" => include_str!("../../schemas/get_user_by_name.json"),
+                "update_user" => include_str!("../../schemas/update_user.json"),
+                "delete_user" => include_str!("../../schemas/delete_user.json"),
+                "login_user" => include_str!("../../schemas/login_user.json"),
+                "logout_user" => include_str!("../../schemas/logout_user.json"),
+                "create_users_with_array_input" => include_str!("../../schemas/create_users_with_array_input.json"),
+                "create_user" => include_str!("../../schemas/create_user.json"),
+                _ => return std::future::ready(Err(McpError::resource_not_found(
+                    format!("Schema not found for endpoint '{}': unknown endpoint", endpoint),
+                    None,
+                ))),
+            };
+            let resource =
+                ResourceContents::text(schema_json, format!("/schema/{ep_lower}"));
+            Ok(ReadResourceResult {
+                contents: vec![resource],
+            })
+        } else {
+            Err(McpError::resource_not_found(
+                format!("Unknown resource URI: {uri}"),
+                None,
+            ))
+        };
+        std::future::ready(result)
+    }
+}
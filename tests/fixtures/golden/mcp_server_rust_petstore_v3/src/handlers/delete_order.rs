@@ -0,0 +1,131 @@
+//! Auto-generated handler for `/delete_order` endpoint.
+
+// Internal imports (std, crate)
+use crate::common::*;
+use crate::config::Config;
+
+// External imports (alphabetized)
+use agenterra_rmcp::handler::server::tool::IntoCallToolResult;
+use agenterra_rmcp::model::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::{debug, error, info, warn};
+use utoipa::ToSchema;
+
+/// Auto-generated parameters struct for `/delete_order` endpoint.
+/// Spec: 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, ToSchema)]
+pub struct DeleteOrderParams {
+#[schemars(description = r#"ID of the order that needs to be deleted"#)]
+    pub order_id: Option<i32>,
+    }
+
+// Implement Endpoint for generic handler
+impl Endpoint for DeleteOrderParams {
+    fn path() -> &'static str {
+        "/store/order/{orderId}"
+    }
+
+    fn get_params(&self) -> HashMap<String, String> {
+        let mut params = HashMap::new();
+        
+        if let Some(val) = &self.order_id {
+            params.insert("order_id".to_string(), val.to_string());
+        }
+        
+        params
+    }
+}
+
+/// Auto-generated properties struct for `/delete_order` endpoint.
+/// Spec: 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, ToSchema)]
+pub struct DeleteOrderProperties {
+}
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct DeleteOrderResponse(pub serde_json::Value);
+
+impl IntoContents for DeleteOrderResponse {
+    fn into_contents(self) -> Vec<Content> {
+        // Convert the response into a Vec<Content> as expected by MCP
+        // Panics only if serialization fails, which should be impossible for valid structs
+        vec![Content::json(self).expect("Failed to serialize DeleteOrderResponse to Content")]
+    }
+}
+
+/// `/store/order/{orderId}` endpoint handler
+/// Delete purchase order by identifier.
+/// For valid response try integer IDs with value < 1000. Anything above 1000 or non-integers will generate API errors.
+#[doc = r#" - `order_id` (i32, optional): ID of the order that needs to be deleted"#]
+#[doc = r#"Verb: GET
+Path: /store/order/{orderId}
+Parameters: DeleteOrderParams
+Responses:
+    200: Successful Operation
+    400: Bad input parameter
+    500: Internal Server Error
+    502: Bad Gateway
+    503: Service Unavailable
+    504: Gateway Timeout
+Tag: store"#]
+pub async fn delete_order_handler(
+    config: &Config,
+    params: &DeleteOrderParams,
+) -> Result<CallToolResult, agenterra_rmcp::Error> {
+    // Log incoming request parameters and request details as structured JSON
+    info!(
+        target = "handler",
+        event = "incoming_request",
+        endpoint = "delete_order",
+        method = "GET",
+        path = "/store/order/{orderId}",
+        params = serde_json::to_string(params).unwrap_or_else(|e| {
+            warn!("Failed to serialize request params: {e}");
+            "{}".to_string()
+        })
+    );
+    debug!(
+        target = "handler",
+        event = "before_api_call",
+        endpoint = "delete_order"
+    );
+    let resp = get_endpoint_response::<_, DeleteOrderResponse>(config, params).await;
+
+    match &resp {
+        Ok(r) => {
+            info!(
+                target = "handler",
+                event = "api_response",
+                endpoint = "delete_order",
+                response = ?r
+            );
+        }
+        Err(e) => {
+            error!(target = "handler", event = "api_error", endpoint = "delete_order", error = ?e);
+        }
+    }
+
+    // Log outgoing API request as structured JSON
+    resp.and_then(|r| r.into_call_tool_result())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+    #[test]
+    fn test_parameters_struct_serialization() {
+        let params = DeleteOrderParams {
+        order_id: None,
+            };
+        let _ = serde_json::to_string(&params).expect("Serializing test params should not fail");
+    }
+
+    #[test]
+    fn test_properties_struct_serialization() {
+        let props = DeleteOrderProperties {
+        };
+        let _ = serde_json::to_string(&props).expect("Serializing test properties should not fail");
+    }
+}
@@ -0,0 +1,137 @@
+//! Auto-generated handler for `/delete_pet` endpoint.
+
+// Internal imports (std, crate)
+use crate::common::*;
+use crate::config::Config;
+
+// External imports (alphabetized)
+use agenterra_rmcp::handler::server::tool::IntoCallToolResult;
+use agenterra_rmcp::model::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::{debug, error, info, warn};
+use utoipa::ToSchema;
+
+/// Auto-generated parameters struct for `/delete_pet` endpoint.
+/// Spec: 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, ToSchema)]
+pub struct DeletePetParams {
+pub api_key: Option<String>,
+    #[schemars(description = r#"Pet id to delete"#)]
+    pub pet_id: Option<i32>,
+    }
+
+// Implement Endpoint for generic handler
+impl Endpoint for DeletePetParams {
+    fn path() -> &'static str {
+        "/pet/{petId}"
+    }
+
+    fn get_params(&self) -> HashMap<String, String> {
+        let mut params = HashMap::new();
+        
+        if let Some(val) = &self.api_key {
+            params.insert("api_key".to_string(), val.to_string());
+        }
+        
+        if let Some(val) = &self.pet_id {
+            params.insert("pet_id".to_string(), val.to_string());
+        }
+        
+        params
+    }
+}
+
+/// Auto-generated properties struct for `/delete_pet` endpoint.
+/// Spec: 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, ToSchema)]
+pub struct DeletePetProperties {
+}
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct DeletePetResponse(pub serde_json::Value);
+
+impl IntoContents for DeletePetResponse {
+    fn into_contents(self) -> Vec<Content> {
+        // Convert the response into a Vec<Content> as expected by MCP
+        // Panics only if serialization fails, which should be impossible for valid structs
+        vec![Content::json(self).expect("Failed to serialize DeletePetResponse to Content")]
+    }
+}
+
+/// `/pet/{petId}` endpoint handler
+/// Deletes a pet.
+/// Delete a pet.
+#[doc = r#" - `api_key` (String, optional): No description - `pet_id` (i32, optional): Pet id to delete"#]
+#[doc = r#"Verb: GET
+Path: /pet/{petId}
+Parameters: DeletePetParams
+Responses:
+    200: Successful Operation
+    400: Bad input parameter
+    500: Internal Server Error
+    502: Bad Gateway
+    503: Service Unavailable
+    504: Gateway Timeout
+Tag: pet"#]
+pub async fn delete_pet_handler(
+    config: &Config,
+    params: &DeletePetParams,
+) -> Result<CallToolResult, agenterra_rmcp::Error> {
+    // Log incoming request parameters and request details as structured JSON
+    info!(
+        target = "handler",
+        event = "incoming_request",
+        endpoint = "delete_pet",
+        method = "GET",
+        path = "/pet/{petId}",
+        params = serde_json::to_string(params).unwrap_or_else(|e| {
+            warn!("Failed to serialize request params: {e}");
+            "{}".to_string()
+        })
+    );
+    debug!(
+        target = "handler",
+        event = "before_api_call",
+        endpoint = "delete_pet"
+    );
+    let resp = get_endpoint_response::<_, DeletePetResponse>(config, params).await;
+
+    match &resp {
+        Ok(r) => {
+            info!(
+                target = "handler",
+                event = "api_response",
+                endpoint = "delete_pet",
+                response = ?r
+            );
+        }
+        Err(e) => {
+            error!(target = "handler", event = "api_error", endpoint = "delete_pet", error = ?e);
+        }
+    }
+
+    // Log outgoing API request as structured JSON
+    resp.and_then(|r| r.into_call_tool_result())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+    #[test]
+    fn test_parameters_struct_serialization() {
+        let params = DeletePetParams {
+        api_key: None,
+            pet_id: None,
+            };
+        let _ = serde_json::to_string(&params).expect("Serializing test params should not fail");
+    }
+
+    #[test]
+    fn test_properties_struct_serialization() {
+        let props = DeletePetProperties {
+        };
+        let _ = serde_json::to_string(&props).expect("Serializing test properties should not fail");
+    }
+}
@@ -0,0 +1,134 @@
+//! Auto-generated handler for `/find_pets_by_tags` endpoint.
+
+// Internal imports (std, crate)
+use crate::common::*;
+use crate::config::Config;
+
+// External imports (alphabetized)
+use agenterra_rmcp::handler::server::tool::IntoCallToolResult;
+use agenterra_rmcp::model::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::{debug, error, info, warn};
+use utoipa::ToSchema;
+
+/// Auto-generated parameters struct for `/find_pets_by_tags` endpoint.
+/// Spec: 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, ToSchema)]
+pub struct FindPetsByTagsParams {
+#[schemars(description = r#"Tags to filter by"#)]
+    pub tags: Option<Vec<String>>,
+    }
+
+// Implement Endpoint for generic handler
+impl Endpoint for FindPetsByTagsParams {
+    fn path() -> &'static str {
+        "/pet/findByTags"
+    }
+
+    fn get_params(&self) -> HashMap<String, String> {
+        let mut params = HashMap::new();
+        
+        if let Some(val) = &self.tags {
+            // Handle array parameters by joining values with commas
+            // This creates query strings like: ?tags=tag1,tag2,tag3
+            // Server must split by comma to reconstruct the array
+            params.insert("tags".to_string(), val.join(","));
+        }
+        
+        params
+    }
+}
+
+/// Auto-generated properties struct for `/find_pets_by_tags` endpoint.
+/// Spec: 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, ToSchema)]
+pub struct FindPetsByTagsProperties {
+}
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct FindPetsByTagsResponse(pub Vec<serde_json::Value>);
+
+impl IntoContents for FindPetsByTagsResponse {
+    fn into_contents(self) -> Vec<Content> {
+        // Convert the response into a Vec<Content> as expected by MCP
+        // Panics only if serialization fails, which should be impossible for valid structs
+        vec![Content::json(self).expect("Failed to serialize FindPetsByTagsResponse to Content")]
+    }
+}
+
+/// `/pet/findByTags` endpoint handler
+/// Finds Pets by tags.
+/// Multiple tags can be provided with comma separated strings. Use tag1, tag2, tag3 for testing.
+#[doc = r#" - `tags` (Vec<String>, optional): Tags to filter by"#]
+#[doc = r#"Verb: GET
+Path: /pet/findByTags
+Parameters: FindPetsByTagsParams
+Responses:
+    200: Successful Operation
+    400: Bad input parameter
+    500: Internal Server Error
+    502: Bad Gateway
+    503: Service Unavailable
+    504: Gateway Timeout
+Tag: pet"#]
+pub async fn find_pets_by_tags_handler(
+    config: &Config,
+    params: &FindPetsByTagsParams,
+) -> Result<CallToolResult, agenterra_rmcp::Error> {
+    // Log incoming request parameters and request details as structured JSON
+    info!(
+        target = "handler",
+        event = "incoming_request",
+        endpoint = "find_pets_by_tags",
+        method = "GET",
+        path = "/pet/findByTags",
+        params = serde_json::to_string(params).unwrap_or_else(|e| {
+            warn!("Failed to serialize request params: {e}");
+            "{}".to_string()
+        })
+    );
+    debug!(
+        target = "handler",
+        event = "before_api_call",
+        endpoint = "find_pets_by_tags"
+    );
+    let resp = get_endpoint_response::<_, FindPetsByTagsResponse>(config, params).await;
+
+    match &resp {
+        Ok(r) => {
+            info!(
+                target = "handler",
+                event = "api_response",
+                endpoint = "find_pets_by_tags",
+                response = ?r
+            );
+        }
+        Err(e) => {
+            error!(target = "handler", event = "api_error", endpoint = "find_pets_by_tags", error = ?e);
+        }
+    }
+
+    // Log outgoing API request as structured JSON
+    resp.and_then(|r| r.into_call_tool_result())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+    #[test]
+    fn test_parameters_struct_serialization() {
+        let params = FindPetsByTagsParams {
+        tags: None,
+            };
+        let _ = serde_json::to_string(&params).expect("Serializing test params should not fail");
+    }
+
+    #[test]
+    fn test_properties_struct_serialization() {
+        let props = FindPetsByTagsProperties {
+        };
+        let _ = serde_json::to_string(&props).expect("Serializing test properties should not fail");
+    }
+}
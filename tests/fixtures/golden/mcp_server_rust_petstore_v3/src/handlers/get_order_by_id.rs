@@ -0,0 +1,144 @@
+//! Auto-generated handler for `/get_order_by_id` endpoint.
+
+// Internal imports (std, crate)
+use crate::common::*;
+use crate::config::Config;
+
+// External imports (alphabetized)
+use agenterra_rmcp::handler::server::tool::IntoCallToolResult;
+use agenterra_rmcp::model::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::{debug, error, info, warn};
+use utoipa::ToSchema;
+
+/// Auto-generated parameters struct for `/get_order_by_id` endpoint.
+/// Spec: 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, ToSchema)]
+pub struct GetOrderByIdParams {
+#[schemars(description = r#"ID of order that needs to be fetched"#)]
+    pub order_id: Option<i32>,
+    }
+
+// Implement Endpoint for generic handler
+impl Endpoint for GetOrderByIdParams {
+    fn path() -> &'static str {
+        "/store/order/{orderId}"
+    }
+
+    fn get_params(&self) -> HashMap<String, String> {
+        let mut params = HashMap::new();
+        
+        if let Some(val) = &self.order_id {
+            params.insert("order_id".to_string(), val.to_string());
+        }
+        
+        params
+    }
+}
+
+/// Auto-generated properties struct for `/get_order_by_id` endpoint.
+/// Spec: 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, ToSchema)]
+pub struct GetOrderByIdProperties {
+}
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct GetOrderByIdResponse {
+    #[schemars(description = r#" - "#)]
+    pub id: Option<i32>,
+    #[schemars(description = r#" - "#)]
+    pub pet_id: Option<i32>,
+    #[schemars(description = r#" - "#)]
+    pub quantity: Option<i32>,
+    #[schemars(description = r#" - "#)]
+    pub ship_date: Option<String>,
+    #[schemars(description = r#" - Order Status"#)]
+    pub status: Option<String>,
+    #[schemars(description = r#" - "#)]
+    pub complete: Option<bool>,
+}
+
+impl IntoContents for GetOrderByIdResponse {
+    fn into_contents(self) -> Vec<Content> {
+        // Convert the response into a Vec<Content> as expected by MCP
+        // Panics only if serialization fails, which should be impossible for valid structs
+        vec![Content::json(self).expect("Failed to serialize GetOrderByIdResponse to Content")]
+    }
+}
+
+/// `/store/order/{orderId}` endpoint handler
+/// Find purchase order by ID.
+/// For valid response try integer IDs with value <= 5 or > 10. Other values will generate exceptions.
+#[doc = r#" - `order_id` (i32, optional): ID of order that needs to be fetched"#]
+#[doc = r#"Verb: GET
+Path: /store/order/{orderId}
+Parameters: GetOrderByIdParams
+Responses:
+    200: Successful Operation
+    400: Bad input parameter
+    500: Internal Server Error
+    502: Bad Gateway
+    503: Service Unavailable
+    504: Gateway Timeout
+Tag: store"#]
+pub async fn get_order_by_id_handler(
+    config: &Config,
+    params: &GetOrderByIdParams,
+) -> Result<CallToolResult, agenterra_rmcp::Error> {
+    // Log incoming request parameters and request details as structured JSON
+    info!(
+        target = "handler",
+        event = "incoming_request",
+        endpoint = "get_order_by_id",
+        method = "GET",
+        path = "/store/order/{orderId}",
+        params = serde_json::to_string(params).unwrap_or_else(|e| {
+            warn!("Failed to serialize request params: {e}");
+            "{}".to_string()
+        })
+    );
+    debug!(
+        target = "handler",
+        event = "before_api_call",
+        endpoint = "get_order_by_id"
+    );
+    let resp = get_endpoint_response::<_, GetOrderByIdResponse>(config, params).await;
+
+    match &resp {
+        Ok(r) => {
+            info!(
+                target = "handler",
+                event = "api_response",
+                endpoint = "get_order_by_id",
+                response = ?r
+            );
+        }
+        Err(e) => {
+            error!(target = "handler", event = "api_error", endpoint = "get_order_by_id", error = ?e);
+        }
+    }
+
+    // Log outgoing API request as structured JSON
+    resp.and_then(|r| r.into_call_tool_result())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+    #[test]
+    fn test_parameters_struct_serialization() {
+        let params = GetOrderByIdParams {
+        order_id: None,
+            };
+        let _ = serde_json::to_string(&params).expect("Serializing test params should not fail");
+    }
+
+    #[test]
+    fn test_properties_struct_serialization() {
+        let props = GetOrderByIdProperties {
+        };
+        let _ = serde_json::to_string(&props).expect("Serializing test properties should not fail");
+    }
+}
@@ -0,0 +1,148 @@
+//! Auto-generated handler for `/get_user_by_name` endpoint.
+
+// Internal imports (std, crate)
+use crate::common::*;
+use crate::config::Config;
+
+// External imports (alphabetized)
+use agenterra_rmcp::handler::server::tool::IntoCallToolResult;
+use agenterra_rmcp::model::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::{debug, error, info, warn};
+use utoipa::ToSchema;
+
+/// Auto-generated parameters struct for `/get_user_by_name` endpoint.
+/// Spec: 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, ToSchema)]
+pub struct GetUserByNameParams {
+#[schemars(description = r#"The name that needs to be fetched. Use user1 for testing"#)]
+    pub username: Option<String>,
+    }
+
+// Implement Endpoint for generic handler
+impl Endpoint for GetUserByNameParams {
+    fn path() -> &'static str {
+        "/user/{username}"
+    }
+
+    fn get_params(&self) -> HashMap<String, String> {
+        let mut params = HashMap::new();
+        
+        if let Some(val) = &self.username {
+            params.insert("username".to_string(), val.to_string());
+        }
+        
+        params
+    }
+}
+
+/// Auto-generated properties struct for `/get_user_by_name` endpoint.
+/// Spec: 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, ToSchema)]
+pub struct GetUserByNameProperties {
+}
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct GetUserByNameResponse {
+    #[schemars(description = r#" - "#)]
+    pub id: Option<i32>,
+    #[schemars(description = r#" - "#)]
+    pub username: Option<String>,
+    #[schemars(description = r#" - "#)]
+    pub first_name: Option<String>,
+    #[schemars(description = r#" - "#)]
+    pub last_name: Option<String>,
+    #[schemars(description = r#" - "#)]
+    pub email: Option<String>,
+    #[schemars(description = r#" - "#)]
+    pub password: Option<String>,
+    #[schemars(description = r#" - "#)]
+    pub phone: Option<String>,
+    #[schemars(description = r#" - User Status"#)]
+    pub user_status: Option<i32>,
+}
+
+impl IntoContents for GetUserByNameResponse {
+    fn into_contents(self) -> Vec<Content> {
+        // Convert the response into a Vec<Content> as expected by MCP
+        // Panics only if serialization fails, which should be impossible for valid structs
+        vec![Content::json(self).expect("Failed to serialize GetUserByNameResponse to Content")]
+    }
+}
+
+/// `/user/{username}` endpoint handler
+/// Get user by user name.
+/// Get user detail based on username.
+#[doc = r#" - `username` (String, optional): The name that needs to be fetched. Use user1 for testing"#]
+#[doc = r#"Verb: GET
+Path: /user/{username}
+Parameters: GetUserByNameParams
+Responses:
+    200: Successful Operation
+    400: Bad input parameter
+    500: Internal Server Error
+    502: Bad Gateway
+    503: Service Unavailable
+    504: Gateway Timeout
+Tag: user"#]
+pub async fn get_user_by_name_handler(
+    config: &Config,
+    params: &GetUserByNameParams,
+) -> Result<CallToolResult, agenterra_rmcp::Error> {
+    // Log incoming request parameters and request details as structured JSON
+    info!(
+        target = "handler",
+        event = "incoming_request",
+        endpoint = "get_user_by_name",
+        method = "GET",
+        path = "/user/{username}",
+        params = serde_json::to_string(params).unwrap_or_else(|e| {
+            warn!("Failed to serialize request params: {e}");
+            "{}".to_string()
+        })
+    );
+    debug!(
+        target = "handler",
+        event = "before_api_call",
+        endpoint = "get_user_by_name"
+    );
+    let resp = get_endpoint_response::<_, GetUserByNameResponse>(config, params).await;
+
+    match &resp {
+        Ok(r) => {
+            info!(
+                target = "handler",
+                event = "api_response",
+                endpoint = "get_user_by_name",
+                response = ?r
+            );
+        }
+        Err(e) => {
+            error!(target = "handler", event = "api_error", endpoint = "get_user_by_name", error = ?e);
+        }
+    }
+
+    // Log outgoing API request as structured JSON
+    resp.and_then(|r| r.into_call_tool_result())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+    #[test]
+    fn test_parameters_struct_serialization() {
+        let params = GetUserByNameParams {
+        username: None,
+            };
+        let _ = serde_json::to_string(&params).expect("Serializing test params should not fail");
+    }
+
+    #[test]
+    fn test_properties_struct_serialization() {
+        let props = GetUserByNameProperties {
+        };
+        let _ = serde_json::to_string(&props).expect("Serializing test properties should not fail");
+    }
+}
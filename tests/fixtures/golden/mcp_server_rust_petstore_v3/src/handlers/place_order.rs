@@ -0,0 +1,152 @@
+//! Auto-generated handler for `/place_order` endpoint.
+
+// Internal imports (std, crate)
+use crate::common::*;
+use crate::config::Config;
+
+// External imports (alphabetized)
+use agenterra_rmcp::handler::server::tool::IntoCallToolResult;
+use agenterra_rmcp::model::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::{debug, error, info, warn};
+use utoipa::ToSchema;
+
+/// Auto-generated parameters struct for `/place_order` endpoint.
+/// Spec: 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, ToSchema)]
+pub struct PlaceOrderParams {
+}
+
+// Implement Endpoint for generic handler
+impl Endpoint for PlaceOrderParams {
+    fn path() -> &'static str {
+        "/store/order"
+    }
+
+    fn get_params(&self) -> HashMap<String, String> {
+        HashMap::new()
+    }
+}
+
+/// Auto-generated properties struct for `/place_order` endpoint.
+/// Spec: 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, ToSchema)]
+pub struct PlaceOrderProperties {
+#[schemars(description = r#" - "#)]
+    pub id: Option<i32>,
+    #[schemars(description = r#" - "#)]
+    pub pet_id: Option<i32>,
+    #[schemars(description = r#" - "#)]
+    pub quantity: Option<i32>,
+    #[schemars(description = r#" - "#)]
+    pub ship_date: Option<String>,
+    #[schemars(description = r#" - Order Status"#)]
+    pub status: Option<String>,
+    #[schemars(description = r#" - "#)]
+    pub complete: Option<bool>,
+    }
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct PlaceOrderResponse {
+    #[schemars(description = r#" - "#)]
+    pub id: Option<i32>,
+    #[schemars(description = r#" - "#)]
+    pub pet_id: Option<i32>,
+    #[schemars(description = r#" - "#)]
+    pub quantity: Option<i32>,
+    #[schemars(description = r#" - "#)]
+    pub ship_date: Option<String>,
+    #[schemars(description = r#" - Order Status"#)]
+    pub status: Option<String>,
+    #[schemars(description = r#" - "#)]
+    pub complete: Option<bool>,
+}
+
+impl IntoContents for PlaceOrderResponse {
+    fn into_contents(self) -> Vec<Content> {
+        // Convert the response into a Vec<Content> as expected by MCP
+        // Panics only if serialization fails, which should be impossible for valid structs
+        vec![Content::json(self).expect("Failed to serialize PlaceOrderResponse to Content")]
+    }
+}
+
+/// `/store/order` endpoint handler
+/// Place an order for a pet.
+/// Place a new order in the store.
+#[doc = r#"Verb: GET
+Path: /store/order
+Parameters: PlaceOrderParams
+Responses:
+    200: Successful Operation
+    400: Bad input parameter
+    500: Internal Server Error
+    502: Bad Gateway
+    503: Service Unavailable
+    504: Gateway Timeout
+Tag: store"#]
+pub async fn place_order_handler(
+    config: &Config,
+    params: &PlaceOrderParams,
+) -> Result<CallToolResult, agenterra_rmcp::Error> {
+    // Log incoming request parameters and request details as structured JSON
+    info!(
+        target = "handler",
+        event = "incoming_request",
+        endpoint = "place_order",
+        method = "GET",
+        path = "/store/order",
+        params = serde_json::to_string(params).unwrap_or_else(|e| {
+            warn!("Failed to serialize request params: {e}");
+            "{}".to_string()
+        })
+    );
+    debug!(
+        target = "handler",
+        event = "before_api_call",
+        endpoint = "place_order"
+    );
+    let resp = get_endpoint_response::<_, PlaceOrderResponse>(config, params).await;
+
+    match &resp {
+        Ok(r) => {
+            info!(
+                target = "handler",
+                event = "api_response",
+                endpoint = "place_order",
+                response = ?r
+            );
+        }
+        Err(e) => {
+            error!(target = "handler", event = "api_error", endpoint = "place_order", error = ?e);
+        }
+    }
+
+    // Log outgoing API request as structured JSON
+    resp.and_then(|r| r.into_call_tool_result())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+    #[test]
+    fn test_parameters_struct_serialization() {
+        let params = PlaceOrderParams {
+        };
+        let _ = serde_json::to_string(&params).expect("Serializing test params should not fail");
+    }
+
+    #[test]
+    fn test_properties_struct_serialization() {
+        let props = PlaceOrderProperties {
+        id: None,
+            pet_id: None,
+            quantity: None,
+            ship_date: None,
+            status: None,
+            complete: None,
+            };
+        let _ = serde_json::to_string(&props).expect("Serializing test properties should not fail");
+    }
+}
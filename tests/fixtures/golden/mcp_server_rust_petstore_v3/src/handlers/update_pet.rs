@@ -0,0 +1,152 @@
+//! Auto-generated handler for `/update_pet` endpoint.
+
+// Internal imports (std, crate)
+use crate::common::*;
+use crate::config::Config;
+
+// External imports (alphabetized)
+use agenterra_rmcp::handler::server::tool::IntoCallToolResult;
+use agenterra_rmcp::model::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::{debug, error, info, warn};
+use utoipa::ToSchema;
+
+/// Auto-generated parameters struct for `/update_pet` endpoint.
+/// Spec: 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, ToSchema)]
+pub struct UpdatePetParams {
+}
+
+// Implement Endpoint for generic handler
+impl Endpoint for UpdatePetParams {
+    fn path() -> &'static str {
+        "/pet"
+    }
+
+    fn get_params(&self) -> HashMap<String, String> {
+        HashMap::new()
+    }
+}
+
+/// Auto-generated properties struct for `/update_pet` endpoint.
+/// Spec: 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, ToSchema)]
+pub struct UpdatePetProperties {
+#[schemars(description = r#" - "#)]
+    pub id: Option<i32>,
+    #[schemars(description = r#" - "#)]
+    pub name: Option<String>,
+    #[schemars(description = r#" - "#)]
+    pub category: Option<serde_json::Value>,
+    #[schemars(description = r#" - "#)]
+    pub photo_urls: Option<Vec<String>>,
+    #[schemars(description = r#" - "#)]
+    pub tags: Option<Vec<serde_json::Value>>,
+    #[schemars(description = r#" - pet status in the store"#)]
+    pub status: Option<String>,
+    }
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct UpdatePetResponse {
+    #[schemars(description = r#" - "#)]
+    pub id: Option<i32>,
+    #[schemars(description = r#" - "#)]
+    pub name: Option<String>,
+    #[schemars(description = r#" - "#)]
+    pub category: Option<serde_json::Value>,
+    #[schemars(description = r#" - "#)]
+    pub photo_urls: Option<Vec<String>>,
+    #[schemars(description = r#" - "#)]
+    pub tags: Option<Vec<serde_json::Value>>,
+    #[schemars(description = r#" - pet status in the store"#)]
+    pub status: Option<String>,
+}
+
+impl IntoContents for UpdatePetResponse {
+    fn into_contents(self) -> Vec<Content> {
+        // Convert the response into a Vec<Content> as expected by MCP
+        // Panics only if serialization fails, which should be impossible for valid structs
+        vec![Content::json(self).expect("Failed to serialize UpdatePetResponse to Content")]
+    }
+}
+
+/// `/pet` endpoint handler
+/// Update an existing pet.
+/// Update an existing pet by Id.
+#[doc = r#"Verb: GET
+Path: /pet
+Parameters: UpdatePetParams
+Responses:
+    200: Successful Operation
+    400: Bad input parameter
+    500: Internal Server Error
+    502: Bad Gateway
+    503: Service Unavailable
+    504: Gateway Timeout
+Tag: pet"#]
+pub async fn update_pet_handler(
+    config: &Config,
+    params: &UpdatePetParams,
+) -> Result<CallToolResult, agenterra_rmcp::Error> {
+    // Log incoming request parameters and request details as structured JSON
+    info!(
+        target = "handler",
+        event = "incoming_request",
+        endpoint = "update_pet",
+        method = "GET",
+        path = "/pet",
+        params = serde_json::to_string(params).unwrap_or_else(|e| {
+            warn!("Failed to serialize request params: {e}");
+            "{}".to_string()
+        })
+    );
+    debug!(
+        target = "handler",
+        event = "before_api_call",
+        endpoint = "update_pet"
+    );
+    let resp = get_endpoint_response::<_, UpdatePetResponse>(config, params).await;
+
+    match &resp {
+        Ok(r) => {
+            info!(
+                target = "handler",
+                event = "api_response",
+                endpoint = "update_pet",
+                response = ?r
+            );
+        }
+        Err(e) => {
+            error!(target = "handler", event = "api_error", endpoint = "update_pet", error = ?e);
+        }
+    }
+
+    // Log outgoing API request as structured JSON
+    resp.and_then(|r| r.into_call_tool_result())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+    #[test]
+    fn test_parameters_struct_serialization() {
+        let params = UpdatePetParams {
+        };
+        let _ = serde_json::to_string(&params).expect("Serializing test params should not fail");
+    }
+
+    #[test]
+    fn test_properties_struct_serialization() {
+        let props = UpdatePetProperties {
+        id: None,
+            name: None,
+            category: None,
+            photo_urls: None,
+            tags: None,
+            status: None,
+            };
+        let _ = serde_json::to_string(&props).expect("Serializing test properties should not fail");
+    }
+}
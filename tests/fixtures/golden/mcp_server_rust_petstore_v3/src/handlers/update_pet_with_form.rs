@@ -0,0 +1,158 @@
+//! Auto-generated handler for `/update_pet_with_form` endpoint.
+
+// Internal imports (std, crate)
+use crate::common::*;
+use crate::config::Config;
+
+// External imports (alphabetized)
+use agenterra_rmcp::handler::server::tool::IntoCallToolResult;
+use agenterra_rmcp::model::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::{debug, error, info, warn};
+use utoipa::ToSchema;
+
+/// Auto-generated parameters struct for `/update_pet_with_form` endpoint.
+/// Spec: 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, ToSchema)]
+pub struct UpdatePetWithFormParams {
+#[schemars(description = r#"ID of pet that needs to be updated"#)]
+    pub pet_id: Option<i32>,
+    #[schemars(description = r#"Name of pet that needs to be updated"#)]
+    pub name: Option<String>,
+    #[schemars(description = r#"Status of pet that needs to be updated"#)]
+    pub status: Option<String>,
+    }
+
+// Implement Endpoint for generic handler
+impl Endpoint for UpdatePetWithFormParams {
+    fn path() -> &'static str {
+        "/pet/{petId}"
+    }
+
+    fn get_params(&self) -> HashMap<String, String> {
+        let mut params = HashMap::new();
+        
+        if let Some(val) = &self.pet_id {
+            params.insert("pet_id".to_string(), val.to_string());
+        }
+        
+        if let Some(val) = &self.name {
+            params.insert("name".to_string(), val.to_string());
+        }
+        
+        if let Some(val) = &self.status {
+            params.insert("status".to_string(), val.to_string());
+        }
+        
+        params
+    }
+}
+
+/// Auto-generated properties struct for `/update_pet_with_form` endpoint.
+/// Spec: 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, ToSchema)]
+pub struct UpdatePetWithFormProperties {
+}
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct UpdatePetWithFormResponse {
+    #[schemars(description = r#" - "#)]
+    pub id: Option<i32>,
+    #[schemars(description = r#" - "#)]
+    pub name: Option<String>,
+    #[schemars(description = r#" - "#)]
+    pub category: Option<serde_json::Value>,
+    #[schemars(description = r#" - "#)]
+    pub photo_urls: Option<Vec<String>>,
+    #[schemars(description = r#" - "#)]
+    pub tags: Option<Vec<serde_json::Value>>,
+    #[schemars(description = r#" - pet status in the store"#)]
+    pub status: Option<String>,
+}
+
+impl IntoContents for UpdatePetWithFormResponse {
+    fn into_contents(self) -> Vec<Content> {
+        // Convert the response into a Vec<Content> as expected by MCP
+        // Panics only if serialization fails, which should be impossible for valid structs
+        vec![Content::json(self).expect("Failed to serialize UpdatePetWithFormResponse to Content")]
+    }
+}
+
+/// `/pet/{petId}` endpoint handler
+/// Updates a pet in the store with form data.
+/// Updates a pet resource based on the form data.
+#[doc = r#" - `pet_id` (i32, optional): ID of pet that needs to be updated - `name` (String, optional): Name of pet that needs to be updated - `status` (String, optional): Status of pet that needs to be updated"#]
+#[doc = r#"Verb: GET
+Path: /pet/{petId}
+Parameters: UpdatePetWithFormParams
+Responses:
+    200: Successful Operation
+    400: Bad input parameter
+    500: Internal Server Error
+    502: Bad Gateway
+    503: Service Unavailable
+    504: Gateway Timeout
+Tag: pet"#]
+pub async fn update_pet_with_form_handler(
+    config: &Config,
+    params: &UpdatePetWithFormParams,
+) -> Result<CallToolResult, agenterra_rmcp::Error> {
+    // Log incoming request parameters and request details as structured JSON
+    info!(
+        target = "handler",
+        event = "incoming_request",
+        endpoint = "update_pet_with_form",
+        method = "GET",
+        path = "/pet/{petId}",
+        params = serde_json::to_string(params).unwrap_or_else(|e| {
+            warn!("Failed to serialize request params: {e}");
+            "{}".to_string()
+        })
+    );
+    debug!(
+        target = "handler",
+        event = "before_api_call",
+        endpoint = "update_pet_with_form"
+    );
+    let resp = get_endpoint_response::<_, UpdatePetWithFormResponse>(config, params).await;
+
+    match &resp {
+        Ok(r) => {
+            info!(
+                target = "handler",
+                event = "api_response",
+                endpoint = "update_pet_with_form",
+                response = ?r
+            );
+        }
+        Err(e) => {
+            error!(target = "handler", event = "api_error", endpoint = "update_pet_with_form", error = ?e);
+        }
+    }
+
+    // Log outgoing API request as structured JSON
+    resp.and_then(|r| r.into_call_tool_result())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+    #[test]
+    fn test_parameters_struct_serialization() {
+        let params = UpdatePetWithFormParams {
+        pet_id: None,
+            name: None,
+            status: None,
+            };
+        let _ = serde_json::to_string(&params).expect("Serializing test params should not fail");
+    }
+
+    #[test]
+    fn test_properties_struct_serialization() {
+        let props = UpdatePetWithFormProperties {
+        };
+        let _ = serde_json::to_string(&props).expect("Serializing test properties should not fail");
+    }
+}
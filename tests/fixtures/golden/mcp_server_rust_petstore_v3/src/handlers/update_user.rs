@@ -0,0 +1,155 @@
+//! Auto-generated handler for `/update_user` endpoint.
+
+// Internal imports (std, crate)
+use crate::common::*;
+use crate::config::Config;
+
+// External imports (alphabetized)
+use agenterra_rmcp::handler::server::tool::IntoCallToolResult;
+use agenterra_rmcp::model::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::{debug, error, info, warn};
+use utoipa::ToSchema;
+
+/// Auto-generated parameters struct for `/update_user` endpoint.
+/// Spec: 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, ToSchema)]
+pub struct UpdateUserParams {
+#[schemars(description = r#"name that need to be deleted"#)]
+    pub username: Option<String>,
+    }
+
+// Implement Endpoint for generic handler
+impl Endpoint for UpdateUserParams {
+    fn path() -> &'static str {
+        "/user/{username}"
+    }
+
+    fn get_params(&self) -> HashMap<String, String> {
+        let mut params = HashMap::new();
+        
+        if let Some(val) = &self.username {
+            params.insert("username".to_string(), val.to_string());
+        }
+        
+        params
+    }
+}
+
+/// Auto-generated properties struct for `/update_user` endpoint.
+/// Spec: 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, ToSchema)]
+pub struct UpdateUserProperties {
+#[schemars(description = r#" - "#)]
+    pub id: Option<i32>,
+    #[schemars(description = r#" - "#)]
+    pub username: Option<String>,
+    #[schemars(description = r#" - "#)]
+    pub first_name: Option<String>,
+    #[schemars(description = r#" - "#)]
+    pub last_name: Option<String>,
+    #[schemars(description = r#" - "#)]
+    pub email: Option<String>,
+    #[schemars(description = r#" - "#)]
+    pub password: Option<String>,
+    #[schemars(description = r#" - "#)]
+    pub phone: Option<String>,
+    #[schemars(description = r#" - User Status"#)]
+    pub user_status: Option<i32>,
+    }
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct UpdateUserResponse(pub serde_json::Value);
+
+impl IntoContents for UpdateUserResponse {
+    fn into_contents(self) -> Vec<Content> {
+        // Convert the response into a Vec<Content> as expected by MCP
+        // Panics only if serialization fails, which should be impossible for valid structs
+        vec![Content::json(self).expect("Failed to serialize UpdateUserResponse to Content")]
+    }
+}
+
+/// `/user/{username}` endpoint handler
+/// Update user resource.
+/// This can only be done by the logged in user.
+#[doc = r#" - `username` (String, optional): name that need to be deleted"#]
+#[doc = r#"Verb: GET
+Path: /user/{username}
+Parameters: UpdateUserParams
+Responses:
+    200: Successful Operation
+    400: Bad input parameter
+    500: Internal Server Error
+    502: Bad Gateway
+    503: Service Unavailable
+    504: Gateway Timeout
+Tag: user"#]
+pub async fn update_user_handler(
+    config: &Config,
+    params: &UpdateUserParams,
+) -> Result<CallToolResult, agenterra_rmcp::Error> {
+    // Log incoming request parameters and request details as structured JSON
+    info!(
+        target = "handler",
+        event = "incoming_request",
+        endpoint = "update_user",
+        method = "GET",
+        path = "/user/{username}",
+        params = serde_json::to_string(params).unwrap_or_else(|e| {
+            warn!("Failed to serialize request params: {e}");
+            "{}".to_string()
+        })
+    );
+    debug!(
+        target = "handler",
+        event = "before_api_call",
+        endpoint = "update_user"
+    );
+    let resp = get_endpoint_response::<_, UpdateUserResponse>(config, params).await;
+
+    match &resp {
+        Ok(r) => {
+            info!(
+                target = "handler",
+                event = "api_response",
+                endpoint = "update_user",
+                response = ?r
+            );
+        }
+        Err(e) => {
+            error!(target = "handler", event = "api_error", endpoint = "update_user", error = ?e);
+        }
+    }
+
+    // Log outgoing API request as structured JSON
+    resp.and_then(|r| r.into_call_tool_result())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+    #[test]
+    fn test_parameters_struct_serialization() {
+        let params = UpdateUserParams {
+        username: None,
+            };
+        let _ = serde_json::to_string(&params).expect("Serializing test params should not fail");
+    }
+
+    #[test]
+    fn test_properties_struct_serialization() {
+        let props = UpdateUserProperties {
+        id: None,
+            username: None,
+            first_name: None,
+            last_name: None,
+            email: None,
+            password: None,
+            phone: None,
+            user_status: None,
+            };
+        let _ = serde_json::to_string(&props).expect("Serializing test properties should not fail");
+    }
+}
@@ -0,0 +1,145 @@
+//! Auto-generated handler for `/upload_file` endpoint.
+
+// Internal imports (std, crate)
+use crate::common::*;
+use crate::config::Config;
+
+// External imports (alphabetized)
+use agenterra_rmcp::handler::server::tool::IntoCallToolResult;
+use agenterra_rmcp::model::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::{debug, error, info, warn};
+use utoipa::ToSchema;
+
+/// Auto-generated parameters struct for `/upload_file` endpoint.
+/// Spec: 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, ToSchema)]
+pub struct UploadFileParams {
+#[schemars(description = r#"ID of pet to update"#)]
+    pub pet_id: Option<i32>,
+    #[schemars(description = r#"Additional Metadata"#)]
+    pub additional_metadata: Option<String>,
+    }
+
+// Implement Endpoint for generic handler
+impl Endpoint for UploadFileParams {
+    fn path() -> &'static str {
+        "/pet/{petId}/uploadImage"
+    }
+
+    fn get_params(&self) -> HashMap<String, String> {
+        let mut params = HashMap::new();
+        
+        if let Some(val) = &self.pet_id {
+            params.insert("pet_id".to_string(), val.to_string());
+        }
+        
+        if let Some(val) = &self.additional_metadata {
+            params.insert("additional_metadata".to_string(), val.to_string());
+        }
+        
+        params
+    }
+}
+
+/// Auto-generated properties struct for `/upload_file` endpoint.
+/// Spec: 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, ToSchema)]
+pub struct UploadFileProperties {
+}
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct UploadFileResponse {
+    #[schemars(description = r#" - "#)]
+    pub code: Option<i32>,
+    #[schemars(description = r#" - "#)]
+    pub type_: Option<String>,
+    #[schemars(description = r#" - "#)]
+    pub message: Option<String>,
+}
+
+impl IntoContents for UploadFileResponse {
+    fn into_contents(self) -> Vec<Content> {
+        // Convert the response into a Vec<Content> as expected by MCP
+        // Panics only if serialization fails, which should be impossible for valid structs
+        vec![Content::json(self).expect("Failed to serialize UploadFileResponse to Content")]
+    }
+}
+
+/// `/pet/{petId}/uploadImage` endpoint handler
+/// Uploads an image.
+/// Upload image of the pet.
+#[doc = r#" - `pet_id` (i32, optional): ID of pet to update - `additional_metadata` (String, optional): Additional Metadata"#]
+#[doc = r#"Verb: GET
+Path: /pet/{petId}/uploadImage
+Parameters: UploadFileParams
+Responses:
+    200: Successful Operation
+    400: Bad input parameter
+    500: Internal Server Error
+    502: Bad Gateway
+    503: Service Unavailable
+    504: Gateway Timeout
+Tag: pet"#]
+pub async fn upload_file_handler(
+    config: &Config,
+    params: &UploadFileParams,
+) -> Result<CallToolResult, agenterra_rmcp::Error> {
+    // Log incoming request parameters and request details as structured JSON
+    info!(
+        target = "handler",
+        event = "incoming_request",
+        endpoint = "upload_file",
+        method = "GET",
+        path = "/pet/{petId}/uploadImage",
+        params = serde_json::to_string(params).unwrap_or_else(|e| {
+            warn!("Failed to serialize request params: {e}");
+            "{}".to_string()
+        })
+    );
+    debug!(
+        target = "handler",
+        event = "before_api_call",
+        endpoint = "upload_file"
+    );
+    let resp = get_endpoint_response::<_, UploadFileResponse>(config, params).await;
+
+    match &resp {
+        Ok(r) => {
+            info!(
+                target = "handler",
+                event = "api_response",
+                endpoint = "upload_file",
+                response = ?r
+            );
+        }
+        Err(e) => {
+            error!(target = "handler", event = "api_error", endpoint = "upload_file", error = ?e);
+        }
+    }
+
+    // Log outgoing API request as structured JSON
+    resp.and_then(|r| r.into_call_tool_result())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+    #[test]
+    fn test_parameters_struct_serialization() {
+        let params = UploadFileParams {
+        pet_id: None,
+            additional_metadata: None,
+            };
+        let _ = serde_json::to_string(&params).expect("Serializing test params should not fail");
+    }
+
+    #[test]
+    fn test_properties_struct_serialization() {
+        let props = UploadFileProperties {
+        };
+        let _ = serde_json::to_string(&props).expect("Serializing test properties should not fail");
+    }
+}
@@ -0,0 +1,192 @@
+//! Golden-file snapshot tests for generated output
+//!
+//! Every case scaffolds a fixture from `tests/fixtures/openapi` twice, in separate
+//! processes, and requires both runs to match each other and the snapshot under
+//! `tests/fixtures/golden/<case>` byte for byte. Any nondeterminism in the pipeline
+//! (hash map iteration, unordered collections, timestamps) fails the test.
+//!
+//! After an intentional change to templates or context builders, refresh the
+//! snapshots with:
+//!
+//! ```bash
+//! AGENTERRA_UPDATE_GOLDEN=1 cargo test --test golden_output_test
+//! ```
+
+use assert_cmd::Command;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+const UPDATE_ENV: &str = "AGENTERRA_UPDATE_GOLDEN";
+
+struct GoldenCase {
+    /// Snapshot directory name under `tests/fixtures/golden`
+    name: &'static str,
+    /// Spec file under `tests/fixtures/openapi`
+    spec: &'static str,
+    /// Extra scaffold arguments
+    args: &'static [&'static str],
+}
+
+const CASES: &[GoldenCase] = &[
+    GoldenCase {
+        name: "mcp_server_rust_petstore_v3",
+        spec: "petstore.openapi.v3.json",
+        args: &[],
+    },
+    GoldenCase {
+        name: "mcp_server_rust_petstore_v2",
+        spec: "petstore.swagger.v2.json",
+        // Swagger 2.0 has no `servers` block to derive the backend URL from
+        args: &["--base-url", "https://petstore.swagger.io/v2"],
+    },
+];
+
+fn fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+/// Scaffold a case into a fresh directory and return the generated project root
+fn scaffold(case: &GoldenCase, work_dir: &Path) -> PathBuf {
+    let spec = fixtures_dir().join("openapi").join(case.spec);
+
+    Command::cargo_bin("agenterra")
+        .unwrap()
+        .args(["scaffold", "mcp", "server", "--project-name", case.name])
+        .arg("--schema-path")
+        .arg(&spec)
+        .arg("--output-dir")
+        .arg(work_dir)
+        .args(case.args)
+        // Hooks would reformat the output the snapshots compare against
+        .arg("--no-hooks")
+        .assert()
+        .success();
+
+    work_dir.join(case.name)
+}
+
+/// Read every file below `root`, keyed by its `/`-separated relative path
+fn read_tree(root: &Path) -> BTreeMap<String, Vec<u8>> {
+    fn walk(root: &Path, dir: &Path, tree: &mut BTreeMap<String, Vec<u8>>) {
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                walk(root, &path, tree);
+            } else {
+                let relative = path.strip_prefix(root).unwrap();
+                let key = relative
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                tree.insert(key, fs::read(&path).unwrap());
+            }
+        }
+    }
+
+    let mut tree = BTreeMap::new();
+    if root.exists() {
+        walk(root, root, &mut tree);
+    }
+    tree
+}
+
+fn write_tree(root: &Path, tree: &BTreeMap<String, Vec<u8>>) {
+    if root.exists() {
+        fs::remove_dir_all(root).unwrap();
+    }
+    for (relative, contents) in tree {
+        let path = root.join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+}
+
+/// Describe the first line where two files diverge
+fn first_difference(expected: &[u8], actual: &[u8]) -> String {
+    let expected = String::from_utf8_lossy(expected);
+    let actual = String::from_utf8_lossy(actual);
+    for (number, (e, a)) in expected.lines().zip(actual.lines()).enumerate() {
+        if e != a {
+            return format!(
+                "line {}:\n      expected: {e}\n      actual:   {a}",
+                number + 1
+            );
+        }
+    }
+    format!(
+        "length differs ({} vs {} lines)",
+        expected.lines().count(),
+        actual.lines().count()
+    )
+}
+
+fn compare_trees(
+    expected: &BTreeMap<String, Vec<u8>>,
+    actual: &BTreeMap<String, Vec<u8>>,
+) -> Vec<String> {
+    let mut problems = Vec::new();
+    for (path, contents) in expected {
+        match actual.get(path) {
+            None => problems.push(format!("missing file: {path}")),
+            Some(generated) if generated != contents => problems.push(format!(
+                "changed file: {path}\n    {}",
+                first_difference(contents, generated)
+            )),
+            Some(_) => {}
+        }
+    }
+    for path in actual.keys().filter(|path| !expected.contains_key(*path)) {
+        problems.push(format!("unexpected file: {path}"));
+    }
+    problems
+}
+
+#[test]
+fn test_generated_output_matches_golden_snapshots() {
+    let update = std::env::var_os(UPDATE_ENV).is_some();
+    let mut failures = Vec::new();
+
+    for case in CASES {
+        let first_dir = TempDir::new().unwrap();
+        let second_dir = TempDir::new().unwrap();
+        let first = read_tree(&scaffold(case, first_dir.path()));
+        let second = read_tree(&scaffold(case, second_dir.path()));
+
+        assert!(!first.is_empty(), "{}: nothing was generated", case.name);
+
+        let unstable = compare_trees(&first, &second);
+        if !unstable.is_empty() {
+            failures.push(format!(
+                "{}: output differs between two identical runs\n  {}",
+                case.name,
+                unstable.join("\n  ")
+            ));
+            continue;
+        }
+
+        let golden_dir = fixtures_dir().join("golden").join(case.name);
+        if update {
+            write_tree(&golden_dir, &first);
+            continue;
+        }
+
+        let drift = compare_trees(&read_tree(&golden_dir), &first);
+        if !drift.is_empty() {
+            failures.push(format!(
+                "{}: output differs from {}\n  {}",
+                case.name,
+                golden_dir.display(),
+                drift.join("\n  ")
+            ));
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{}\n\nIf the change is intentional, rerun with {UPDATE_ENV}=1 to refresh the snapshots.",
+        failures.join("\n\n")
+    );
+}